@@ -77,9 +77,12 @@ fi
 # Get status from D-Bus
 STATUS=$(busctl --user get-property "$DBUS_DEST" "$DBUS_PATH" "$DBUS_IFACE" "IsRecording" 2>/dev/null | awk '{print $2}')
 QUEUE=$(busctl --user get-property "$DBUS_DEST" "$DBUS_PATH" "$DBUS_IFACE" "QueueDepth" 2>/dev/null | awk '{print $2}')
+STATE=$(busctl --user get-property "$DBUS_DEST" "$DBUS_PATH" "$DBUS_IFACE" "State" 2>/dev/null | awk '{print $2}' | tr -d '"')
 
 # Determine state and output
-if [[ "$STATUS" == "true" ]]; then
+if [[ "$STATE" == "listening" ]]; then
+    output "$ICON_LISTENING" "$COLOR_RECORDING"
+elif [[ "$STATUS" == "true" ]]; then
     output "$ICON_RECORDING" "$COLOR_RECORDING"
 elif [[ "${QUEUE:-0}" -gt 0 ]]; then
     output "$ICON_PROCESSING" "$COLOR_PROCESSING"
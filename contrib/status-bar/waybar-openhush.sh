@@ -63,9 +63,14 @@ fi
 # Get status from D-Bus
 STATUS=$(busctl --user get-property "$DBUS_DEST" "$DBUS_PATH" "$DBUS_IFACE" "IsRecording" 2>/dev/null | awk '{print $2}')
 QUEUE=$(busctl --user get-property "$DBUS_DEST" "$DBUS_PATH" "$DBUS_IFACE" "QueueDepth" 2>/dev/null | awk '{print $2}')
+STATE=$(busctl --user get-property "$DBUS_DEST" "$DBUS_PATH" "$DBUS_IFACE" "State" 2>/dev/null | awk '{print $2}' | tr -d '"')
 
 # Determine state and icon
-if [[ "$STATUS" == "true" ]]; then
+if [[ "$STATE" == "listening" ]]; then
+    ICON="$ICON_LISTENING"
+    CLASS="hands-free"
+    TOOLTIP="OpenHush: Hands-free dictation"
+elif [[ "$STATUS" == "true" ]]; then
     ICON="$ICON_RECORDING"
     CLASS="recording"
     TOOLTIP="OpenHush: Recording..."
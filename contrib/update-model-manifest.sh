@@ -0,0 +1,77 @@
+#!/usr/bin/env bash
+# Regenerate src/model_hashes.toml, the SHA-256 manifest built into openhush.
+#
+# Files on Hugging Face are read from the Hub API (the LFS object id of a
+# file is its SHA-256). Everything else is downloaded and hashed.
+#
+# Usage: contrib/update-model-manifest.sh [output]
+# Requires: curl, jq, sha256sum
+
+set -euo pipefail
+
+OUTPUT="${1:-$(dirname "$0")/../src/model_hashes.toml}"
+
+entry() {
+    printf '\n[[artifact]]\npath = "%s"\nsha256 = "%s"\nsize = %s\n' "$1" "$2" "$3"
+}
+
+# Download a file and hash it: <url> <path in the models directory>
+hash_url() {
+    local tmp
+    tmp=$(mktemp)
+    curl -fsSL -o "$tmp" "$1"
+    entry "$2" "$(sha256sum "$tmp" | cut -d' ' -f1)" "$(stat -c %s "$tmp")"
+    rm -f "$tmp"
+}
+
+# Files of a Hugging Face repository directory:
+# <repo> <directory or ""> <name regex> <path prefix in the models directory>
+hash_hf() {
+    local repo=$1 dir=$2 filter=$3 prefix=$4
+    curl -fsSL "https://huggingface.co/api/models/${repo}/tree/main${dir:+/$dir}" |
+        jq -r --arg filter "$filter" '
+            .[] | select(.type == "file")
+            | (.path | split("/") | last) as $name
+            | select($name | test($filter))
+            | [.path, $name, (.lfs.oid // ""), (.lfs.size // .size)] | @tsv' |
+        while IFS=$'\t' read -r path name sha size; do
+            if [ -n "$sha" ]; then
+                entry "${prefix}${name}" "$sha" "$size"
+            else
+                # Not stored in LFS: the API only has the git blob id
+                hash_url "https://huggingface.co/${repo}/resolve/main/${path}" "${prefix}${name}"
+            fi
+        done
+}
+
+{
+    echo "# SHA-256 and size of every model file openhush downloads from upstream."
+    echo "# Generated by contrib/update-model-manifest.sh on $(date -u +%Y-%m-%d);"
+    echo "# do not edit by hand."
+
+    # Whisper
+    hash_hf ggerganov/whisper.cpp "" '^ggml-.*\.bin$' ""
+    hash_hf distil-whisper/distil-large-v3-ggml "" '^ggml-.*\.bin$' ""
+
+    # M2M-100
+    for model in "418M m2m100-418m" "1.2B m2m100-1.2b"; do
+        set -- $model
+        hash_hf "optimum/m2m100_$1" onnx '^(encoder|decoder)_model\.onnx$' "m2m100/$2/"
+        hash_hf "optimum/m2m100_$1" "" '^tokenizer\.json$' "m2m100/$2/"
+    done
+
+    # openWakeWord
+    for name in melspectrogram.onnx embedding_model.onnx hey_jarvis_v0.1.onnx; do
+        hash_url "https://github.com/dscripka/openWakeWord/releases/download/v0.5.1/$name" \
+            "wake_word/$name"
+    done
+
+    # Diarization
+    for name in segmentation-3.0.onnx wespeaker_en_voxceleb_CAM++.onnx; do
+        hash_url "https://github.com/thewh1teagle/pyannote-rs/releases/download/v0.1.0/$name" \
+            "diarization/$name"
+    done
+} >"$OUTPUT.tmp"
+
+mv "$OUTPUT.tmp" "$OUTPUT"
+echo "Wrote $(grep -c '^\[\[artifact\]\]' "$OUTPUT") entries to $OUTPUT"
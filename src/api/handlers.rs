@@ -1,10 +1,12 @@
 //! API request handlers.
 
-use axum::{extract::State, http::StatusCode, Json};
+use axum::{body::Bytes, extract::State, http::StatusCode, Json};
 use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
 use utoipa::ToSchema;
 
 use super::state::{ApiCommand, ApiState};
+use crate::input::load_wav_bytes;
 
 /// Health check response.
 #[derive(Debug, Serialize, ToSchema)]
@@ -57,6 +59,28 @@ pub struct RecordingAction {
     pub action: String,
 }
 
+/// Replay request.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct ReplayRequest {
+    /// Seconds of buffered audio to transcribe (default: `replay.default_secs`)
+    #[serde(default)]
+    pub secs: Option<f32>,
+}
+
+/// Transcription of an uploaded file.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TranscribeResponse {
+    /// Always true on success
+    pub ok: bool,
+    /// Transcribed text
+    pub text: String,
+    /// Duration of the audio in seconds
+    pub duration_secs: f32,
+    /// Language detected or used, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
 /// Health check endpoint (no auth required).
 ///
 /// Returns basic health status for load balancers and monitoring.
@@ -217,3 +241,132 @@ pub async fn toggle_recording(
         message: Some("Recording toggled".to_string()),
     }))
 }
+
+/// Transcribe recent audio.
+///
+/// Transcribes the last seconds already captured by the always-on ring
+/// buffer, so speech from before the request is not lost. The text is
+/// output like a dictation.
+#[utoipa::path(
+    post,
+    path = "/api/v1/recording/replay",
+    request_body(content = ReplayRequest, description = "Optional; omit the body for the default duration"),
+    responses(
+        (status = 200, description = "Replay queued", body = SuccessResponse),
+        (status = 400, description = "Invalid duration", body = ErrorResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failed to queue replay", body = ErrorResponse)
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Recording"
+)]
+pub async fn transcribe_last(
+    State(state): State<ApiState>,
+    request: Option<Json<ReplayRequest>>,
+) -> Result<Json<SuccessResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let secs = request.and_then(|Json(request)| request.secs);
+    if secs.is_some_and(|secs| !secs.is_finite() || secs <= 0.0) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                ok: false,
+                error: "secs must be a positive number".to_string(),
+            }),
+        ));
+    }
+
+    state
+        .cmd_tx
+        .send(ApiCommand::TranscribeLast(secs))
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    ok: false,
+                    error: format!("Failed to send command: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(SuccessResponse {
+        ok: true,
+        message: Some("Replay queued".to_string()),
+    }))
+}
+
+/// Transcribe an uploaded WAV file.
+///
+/// The file is queued as background work, so it never delays live
+/// dictation, and the text is returned instead of being typed. Uploads
+/// longer than `api.limits.max_upload_secs` are rejected.
+#[utoipa::path(
+    post,
+    path = "/api/v1/transcribe",
+    request_body(content = Vec<u8>, description = "WAV file", content_type = "audio/wav"),
+    responses(
+        (status = 200, description = "Transcription", body = TranscribeResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 413, description = "Upload too large or too long", body = ErrorResponse),
+        (status = 415, description = "Not a WAV file", body = ErrorResponse),
+        (status = 500, description = "Transcription failed", body = ErrorResponse)
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Transcription"
+)]
+pub async fn transcribe_file(
+    State(state): State<ApiState>,
+    body: Bytes,
+) -> Result<Json<TranscribeResponse>, (StatusCode, Json<ErrorResponse>)> {
+    state.limits.check_upload(&body)?;
+
+    let quality = state.resampling_quality;
+    let audio = tokio::task::spawn_blocking(move || load_wav_bytes(&body, quality))
+        .await
+        .map_err(|e| internal_error(format!("Failed to decode upload: {}", e)))?
+        .map_err(|e| {
+            (
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                Json(ErrorResponse {
+                    ok: false,
+                    error: e.to_string(),
+                }),
+            )
+        })?;
+
+    let (reply_tx, mut reply_rx) = mpsc::channel(1);
+    state
+        .cmd_tx
+        .send(ApiCommand::Transcribe {
+            audio,
+            reply: reply_tx,
+        })
+        .await
+        .map_err(|e| internal_error(format!("Failed to send command: {}", e)))?;
+
+    let result = reply_rx
+        .recv()
+        .await
+        .ok_or_else(|| internal_error("Daemon stopped before the transcription finished".into()))?;
+    if let Some(error) = result.error {
+        return Err(internal_error(error));
+    }
+
+    Ok(Json(TranscribeResponse {
+        ok: true,
+        text: result.text,
+        duration_secs: result.duration_secs,
+        language: result.language,
+    }))
+}
+
+fn internal_error(error: String) -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse { ok: false, error }),
+    )
+}
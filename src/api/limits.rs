@@ -0,0 +1,356 @@
+//! Rate limiting and request size limits.
+//!
+//! Requests are metered by two token buckets: one per client IP and one per
+//! API key. A request must take a token from every bucket that applies to
+//! it; otherwise it is rejected with `429 Too Many Requests` and a
+//! `Retry-After` header. The per-key bucket is only checked after
+//! authentication, so made-up keys cannot create buckets or drain the
+//! budget of a real one. Oversized bodies are rejected with `413` based on
+//! `Content-Length`, before anything is read.
+
+use axum::{
+    body::Body,
+    extract::{ConnectInfo, State},
+    http::{header, Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::auth::{hash_api_key, API_KEY_HEADER};
+use super::handlers::ErrorResponse;
+use super::state::ApiState;
+use crate::config::ApiLimitsConfig;
+
+/// Drop idle buckets once a limiter tracks this many clients.
+const MAX_TRACKED_CLIENTS: usize = 4096;
+
+/// A single token bucket.
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Token-bucket rate limiter keyed by client identity.
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: f64,
+    refill_per_sec: f64,
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `burst` requests at once, refilled at
+    /// `per_sec` requests per second.
+    pub fn new(per_sec: f32, burst: u32) -> Self {
+        Self {
+            capacity: f64::from(burst.max(1)),
+            refill_per_sec: f64::from(per_sec.max(f32::EPSILON)),
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Take one token for `key`.
+    ///
+    /// Returns `Err(retry_after)` if the bucket is empty.
+    pub fn check(&self, key: &str) -> Result<(), Duration> {
+        self.check_at(key, Instant::now())
+    }
+
+    fn check_at(&self, key: &str, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+
+        if buckets.len() >= MAX_TRACKED_CLIENTS && !buckets.contains_key(key) {
+            self.prune(&mut buckets, now);
+        }
+
+        let bucket = buckets.entry(key.to_string()).or_insert(TokenBucket {
+            tokens: self.capacity,
+            updated: now,
+        });
+
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            let wait = (1.0 - bucket.tokens) / self.refill_per_sec;
+            Err(Duration::from_secs_f64(wait))
+        }
+    }
+
+    /// Remove buckets that have refilled completely (idle clients).
+    fn prune(&self, buckets: &mut HashMap<String, TokenBucket>, now: Instant) {
+        let full_after = self.capacity / self.refill_per_sec;
+        buckets.retain(|_, b| now.saturating_duration_since(b.updated).as_secs_f64() < full_after);
+    }
+}
+
+/// Rate limiters and size limits shared by all requests.
+#[derive(Debug)]
+pub struct ApiLimits {
+    per_ip: Option<RateLimiter>,
+    per_key: Option<RateLimiter>,
+    max_body_bytes: u64,
+    max_upload_secs: u32,
+}
+
+impl ApiLimits {
+    /// Build limits from config.
+    pub fn from_config(config: &ApiLimitsConfig) -> Self {
+        let (per_ip, per_key) = if config.rate_limit {
+            (
+                Some(RateLimiter::new(config.per_ip_per_sec, config.per_ip_burst)),
+                Some(RateLimiter::new(
+                    config.per_key_per_sec,
+                    config.per_key_burst,
+                )),
+            )
+        } else {
+            (None, None)
+        };
+
+        Self {
+            per_ip,
+            per_key,
+            max_body_bytes: config.max_body_bytes,
+            max_upload_secs: config.max_upload_secs,
+        }
+    }
+
+    /// Check an uploaded audio file against the duration limit.
+    ///
+    /// Reads only the WAV header, so oversized recordings are rejected
+    /// before any decoding or resampling work.
+    pub fn check_upload(&self, bytes: &[u8]) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+        let Some(duration) = wav_duration_secs(bytes) else {
+            return Err(error_response(
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                "Upload is not a readable WAV file".to_string(),
+            ));
+        };
+
+        if duration > self.max_upload_secs as f32 {
+            return Err(error_response(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                format!(
+                    "Audio is {:.0}s long, maximum is {}s",
+                    duration, self.max_upload_secs
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Middleware enforcing body size and per-IP rate limits.
+pub async fn enforce_limits(
+    State(state): State<ApiState>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let limits = &state.limits;
+
+    // Reject oversized bodies up front using the declared length
+    let content_length = request
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok());
+    if content_length.is_some_and(|len| len > limits.max_body_bytes) {
+        return error_response(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!("Request body exceeds {} bytes", limits.max_body_bytes),
+        )
+        .into_response();
+    }
+
+    // Unix socket clients have no address and are only keyed by API key
+    if let Some(ref limiter) = limits.per_ip {
+        let ip: Option<IpAddr> = request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|info| info.0.ip());
+        if let Some(ip) = ip {
+            if let Err(retry_after) = limiter.check(&ip.to_string()) {
+                return too_many_requests(retry_after);
+            }
+        }
+    }
+
+    next.run(request).await
+}
+
+/// Middleware enforcing the per-key rate limit.
+///
+/// Must run inside [`require_api_key`](super::auth::require_api_key), so
+/// only keys that passed authentication get a bucket.
+pub async fn enforce_key_limit(
+    State(state): State<ApiState>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    if let Some(ref limiter) = state.limits.per_key {
+        // Key buckets are stored by hash so raw keys never sit in memory
+        let key_hash = request
+            .headers()
+            .get(API_KEY_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .map(hash_api_key);
+        if let Some(key_hash) = key_hash {
+            if let Err(retry_after) = limiter.check(&key_hash) {
+                return too_many_requests(retry_after);
+            }
+        }
+    }
+
+    next.run(request).await
+}
+
+fn error_response(status: StatusCode, error: String) -> (StatusCode, Json<ErrorResponse>) {
+    (status, Json(ErrorResponse { ok: false, error }))
+}
+
+fn too_many_requests(retry_after: Duration) -> Response {
+    // Round up so clients never retry a moment too early
+    let secs = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+    (
+        StatusCode::TOO_MANY_REQUESTS,
+        [(header::RETRY_AFTER, secs.max(1).to_string())],
+        Json(ErrorResponse {
+            ok: false,
+            error: "Rate limit exceeded".to_string(),
+        }),
+    )
+        .into_response()
+}
+
+/// Compute the duration of a PCM WAV file from its header.
+///
+/// Walks the RIFF chunks for `fmt ` (byte rate) and `data` (length).
+/// Returns `None` if the header is not a valid WAV header.
+pub fn wav_duration_secs(bytes: &[u8]) -> Option<f32> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return None;
+    }
+
+    let mut pos = 12;
+    let mut byte_rate: Option<u32> = None;
+
+    while pos + 8 <= bytes.len() {
+        let id = &bytes[pos..pos + 4];
+        let size = u32::from_le_bytes(bytes[pos + 4..pos + 8].try_into().ok()?);
+        let body = pos + 8;
+
+        match id {
+            b"fmt " => {
+                // byte_rate lives at offset 8 within the fmt chunk
+                let rate = bytes.get(body + 8..body + 12)?;
+                byte_rate = Some(u32::from_le_bytes(rate.try_into().ok()?));
+            }
+            b"data" => {
+                let rate = byte_rate.filter(|r| *r > 0)?;
+                return Some(size as f32 / rate as f32);
+            }
+            _ => {}
+        }
+
+        // Chunks are padded to even sizes
+        pos = body
+            .checked_add(size as usize)?
+            .checked_add(size as usize % 2)?;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wav_header(sample_rate: u32, channels: u16, data_len: u32) -> Vec<u8> {
+        let byte_rate = sample_rate * u32::from(channels) * 2;
+        let mut v = Vec::new();
+        v.extend_from_slice(b"RIFF");
+        v.extend_from_slice(&(36 + data_len).to_le_bytes());
+        v.extend_from_slice(b"WAVE");
+        v.extend_from_slice(b"fmt ");
+        v.extend_from_slice(&16u32.to_le_bytes());
+        v.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        v.extend_from_slice(&channels.to_le_bytes());
+        v.extend_from_slice(&sample_rate.to_le_bytes());
+        v.extend_from_slice(&byte_rate.to_le_bytes());
+        v.extend_from_slice(&(channels * 2).to_le_bytes());
+        v.extend_from_slice(&16u16.to_le_bytes());
+        v.extend_from_slice(b"data");
+        v.extend_from_slice(&data_len.to_le_bytes());
+        v
+    }
+
+    #[test]
+    fn test_token_bucket_burst_then_reject() {
+        let limiter = RateLimiter::new(1.0, 3);
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            assert!(limiter.check_at("client", now).is_ok());
+        }
+        let retry = limiter.check_at("client", now).unwrap_err();
+        assert!(retry <= Duration::from_secs(1));
+
+        // Other clients have their own bucket
+        assert!(limiter.check_at("other", now).is_ok());
+    }
+
+    #[test]
+    fn test_token_bucket_refills() {
+        let limiter = RateLimiter::new(2.0, 1);
+        let now = Instant::now();
+
+        assert!(limiter.check_at("client", now).is_ok());
+        assert!(limiter.check_at("client", now).is_err());
+        assert!(limiter
+            .check_at("client", now + Duration::from_millis(600))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_wav_duration_from_header() {
+        // 16kHz mono 16-bit: 32000 bytes per second
+        let header = wav_header(16000, 1, 32000 * 90);
+        let duration = wav_duration_secs(&header).unwrap();
+        assert!((duration - 90.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_wav_duration_rejects_non_wav() {
+        assert!(wav_duration_secs(b"ID3\x04not a wav file").is_none());
+        assert!(wav_duration_secs(&[]).is_none());
+    }
+
+    #[test]
+    fn test_check_upload_duration_limit() {
+        let limits = ApiLimits::from_config(&ApiLimitsConfig {
+            max_upload_secs: 60,
+            ..Default::default()
+        });
+
+        assert!(limits
+            .check_upload(&wav_header(16000, 1, 32000 * 30))
+            .is_ok());
+
+        let (status, _) = limits
+            .check_upload(&wav_header(16000, 1, 32000 * 120))
+            .unwrap_err();
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
@@ -9,6 +9,9 @@
 //! - Localhost only by default
 //! - API key authentication required for all endpoints except health check
 //! - CORS restricted by default
+//! - Optional TLS (`[api.tls]`) and Unix domain socket listener
+//!   (`unix_socket`), where filesystem permissions gate access
+//! - Per-IP and per-key rate limits and body size limits (`[api.limits]`)
 //!
 //! # Usage
 //!
@@ -27,36 +30,200 @@
 
 mod auth;
 mod handlers;
+pub mod limits;
 mod routes;
 pub mod state;
+pub mod tls;
 
 pub use auth::{generate_api_key, hash_api_key};
 pub use routes::create_router;
 pub use state::{ApiCommand, ApiState, DaemonStatus};
 
 use crate::config::ApiConfig;
+use axum::Router;
 use std::net::SocketAddr;
 use tokio::net::TcpListener;
 use tracing::{error, info};
 
 /// Start the API server.
+///
+/// Serves the router on the TCP `bind` address (HTTPS when TLS is enabled)
+/// and, on Unix, on the configured Unix domain socket. Returns when any
+/// listener fails.
 pub async fn serve(state: ApiState, config: &ApiConfig) -> anyhow::Result<()> {
+    if config.bind.is_empty() && config.unix_socket.is_none() {
+        anyhow::bail!("API has no listener: set api.bind or api.unix_socket");
+    }
+
+    let router = create_router(state, config);
+
+    let tcp = async {
+        if config.bind.is_empty() {
+            return std::future::pending::<anyhow::Result<()>>().await;
+        }
+        serve_tcp(router.clone(), config).await
+    };
+
+    let unix = async {
+        match config.unix_socket {
+            #[cfg(unix)]
+            Some(ref path) => serve_unix(router.clone(), path, config.unix_socket_mode).await,
+            #[cfg(not(unix))]
+            Some(_) => {
+                tracing::warn!("api.unix_socket is only supported on Unix, ignoring");
+                std::future::pending().await
+            }
+            None => std::future::pending().await,
+        }
+    };
+
+    tokio::select! {
+        result = tcp => result,
+        result = unix => result,
+    }
+}
+
+/// Serve on the TCP bind address, with TLS if enabled.
+async fn serve_tcp(router: Router, config: &ApiConfig) -> anyhow::Result<()> {
     let addr: SocketAddr = config
         .bind
         .parse()
         .map_err(|e| anyhow::anyhow!("Invalid API bind address '{}': {}", config.bind, e))?;
 
-    let router = create_router(state, config);
-
-    info!("Starting REST API server on {}", addr);
+    let scheme = if config.tls.enabled { "https" } else { "http" };
+    info!("Starting REST API server on {}://{}", scheme, addr);
     if config.swagger_ui {
-        info!("Swagger UI available at http://{}/swagger-ui/", addr);
+        info!("Swagger UI available at {}://{}/swagger-ui/", scheme, addr);
+    }
+
+    if config.tls.enabled {
+        let (cert, key) = tls::resolve_cert_paths(&config.tls)?;
+        let rustls_config = axum_server::tls_rustls::RustlsConfig::from_pem_file(&cert, &key)
+            .await
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Failed to load TLS certificate {} / key {}: {}",
+                    cert.display(),
+                    key.display(),
+                    e
+                )
+            })?;
+
+        return axum_server::bind_rustls(addr, rustls_config)
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
+            .await
+            .map_err(|e| {
+                error!("API server error: {}", e);
+                anyhow::anyhow!("API server error: {}", e)
+            });
     }
 
     let listener = TcpListener::bind(addr).await?;
 
+    // Client addresses are needed for per-IP rate limiting
+    axum::serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .map_err(|e| {
+        error!("API server error: {}", e);
+        anyhow::anyhow!("API server error: {}", e)
+    })
+}
+
+/// Serve on a Unix domain socket, restricting access by file mode.
+#[cfg(unix)]
+async fn serve_unix(router: Router, path: &str, mode: u32) -> anyhow::Result<()> {
+    let path = std::path::Path::new(path);
+    let listener = bind_unix(path, mode)?;
+
+    info!(
+        "Starting REST API server on unix:{} (mode {:o})",
+        path.display(),
+        mode
+    );
+
     axum::serve(listener, router).await.map_err(|e| {
         error!("API server error: {}", e);
         anyhow::anyhow!("API server error: {}", e)
     })
 }
+
+/// Bind a Unix socket that is never reachable with looser permissions.
+///
+/// The socket is bound in a private (0700) directory next to `path`, given
+/// `mode`, then renamed into place. An existing file at `path` is only
+/// replaced if it is a socket (left behind by a previous run).
+#[cfg(unix)]
+fn bind_unix(path: &std::path::Path, mode: u32) -> anyhow::Result<tokio::net::UnixListener> {
+    use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => std::path::Path::new("."),
+    };
+    std::fs::create_dir_all(parent)?;
+    match std::fs::symlink_metadata(path) {
+        Ok(meta) if meta.file_type().is_socket() => std::fs::remove_file(path)?,
+        Ok(_) => anyhow::bail!(
+            "Refusing to replace {}: it exists and is not a socket",
+            path.display()
+        ),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e.into()),
+    }
+
+    let staging = parent.join(format!(".openhush-api-{}", std::process::id()));
+    if staging.exists() {
+        std::fs::remove_dir_all(&staging)?;
+    }
+    std::fs::DirBuilder::new().mode(0o700).create(&staging)?;
+    let staged = staging.join("api.sock");
+    let bound = tokio::net::UnixListener::bind(&staged)
+        .map_err(|e| anyhow::anyhow!("Failed to bind API socket {}: {}", path.display(), e))
+        .and_then(|listener| {
+            std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(mode))?;
+            std::fs::rename(&staged, path)?;
+            Ok(listener)
+        });
+    let _ = std::fs::remove_dir_all(&staging);
+    bound
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+
+    #[tokio::test]
+    async fn test_bind_unix_sets_mode_and_replaces_stale_socket() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("run").join("api.sock");
+
+        let listener = bind_unix(&path, 0o600).unwrap();
+        let meta = std::fs::symlink_metadata(&path).unwrap();
+        assert!(meta.file_type().is_socket());
+        assert_eq!(meta.permissions().mode() & 0o777, 0o600);
+        drop(listener);
+
+        // The socket left behind is replaced; the staging directory is gone
+        let _listener = bind_unix(&path, 0o660).unwrap();
+        let meta = std::fs::symlink_metadata(&path).unwrap();
+        assert_eq!(meta.permissions().mode() & 0o777, 0o660);
+        assert_eq!(
+            std::fs::read_dir(path.parent().unwrap()).unwrap().count(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bind_unix_keeps_other_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.txt");
+        std::fs::write(&path, "keep me").unwrap();
+
+        assert!(bind_unix(&path, 0o600).is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "keep me");
+    }
+}
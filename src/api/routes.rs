@@ -1,6 +1,7 @@
 //! API router setup with Swagger UI and middleware.
 
 use axum::{
+    extract::DefaultBodyLimit,
     middleware,
     routing::{get, post},
     Router,
@@ -12,9 +13,11 @@ use utoipa_swagger_ui::SwaggerUi;
 
 use super::auth::require_api_key;
 use super::handlers::{
-    self, get_status, health, start_recording, stop_recording, toggle_recording, ErrorResponse,
-    HealthResponse, RecordingAction, StatusResponse, SuccessResponse,
+    self, get_status, health, start_recording, stop_recording, toggle_recording, transcribe_file,
+    transcribe_last, ErrorResponse, HealthResponse, RecordingAction, ReplayRequest, StatusResponse,
+    SuccessResponse, TranscribeResponse,
 };
+use super::limits::{enforce_key_limit, enforce_limits};
 use super::state::ApiState;
 use crate::config::ApiConfig;
 
@@ -34,6 +37,8 @@ use crate::config::ApiConfig;
         handlers::start_recording,
         handlers::stop_recording,
         handlers::toggle_recording,
+        handlers::transcribe_last,
+        handlers::transcribe_file,
     ),
     components(
         schemas(
@@ -42,12 +47,15 @@ use crate::config::ApiConfig;
             SuccessResponse,
             ErrorResponse,
             RecordingAction,
+            ReplayRequest,
+            TranscribeResponse,
         )
     ),
     tags(
         (name = "Health", description = "Health check endpoints"),
         (name = "Status", description = "Daemon status endpoints"),
         (name = "Recording", description = "Recording control endpoints"),
+        (name = "Transcription", description = "File transcription endpoints"),
     ),
     modifiers(&SecurityAddon)
 )]
@@ -82,15 +90,29 @@ pub fn create_router(state: ApiState, config: &ApiConfig) -> Router {
         .route("/api/v1/recording/start", post(start_recording))
         .route("/api/v1/recording/stop", post(stop_recording))
         .route("/api/v1/recording/toggle", post(toggle_recording))
+        .route("/api/v1/recording/replay", post(transcribe_last))
+        .route("/api/v1/transcribe", post(transcribe_file))
+        // Layers run outside-in from the last one: authenticate first, then
+        // meter the (now verified) key
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            enforce_key_limit,
+        ))
         .route_layer(middleware::from_fn_with_state(
             state.clone(),
             require_api_key,
         ));
 
     // Build main router
+    let max_body_bytes = usize::try_from(config.limits.max_body_bytes).unwrap_or(usize::MAX);
     let mut router = Router::new()
         .merge(public_routes)
         .merge(protected_routes)
+        .layer(DefaultBodyLimit::max(max_body_bytes))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            enforce_limits,
+        ))
         .with_state(state);
 
     // Add Swagger UI if enabled
@@ -124,3 +146,80 @@ pub fn create_router(state: ApiState, config: &ApiConfig) -> Router {
 
     router.layer(cors).layer(TraceLayer::new_for_http())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::auth::{hash_api_key, API_KEY_HEADER};
+    use crate::api::state::{ApiCommand, DaemonStatus};
+    use crate::config::ApiLimitsConfig;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use std::sync::Arc;
+    use tokio::sync::{mpsc, RwLock};
+    use tower::ServiceExt;
+
+    fn router(limits: ApiLimitsConfig) -> (Router, mpsc::Receiver<ApiCommand>) {
+        let (tx, rx) = mpsc::channel(10);
+        let state = ApiState::new(
+            Arc::new(RwLock::new(DaemonStatus::default())),
+            tx,
+            Some(hash_api_key("secret")),
+        )
+        .with_limits(&limits);
+        let config = ApiConfig {
+            swagger_ui: false,
+            limits,
+            ..Default::default()
+        };
+        (create_router(state, &config), rx)
+    }
+
+    async fn status_with_key(router: &Router, key: &str) -> StatusCode {
+        let request = Request::get("/api/v1/status")
+            .header(API_KEY_HEADER, key)
+            .body(Body::empty())
+            .unwrap();
+        router.clone().oneshot(request).await.unwrap().status()
+    }
+
+    // ===================
+    // Middleware Order Tests
+    // ===================
+
+    #[tokio::test]
+    async fn test_key_limit_only_meters_authenticated_keys() {
+        let (router, _rx) = router(ApiLimitsConfig {
+            per_key_burst: 1,
+            per_key_per_sec: 0.001,
+            ..Default::default()
+        });
+
+        // Wrong keys are rejected by auth and never reach the key bucket
+        for _ in 0..3 {
+            assert_eq!(
+                status_with_key(&router, "guess").await,
+                StatusCode::UNAUTHORIZED
+            );
+        }
+
+        assert_eq!(status_with_key(&router, "secret").await, StatusCode::OK);
+        assert_eq!(
+            status_with_key(&router, "secret").await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transcribe_rejects_non_wav_upload() {
+        let (router, mut rx) = router(ApiLimitsConfig::default());
+        let request = Request::post("/api/v1/transcribe")
+            .header(API_KEY_HEADER, "secret")
+            .body(Body::from("not audio"))
+            .unwrap();
+
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        assert!(rx.try_recv().is_err());
+    }
+}
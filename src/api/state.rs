@@ -3,6 +3,11 @@
 use std::sync::Arc;
 use tokio::sync::{mpsc, RwLock};
 
+use super::limits::ApiLimits;
+use crate::config::{ApiLimitsConfig, ResamplingQuality};
+use crate::input::AudioBuffer;
+use crate::queue::TranscriptionResult;
+
 /// Commands sent from API to daemon.
 #[derive(Debug, Clone)]
 #[allow(clippy::enum_variant_names)] // Consistent with DaemonCommand pattern
@@ -13,6 +18,15 @@ pub enum ApiCommand {
     StopRecording,
     /// Toggle recording state
     ToggleRecording,
+    /// Transcribe the last seconds already in the ring buffer
+    /// (None = configured default)
+    TranscribeLast(Option<f32>),
+    /// Transcribe a whole file as batch work and send the result back
+    /// (API uploads and `openhush transcribe`)
+    Transcribe {
+        audio: AudioBuffer,
+        reply: mpsc::Sender<TranscriptionResult>,
+    },
 }
 
 /// Current daemon status exposed to API.
@@ -37,6 +51,10 @@ pub struct ApiState {
     pub cmd_tx: mpsc::Sender<ApiCommand>,
     /// API key hash for authentication (SHA-256 hex)
     pub api_key_hash: Option<String>,
+    /// Rate limiters and request size limits
+    pub limits: Arc<ApiLimits>,
+    /// Resampler used for uploads that are not 16kHz
+    pub resampling_quality: ResamplingQuality,
 }
 
 impl ApiState {
@@ -50,8 +68,22 @@ impl ApiState {
             status,
             cmd_tx,
             api_key_hash,
+            limits: Arc::new(ApiLimits::from_config(&ApiLimitsConfig::default())),
+            resampling_quality: ResamplingQuality::default(),
         }
     }
+
+    /// Replace the default limits with configured ones.
+    pub fn with_limits(mut self, config: &ApiLimitsConfig) -> Self {
+        self.limits = Arc::new(ApiLimits::from_config(config));
+        self
+    }
+
+    /// Use the configured resampler for uploads.
+    pub fn with_resampling_quality(mut self, quality: ResamplingQuality) -> Self {
+        self.resampling_quality = quality;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -72,6 +104,9 @@ mod tests {
 
         let cmd = ApiCommand::ToggleRecording;
         assert_eq!(format!("{:?}", cmd), "ToggleRecording");
+
+        let cmd = ApiCommand::TranscribeLast(Some(20.0));
+        assert_eq!(format!("{:?}", cmd), "TranscribeLast(Some(20.0))");
     }
 
     #[test]
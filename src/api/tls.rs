@@ -0,0 +1,160 @@
+//! TLS certificate handling for the REST API.
+//!
+//! Certificates are PEM files on disk. If TLS is enabled without explicit
+//! paths, a self-signed certificate for `localhost` is generated under the
+//! config directory so the API can be reached over HTTPS on a trusted LAN
+//! without setting up a CA.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use tracing::{info, warn};
+
+use crate::config::{ApiTlsConfig, Config, ConfigError};
+
+/// Default certificate file name inside the TLS directory.
+const CERT_FILE: &str = "cert.pem";
+
+/// Default private key file name inside the TLS directory.
+const KEY_FILE: &str = "key.pem";
+
+#[derive(Error, Debug)]
+pub enum TlsError {
+    #[error("Config error: {0}")]
+    Config(#[from] ConfigError),
+
+    #[error("Certificate generation failed: {0}")]
+    Generate(String),
+
+    #[error("Certificate file not found: {0}")]
+    NotFound(PathBuf),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Directory holding generated TLS material (`~/.config/openhush/tls`).
+pub fn tls_dir() -> Result<PathBuf, TlsError> {
+    Ok(Config::config_dir()?.join("tls"))
+}
+
+/// Resolve certificate and key paths for the given TLS config.
+///
+/// Explicit paths must exist. Without explicit paths, the default location
+/// is used and a self-signed certificate is generated there if missing.
+pub fn resolve_cert_paths(config: &ApiTlsConfig) -> Result<(PathBuf, PathBuf), TlsError> {
+    if let (Some(cert), Some(key)) = (&config.cert_path, &config.key_path) {
+        let cert = PathBuf::from(cert);
+        let key = PathBuf::from(key);
+        for path in [&cert, &key] {
+            if !path.exists() {
+                return Err(TlsError::NotFound(path.clone()));
+            }
+        }
+        return Ok((cert, key));
+    }
+
+    let dir = tls_dir()?;
+    let cert = dir.join(CERT_FILE);
+    let key = dir.join(KEY_FILE);
+    if !cert.exists() || !key.exists() {
+        warn!(
+            "No TLS certificate configured, generating a self-signed one in {}",
+            dir.display()
+        );
+        generate_self_signed(&dir, &default_hostnames())?;
+    }
+    Ok((cert, key))
+}
+
+/// Host names included in a generated certificate by default.
+pub fn default_hostnames() -> Vec<String> {
+    vec![
+        "localhost".to_string(),
+        "127.0.0.1".to_string(),
+        "::1".to_string(),
+    ]
+}
+
+/// Generate a self-signed certificate and key into `dir`.
+///
+/// Returns the certificate and key paths. Existing files are overwritten.
+/// The key file is written with owner-only permissions on Unix.
+pub fn generate_self_signed(
+    dir: &Path,
+    hostnames: &[String],
+) -> Result<(PathBuf, PathBuf), TlsError> {
+    let certified = rcgen::generate_simple_self_signed(hostnames.to_vec())
+        .map_err(|e| TlsError::Generate(e.to_string()))?;
+
+    fs::create_dir_all(dir)?;
+    let cert_path = dir.join(CERT_FILE);
+    let key_path = dir.join(KEY_FILE);
+
+    fs::write(&cert_path, certified.cert.pem())?;
+    write_private(&key_path, certified.signing_key.serialize_pem().as_bytes())?;
+
+    info!(
+        "Generated self-signed certificate for {} at {}",
+        hostnames.join(", "),
+        cert_path.display()
+    );
+    Ok((cert_path, key_path))
+}
+
+/// Write a file readable only by the current user.
+#[cfg(unix)]
+fn write_private(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    file.write_all(contents)
+}
+
+#[cfg(not(unix))]
+fn write_private(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    fs::write(path, contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_generate_self_signed_writes_pem_files() {
+        let dir = tempdir().unwrap();
+        let (cert, key) = generate_self_signed(dir.path(), &default_hostnames()).unwrap();
+
+        let cert_pem = fs::read_to_string(&cert).unwrap();
+        let key_pem = fs::read_to_string(&key).unwrap();
+        assert!(cert_pem.contains("BEGIN CERTIFICATE"));
+        assert!(key_pem.contains("PRIVATE KEY"));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&key).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn test_resolve_explicit_paths_missing() {
+        let config = ApiTlsConfig {
+            enabled: true,
+            cert_path: Some("/nonexistent/cert.pem".to_string()),
+            key_path: Some("/nonexistent/key.pem".to_string()),
+        };
+        assert!(matches!(
+            resolve_cert_paths(&config),
+            Err(TlsError::NotFound(_))
+        ));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::engine::whisper::is_supported_language;
 use crate::output::actions::ActionConfig;
 use crate::vad::VadConfig;
 use directories::ProjectDirs;
@@ -140,6 +141,9 @@ pub struct Config {
     #[serde(default)]
     pub audio: AudioConfig,
 
+    #[serde(default)]
+    pub replay: ReplayConfig,
+
     #[serde(default)]
     pub output: OutputConfig,
 
@@ -194,6 +198,10 @@ pub struct Config {
     /// Meeting summarization settings
     #[serde(default)]
     pub summarization: SummarizationConfig,
+
+    /// Model download and integrity settings
+    #[serde(default)]
+    pub models: ModelsConfig,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -247,6 +255,14 @@ pub struct AppProfile {
     /// Override transcription preset: "instant", "balanced", "quality"
     #[serde(default)]
     pub preset: Option<String>,
+
+    /// Override transcription backend: "local" or "remote"
+    #[serde(default)]
+    pub backend: Option<TranscriptionBackendKind>,
+
+    /// Override transcription language: "auto" or ISO code
+    #[serde(default)]
+    pub language: Option<String>,
 }
 
 impl AppProfile {
@@ -405,11 +421,20 @@ pub struct WakeWordConfig {
     #[serde(default)]
     pub enabled: bool,
 
-    /// Path to wake word model file (.rpw)
-    /// If not set, uses default "hey open hush" model
+    /// Path to an openWakeWord model (.onnx) replacing the built-in
+    /// "hey jarvis" model. Ignored when `models` is set.
     #[serde(default)]
     pub model_path: Option<String>,
 
+    /// Wake word models loaded together, each bound to an action
+    #[serde(default)]
+    pub models: Vec<WakeWordModel>,
+
+    /// Phrase that ends hands-free dictation when said at the end of an
+    /// utterance (e.g. "stop dictation"); removed from the output
+    #[serde(default)]
+    pub stop_phrase: Option<String>,
+
     /// Detection sensitivity (0.0 = strict, 1.0 = loose)
     #[serde(default = "default_wake_word_sensitivity")]
     pub sensitivity: f32,
@@ -436,6 +461,8 @@ impl Default for WakeWordConfig {
         Self {
             enabled: false, // Opt-in (always-on microphone has privacy implications)
             model_path: None,
+            models: Vec::new(),
+            stop_phrase: None,
             sensitivity: default_wake_word_sensitivity(),
             threshold: default_wake_word_threshold(),
             timeout_secs: default_wake_word_timeout(),
@@ -445,6 +472,40 @@ impl Default for WakeWordConfig {
     }
 }
 
+/// What a wake word does when detected.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WakeWordAction {
+    /// Start hands-free dictation
+    #[default]
+    Dictate,
+    /// Start (or stop) recording a meeting to a file
+    Meeting,
+    /// Stop the running dictation or meeting, discarding untranscribed audio
+    Cancel,
+    /// Dictate one utterance and translate it
+    Translate,
+}
+
+/// A wake word model and the action it triggers.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WakeWordModel {
+    /// Name shown in logs and notifications (e.g. "hey jarvis")
+    pub name: String,
+
+    /// openWakeWord classifier (.onnx), absolute or relative to the
+    /// wake word models directory
+    pub path: String,
+
+    /// Detection threshold (0.0 - 1.0); defaults to `wake_word.threshold`
+    #[serde(default)]
+    pub threshold: Option<f32>,
+
+    /// Action triggered on detection
+    #[serde(default)]
+    pub action: WakeWordAction,
+}
+
 fn default_wake_word_sensitivity() -> f32 {
     0.5 // Balanced sensitivity
 }
@@ -480,6 +541,24 @@ pub struct ApiConfig {
     /// Allowed CORS origins (empty = same-origin only)
     #[serde(default)]
     pub cors_origins: Vec<String>,
+
+    /// TLS settings for the TCP listener
+    #[serde(default)]
+    pub tls: ApiTlsConfig,
+
+    /// Serve the API on a Unix domain socket as well (Unix only).
+    /// Access is controlled by the socket's filesystem permissions.
+    /// Set `bind = ""` to serve only on the socket.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unix_socket: Option<String>,
+
+    /// Permission bits for the Unix socket (default: 0o600, owner only)
+    #[serde(default = "default_api_unix_socket_mode")]
+    pub unix_socket_mode: u32,
+
+    /// Request rate and size limits
+    #[serde(default)]
+    pub limits: ApiLimitsConfig,
 }
 
 impl Default for ApiConfig {
@@ -490,6 +569,10 @@ impl Default for ApiConfig {
             api_key_hash: None,
             swagger_ui: true,
             cors_origins: vec![],
+            tls: ApiTlsConfig::default(),
+            unix_socket: None,
+            unix_socket_mode: default_api_unix_socket_mode(),
+            limits: ApiLimitsConfig::default(),
         }
     }
 }
@@ -498,6 +581,104 @@ fn default_api_bind() -> String {
     "127.0.0.1:8080".to_string()
 }
 
+fn default_api_unix_socket_mode() -> u32 {
+    0o600 // Owner read/write only
+}
+
+/// Rate limiting and request size limits for the REST API.
+///
+/// Rates use token buckets: each client may burst up to `*_burst` requests,
+/// refilled at `*_per_sec` requests per second. Exceeding the limit returns
+/// `429 Too Many Requests` with a `Retry-After` header.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ApiLimitsConfig {
+    /// Enable rate limiting
+    #[serde(default = "default_true")]
+    pub rate_limit: bool,
+
+    /// Sustained requests per second per client IP
+    #[serde(default = "default_api_per_ip_per_sec")]
+    pub per_ip_per_sec: f32,
+
+    /// Burst size per client IP
+    #[serde(default = "default_api_per_ip_burst")]
+    pub per_ip_burst: u32,
+
+    /// Sustained requests per second per API key
+    #[serde(default = "default_api_per_key_per_sec")]
+    pub per_key_per_sec: f32,
+
+    /// Burst size per API key
+    #[serde(default = "default_api_per_key_burst")]
+    pub per_key_burst: u32,
+
+    /// Maximum request body size in bytes (checked before reading the body)
+    #[serde(default = "default_api_max_body_bytes")]
+    pub max_body_bytes: u64,
+
+    /// Maximum duration of uploaded audio in seconds (checked from the
+    /// file header, before decoding)
+    #[serde(default = "default_api_max_upload_secs")]
+    pub max_upload_secs: u32,
+}
+
+impl Default for ApiLimitsConfig {
+    fn default() -> Self {
+        Self {
+            rate_limit: true,
+            per_ip_per_sec: default_api_per_ip_per_sec(),
+            per_ip_burst: default_api_per_ip_burst(),
+            per_key_per_sec: default_api_per_key_per_sec(),
+            per_key_burst: default_api_per_key_burst(),
+            max_body_bytes: default_api_max_body_bytes(),
+            max_upload_secs: default_api_max_upload_secs(),
+        }
+    }
+}
+
+fn default_api_per_ip_per_sec() -> f32 {
+    2.0
+}
+
+fn default_api_per_ip_burst() -> u32 {
+    20
+}
+
+fn default_api_per_key_per_sec() -> f32 {
+    5.0
+}
+
+fn default_api_per_key_burst() -> u32 {
+    50
+}
+
+fn default_api_max_body_bytes() -> u64 {
+    100 * 1024 * 1024 // 100 MB - roughly 50 minutes of 16-bit 16kHz stereo WAV
+}
+
+fn default_api_max_upload_secs() -> u32 {
+    3600 // 1 hour
+}
+
+/// TLS configuration for the REST API.
+///
+/// When enabled without explicit paths, a self-signed certificate is
+/// generated under `~/.config/openhush/tls/` on first start.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ApiTlsConfig {
+    /// Serve HTTPS instead of plain HTTP on `bind`
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Path to PEM-encoded certificate chain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cert_path: Option<String>,
+
+    /// Path to PEM-encoded private key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_path: Option<String>,
+}
+
 /// Meeting summarization configuration
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SummarizationConfig {
@@ -620,15 +801,118 @@ fn default_openai_base_url() -> String {
     "https://api.openai.com/v1".to_string()
 }
 
+/// Model download and integrity settings.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ModelsConfig {
+    /// URL of a pinned model manifest (TOML with SHA-256 and size per file).
+    /// Fetched before downloads and `openhush model verify`. Its entries add
+    /// to or replace the manifest built into the binary, e.g. for models
+    /// served from a mirror.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest_url: Option<String>,
+
+    /// Mirror base URLs replacing Hugging Face / GitHub downloads
+    #[serde(default)]
+    pub mirrors: ModelMirrors,
+}
+
+/// Model families with separate download sources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelFamily {
+    Whisper,
+    M2M100,
+    WakeWord,
+    Diarization,
+}
+
+/// Mirror base URL per model family.
+///
+/// A mirror serves files in the same layout as the local models directory
+/// (e.g. `<whisper>/ggml-small.bin`, `<m2m100>/m2m100-418m/tokenizer.json`),
+/// so an extracted `openhush model export` bundle can be served as-is.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ModelMirrors {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub whisper: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub m2m100: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wake_word: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diarization: Option<String>,
+}
+
+impl ModelMirrors {
+    /// Mirror base URL for a model family, if configured.
+    pub fn get(&self, family: ModelFamily) -> Option<&str> {
+        match family {
+            ModelFamily::Whisper => self.whisper.as_deref(),
+            ModelFamily::M2M100 => self.m2m100.as_deref(),
+            ModelFamily::WakeWord => self.wake_word.as_deref(),
+            ModelFamily::Diarization => self.diarization.as_deref(),
+        }
+    }
+
+    /// Download URL for `file` (relative to the family's directory), or
+    /// `upstream` if no mirror is configured.
+    pub fn url(&self, family: ModelFamily, file: &str, upstream: String) -> String {
+        match self.get(family) {
+            Some(base) => format!("{}/{}", base.trim_end_matches('/'), file),
+            None => upstream,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct HotkeyConfig {
     /// The trigger key (e.g., "ControlRight", "F12")
     #[serde(default = "default_hotkey")]
     pub key: String,
 
-    /// Mode: "push_to_talk" or "toggle"
+    /// Mode: "push_to_talk", "toggle" or "continuous" (hands-free, VAD-segmented)
     #[serde(default = "default_mode")]
     pub mode: String,
+
+    /// Key that switches to the next transcription language (e.g., "F8").
+    /// Cycles through "auto" and `transcription.allowed_languages`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language_key: Option<String>,
+}
+
+/// Retroactive transcription of audio already in the ring buffer
+/// ("what did they just say?").
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ReplayConfig {
+    /// Hotkey that transcribes the last `default_secs` seconds (e.g., "F9").
+    /// Without it, replays are requested via CLI, IPC, D-Bus or the REST API.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+
+    /// Seconds transcribed when a request does not say how many
+    #[serde(default = "default_replay_secs")]
+    pub default_secs: f32,
+
+    /// Keep at least this many seconds in the ring buffer.
+    /// Raises `audio.prebuffer_duration_secs` when larger.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buffer_secs: Option<f32>,
+}
+
+impl Default for ReplayConfig {
+    fn default() -> Self {
+        Self {
+            key: None,
+            default_secs: default_replay_secs(),
+            buffer_secs: None,
+        }
+    }
+}
+
+fn default_replay_secs() -> f32 {
+    15.0
 }
 
 /// Transcription mode preset for speed vs quality tradeoff.
@@ -666,15 +950,28 @@ pub struct TranscriptionConfig {
     #[serde(default)]
     pub preset: TranscriptionPreset,
 
-    /// Whisper model: tiny, base, small, medium, large-v3
+    /// Whisper model: tiny, base, small, medium, large-v3, large-v3-turbo,
+    /// distil-large-v3, English-only variants (e.g. "small.en"), quantized
+    /// variants (e.g. "medium-q5", "large-v3-turbo-q8") or the name of an
+    /// entry in `custom_models`.
     /// Only used when preset = "custom"
     #[serde(default = "default_model")]
     pub model: String,
 
+    /// Local ggml models registered by name (e.g. fine-tuned domain models)
+    #[serde(default)]
+    pub custom_models: Vec<CustomModelConfig>,
+
     /// Language: "auto" or ISO code (en, de, etc.)
     #[serde(default = "default_language")]
     pub language: String,
 
+    /// Languages "auto" may detect (e.g. ["de", "en"]). Short utterances
+    /// are then never misdetected as a language outside the set.
+    /// Empty allows every language.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_languages: Vec<String>,
+
     /// Device: "cuda" or "cpu"
     #[serde(default = "default_device")]
     pub device: String,
@@ -692,9 +989,133 @@ pub struct TranscriptionConfig {
     /// If false, model is loaded lazily on first transcription request.
     #[serde(default = "default_true")]
     pub preload: bool,
+
+    /// Larger model kept warm next to the live model (e.g. "large-v3").
+    /// Recordings, file transcription and refinement passes use it, while
+    /// live push-to-talk chunks stay on the faster effective model.
+    /// Unset means every job uses the effective model.
+    #[serde(default)]
+    pub accurate_model: Option<String>,
+
+    /// Memory budget for warm engines in MB (0 = unlimited).
+    /// Workers share model weights, so each model counts once however
+    /// many workers there are. When exceeded, the least recently used
+    /// engine is unloaded from every worker.
+    #[serde(default)]
+    pub engine_memory_budget_mb: u64,
+
+    /// Two-pass refinement of push-to-talk recordings: the live model's
+    /// draft is output immediately, then `accurate_model` re-transcribes
+    /// the same audio in the background. Requires `accurate_model`.
+    #[serde(default)]
+    pub refine: RefineMode,
+
+    /// Transcription backend: "local" (whisper-rs) or "remote"
+    /// (OpenAI-compatible server from `[transcription.remote]`)
+    #[serde(default)]
+    pub backend: TranscriptionBackendKind,
+
+    /// Remote transcription server settings
+    #[serde(default)]
+    pub remote: RemoteTranscriptionConfig,
+}
+
+/// Which backend transcribes audio
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptionBackendKind {
+    /// Local whisper-rs engine
+    #[default]
+    Local,
+    /// OpenAI-compatible HTTP transcription server
+    Remote,
+}
+
+/// OpenAI-compatible remote transcription server (e.g. a team whisper server)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RemoteTranscriptionConfig {
+    /// Base URL (e.g. "http://whisper.lan:8000/v1")
+    #[serde(default)]
+    pub url: String,
+
+    /// API key (supports "keyring:" prefix for secure storage), empty for none
+    #[serde(default)]
+    pub api_key: String,
+
+    /// Model name sent to the server
+    #[serde(default = "default_remote_model")]
+    pub model: String,
+
+    /// Request timeout in seconds
+    #[serde(default = "default_remote_timeout")]
+    pub timeout_secs: u32,
+
+    /// Use the local model when the server is unreachable or fails
+    #[serde(default = "default_true")]
+    pub fallback_to_local: bool,
+
+    /// Presets that use the remote server even with `backend = "local"`
+    #[serde(default)]
+    pub presets: Vec<TranscriptionPreset>,
+}
+
+impl Default for RemoteTranscriptionConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            api_key: String::new(),
+            model: default_remote_model(),
+            timeout_secs: default_remote_timeout(),
+            fallback_to_local: true,
+            presets: Vec::new(),
+        }
+    }
+}
+
+fn default_remote_model() -> String {
+    "whisper-1".to_string()
+}
+
+fn default_remote_timeout() -> u32 {
+    30
+}
+
+/// What to do with the accurate transcription of a push-to-talk recording
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RefineMode {
+    /// No second pass
+    #[default]
+    Off,
+    /// Put the corrected text in history and clipboard
+    History,
+    /// Also replace the typed draft in place (backspace and retype)
+    Replace,
+}
+
+/// A local ggml model file registered under a name.
+///
+/// Custom models take precedence over built-in models of the same name.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CustomModelConfig {
+    /// Name used for `transcription.model` and in `openhush model list`
+    pub name: String,
+
+    /// Path to the ggml model file (`~` is expanded)
+    pub path: String,
+
+    /// Short description shown in `openhush model list`
+    #[serde(default)]
+    pub description: Option<String>,
 }
 
 impl TranscriptionConfig {
+    /// Look up a registered custom model by name.
+    #[must_use]
+    pub fn custom_model(&self, name: &str) -> Option<&CustomModelConfig> {
+        self.custom_models.iter().find(|m| m.name == name)
+    }
+
     /// Get the effective model based on preset.
     #[must_use]
     pub fn effective_model(&self) -> &str {
@@ -704,6 +1125,48 @@ impl TranscriptionConfig {
             self.preset.model()
         }
     }
+
+    /// Get the model used for recordings, files and refinement passes.
+    ///
+    /// Falls back to the effective model when no accurate model is set.
+    #[must_use]
+    pub fn effective_accurate_model(&self) -> &str {
+        self.accurate_model
+            .as_deref()
+            .unwrap_or_else(|| self.effective_model())
+    }
+
+    /// Get the backend for the current preset.
+    #[must_use]
+    pub fn effective_backend(&self) -> TranscriptionBackendKind {
+        if self.remote.presets.contains(&self.preset) {
+            TranscriptionBackendKind::Remote
+        } else {
+            self.backend
+        }
+    }
+
+    /// Language the language key switches to after `current`.
+    ///
+    /// Cycles through "auto" followed by `allowed_languages`. Without
+    /// allowed languages it toggles between "auto" and the configured
+    /// language.
+    #[must_use]
+    pub fn next_language(&self, current: &str) -> String {
+        let mut cycle = vec!["auto"];
+        if self.allowed_languages.is_empty() {
+            cycle.push(&self.language);
+        } else {
+            cycle.extend(self.allowed_languages.iter().map(String::as_str));
+        }
+        cycle.dedup();
+
+        let next = cycle
+            .iter()
+            .position(|lang| *lang == current)
+            .map_or(0, |i| (i + 1) % cycle.len());
+        cycle[next].to_string()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -810,6 +1273,15 @@ pub struct TranslationConfig {
     /// Timeout for translation requests in seconds
     #[serde(default = "default_translation_timeout")]
     pub timeout_secs: u32,
+
+    /// Further languages to translate into. Only `target_language` is
+    /// typed; these go to actions with a matching `language` filter
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_targets: Vec<String>,
+
+    /// Round-trip quality check for translations
+    #[serde(default)]
+    pub back_translation: BackTranslationConfig,
 }
 
 impl Default for TranslationConfig {
@@ -823,10 +1295,38 @@ impl Default for TranslationConfig {
             ollama_url: default_ollama_url(),
             ollama_model: default_translation_ollama_model(),
             timeout_secs: default_translation_timeout(),
+            extra_targets: Vec::new(),
+            back_translation: BackTranslationConfig::default(),
+        }
+    }
+}
+
+/// Back-translation check: translate the result back into the source
+/// language and flag it when the round trip diverges from the source.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BackTranslationConfig {
+    /// Enable the check (doubles the translation work)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Word overlap (0.0 to 1.0) below which a translation is flagged
+    #[serde(default = "default_min_similarity")]
+    pub min_similarity: f32,
+}
+
+impl Default for BackTranslationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_similarity: default_min_similarity(),
         }
     }
 }
 
+fn default_min_similarity() -> f32 {
+    0.5
+}
+
 fn default_target_language() -> String {
     "en".to_string()
 }
@@ -852,6 +1352,43 @@ pub struct FeedbackConfig {
     /// Show desktop notification
     #[serde(default = "default_true")]
     pub visual: bool,
+
+    /// Pause or duck playing media players (MPRIS) while recording (Linux)
+    #[serde(default)]
+    pub media_control: MediaControl,
+
+    /// Volume applied to players in duck mode, relative to their volume (0.0-1.0)
+    #[serde(default = "default_duck_volume")]
+    pub duck_volume: f64,
+}
+
+/// What to do with playing media players while recording
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaControl {
+    /// Leave media players alone
+    #[default]
+    Off,
+    /// Pause playing players and resume them when recording stops
+    Pause,
+    /// Lower the volume of playing players and restore it afterwards
+    Duck,
+}
+
+impl MediaControl {
+    /// Get display name for the setting
+    #[must_use]
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            MediaControl::Off => "Off",
+            MediaControl::Pause => "Pause",
+            MediaControl::Duck => "Lower volume",
+        }
+    }
+}
+
+fn default_duck_volume() -> f64 {
+    0.2 // 20% of the player's volume
 }
 
 /// Audio resampling quality
@@ -876,6 +1413,9 @@ pub enum BackpressureStrategy {
     DropOldest,
     /// Reject new jobs when queue is full
     DropNewest,
+    /// Accept new jobs but persist audio beyond `max_pending` queued jobs
+    /// to disk until a worker is free, instead of dropping it
+    SpillToDisk,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -915,6 +1455,37 @@ pub struct QueueConfig {
     /// Default: 0.2 (20% margin)
     #[serde(default = "default_chunk_safety_margin")]
     pub chunk_safety_margin: f32,
+
+    /// Number of parallel transcription workers.
+    /// Workers share the loaded model and each keep their own decoding state,
+    /// so chunks of a long recording and queued recordings run concurrently.
+    /// `0` picks a count from the available CPU cores.
+    #[serde(default = "default_workers")]
+    pub workers: u32,
+
+    /// Journal submitted audio to disk until its text is output, so
+    /// dictations in flight during a crash are replayed on the next start.
+    /// Off by default: it keeps recordings of speech on disk unencrypted.
+    #[serde(default)]
+    pub journal: bool,
+}
+
+impl QueueConfig {
+    /// Maximum number of parallel workers.
+    pub const MAX_WORKERS: u32 = 16;
+
+    /// Resolve the configured worker count (`0` = auto).
+    #[must_use]
+    pub fn worker_count(&self) -> usize {
+        if self.workers > 0 {
+            return self.workers as usize;
+        }
+        // Whisper already uses up to 4 threads per state
+        std::thread::available_parallelism()
+            .map(|n| n.get() / 4)
+            .unwrap_or(1)
+            .clamp(1, Self::MAX_WORKERS as usize)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -935,6 +1506,20 @@ pub struct AudioConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub input_device: Option<String>,
 
+    /// Microphones to prefer, highest priority first (e.g. a headset, then
+    /// the laptop mic). Entries match a device ID, a device name, or part of
+    /// a name (case-insensitive). The daemon switches to the best available
+    /// device when devices are plugged in or removed, falling back to
+    /// `input_device` and then the system default.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub preferred_devices: Vec<String>,
+
+    /// Application whose audio `openhush record` captures when no
+    /// `--source` is given (Linux). Matches the application name,
+    /// executable or stream title, e.g. "zoom" or "Google Meet".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capture_app: Option<String>,
+
     /// Duration of the always-on audio ring buffer in seconds.
     /// This enables instant recording with no startup delay.
     /// Higher values use more memory (~2MB per 30 seconds at 16kHz).
@@ -972,12 +1557,18 @@ pub struct AudioConfig {
     /// Noise reduction settings (RNNoise)
     #[serde(default)]
     pub noise_reduction: NoiseReductionConfig,
+
+    /// Acoustic echo cancellation (removes speaker bleed from the mic)
+    #[serde(default)]
+    pub echo_cancellation: EchoCancellationConfig,
 }
 
 impl Default for AudioConfig {
     fn default() -> Self {
         Self {
             input_device: None,
+            preferred_devices: Vec::new(),
+            capture_app: None,
             prebuffer_duration_secs: default_prebuffer_duration(),
             resampling_quality: ResamplingQuality::default(),
             channels: ChannelSelection::default(),
@@ -986,38 +1577,71 @@ impl Default for AudioConfig {
             compression: CompressionConfig::default(),
             limiter: LimiterConfig::default(),
             noise_reduction: NoiseReductionConfig::default(),
+            echo_cancellation: EchoCancellationConfig::default(),
         }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct NoiseReductionConfig {
-    /// Enable RNNoise neural network noise reduction
+pub struct EchoCancellationConfig {
+    /// Subtract system audio playing through the speakers from the mic
     #[serde(default)]
     pub enabled: bool,
 
-    /// Strength of noise reduction (0.0 to 1.0)
-    /// Higher values = more aggressive noise removal
-    #[serde(default = "default_noise_reduction_strength")]
-    pub strength: f32,
+    /// System audio source used as the echo reference
+    /// (monitor source on Linux). If not set, uses the default output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference_source: Option<String>,
+
+    /// Longest echo to cancel in milliseconds, counted from the direct
+    /// sound (the speaker-to-mic delay is measured separately). Longer
+    /// tails cost more CPU.
+    #[serde(default = "default_echo_tail_ms")]
+    pub tail_ms: u32,
 }
 
-impl Default for NoiseReductionConfig {
+impl Default for EchoCancellationConfig {
     fn default() -> Self {
         Self {
             enabled: false, // Opt-in feature
-            strength: default_noise_reduction_strength(),
+            reference_source: None,
+            tail_ms: default_echo_tail_ms(),
         }
     }
 }
 
-fn default_noise_reduction_strength() -> f32 {
-    1.0 // Full strength by default when enabled
+fn default_echo_tail_ms() -> u32 {
+    64
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct NormalizationConfig {
-    /// Enable RMS normalization
+pub struct NoiseReductionConfig {
+    /// Enable RNNoise neural network noise reduction
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Strength of noise reduction (0.0 to 1.0)
+    /// Higher values = more aggressive noise removal
+    #[serde(default = "default_noise_reduction_strength")]
+    pub strength: f32,
+}
+
+impl Default for NoiseReductionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false, // Opt-in feature
+            strength: default_noise_reduction_strength(),
+        }
+    }
+}
+
+fn default_noise_reduction_strength() -> f32 {
+    1.0 // Full strength by default when enabled
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NormalizationConfig {
+    /// Enable RMS normalization
     #[serde(default)]
     pub enabled: bool,
 
@@ -1117,6 +1741,10 @@ fn default_chunk_safety_margin() -> f32 {
     0.2 // 20% safety margin for auto-tuned chunk interval
 }
 
+fn default_workers() -> u32 {
+    1
+}
+
 fn default_true() -> bool {
     true
 }
@@ -1163,6 +1791,7 @@ impl Default for HotkeyConfig {
         Self {
             key: default_hotkey(),
             mode: default_mode(),
+            language_key: None,
         }
     }
 }
@@ -1172,11 +1801,18 @@ impl Default for TranscriptionConfig {
         Self {
             preset: TranscriptionPreset::default(),
             model: default_model(),
+            custom_models: Vec::new(),
             language: default_language(),
+            allowed_languages: Vec::new(),
             device: default_device(),
             translate: false,
             idle_unload_secs: 0,
             preload: true,
+            accurate_model: None,
+            engine_memory_budget_mb: 0,
+            refine: RefineMode::Off,
+            backend: TranscriptionBackendKind::default(),
+            remote: RemoteTranscriptionConfig::default(),
         }
     }
 }
@@ -1209,6 +1845,8 @@ impl Default for FeedbackConfig {
         Self {
             audio: true,
             visual: true,
+            media_control: MediaControl::default(),
+            duck_volume: default_duck_volume(),
         }
     }
 }
@@ -1223,6 +1861,8 @@ impl Default for QueueConfig {
             separator: default_separator(),
             chunk_interval_secs: default_chunk_interval(),
             chunk_safety_margin: default_chunk_safety_margin(),
+            workers: default_workers(),
+            journal: false,
         }
     }
 }
@@ -1318,6 +1958,27 @@ impl Config {
             ));
         }
 
+        // Validate replay settings
+        if self.replay.default_secs <= 0.0 {
+            return Err(ConfigError::ValidationError(
+                "replay default_secs must be positive".into(),
+            ));
+        }
+        if let Some(buffer_secs) = self.replay.buffer_secs {
+            if buffer_secs <= 0.0 || buffer_secs > 300.0 {
+                return Err(ConfigError::ValidationError(
+                    "replay buffer_secs must be between 0 and 300 seconds".into(),
+                ));
+            }
+        }
+        if let Some(ref key) = self.replay.key {
+            if key.eq_ignore_ascii_case(&self.hotkey.key) {
+                return Err(ConfigError::ValidationError(
+                    "replay key must differ from the recording hotkey".into(),
+                ));
+            }
+        }
+
         // Validate chunk safety margin
         if self.queue.chunk_safety_margin < 0.0 || self.queue.chunk_safety_margin > 2.0 {
             return Err(ConfigError::ValidationError(
@@ -1325,6 +1986,13 @@ impl Config {
             ));
         }
 
+        if self.queue.workers > QueueConfig::MAX_WORKERS {
+            return Err(ConfigError::ValidationError(format!(
+                "queue workers cannot exceed {}",
+                QueueConfig::MAX_WORKERS
+            )));
+        }
+
         // Validate model name doesn't contain path traversal
         if self.transcription.model.contains("..") || self.transcription.model.contains('/') {
             return Err(ConfigError::ValidationError(
@@ -1332,6 +2000,31 @@ impl Config {
             ));
         }
 
+        // Validate custom model names (used like built-in model names)
+        for (i, custom) in self.transcription.custom_models.iter().enumerate() {
+            if custom.name.is_empty() || custom.name.contains("..") || custom.name.contains('/') {
+                return Err(ConfigError::ValidationError(format!(
+                    "custom model name '{}' is invalid",
+                    custom.name
+                )));
+            }
+            if custom.path.is_empty() {
+                return Err(ConfigError::ValidationError(format!(
+                    "custom model '{}' has no path",
+                    custom.name
+                )));
+            }
+            if self.transcription.custom_models[..i]
+                .iter()
+                .any(|other| other.name == custom.name)
+            {
+                return Err(ConfigError::ValidationError(format!(
+                    "custom model '{}' is defined more than once",
+                    custom.name
+                )));
+            }
+        }
+
         // Validate audio processing parameters
         if self.audio.normalization.target_db > 0.0 {
             return Err(ConfigError::ValidationError(
@@ -1351,6 +2044,23 @@ impl Config {
             ));
         }
 
+        if !(10..=1000).contains(&self.audio.echo_cancellation.tail_ms) {
+            return Err(ConfigError::ValidationError(
+                "echo_cancellation tail_ms must be between 10 and 1000".into(),
+            ));
+        }
+
+        if self
+            .audio
+            .preferred_devices
+            .iter()
+            .any(|d| d.trim().is_empty())
+        {
+            return Err(ConfigError::ValidationError(
+                "preferred_devices entries must not be empty".into(),
+            ));
+        }
+
         // Validate noise reduction settings
         if self.audio.noise_reduction.strength < 0.0 || self.audio.noise_reduction.strength > 1.0 {
             return Err(ConfigError::ValidationError(
@@ -1364,6 +2074,179 @@ impl Config {
                 "vad threshold must be between 0.0 and 1.0".into(),
             ));
         }
+        if !self.vad.max_utterance_secs.is_finite() || self.vad.max_utterance_secs < 0.0 {
+            return Err(ConfigError::ValidationError(
+                "vad max_utterance_secs must be 0 (unlimited) or positive".into(),
+            ));
+        }
+
+        // Validate wake word settings
+        if !(0.0..=1.0).contains(&self.wake_word.threshold) {
+            return Err(ConfigError::ValidationError(
+                "wake_word threshold must be between 0.0 and 1.0".into(),
+            ));
+        }
+        for (i, model) in self.wake_word.models.iter().enumerate() {
+            if model.name.trim().is_empty() || model.path.trim().is_empty() {
+                return Err(ConfigError::ValidationError(
+                    "wake_word models need a name and a path".into(),
+                ));
+            }
+            if model.threshold.is_some_and(|t| !(0.0..=1.0).contains(&t)) {
+                return Err(ConfigError::ValidationError(format!(
+                    "wake_word model '{}' threshold must be between 0.0 and 1.0",
+                    model.name
+                )));
+            }
+            if self.wake_word.models[..i]
+                .iter()
+                .any(|m| m.name.eq_ignore_ascii_case(&model.name))
+            {
+                return Err(ConfigError::ValidationError(format!(
+                    "duplicate wake_word model '{}'",
+                    model.name
+                )));
+            }
+        }
+        if self
+            .wake_word
+            .stop_phrase
+            .as_ref()
+            .is_some_and(|p| p.trim().is_empty())
+        {
+            return Err(ConfigError::ValidationError(
+                "wake_word stop_phrase must not be empty".into(),
+            ));
+        }
+
+        // Validate media ducking volume
+        if !(0.0..=1.0).contains(&self.feedback.duck_volume) {
+            return Err(ConfigError::ValidationError(
+                "feedback duck_volume must be between 0.0 and 1.0".into(),
+            ));
+        }
+
+        // Validate API TLS settings (cert and key must be given together)
+        if self.api.tls.cert_path.is_some() != self.api.tls.key_path.is_some() {
+            return Err(ConfigError::ValidationError(
+                "api.tls cert_path and key_path must be set together".into(),
+            ));
+        }
+
+        let rates = [
+            self.api.limits.per_ip_per_sec,
+            self.api.limits.per_key_per_sec,
+        ];
+        if rates.iter().any(|rate| !rate.is_finite() || *rate <= 0.0) {
+            return Err(ConfigError::ValidationError(
+                "api limits per_ip_per_sec and per_key_per_sec must be positive numbers".into(),
+            ));
+        }
+
+        if self.api.limits.per_ip_burst == 0 || self.api.limits.per_key_burst == 0 {
+            return Err(ConfigError::ValidationError(
+                "api limits burst sizes must be at least 1".into(),
+            ));
+        }
+
+        if self.api.unix_socket_mode > 0o777 {
+            return Err(ConfigError::ValidationError(
+                "api unix_socket_mode must be a permission mode (e.g. 0o600)".into(),
+            ));
+        }
+
+        if let Some(code) = self
+            .transcription
+            .allowed_languages
+            .iter()
+            .find(|code| !is_supported_language(code))
+        {
+            return Err(ConfigError::ValidationError(format!(
+                "transcription allowed_languages: '{}' is not a supported language code (e.g. \"en\")",
+                code
+            )));
+        }
+
+        if let Some(code) = self
+            .translation
+            .extra_targets
+            .iter()
+            .find(|code| !is_supported_language(code))
+        {
+            return Err(ConfigError::ValidationError(format!(
+                "translation extra_targets: '{}' is not a supported language code (e.g. \"fr\")",
+                code
+            )));
+        }
+
+        if let Some(action) = self.output.actions.iter().find(|a| {
+            a.language()
+                .is_some_and(|l| l != "*" && !is_supported_language(l))
+        }) {
+            return Err(ConfigError::ValidationError(format!(
+                "{} action: language must be \"*\" or a language code",
+                action.name()
+            )));
+        }
+
+        if !(0.0..=1.0).contains(&self.translation.back_translation.min_similarity) {
+            return Err(ConfigError::ValidationError(
+                "translation back_translation min_similarity must be between 0.0 and 1.0".into(),
+            ));
+        }
+
+        if let Some(profile) = self.profiles.iter().find(|p| {
+            p.language
+                .as_deref()
+                .is_some_and(|l| l != "auto" && !is_supported_language(l))
+        }) {
+            return Err(ConfigError::ValidationError(format!(
+                "profile '{}': language must be \"auto\" or a language code",
+                profile.name
+            )));
+        }
+
+        if self.transcription.refine != RefineMode::Off
+            && self.transcription.accurate_model.is_none()
+        {
+            return Err(ConfigError::ValidationError(
+                "transcription refine requires accurate_model to be set".into(),
+            ));
+        }
+
+        let remote = &self.transcription.remote;
+        let uses_remote = self.transcription.backend == TranscriptionBackendKind::Remote
+            || !remote.presets.is_empty();
+        if uses_remote && !remote.url.starts_with("https://") && !remote.url.starts_with("http://")
+        {
+            return Err(ConfigError::ValidationError(
+                "transcription remote url must be an http(s) URL".into(),
+            ));
+        }
+
+        if let Some(ref url) = self.models.manifest_url {
+            if !url.starts_with("https://") && !url.starts_with("http://") {
+                return Err(ConfigError::ValidationError(
+                    "models manifest_url must be an http(s) URL".into(),
+                ));
+            }
+        }
+
+        for family in [
+            ModelFamily::Whisper,
+            ModelFamily::M2M100,
+            ModelFamily::WakeWord,
+            ModelFamily::Diarization,
+        ] {
+            if let Some(url) = self.models.mirrors.get(family) {
+                if !url.starts_with("https://") && !url.starts_with("http://") {
+                    return Err(ConfigError::ValidationError(format!(
+                        "model mirror '{}' must be an http(s) URL",
+                        url
+                    )));
+                }
+            }
+        }
 
         // Validate vocabulary path if specified
         if let Some(ref path) = self.vocabulary.path {
@@ -1407,7 +2290,6 @@ impl Config {
     /// Find the matching profile for an app name.
     ///
     /// Returns the first matching profile, or None if no profile matches.
-    #[allow(dead_code)]
     pub fn find_profile(&self, app_name: &str) -> Option<&AppProfile> {
         self.profiles.iter().find(|p| p.matches(app_name))
     }
@@ -1423,6 +2305,33 @@ impl Config {
             None => true, // Default enabled
         }
     }
+
+    /// Get the transcription backend for the given app.
+    ///
+    /// A matching profile's `backend` overrides the preset's backend.
+    pub fn backend_for_app(&self, app_name: &str) -> TranscriptionBackendKind {
+        self.find_profile(app_name)
+            .and_then(|profile| profile.backend)
+            .unwrap_or_else(|| self.transcription.effective_backend())
+    }
+
+    /// Get the transcription language for the given app.
+    ///
+    /// A matching profile's `language` overrides `transcription.language`.
+    pub fn language_for_app(&self, app_name: &str) -> &str {
+        self.find_profile(app_name)
+            .and_then(|profile| profile.language.as_deref())
+            .unwrap_or(&self.transcription.language)
+    }
+
+    /// Duration of the always-on ring buffer in seconds, including room
+    /// for replays.
+    pub fn ring_buffer_secs(&self) -> f32 {
+        match self.replay.buffer_secs {
+            Some(secs) => secs.max(self.audio.prebuffer_duration_secs),
+            None => self.audio.prebuffer_duration_secs,
+        }
+    }
 }
 
 /// Show current configuration
@@ -1620,6 +2529,23 @@ mod tests {
         assert_eq!(config.effective_model(), "tiny");
     }
 
+    #[test]
+    fn test_effective_accurate_model() {
+        let config = TranscriptionConfig {
+            preset: TranscriptionPreset::Instant,
+            ..Default::default()
+        };
+        assert_eq!(config.effective_accurate_model(), "small");
+
+        let config = TranscriptionConfig {
+            preset: TranscriptionPreset::Instant,
+            accurate_model: Some("large-v3".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.effective_model(), "small");
+        assert_eq!(config.effective_accurate_model(), "large-v3");
+    }
+
     // ===================
     // Validation Tests
     // ===================
@@ -1686,6 +2612,58 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_queue_workers() {
+        let mut config = Config::default();
+        assert_eq!(config.queue.worker_count(), 1);
+
+        config.queue.workers = 4;
+        assert_eq!(config.queue.worker_count(), 4);
+        assert!(config.validate().is_ok());
+
+        config.queue.workers = 0;
+        let auto = config.queue.worker_count();
+        assert!((1..=QueueConfig::MAX_WORKERS as usize).contains(&auto));
+
+        config.queue.workers = QueueConfig::MAX_WORKERS + 1;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_queue_journal_default() {
+        // Opt-in, since it keeps speech audio on disk
+        assert!(!Config::default().queue.journal);
+
+        let config: Config = toml::from_str("[queue]\njournal = true\n").unwrap();
+        assert!(config.queue.journal);
+    }
+
+    #[test]
+    fn test_replay_config() {
+        let mut config = Config::default();
+        assert!(config.replay.key.is_none());
+        assert_eq!(config.ring_buffer_secs(), 30.0);
+
+        // A larger replay buffer grows the ring buffer, a smaller one doesn't shrink it
+        config.replay.buffer_secs = Some(120.0);
+        assert!(config.validate().is_ok());
+        assert_eq!(config.ring_buffer_secs(), 120.0);
+        config.replay.buffer_secs = Some(10.0);
+        assert_eq!(config.ring_buffer_secs(), 30.0);
+
+        config.replay.buffer_secs = Some(600.0);
+        assert!(config.validate().is_err());
+        config.replay.buffer_secs = None;
+
+        config.replay.key = Some("controlright".to_string());
+        assert!(config.validate().is_err());
+        config.replay.key = Some("F9".to_string());
+        assert!(config.validate().is_ok());
+
+        config.replay.default_secs = 0.0;
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_validate_model_path_traversal() {
         let mut config = Config::default();
@@ -1703,6 +2681,59 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_validate_custom_models() {
+        let mut config = Config::default();
+        config.transcription.custom_models = vec![CustomModelConfig {
+            name: "legal".into(),
+            path: "~/models/ggml-legal.bin".into(),
+            description: None,
+        }];
+        assert!(config.validate().is_ok());
+        assert!(config.transcription.custom_model("legal").is_some());
+        assert!(config.transcription.custom_model("medical").is_none());
+
+        config
+            .transcription
+            .custom_models
+            .push(config.transcription.custom_models[0].clone());
+        assert!(config
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("more than once"));
+
+        config.transcription.custom_models = vec![CustomModelConfig {
+            name: "../legal".into(),
+            path: "/models/legal.bin".into(),
+            description: None,
+        }];
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_parse_custom_models() {
+        let toml_str = r#"
+            [transcription]
+            preset = "custom"
+            model = "legal"
+
+            [[transcription.custom_models]]
+            name = "legal"
+            path = "/opt/models/ggml-legal-medium-q5_0.bin"
+            description = "Fine-tuned on court transcripts"
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.transcription.effective_model(), "legal");
+        let custom = config.transcription.custom_model("legal").unwrap();
+        assert_eq!(custom.path, "/opt/models/ggml-legal-medium-q5_0.bin");
+        assert_eq!(
+            custom.description.as_deref(),
+            Some("Fine-tuned on court transcripts")
+        );
+    }
+
     #[test]
     fn test_validate_normalization_target() {
         let mut config = Config::default();
@@ -1783,6 +2814,107 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_validate_wake_word_models() {
+        let mut config = Config::default();
+        config.wake_word.models = vec![
+            WakeWordModel {
+                name: "hey jarvis".into(),
+                path: "hey_jarvis_v0.1.onnx".into(),
+                threshold: Some(0.6),
+                action: WakeWordAction::Dictate,
+            },
+            WakeWordModel {
+                name: "alexa".into(),
+                path: "/opt/models/alexa.onnx".into(),
+                threshold: None,
+                action: WakeWordAction::Cancel,
+            },
+        ];
+        assert!(config.validate().is_ok());
+
+        config.wake_word.models[1].threshold = Some(1.5);
+        assert!(config.validate().is_err());
+
+        config.wake_word.models[1].threshold = None;
+        config.wake_word.models[1].name = "Hey Jarvis".into();
+        assert!(config.validate().is_err());
+
+        config.wake_word.models[1].name = "alexa".into();
+        config.wake_word.stop_phrase = Some("  ".into());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_wake_word_models_from_toml() {
+        let config: Config = toml::from_str(
+            r#"
+            [wake_word]
+            enabled = true
+            stop_phrase = "stop dictation"
+
+            [[wake_word.models]]
+            name = "hey jarvis"
+            path = "hey_jarvis_v0.1.onnx"
+
+            [[wake_word.models]]
+            name = "hey meeting"
+            path = "/opt/hey_meeting.onnx"
+            threshold = 0.7
+            action = "meeting"
+            "#,
+        )
+        .unwrap();
+        let models = &config.wake_word.models;
+        assert_eq!(models.len(), 2);
+        assert_eq!(models[0].action, WakeWordAction::Dictate);
+        assert_eq!(models[0].threshold, None);
+        assert_eq!(models[1].action, WakeWordAction::Meeting);
+        assert_eq!(models[1].threshold, Some(0.7));
+        assert_eq!(
+            config.wake_word.stop_phrase.as_deref(),
+            Some("stop dictation")
+        );
+    }
+
+    #[test]
+    fn test_validate_vad_max_utterance() {
+        let mut config = Config::default();
+
+        config.vad.max_utterance_secs = 0.0;
+        assert!(config.validate().is_ok());
+
+        config.vad.max_utterance_secs = -1.0;
+        assert!(config.validate().is_err());
+
+        config.vad.max_utterance_secs = f32::NAN;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_echo_cancellation_tail() {
+        let mut config = Config::default();
+        assert!(!config.audio.echo_cancellation.enabled);
+        assert!(config.validate().is_ok());
+
+        config.audio.echo_cancellation.tail_ms = 5;
+        assert!(config.validate().is_err());
+
+        config.audio.echo_cancellation.tail_ms = 2000;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_preferred_devices() {
+        let mut config = Config::default();
+
+        config.audio.preferred_devices = vec!["Headset".into(), "Built-in".into()];
+        assert!(config.validate().is_ok());
+
+        config.audio.preferred_devices.push("  ".into());
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_validate_vocabulary_path_traversal() {
         let mut config = Config::default();
@@ -1794,6 +2926,262 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("path traversal"));
     }
 
+    #[test]
+    fn test_validate_api_tls_paths() {
+        let mut config = Config::default();
+
+        config.api.tls.cert_path = Some("/etc/openhush/cert.pem".to_string());
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("set together"));
+
+        config.api.tls.key_path = Some("/etc/openhush/key.pem".to_string());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_api_unix_socket_mode() {
+        let mut config = Config::default();
+
+        config.api.unix_socket_mode = 0o660;
+        assert!(config.validate().is_ok());
+
+        config.api.unix_socket_mode = 0o1777;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_api_limits() {
+        let mut config = Config::default();
+
+        config.api.limits.per_ip_per_sec = 0.0;
+        assert!(config.validate().is_err());
+
+        config.api.limits.per_ip_per_sec = f32::NAN;
+        assert!(config.validate().is_err());
+
+        config.api.limits.per_ip_per_sec = 1.0;
+        config.api.limits.per_key_per_sec = f32::INFINITY;
+        assert!(config.validate().is_err());
+
+        config.api.limits.per_key_per_sec = 1.0;
+        config.api.limits.per_key_burst = 0;
+        assert!(config.validate().is_err());
+
+        config.api.limits.per_key_burst = 1;
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_duck_volume() {
+        let mut config = Config::default();
+
+        config.feedback.duck_volume = 0.0;
+        assert!(config.validate().is_ok());
+
+        config.feedback.duck_volume = 1.5;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_refine_requires_accurate_model() {
+        let mut config = Config::default();
+        config.transcription.refine = RefineMode::Replace;
+        assert!(config.validate().is_err());
+
+        config.transcription.accurate_model = Some("large-v3".into());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_allowed_languages() {
+        let mut config = Config::default();
+        config.transcription.allowed_languages = vec!["de".into(), "en".into()];
+        assert!(config.validate().is_ok());
+
+        config.transcription.allowed_languages = vec!["German".into()];
+        assert!(config.validate().is_err());
+
+        // Well-formed, but not a language Whisper knows
+        config.transcription.allowed_languages = vec!["xx".into()];
+        assert!(config.validate().is_err());
+
+        config.transcription.allowed_languages = vec!["auto".into()];
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_translation_targets() {
+        let mut config = Config::default();
+        config.translation.extra_targets = vec!["fr".into(), "es".into()];
+        config.translation.back_translation.min_similarity = 0.3;
+        assert!(config.validate().is_ok());
+
+        config.translation.extra_targets = vec!["French".into()];
+        assert!(config.validate().is_err());
+
+        config.translation.extra_targets.clear();
+        config.translation.back_translation.min_similarity = 1.5;
+        assert!(config.validate().is_err());
+        config.translation.back_translation.min_similarity = 0.5;
+
+        let action = |language: &str| -> ActionConfig {
+            toml::from_str(&format!(
+                "type = \"shell\"\ncommand = \"true\"\nlanguage = \"{}\"",
+                language
+            ))
+            .unwrap()
+        };
+        config.output.actions = vec![action("fr"), action("*")];
+        assert!(config.validate().is_ok());
+        config.output.actions = vec![action("French")];
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_parse_back_translation_defaults() {
+        let config: TranslationConfig = toml::from_str(
+            r#"
+target_language = "en"
+extra_targets = ["fr"]
+
+[back_translation]
+enabled = true
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.extra_targets, vec!["fr".to_string()]);
+        assert!(config.back_translation.enabled);
+        assert_eq!(config.back_translation.min_similarity, 0.5);
+    }
+
+    #[test]
+    fn test_next_language_cycles() {
+        let mut config = TranscriptionConfig {
+            allowed_languages: vec!["de".into(), "en".into()],
+            ..Default::default()
+        };
+        assert_eq!(config.next_language("auto"), "de");
+        assert_eq!(config.next_language("de"), "en");
+        assert_eq!(config.next_language("en"), "auto");
+        assert_eq!(config.next_language("fr"), "auto");
+
+        // Without allowed languages: toggle auto <-> configured language
+        config.allowed_languages.clear();
+        config.language = "de".into();
+        assert_eq!(config.next_language("de"), "auto");
+        assert_eq!(config.next_language("auto"), "de");
+
+        config.language = "auto".into();
+        assert_eq!(config.next_language("auto"), "auto");
+    }
+
+    #[test]
+    fn test_language_for_app() {
+        let toml_str = r#"
+[transcription]
+language = "en"
+
+[[profiles]]
+name = "Team chat"
+apps = ["slack"]
+language = "de"
+"#;
+        let mut config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.language_for_app("Slack"), "de");
+        assert_eq!(config.language_for_app("firefox"), "en");
+
+        config.profiles[0].language = Some("Deutsch".into());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_transcription_backend_selection() {
+        let toml_str = r#"
+[transcription]
+preset = "quality"
+
+[transcription.remote]
+url = "http://whisper.lan:8000/v1"
+presets = ["quality"]
+
+[[profiles]]
+name = "Terminal"
+apps = ["alacritty"]
+backend = "local"
+"#;
+        let mut config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.validate().is_ok());
+        assert!(config.transcription.remote.fallback_to_local);
+        assert_eq!(
+            config.transcription.effective_backend(),
+            TranscriptionBackendKind::Remote
+        );
+        assert_eq!(
+            config.backend_for_app("Alacritty"),
+            TranscriptionBackendKind::Local
+        );
+        assert_eq!(
+            config.backend_for_app("firefox"),
+            TranscriptionBackendKind::Remote
+        );
+
+        config.transcription.preset = TranscriptionPreset::Instant;
+        assert_eq!(
+            config.transcription.effective_backend(),
+            TranscriptionBackendKind::Local
+        );
+
+        config.transcription.remote.url = String::new();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_manifest_url() {
+        let mut config = Config::default();
+        assert!(config.models.manifest_url.is_none());
+
+        config.models.manifest_url = Some("https://models.example.com/manifest.toml".into());
+        assert!(config.validate().is_ok());
+
+        config.models.manifest_url = Some("file:///etc/passwd".into());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_model_mirrors() {
+        let toml = r#"
+            [models.mirrors]
+            whisper = "http://mirror.lan/models/"
+            m2m100 = "http://mirror.lan/models/m2m100"
+        "#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.validate().is_ok());
+
+        let mirrors = &config.models.mirrors;
+        assert_eq!(
+            mirrors.url(ModelFamily::Whisper, "ggml-small.bin", "upstream".into()),
+            "http://mirror.lan/models/ggml-small.bin"
+        );
+        assert_eq!(
+            mirrors.url(
+                ModelFamily::M2M100,
+                "m2m100-418m/tokenizer.json",
+                "upstream".into()
+            ),
+            "http://mirror.lan/models/m2m100/m2m100-418m/tokenizer.json"
+        );
+        assert_eq!(
+            mirrors.url(ModelFamily::WakeWord, "x.onnx", "upstream".into()),
+            "upstream"
+        );
+
+        let mut config = Config::default();
+        config.models.mirrors.diarization = Some("/srv/models".into());
+        assert!(config.validate().is_err());
+    }
+
     // ===================
     // TOML Parsing Tests
     // ===================
@@ -1837,6 +3225,57 @@ backpressure_strategy = "drop_oldest"
             config.queue.backpressure_strategy,
             BackpressureStrategy::DropOldest
         );
+
+        let toml_str = r#"
+[queue]
+backpressure_strategy = "spill_to_disk"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.queue.backpressure_strategy,
+            BackpressureStrategy::SpillToDisk
+        );
+    }
+
+    #[test]
+    fn test_parse_api_tls_and_socket() {
+        let toml_str = r#"
+[api]
+enabled = true
+bind = ""
+unix_socket = "/run/user/1000/openhush-api.sock"
+unix_socket_mode = 0o660
+
+[api.tls]
+enabled = true
+cert_path = "/etc/openhush/cert.pem"
+key_path = "/etc/openhush/key.pem"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.api.bind.is_empty());
+        assert_eq!(
+            config.api.unix_socket.as_deref(),
+            Some("/run/user/1000/openhush-api.sock")
+        );
+        assert_eq!(config.api.unix_socket_mode, 0o660);
+        assert!(config.api.tls.enabled);
+        assert_eq!(
+            config.api.tls.key_path.as_deref(),
+            Some("/etc/openhush/key.pem")
+        );
+    }
+
+    #[test]
+    fn test_parse_feedback_media_control() {
+        let toml_str = r#"
+[feedback]
+media_control = "duck"
+duck_volume = 0.3
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.feedback.media_control, MediaControl::Duck);
+        assert!((config.feedback.duck_volume - 0.3).abs() < f64::EPSILON);
+        assert!(config.feedback.audio);
     }
 
     #[test]
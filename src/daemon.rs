@@ -9,23 +9,34 @@
 
 use crate::api::{self, ApiCommand, ApiState};
 use crate::config::{
-    Config, CorrectionConfig, TranslationConfig, TranslationEngine as TranslationEngineType,
-    VocabularyConfig,
+    BackpressureStrategy, Config, CorrectionConfig, ModelsConfig, RefineMode,
+    TranscriptionBackendKind, TranscriptionConfig, TranslationConfig,
+    TranslationEngine as TranslationEngineType, VocabularyConfig, WakeWordAction,
 };
+use crate::context::ContextDetector;
 use crate::correction::TextCorrector;
 #[cfg(target_os = "linux")]
-use crate::dbus::{DaemonCommand, DaemonStatus, DbusService};
+use crate::dbus::{DaemonStatus, DbusService, MediaController};
 use crate::download_queue::{acquire_download_slot, DownloadPriority};
-use crate::engine::{WhisperEngine, WhisperError};
+use crate::engine::catalog::{resolve_model, ModelSpec};
+use crate::engine::{HttpBackend, HttpBackendConfig, SharedBackend, WhisperEngine, WhisperError};
 #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
 use crate::gui;
-use crate::input::wake_word::{WakeWordDetector, WakeWordError};
-use crate::input::{AudioMark, AudioRecorder, AudioRecorderError, HotkeyEvent, HotkeyListener};
-use crate::ipc::{IpcCommand, IpcEvent, IpcResponse, IpcServer, IpcServerHandle};
+use crate::history::TranscriptionHistory;
+use crate::input::wake_word::{strip_stop_phrase, WakeWordDetector, WakeWordError};
+use crate::input::{
+    load_wav_file, spawn_device_watcher, AudioMark, AudioRecorder, AudioRecorderError,
+    DeviceSnapshot, HotkeyEvent, HotkeyListener,
+};
+use crate::ipc::{IpcCommand, IpcEvent, IpcResponse, IpcServer, IpcServerHandle, WorkerQueueStats};
 use crate::output::{ActionContext, ActionRunner, OutputError, OutputHandler};
 use crate::platform::{AudioFeedback, CurrentPlatform, Notifier, Platform};
 use crate::queue::{
-    worker::spawn_worker, TranscriptionJob, TranscriptionResult, TranscriptionTracker,
+    files::FileJobs,
+    journal::Journal,
+    pool::{spawn_pool, PoolConfig, PoolStats},
+    spill::SpillStore,
+    EngineRole, JobPriority, TranscriptionJob, TranscriptionResult, TranscriptionTracker,
     WorkerCommand,
 };
 use crate::translation::{
@@ -33,12 +44,14 @@ use crate::translation::{
     OllamaTranslator, SentenceBuffer, Translator,
 };
 #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
-use crate::tray::{TrayEvent, TrayManager};
+use crate::tray::{TrayEvent, TrayManager, TrayState, TrayStatus, TRAY_RECENT_LIMIT};
 use crate::vad::VadConfig;
 use crate::vad::{silero::SileroVad, VadEngine, VadError, VadState};
 use crate::vocabulary::{VocabularyError, VocabularyManager};
 use futures_util::FutureExt;
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use thiserror::Error;
 use tokio::sync::{mpsc, RwLock};
@@ -53,6 +66,9 @@ const VAD_PROCESS_INTERVAL_MS: u64 = 32;
 /// Audio level update interval for UI visualization (50ms = 20 Hz)
 const AUDIO_LEVEL_INTERVAL_MS: u64 = 50;
 
+/// How often to check for audio input devices being plugged in or removed
+const DEVICE_POLL_INTERVAL_SECS: u64 = 2;
+
 // ============================================================================
 // Initialization Functions
 // ============================================================================
@@ -158,7 +174,10 @@ async fn init_corrector(config: &CorrectionConfig) -> Option<Arc<TextCorrector>>
 ///
 /// For M2M-100: If model not downloaded, spawns background download and returns
 /// Ollama as fallback translator while downloading.
-async fn init_translator(config: &TranslationConfig) -> Option<Arc<Translator>> {
+pub(crate) async fn init_translator(
+    config: &TranslationConfig,
+    models: &ModelsConfig,
+) -> Option<Arc<Translator>> {
     if !config.enabled {
         return None;
     }
@@ -217,7 +236,7 @@ async fn init_translator(config: &TranslationConfig) -> Option<Arc<Translator>>
                 );
 
                 // Spawn background download task
-                spawn_m2m100_download(model);
+                spawn_m2m100_download(model, models.clone());
 
                 // Return Ollama as fallback while downloading
                 info!("Using Ollama for translation while M2M-100 downloads...");
@@ -259,7 +278,14 @@ async fn init_ollama_fallback(config: &TranslationConfig) -> Option<Arc<Translat
 }
 
 /// Spawn background task to download Whisper model (high priority).
-fn spawn_whisper_download(model: crate::engine::whisper::WhisperModel) {
+///
+/// Progress is announced as `ModelProgress` with the "downloading" status.
+fn spawn_whisper_download(
+    model: ModelSpec,
+    models: ModelsConfig,
+    name: String,
+    events: Arc<DaemonEvents>,
+) {
     use crate::engine::whisper::{download_model, format_size};
 
     tokio::spawn(async move {
@@ -271,12 +297,29 @@ fn spawn_whisper_download(model: crate::engine::whisper::WhisperModel) {
             model.filename()
         );
 
-        let mut last_percent = 0u32;
-        let result = download_model(model, |downloaded, total| {
+        // The callback can't await, so progress goes out from its own task
+        let (progress_tx, mut progress_rx) = mpsc::unbounded_channel::<f32>();
+        let announce = {
+            let events = Arc::clone(&events);
+            let name = name.clone();
+            tokio::spawn(async move {
+                while let Some(progress) = progress_rx.recv().await {
+                    events.model_progress(&name, progress, "downloading").await;
+                }
+            })
+        };
+
+        let mut last_logged = 0u32;
+        let mut last_announced = None;
+        let result = download_model(model, &models, |downloaded, total| {
             if total > 0 {
                 let percent = ((downloaded as f64 / total as f64) * 100.0) as u32;
-                if percent >= last_percent + 10 {
-                    last_percent = percent;
+                if last_announced != Some(percent) {
+                    last_announced = Some(percent);
+                    let _ = progress_tx.send(percent as f32 / 100.0);
+                }
+                if percent >= last_logged + 10 {
+                    last_logged = percent;
                     info!(
                         "Whisper download: {}% ({} / {})",
                         percent,
@@ -287,6 +330,8 @@ fn spawn_whisper_download(model: crate::engine::whisper::WhisperModel) {
             }
         })
         .await;
+        drop(progress_tx);
+        let _ = announce.await;
 
         // Guard dropped here, releasing the download slot
 
@@ -297,6 +342,7 @@ fn spawn_whisper_download(model: crate::engine::whisper::WhisperModel) {
                     model.filename(),
                     path.display()
                 );
+                events.model_progress(&name, 1.0, "downloaded").await;
                 info!("Restart daemon to enable transcription.");
 
                 // Show desktop notification
@@ -310,6 +356,7 @@ fn spawn_whisper_download(model: crate::engine::whisper::WhisperModel) {
             }
             Err(e) => {
                 error!("Failed to download Whisper {}: {}", model.filename(), e);
+                events.error("model_download_failed", &e.to_string()).await;
             }
         }
     });
@@ -317,7 +364,7 @@ fn spawn_whisper_download(model: crate::engine::whisper::WhisperModel) {
 
 /// Spawn background task to download M2M-100 model (low priority).
 /// Waits for any higher-priority downloads to complete first.
-fn spawn_m2m100_download(model: M2M100Model) {
+fn spawn_m2m100_download(model: M2M100Model, models: ModelsConfig) {
     tokio::spawn(async move {
         // Acquire low-priority download slot (waits for high/medium priority downloads)
         info!("M2M-100 download queued (low priority)...");
@@ -326,7 +373,7 @@ fn spawn_m2m100_download(model: M2M100Model) {
         info!("Background download started for M2M-100 {}", model.name());
 
         let mut last_percent = 0u64;
-        let result = download_m2m100_model(model, |filename, downloaded, total| {
+        let result = download_m2m100_model(model, &models, |filename, downloaded, total| {
             // Log progress every 10%
             if let Some(percent) = (downloaded * 100).checked_div(total) {
                 if percent >= last_percent + 10 {
@@ -367,7 +414,7 @@ fn spawn_m2m100_download(model: M2M100Model) {
 
 /// Spawn background task to download wake word models (medium priority).
 /// Waits for any higher-priority downloads to complete first.
-fn spawn_wake_word_download() {
+fn spawn_wake_word_download(models: ModelsConfig) {
     use crate::input::wake_word::WakeWordDetector;
 
     tokio::spawn(async move {
@@ -377,7 +424,7 @@ fn spawn_wake_word_download() {
 
         info!("Background download started for wake word models");
 
-        match WakeWordDetector::download_models().await {
+        match WakeWordDetector::download_models(&models).await {
             Ok(_) => {
                 info!("Wake word models downloaded successfully");
                 info!("Restart daemon to enable wake word detection.");
@@ -415,6 +462,57 @@ fn notify_backpressure(notify_enabled: bool) {
     }
 }
 
+/// Start recording a meeting (microphone and system audio) in a separate
+/// `openhush record` process.
+///
+/// Returns the process and the transcript path.
+fn spawn_meeting_recording() -> Result<(std::process::Child, PathBuf), DaemonError> {
+    let dir = Config::data_dir()?.join("recordings");
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!(
+        "meeting-{}.txt",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+
+    let child = std::process::Command::new(std::env::current_exe()?)
+        .args([
+            "record",
+            "--source",
+            "both",
+            "--format",
+            "timestamped",
+            "--output",
+        ])
+        .arg(&path)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .spawn()?;
+    Ok((child, path))
+}
+
+/// Stop a meeting recording, letting it write the transcript.
+fn stop_meeting_recording(mut child: std::process::Child) {
+    #[cfg(unix)]
+    {
+        use nix::sys::signal::{kill, Signal};
+        use nix::unistd::Pid;
+
+        // Same as Ctrl+C: the recorder finishes transcribing before exiting
+        if let Err(e) = kill(Pid::from_raw(child.id() as i32), Signal::SIGINT) {
+            warn!("Failed to stop meeting recording: {}", e);
+        }
+    }
+    #[cfg(not(unix))]
+    if let Err(e) = child.kill() {
+        warn!("Failed to stop meeting recording: {}", e);
+    }
+
+    // Reap the process without blocking the daemon
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+}
+
 /// Reload configuration from disk and update runtime state.
 fn reload_config(config: &mut Config, separator: &mut String) {
     match Config::load() {
@@ -447,6 +545,477 @@ impl BackpressureConfig {
             notify: queue.notify_on_backpressure,
         }
     }
+
+    /// Register a chunk with the tracker, applying the backpressure strategy.
+    ///
+    /// Chunks dropped to make room are removed from the journal, as their
+    /// text will never be output. Returns whether the chunk was accepted.
+    fn admit(
+        &self,
+        tracker: &mut TranscriptionTracker,
+        journal: &mut Option<Journal>,
+        sequence_id: u64,
+        chunk_id: u32,
+    ) -> bool {
+        let accepted = tracker.add_pending_with_config(
+            sequence_id,
+            chunk_id,
+            self.max_pending,
+            self.high_water_mark,
+            self.strategy,
+        );
+        for (sequence_id, chunk_id) in tracker.take_dropped() {
+            if let Some(journal) = journal.as_mut() {
+                journal.complete(sequence_id, chunk_id);
+            }
+        }
+        accepted
+    }
+}
+
+/// Publishes daemon events to history, D-Bus and IPC clients.
+///
+/// Bundles the notification channels so call sites in the main loop do not
+/// need to repeat platform-specific code.
+struct DaemonEvents {
+    history: Arc<RwLock<TranscriptionHistory>>,
+    ipc_handle: Option<IpcServerHandle>,
+    #[cfg(target_os = "linux")]
+    dbus_service: Option<DbusService>,
+    #[cfg(target_os = "linux")]
+    dbus_status: Arc<RwLock<DaemonStatus>>,
+}
+
+impl DaemonEvents {
+    /// Record output text in history and announce it.
+    async fn transcription(
+        &self,
+        text: &str,
+        duration_secs: f32,
+        llm_corrected: bool,
+        sequence_id: u64,
+        language: Option<&str>,
+    ) {
+        let item =
+            self.history
+                .write()
+                .await
+                .push(text.trim(), duration_secs, llm_corrected, language);
+
+        if let Some(ref handle) = self.ipc_handle {
+            handle.broadcast(IpcEvent::TranscriptionComplete {
+                id: item.id,
+                recording_id: sequence_id,
+                text: item.text.clone(),
+                duration_secs: item.duration_secs,
+                llm_corrected,
+                language: item.language.clone(),
+            });
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            self.dbus_status.write().await.last_transcription = item.text.clone();
+            if let Some(ref service) = self.dbus_service {
+                if let Err(e) = service.emit_transcription_complete(&item).await {
+                    debug!("Failed to emit D-Bus TranscriptionComplete: {}", e);
+                }
+            }
+        }
+    }
+
+    /// Announce a failure to clients.
+    async fn error(&self, code: &str, message: &str) {
+        if let Some(ref handle) = self.ipc_handle {
+            handle.broadcast(IpcEvent::Error {
+                code: code.to_string(),
+                message: message.to_string(),
+            });
+        }
+
+        #[cfg(target_os = "linux")]
+        if let Some(ref service) = self.dbus_service {
+            if let Err(e) = service.emit_error(code, message).await {
+                debug!("Failed to emit D-Bus ErrorOccurred: {}", e);
+            }
+        }
+    }
+
+    /// Announce model load/unload progress (0.0 to 1.0).
+    async fn model_progress(&self, model: &str, progress: f32, status: &str) {
+        if let Some(ref handle) = self.ipc_handle {
+            handle.broadcast(IpcEvent::ModelProgress {
+                model: model.to_string(),
+                progress,
+                status: status.to_string(),
+            });
+        }
+
+        #[cfg(target_os = "linux")]
+        if let Some(ref service) = self.dbus_service {
+            if let Err(e) = service
+                .emit_model_progress(model, f64::from(progress), status)
+                .await
+            {
+                debug!("Failed to emit D-Bus ModelProgress: {}", e);
+            }
+        }
+    }
+
+    /// Announce that capture moved to another input device.
+    fn device_changed(&self, device_id: &str, name: &str) {
+        if let Some(ref handle) = self.ipc_handle {
+            handle.broadcast(IpcEvent::DeviceChanged {
+                device_id: device_id.to_string(),
+                name: name.to_string(),
+            });
+        }
+    }
+
+    /// Update live status fields and emit D-Bus `PropertiesChanged` for
+    /// anything that changed since the last call.
+    #[cfg(target_os = "linux")]
+    async fn sync_status(&self, is_recording: bool, hands_free: bool, queue_depth: usize) {
+        let Some(ref service) = self.dbus_service else {
+            return;
+        };
+        let snapshot = {
+            let mut status = self.dbus_status.write().await;
+            status.is_recording = is_recording;
+            status.hands_free = hands_free;
+            status.queue_depth = queue_depth as u32;
+            status.clone()
+        };
+        if let Err(e) = service.sync_properties(&snapshot).await {
+            debug!("Failed to emit D-Bus property changes: {}", e);
+        }
+    }
+}
+
+/// Get the path to a named Whisper model file.
+fn model_path_for(transcription: &TranscriptionConfig, name: &str) -> Result<PathBuf, DaemonError> {
+    match resolve_model(name, transcription) {
+        Ok(resolved) => Ok(resolved.path()?),
+        // Unknown names keep the ggml-<name>.bin convention so that
+        // manually placed model files still load
+        Err(_) => {
+            let data_dir = Config::data_dir()?;
+            Ok(data_dir.join("models").join(format!("ggml-{}.bin", name)))
+        }
+    }
+}
+
+/// Create a new Whisper engine for a named model.
+fn create_engine_for(
+    transcription: &TranscriptionConfig,
+    name: &str,
+) -> Result<WhisperEngine, DaemonError> {
+    let model_path = model_path_for(transcription, name)?;
+
+    if !model_path.exists() {
+        return Err(DaemonError::Whisper(WhisperError::ModelNotFound(
+            model_path,
+            name.to_string(),
+        )));
+    }
+
+    let use_gpu = transcription.device.to_lowercase() != "cpu";
+    let mut engine = WhisperEngine::new(
+        &model_path,
+        &transcription.language,
+        transcription.translate,
+        use_gpu,
+    )?;
+    engine.set_allowed_languages(&transcription.allowed_languages);
+
+    Ok(engine)
+}
+
+/// Name of the accurate model, if it differs from the live model.
+fn accurate_model_name(transcription: &TranscriptionConfig) -> Option<&str> {
+    let name = transcription.accurate_model.as_deref()?;
+    (name != transcription.effective_model()).then_some(name)
+}
+
+/// Build the remote transcription backend if the preset or an app profile
+/// uses it. Each job then says whether it goes to the server.
+///
+/// Errors are logged and leave transcription on the local model.
+fn create_remote_backend(config: &Config) -> Option<SharedBackend> {
+    let transcription = &config.transcription;
+    let used = transcription.effective_backend() == TranscriptionBackendKind::Remote
+        || config
+            .profiles
+            .iter()
+            .any(|p| p.backend == Some(TranscriptionBackendKind::Remote));
+    if !used {
+        return None;
+    }
+    let remote = &transcription.remote;
+    let api_key = if remote.api_key.is_empty() {
+        String::new()
+    } else {
+        match crate::secrets::resolve_secret(&remote.api_key, &crate::secrets::SecretStore::new()) {
+            Ok(key) => key,
+            Err(e) => {
+                warn!("Failed to resolve remote transcription API key: {}", e);
+                return None;
+            }
+        }
+    };
+    let config = HttpBackendConfig {
+        base_url: remote.url.clone(),
+        api_key,
+        model: remote.model.clone(),
+        language: transcription.language.clone(),
+        translate: transcription.translate,
+        timeout_secs: remote.timeout_secs,
+    };
+    match HttpBackend::new(config) {
+        Ok(backend) => Some(Arc::new(backend)),
+        Err(e) => {
+            warn!("Remote transcription unavailable, using local model: {}", e);
+            None
+        }
+    }
+}
+
+/// Load state of the accurate engine used for refinement passes.
+struct AccurateEngineState {
+    /// The accurate engine was sent to the workers
+    ready: AtomicBool,
+    /// A background load is in progress
+    loading: AtomicBool,
+    /// Pool evictions of the accurate engine when it was last sent
+    evictions: AtomicU64,
+    stats: PoolStats,
+}
+
+impl AccurateEngineState {
+    fn new(stats: PoolStats) -> Self {
+        Self {
+            ready: AtomicBool::new(false),
+            loading: AtomicBool::new(false),
+            evictions: AtomicU64::new(0),
+            stats,
+        }
+    }
+
+    /// Whether the workers still have the accurate engine resident.
+    fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::Acquire)
+            && self.stats.accurate_evictions() == self.evictions.load(Ordering::Acquire)
+    }
+
+    fn set_ready(&self, ready: bool) {
+        self.ready.store(ready, Ordering::Release);
+    }
+
+    /// Hand a loaded accurate engine to the workers.
+    ///
+    /// Returns false if the worker channel is closed.
+    async fn send(&self, engine: WhisperEngine, command_tx: &mpsc::Sender<WorkerCommand>) -> bool {
+        // Evictions from here on undo this load
+        self.evictions
+            .store(self.stats.accurate_evictions(), Ordering::Release);
+        let sent = command_tx
+            .send(WorkerCommand::LoadEngineAs(EngineRole::Accurate, engine))
+            .await
+            .is_ok();
+        self.set_ready(sent);
+        sent
+    }
+
+    /// Load the accurate engine off the main loop, unless already loading.
+    ///
+    /// Recordings made while it loads are not refined.
+    fn load_in_background(
+        self: &Arc<Self>,
+        transcription: TranscriptionConfig,
+        command_tx: mpsc::Sender<WorkerCommand>,
+    ) {
+        if self.loading.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let state = Arc::clone(self);
+        tokio::spawn(async move {
+            let loaded = tokio::task::spawn_blocking(move || {
+                let name = accurate_model_name(&transcription)?.to_string();
+                Some(
+                    create_engine_for(&transcription, &name)
+                        .map(|engine| (name, engine))
+                        .map_err(|e| e.to_string()),
+                )
+            })
+            .await;
+            match loaded {
+                Ok(Some(Ok((name, engine)))) => {
+                    if state.send(engine, &command_tx).await {
+                        info!("Accurate model loaded for refinement ({})", name);
+                    }
+                }
+                Ok(Some(Err(e))) => warn!("Failed to load accurate model: {}", e),
+                Ok(None) => {}
+                Err(e) => warn!("Accurate model load task failed: {}", e),
+            }
+            state.loading.store(false, Ordering::Release);
+        });
+    }
+}
+
+/// Apply vocabulary replacements and LLM correction to transcribed text.
+async fn apply_text_pipeline(
+    mut text: String,
+    vocabulary_manager: &Option<Arc<VocabularyManager>>,
+    text_corrector: &Option<Arc<TextCorrector>>,
+) -> String {
+    // Apply vocabulary replacements
+    if let Some(ref vocab) = vocabulary_manager {
+        text = vocab.apply(&text).await;
+    }
+
+    // Apply LLM correction (includes filler removal)
+    if let Some(ref corrector) = text_corrector {
+        match corrector.correct(&text).await {
+            Ok(corrected) => text = corrected,
+            Err(e) => warn!("LLM correction failed: {}", e),
+        }
+    }
+
+    text
+}
+
+/// Journal a job's audio before it is submitted.
+fn journal_job(journal: &mut Option<Journal>, job: &TranscriptionJob) {
+    if let Some(journal) = journal {
+        journal.record(job);
+    }
+}
+
+/// Save a dictation recovered from the journal to history.
+///
+/// The window it was meant for is gone, so the text is not typed. If it
+/// could not be transcribed the audio stays journaled for the next start.
+/// Returns whether text was saved.
+async fn output_recovered(
+    result: TranscriptionResult,
+    journal: &mut Option<Journal>,
+    vocabulary_manager: &Option<Arc<VocabularyManager>>,
+    text_corrector: &Option<Arc<TextCorrector>>,
+    events: &DaemonEvents,
+) -> bool {
+    if let Some(ref error) = result.error {
+        warn!(
+            "Recovered dictation failed, keeping its audio for the next start: {}",
+            error
+        );
+        return false;
+    }
+
+    let text = apply_text_pipeline(result.text, vocabulary_manager, text_corrector).await;
+    let saved = !text.trim().is_empty();
+    if saved {
+        info!("♻️ Recovered dictation ({} chars)", text.len());
+        events
+            .transcription(
+                &text,
+                result.duration_secs,
+                text_corrector.is_some(),
+                result.sequence_id,
+                result.language.as_deref(),
+            )
+            .await;
+    }
+    if let Some(journal) = journal.as_mut() {
+        journal.complete(result.sequence_id, result.chunk_id);
+    }
+    saved
+}
+
+/// Output refinements whose drafts have been fully output.
+///
+/// The refined text is post-processed like the draft, and translated when
+/// the draft was (`translators` holds the translator of such recordings).
+/// If it differs, it goes to history and clipboard, and in `Replace` mode
+/// the typed draft is corrected in place when it is still the most recent
+/// output.
+#[allow(clippy::too_many_arguments)]
+async fn output_refinements(
+    tracker: &mut TranscriptionTracker,
+    refine_mode: RefineMode,
+    vocabulary_manager: &Option<Arc<VocabularyManager>>,
+    text_corrector: &Option<Arc<TextCorrector>>,
+    translators: &mut HashMap<u64, Arc<Translator>>,
+    translation_config: &TranslationConfig,
+    output_handler: &OutputHandler,
+    events: &DaemonEvents,
+) {
+    for refinement in tracker.take_refinements() {
+        let result = &refinement.result;
+        let translator = translators.remove(&result.sequence_id);
+        if result.error.is_some() || result.text.trim().is_empty() {
+            debug!("Refinement produced no text (seq {})", result.sequence_id);
+            continue;
+        }
+
+        let mut refined =
+            apply_text_pipeline(result.text.clone(), vocabulary_manager, text_corrector).await;
+        if let Some(translator) = translator {
+            let source_lang = result.language.as_deref().unwrap_or("auto");
+            let target_lang = &translation_config.target_language;
+            match translator
+                .translate(&refined, source_lang, target_lang)
+                .await
+            {
+                Ok(translated) => {
+                    refined =
+                        format_translation(&refined, &translated, source_lang, translation_config);
+                }
+                Err(e) => {
+                    // Keep the translated draft rather than replace it with the source
+                    warn!(
+                        "Refinement translation failed (seq {}): {}",
+                        result.sequence_id, e
+                    );
+                    continue;
+                }
+            }
+        }
+        if refined
+            .split_whitespace()
+            .eq(refinement.draft.split_whitespace())
+        {
+            debug!("Refinement matches draft (seq {})", result.sequence_id);
+            continue;
+        }
+
+        let in_place = refine_mode == RefineMode::Replace
+            && tracker.last_output_id() == Some(result.sequence_id);
+        info!(
+            "✨ Refined output (seq {}, {} chars{})",
+            result.sequence_id,
+            refined.len(),
+            if in_place { ", replacing draft" } else { "" }
+        );
+        if let Err(e) =
+            output_handler.refine(&refinement.draft, &refinement.retyped(&refined), in_place)
+        {
+            error!("Refined output failed: {}", e);
+        }
+
+        events
+            .transcription(
+                &refined,
+                result.duration_secs,
+                text_corrector.is_some(),
+                result.sequence_id,
+                result.language.as_deref(),
+            )
+            .await;
+    }
+
+    // Forget translators of refinements that were dropped
+    translators.retain(|&sequence_id, _| tracker.expects_refinement(sequence_id));
 }
 
 /// Process and output a transcription result.
@@ -457,6 +1026,8 @@ impl BackpressureConfig {
 /// Translation uses sentence buffering - text is accumulated until complete
 /// sentences are detected, then translated and output. On is_final, the buffer
 /// is flushed to ensure all remaining text is processed.
+///
+/// Returns the texts that were output, in order.
 #[allow(clippy::too_many_arguments)]
 async fn process_and_output(
     result: TranscriptionResult,
@@ -468,8 +1039,9 @@ async fn process_and_output(
     sentence_buffer: &mut SentenceBuffer,
     output_handler: &OutputHandler,
     action_runner: &ActionRunner,
+    follow_ups: &TranslationFollowUps,
     model_name: &str,
-) {
+) -> Vec<String> {
     if result.text.is_empty() {
         debug!(
             "Empty transcription result (seq {}.{})",
@@ -477,19 +1049,23 @@ async fn process_and_output(
         );
         // Even if empty, flush buffer on final chunk
         if result.is_final {
-            flush_and_translate(
+            return flush_and_translate(
                 sentence_buffer,
                 translator,
                 translation_config,
                 output_handler,
                 action_runner,
+                follow_ups,
                 model_name,
                 result.sequence_id,
                 result.duration_secs,
+                result.language.as_deref(),
             )
-            .await;
+            .await
+            .into_iter()
+            .collect();
         }
-        return;
+        return Vec::new();
     }
 
     // Add separator before chunks after the first
@@ -498,18 +1074,7 @@ async fn process_and_output(
         text.insert_str(0, chunk_separator);
     }
 
-    // Apply vocabulary replacements
-    if let Some(ref vocab) = vocabulary_manager {
-        text = vocab.apply(&text).await;
-    }
-
-    // Apply LLM correction (includes filler removal)
-    if let Some(ref corrector) = text_corrector {
-        match corrector.correct(&text).await {
-            Ok(corrected) => text = corrected,
-            Err(e) => warn!("LLM correction failed: {}", e),
-        }
-    }
+    let text = apply_text_pipeline(text, vocabulary_manager, text_corrector).await;
 
     // If translation is disabled, output directly
     if translator.is_none() {
@@ -523,42 +1088,52 @@ async fn process_and_output(
             result.duration_secs,
         )
         .await;
-        return;
+        return vec![text];
     }
 
     // Translation enabled - use sentence buffer
     let sentences = sentence_buffer.add(&text);
+    let mut outputs = Vec::with_capacity(sentences.len() + 1);
 
     // Translate and output complete sentences
     for sentence in sentences {
-        translate_and_output(
+        let output = translate_and_output(
             &sentence,
             translator,
             translation_config,
             output_handler,
             action_runner,
+            follow_ups,
             model_name,
             result.sequence_id,
             result.chunk_id,
             result.duration_secs,
+            result.language.as_deref().unwrap_or("auto"),
         )
         .await;
+        outputs.push(output);
     }
 
     // On final chunk, flush remaining buffer
     if result.is_final {
-        flush_and_translate(
-            sentence_buffer,
-            translator,
-            translation_config,
-            output_handler,
-            action_runner,
-            model_name,
-            result.sequence_id,
-            result.duration_secs,
-        )
-        .await;
+        outputs.extend(
+            flush_and_translate(
+                sentence_buffer,
+                translator,
+                translation_config,
+                output_handler,
+                action_runner,
+                follow_ups,
+                model_name,
+                result.sequence_id,
+                result.duration_secs,
+                result.language.as_deref(),
+            )
+            .await,
+        );
     }
+
+    outputs
 }
 
 /// Output text without translation.
@@ -593,7 +1168,33 @@ async fn output_text(
     }
 }
 
+/// Text to type for a translation, with the original if it is preserved.
+fn format_translation(
+    text: &str,
+    translated: &str,
+    source_lang: &str,
+    translation_config: &TranslationConfig,
+) -> String {
+    if translation_config.preserve_original {
+        format!(
+            "[{}] {}\n[{}] {}",
+            source_lang.to_uppercase(),
+            text,
+            translation_config.target_language.to_uppercase(),
+            translated
+        )
+    } else {
+        translated.to_string()
+    }
+}
+
 /// Translate a sentence and output.
+///
+/// The translation into `target_language` is typed right away. The
+/// back-translation check and the translations into `extra_targets` are
+/// handed to `follow_ups`, so they never hold up the next output.
+///
+/// Returns the text that was output (translation or original on failure).
 #[allow(clippy::too_many_arguments)]
 async fn translate_and_output(
     text: &str,
@@ -601,36 +1202,37 @@ async fn translate_and_output(
     translation_config: &TranslationConfig,
     output_handler: &OutputHandler,
     action_runner: &ActionRunner,
+    follow_ups: &TranslationFollowUps,
     model_name: &str,
     sequence_id: u64,
     chunk_id: u32,
     duration_secs: f32,
-) {
+    source_lang: &str,
+) -> String {
     let Some(ref trans) = translator else {
-        return;
+        return String::new();
     };
 
-    let source_lang = "auto";
     let target_lang = &translation_config.target_language;
+    let ctx = ActionContext::new(
+        text.to_string(),
+        duration_secs,
+        model_name.to_string(),
+        sequence_id,
+    );
 
-    let output = match trans.translate(text, source_lang, target_lang).await {
+    let (output, primary) = match trans.translate(text, source_lang, target_lang).await {
         Ok(translated) => {
             debug!("Translation: '{}' -> '{}'", text, translated);
-            if translation_config.preserve_original {
-                format!(
-                    "[{}] {}\n[{}] {}",
-                    source_lang.to_uppercase(),
-                    text,
-                    target_lang.to_uppercase(),
-                    translated
-                )
-            } else {
-                translated
-            }
+            let output = format_translation(text, &translated, source_lang, translation_config);
+            let mut primary_ctx = ctx.clone().with_language(target_lang);
+            primary_ctx.text.clone_from(&output);
+            (output, Some((translated, primary_ctx)))
         }
         Err(e) => {
             warn!("Translation failed: {}", e);
-            text.to_string() // Fall back to original
+            // Fall back to original
+            (text.to_string(), None)
         }
     };
 
@@ -645,42 +1247,203 @@ async fn translate_and_output(
         error!("Output failed: {}", e);
     }
 
-    if action_runner.has_actions() {
-        let ctx = ActionContext::new(output, duration_secs, model_name.to_string(), sequence_id);
-        action_runner.run_all(&ctx).await;
+    // Actions get the typed text as is unless it is still to be checked
+    let check = translation_config.back_translation.enabled && primary.is_some();
+    if action_runner.has_actions() && !check {
+        let primary_ctx = match &primary {
+            Some((_, primary_ctx)) => primary_ctx.clone(),
+            None => ctx.clone(),
+        };
+        action_runner.run_all(&primary_ctx).await;
     }
-}
 
-/// Flush sentence buffer and translate remaining text.
-#[allow(clippy::too_many_arguments)]
+    // Extra targets are only of use to language-bound actions
+    let extra_targets: Vec<String> = if action_runner.has_language_actions() {
+        translation_config
+            .extra_targets
+            .iter()
+            .filter(|lang| *lang != target_lang)
+            .cloned()
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    if check || !extra_targets.is_empty() {
+        follow_ups.submit(TranslationFollowUp {
+            translator: Arc::clone(trans),
+            source_lang: source_lang.to_string(),
+            target_lang: target_lang.clone(),
+            primary: primary.filter(|_| check),
+            extra_targets,
+            ctx,
+            check: translation_config.back_translation.enabled,
+            min_similarity: translation_config.back_translation.min_similarity,
+        });
+    }
+
+    output
+}
+
+/// Translation work left once a sentence has been typed: the
+/// back-translation check of the typed translation and the translations
+/// into extra targets, with the actions that receive them.
+struct TranslationFollowUp {
+    translator: Arc<Translator>,
+    source_lang: String,
+    target_lang: String,
+    /// Typed translation (without the original) and its action context,
+    /// when it still has to be checked
+    primary: Option<(String, ActionContext)>,
+    extra_targets: Vec<String>,
+    /// Context of the source sentence
+    ctx: ActionContext,
+    check: bool,
+    min_similarity: f32,
+}
+
+/// Runs [`TranslationFollowUp`]s on a background task, one at a time so
+/// actions still see sentences in the order they were dictated.
+#[derive(Clone)]
+struct TranslationFollowUps {
+    tx: mpsc::UnboundedSender<TranslationFollowUp>,
+}
+
+impl TranslationFollowUps {
+    /// Start the background task. With `notify`, translations whose round
+    /// trip diverges too far are shown in a notification.
+    fn spawn(action_runner: Arc<ActionRunner>, notify: bool) -> Self {
+        let (tx, mut rx) = mpsc::unbounded_channel::<TranslationFollowUp>();
+        tokio::spawn(async move {
+            while let Some(follow_up) = rx.recv().await {
+                follow_up.run(&action_runner, notify).await;
+            }
+        });
+        Self { tx }
+    }
+
+    fn submit(&self, follow_up: TranslationFollowUp) {
+        if self.tx.send(follow_up).is_err() {
+            debug!("Translation follow-up task stopped, dropping work");
+        }
+    }
+}
+
+impl TranslationFollowUp {
+    async fn run(mut self, action_runner: &ActionRunner, notify: bool) {
+        let source = &self.ctx.text;
+        if let Some((translated, primary_ctx)) = self.primary.take() {
+            let similarity = self
+                .translator
+                .round_trip(source, &translated, &self.source_lang, &self.target_lang)
+                .await;
+            let primary_ctx = self.round_trip_context(primary_ctx, similarity, notify);
+            if action_runner.has_actions() {
+                action_runner.run_all(&primary_ctx).await;
+            }
+        }
+
+        for lang in &self.extra_targets {
+            match self
+                .translator
+                .translate_checked(source, &self.source_lang, lang, self.check)
+                .await
+            {
+                Ok((translated, similarity)) => {
+                    debug!("Translation ({}): '{}' -> '{}'", lang, source, translated);
+                    let mut ctx = self.ctx.clone().with_language(lang).for_extra_target();
+                    ctx.text = translated;
+                    let ctx = self.round_trip_context(ctx, similarity, notify);
+                    action_runner.run_all(&ctx).await;
+                }
+                Err(e) => warn!("Translation into '{}' failed: {}", lang, e),
+            }
+        }
+    }
+
+    /// Attach a back-translation result to an action context, warning (and
+    /// with `notify`, telling the user) when the similarity falls below the
+    /// configured threshold.
+    fn round_trip_context(
+        &self,
+        ctx: ActionContext,
+        similarity: Option<f32>,
+        notify: bool,
+    ) -> ActionContext {
+        let Some(similarity) = similarity else {
+            return ctx;
+        };
+        let flagged = similarity < self.min_similarity;
+        if flagged {
+            let language = ctx.language.as_deref().unwrap_or_default();
+            warn!(
+                "Translation into '{}' may be unreliable: round trip kept {:.0}% of the source (seq {})",
+                language,
+                similarity * 100.0,
+                ctx.seq_id
+            );
+            if notify {
+                notify_unreliable_translation(language, &ctx.text, similarity);
+            }
+        }
+        ctx.with_round_trip(similarity, flagged)
+    }
+}
+
+/// Tell the user a translation did not survive the round trip.
+fn notify_unreliable_translation(language: &str, text: &str, similarity: f32) {
+    let body = format!(
+        "Translation into '{}' may be wrong ({:.0}% kept):\n{}",
+        language,
+        similarity * 100.0,
+        text
+    );
+    if let Err(e) = notify_rust::Notification::new()
+        .summary("OpenHush")
+        .body(&body)
+        .show()
+    {
+        debug!("Failed to show translation notification: {}", e);
+    }
+}
+
+/// Flush sentence buffer and translate remaining text.
+///
+/// Returns the output text, if anything was left in the buffer.
+#[allow(clippy::too_many_arguments)]
 async fn flush_and_translate(
     sentence_buffer: &mut SentenceBuffer,
     translator: &Option<Arc<Translator>>,
     translation_config: &TranslationConfig,
     output_handler: &OutputHandler,
     action_runner: &ActionRunner,
+    follow_ups: &TranslationFollowUps,
     model_name: &str,
     sequence_id: u64,
     duration_secs: f32,
-) {
-    if let Some(remaining) = sentence_buffer.flush() {
-        debug!(
-            "Flushing sentence buffer: {} chars remaining",
-            remaining.len()
-        );
+    source_lang: Option<&str>,
+) -> Option<String> {
+    let remaining = sentence_buffer.flush()?;
+    debug!(
+        "Flushing sentence buffer: {} chars remaining",
+        remaining.len()
+    );
+    Some(
         translate_and_output(
             &remaining,
             translator,
             translation_config,
             output_handler,
             action_runner,
+            follow_ups,
             model_name,
             sequence_id,
             0, // chunk_id unknown at flush time
             duration_secs,
+            source_lang.unwrap_or("auto"),
         )
-        .await;
-    }
+        .await,
+    )
 }
 
 // ============================================================================
@@ -733,6 +1496,16 @@ pub enum DaemonError {
     WorkerFailed,
 }
 
+/// How a recording is transcribed, fixed when it starts so chunks still
+/// queued after a focus or language change keep their settings.
+#[derive(Debug, Clone, PartialEq)]
+struct RecordingSettings {
+    /// Language to transcribe in ("auto" or an ISO code)
+    language: String,
+    /// Local Whisper or the remote server
+    backend: TranscriptionBackendKind,
+}
+
 /// Daemon state machine
 ///
 /// Note: There is no "Transcribing" state anymore - transcription happens
@@ -749,6 +1522,8 @@ enum DaemonState {
         last_chunk_pos: usize,
         /// Next chunk ID (0, 1, 2, ...)
         next_chunk_id: u32,
+        /// How the recording is transcribed
+        settings: RecordingSettings,
     },
     /// Continuous recording with VAD-based segmentation
     ContinuousRecording {
@@ -760,14 +1535,40 @@ enum DaemonState {
         last_vad_pos: usize,
         /// Next chunk ID (0, 1, 2, ...)
         next_chunk_id: u32,
+        /// How the recording is transcribed
+        settings: RecordingSettings,
     },
 }
 
+/// Control commands, sent over D-Bus or from the tray menu.
+#[derive(Debug, Clone)]
+#[allow(clippy::enum_variant_names)]
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))] // D-Bus only sends some
+pub enum DaemonCommand {
+    StartRecording,
+    StopRecording,
+    ToggleRecording,
+    /// Load the Whisper model into GPU memory
+    LoadModel,
+    /// Unload the Whisper model to free GPU memory
+    UnloadModel,
+    /// Abort the current recording and discard pending transcriptions
+    Cancel,
+    /// Switch the transcription language ("auto" for detection)
+    SetLanguage(String),
+    /// Transcribe the last seconds already in the ring buffer
+    /// (None = configured default)
+    TranscribeLast(Option<f32>),
+}
+
 /// Main daemon struct
 pub struct Daemon {
     config: Config,
     platform: CurrentPlatform,
     state: DaemonState,
+    /// Language picked at runtime (language key, tray or D-Bus); it wins
+    /// over the configured and profile languages until the next reload
+    pinned_language: Option<String>,
 }
 
 impl Daemon {
@@ -793,40 +1594,254 @@ impl Daemon {
             config,
             platform,
             state: DaemonState::Idle,
+            pinned_language: None,
         })
     }
 
     /// Get the path to the Whisper model file
     fn model_path(&self) -> Result<PathBuf, DaemonError> {
-        let data_dir = Config::data_dir()?;
-        let model_file = format!("ggml-{}.bin", self.config.transcription.effective_model());
-        let path = data_dir.join("models").join(&model_file);
-        Ok(path)
+        model_path_for(
+            &self.config.transcription,
+            self.config.transcription.effective_model(),
+        )
     }
 
     /// Create a new Whisper engine with the configured model.
     ///
     /// This method can be called to load/reload the model on demand.
     fn create_engine(&self) -> Result<WhisperEngine, DaemonError> {
-        let model_path = self.model_path()?;
-        let effective_model = self.config.transcription.effective_model().to_string();
+        create_engine_for(
+            &self.config.transcription,
+            self.config.transcription.effective_model(),
+        )
+    }
 
-        if !model_path.exists() {
-            return Err(DaemonError::Whisper(WhisperError::ModelNotFound(
-                model_path,
-                effective_model,
-            )));
+    /// Create the live engine on a blocking thread, so reading (and
+    /// verifying) the weights does not stall the main loop.
+    async fn create_engine_blocking(&self) -> Result<WhisperEngine, DaemonError> {
+        let transcription = self.config.transcription.clone();
+        tokio::task::spawn_blocking(move || {
+            create_engine_for(&transcription, transcription.effective_model())
+        })
+        .await
+        .map_err(|e| DaemonError::Io(std::io::Error::other(e)))?
+    }
+
+    /// Load the live model before a job is queued if lazy loading left it
+    /// unloaded. `purpose` only shows up in the log.
+    async fn auto_load_model(
+        &self,
+        purpose: &str,
+        command_tx: &mpsc::Sender<WorkerCommand>,
+        events: &DaemonEvents,
+        model_loaded: &mut bool,
+        last_transcription_time: &mut Option<std::time::Instant>,
+    ) {
+        #[cfg(target_os = "linux")]
+        let loaded = events.dbus_status.read().await.model_loaded;
+        #[cfg(not(target_os = "linux"))]
+        let loaded = *model_loaded;
+        if loaded {
+            return;
         }
 
-        let use_gpu = self.config.transcription.device.to_lowercase() != "cpu";
-        let engine = WhisperEngine::new(
-            &model_path,
-            &self.config.transcription.language,
-            self.config.transcription.translate,
-            use_gpu,
-        )?;
+        info!("Auto-loading model for {}...", purpose);
+        match self.create_engine_blocking().await {
+            Ok(engine) => {
+                if command_tx
+                    .send(WorkerCommand::LoadEngine(engine))
+                    .await
+                    .is_ok()
+                {
+                    #[cfg(target_os = "linux")]
+                    {
+                        events.dbus_status.write().await.model_loaded = true;
+                    }
+                    *model_loaded = true;
+                    *last_transcription_time = Some(std::time::Instant::now());
+                    info!("Model auto-loaded successfully");
+                }
+            }
+            Err(e) => {
+                error!("Failed to auto-load model: {}", e);
+                events.error("model_load_failed", &e.to_string()).await;
+            }
+        }
+    }
 
-        Ok(engine)
+    /// Create the accurate engine kept warm next to the live one.
+    ///
+    /// Returns `None` when no separate accurate model is configured.
+    fn create_accurate_engine(&self) -> Option<Result<WhisperEngine, DaemonError>> {
+        let name = accurate_model_name(&self.config.transcription)?;
+        Some(create_engine_for(&self.config.transcription, name))
+    }
+
+    /// Enter hands-free dictation: VAD splits the audio into utterances,
+    /// each transcribed and output on its own.
+    ///
+    /// Returns the VAD timer that drives the segmentation.
+    fn start_hands_free(
+        &mut self,
+        audio_recorder: &AudioRecorder,
+        vad_engine: &mut Option<Box<dyn VadEngine>>,
+        vad_state: &mut Option<VadState>,
+        tracker: &mut TranscriptionTracker,
+        settings: RecordingSettings,
+    ) -> tokio::time::Interval {
+        let mark = audio_recorder.mark();
+        info!(
+            "🎙️ Hands-free dictation started (sequence_id: {})",
+            mark.sequence_id
+        );
+
+        if let Some(ref mut state) = vad_state {
+            state.reset();
+        }
+        if let Some(ref mut engine) = vad_engine {
+            engine.reset();
+        }
+        tracker.reset_dedup();
+
+        self.state = DaemonState::ContinuousRecording {
+            mark,
+            speech_start_pos: None,
+            last_vad_pos: audio_recorder.current_position(),
+            next_chunk_id: 0,
+            settings,
+        };
+
+        let mut timer =
+            tokio::time::interval(tokio::time::Duration::from_millis(VAD_PROCESS_INTERVAL_MS));
+        timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        timer
+    }
+
+    /// Start a recording in the configured mode: hands-free dictation in
+    /// continuous mode, otherwise a recording streamed in chunks until it
+    /// is stopped.
+    ///
+    /// The recording keeps the language and backend it started with, so
+    /// chunks still queued when they change are transcribed correctly.
+    #[allow(clippy::too_many_arguments)]
+    async fn start_recording(
+        &mut self,
+        is_continuous_mode: bool,
+        chunk_interval: Option<tokio::time::Duration>,
+        audio_recorder: &AudioRecorder,
+        vad_engine: &mut Option<Box<dyn VadEngine>>,
+        vad_state: &mut Option<VadState>,
+        tracker: &mut TranscriptionTracker,
+        chunk_timer: &mut Option<tokio::time::Interval>,
+        vad_timer: &mut Option<tokio::time::Interval>,
+    ) {
+        let settings = self.recording_settings().await;
+        if is_continuous_mode {
+            *vad_timer = Some(self.start_hands_free(
+                audio_recorder,
+                vad_engine,
+                vad_state,
+                tracker,
+                settings,
+            ));
+            return;
+        }
+
+        let mark = audio_recorder.mark();
+        let start_pos = audio_recorder.current_position();
+        debug!(
+            "Recording started, marked position (sequence_id: {}, pos: {})",
+            mark.sequence_id, start_pos
+        );
+
+        // Reset deduplication state for new recording
+        tracker.reset_dedup();
+
+        // Start chunk timer if streaming is enabled
+        if let Some(interval) = chunk_interval {
+            let mut timer = tokio::time::interval(interval);
+            timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            *chunk_timer = Some(timer);
+        }
+
+        self.state = DaemonState::Recording {
+            mark,
+            last_chunk_pos: start_pos,
+            next_chunk_id: 0,
+            settings,
+        };
+    }
+
+    /// Transcription language: the one picked at runtime, else the
+    /// configured one.
+    fn language(&self) -> &str {
+        self.pinned_language
+            .as_deref()
+            .unwrap_or(&self.config.transcription.language)
+    }
+
+    /// Language and backend for a new recording.
+    ///
+    /// A language picked at runtime wins. Otherwise a profile with a
+    /// `language` or `backend` for the focused application overrides the
+    /// configured one; the focused application is only looked up when such
+    /// a profile exists.
+    async fn recording_settings(&self) -> RecordingSettings {
+        let config = &self.config;
+        let mut settings = RecordingSettings {
+            language: self.language().to_string(),
+            backend: config.transcription.effective_backend(),
+        };
+        let by_language =
+            self.pinned_language.is_none() && config.profiles.iter().any(|p| p.language.is_some());
+        let by_backend = config.profiles.iter().any(|p| p.backend.is_some());
+        if !by_language && !by_backend {
+            return settings;
+        }
+
+        match tokio::task::spawn_blocking(|| ContextDetector::new().get_active_context()).await {
+            Ok(Ok(context)) => {
+                if by_language {
+                    settings.language = config.language_for_app(&context.app_name).to_string();
+                }
+                settings.backend = config.backend_for_app(&context.app_name);
+            }
+            Ok(Err(e)) => debug!("Focused application unknown, using defaults: {}", e),
+            Err(_) => {}
+        }
+        settings
+    }
+
+    /// Abort the current recording and everything it has queued: pending
+    /// chunks, a half-translated sentence and the journaled audio.
+    ///
+    /// Returns how many pending chunks were discarded.
+    fn cancel_recording(
+        &mut self,
+        chunk_timer: &mut Option<tokio::time::Interval>,
+        vad_timer: &mut Option<tokio::time::Interval>,
+        translate_once: &mut Option<(u64, Arc<Translator>)>,
+        tracker: &mut TranscriptionTracker,
+        sentence_buffer: &mut SentenceBuffer,
+        journal: &mut Option<Journal>,
+    ) -> usize {
+        let active_sequence = match &self.state {
+            DaemonState::Recording { mark, .. } | DaemonState::ContinuousRecording { mark, .. } => {
+                Some(mark.sequence_id)
+            }
+            DaemonState::Idle => None,
+        };
+        *chunk_timer = None;
+        *vad_timer = None;
+        *translate_once = None;
+        self.state = DaemonState::Idle;
+
+        let discarded = tracker.cancel_all(active_sequence);
+        let _ = sentence_buffer.flush();
+        if let Some(journal) = journal.as_mut() {
+            journal.discard_live();
+        }
+        discarded
     }
 
     /// Main daemon loop
@@ -867,23 +1882,41 @@ impl Daemon {
             info!("System tray not yet supported on this platform");
         }
 
+        // Recent transcriptions, shared with D-Bus and IPC clients
+        let history = Arc::new(RwLock::new(TranscriptionHistory::default()));
+
         // Initialize D-Bus service (Linux only)
         // Note: model_loaded will be updated after engine is loaded
         #[cfg(target_os = "linux")]
         let dbus_status = Arc::new(RwLock::new(DaemonStatus {
             is_recording: false,
+            hands_free: false,
             queue_depth: 0,
             model_loaded: self.config.transcription.preload,
+            model: self.config.transcription.effective_model().to_string(),
+            language: self.config.transcription.language.clone(),
+            last_transcription: String::new(),
         }));
         #[cfg(target_os = "linux")]
-        let (dbus_service, mut dbus_rx) = match DbusService::start(dbus_status.clone()).await {
-            Ok((service, rx)) => (Some(service), Some(rx)),
+        let (dbus_service, mut dbus_rx) =
+            match DbusService::start(dbus_status.clone(), history.clone()).await {
+                Ok((service, rx)) => (Some(service), Some(rx)),
+                Err(e) => {
+                    warn!(
+                        "D-Bus service unavailable: {}. Continuing without D-Bus control.",
+                        e
+                    );
+                    (None, None)
+                }
+            };
+
+        // Pause or duck media players while recording (Linux only)
+        #[cfg(target_os = "linux")]
+        let media_controller = match MediaController::new(&self.config.feedback).await {
+            Ok(controller) => controller,
             Err(e) => {
-                warn!(
-                    "D-Bus service unavailable: {}. Continuing without D-Bus control.",
-                    e
-                );
-                (None, None)
+                warn!("Media control unavailable: {}. Continuing without it.", e);
+                None
             }
         };
 
@@ -906,6 +1939,15 @@ impl Daemon {
         #[cfg(not(unix))]
         let (ipc_server, ipc_handle): (Option<IpcServer>, Option<IpcServerHandle>) = (None, None);
 
+        let events = Arc::new(DaemonEvents {
+            history: history.clone(),
+            ipc_handle: ipc_handle.clone(),
+            #[cfg(target_os = "linux")]
+            dbus_service,
+            #[cfg(target_os = "linux")]
+            dbus_status: dbus_status.clone(),
+        });
+
         // Initialize REST API server if enabled
         let api_status = Arc::new(RwLock::new(api::DaemonStatus {
             running: true,
@@ -920,7 +1962,9 @@ impl Daemon {
                     api_status.clone(),
                     api_cmd_tx.clone(),
                     self.config.api.api_key_hash.clone(),
-                );
+                )
+                .with_limits(&self.config.api.limits)
+                .with_resampling_quality(self.config.audio.resampling_quality);
                 let api_config = self.config.api.clone();
                 Some(tokio::spawn(
                     async move { api::serve(state, &api_config).await },
@@ -931,19 +1975,25 @@ impl Daemon {
 
         // Check if model exists - download in background if missing
         let model_path = self.model_path()?;
-        let effective_model = self.config.transcription.effective_model().to_string();
+        let mut effective_model = self.config.transcription.effective_model().to_string();
         let model_downloading = if !model_path.exists() {
             warn!(
                 "Model not found at: {}. Starting background download...",
                 model_path.display()
             );
 
-            // Parse model name to WhisperModel enum
-            if let Ok(whisper_model) =
-                effective_model.parse::<crate::engine::whisper::WhisperModel>()
-            {
+            // Only built-in models can be downloaded, not custom model files
+            let spec = resolve_model(&effective_model, &self.config.transcription)
+                .ok()
+                .and_then(|resolved| resolved.spec());
+            if let Some(spec) = spec {
                 // Spawn background download (high priority)
-                spawn_whisper_download(whisper_model);
+                spawn_whisper_download(
+                    spec,
+                    self.config.models.clone(),
+                    effective_model.clone(),
+                    Arc::clone(&events),
+                );
 
                 // Show notification
                 #[cfg(unix)]
@@ -960,7 +2010,7 @@ impl Daemon {
                 true
             } else {
                 error!(
-                    "Unknown model '{}'. Cannot download automatically.",
+                    "Model '{}' is not a downloadable model. Cannot download automatically.",
                     effective_model
                 );
                 false
@@ -974,12 +2024,13 @@ impl Daemon {
         let (initial_engine, chunk_interval_secs) = if preload {
             let use_gpu = self.config.transcription.device.to_lowercase() != "cpu";
             info!("Loading Whisper model (GPU: {})...", use_gpu);
-            let engine = WhisperEngine::new(
+            let mut engine = WhisperEngine::new(
                 &model_path,
                 &self.config.transcription.language,
                 self.config.transcription.translate,
                 use_gpu,
             )?;
+            engine.set_allowed_languages(&self.config.transcription.allowed_languages);
             info!(
                 "Model loaded successfully (translate={}, device={})",
                 self.config.transcription.translate, self.config.transcription.device
@@ -1051,16 +2102,18 @@ impl Daemon {
         let output_handler = OutputHandler::new(&self.config.output);
 
         // Initialize post-transcription action runner
-        let action_runner = ActionRunner::new(self.config.output.actions.clone());
+        let action_runner = Arc::new(ActionRunner::new(self.config.output.actions.clone()));
         if action_runner.has_actions() {
             info!(
                 "Post-transcription actions enabled ({} action(s))",
                 self.config.output.actions.len()
             );
         }
+        let follow_ups =
+            TranslationFollowUps::spawn(Arc::clone(&action_runner), self.config.feedback.visual);
 
         // Initialize always-on audio recorder with ring buffer
-        let prebuffer_secs = self.config.audio.prebuffer_duration_secs;
+        let prebuffer_secs = self.config.ring_buffer_secs();
         let resampling_quality = self.config.audio.resampling_quality;
         let channel_mix = match &self.config.audio.channels {
             crate::config::ChannelSelection::All => crate::input::ChannelMix::All,
@@ -1068,12 +2121,35 @@ impl Daemon {
                 crate::input::ChannelMix::Select(channels.clone())
             }
         };
-        let audio_recorder = AudioRecorder::new_always_on_with_device(
+        // Start on the highest-priority microphone that is plugged in right now
+        let input_device = if self.config.audio.preferred_devices.is_empty() {
+            self.config.audio.input_device.clone()
+        } else {
+            DeviceSnapshot::capture()
+                .select(
+                    &self.config.audio.preferred_devices,
+                    self.config.audio.input_device.as_deref(),
+                )
+                .map(|d| d.id.clone())
+                .or_else(|| self.config.audio.input_device.clone())
+        };
+        let mut audio_recorder = AudioRecorder::new_always_on_with_device(
             prebuffer_secs,
             resampling_quality,
             channel_mix,
-            self.config.audio.input_device.as_deref(),
+            input_device.as_deref(),
         )?;
+        if self.config.audio.echo_cancellation.enabled {
+            if let Err(e) =
+                audio_recorder.enable_echo_cancellation(&self.config.audio.echo_cancellation)
+            {
+                warn!("{}. Continuing without echo cancellation.", e);
+            }
+        }
+
+        // Follow devices being plugged in or removed and the default changing
+        let mut device_rx =
+            spawn_device_watcher(std::time::Duration::from_secs(DEVICE_POLL_INTERVAL_SECS));
         info!(
             "Always-on audio capture initialized ({:.0}s ring buffer, {:?} resampling)",
             prebuffer_secs, resampling_quality
@@ -1090,14 +2166,14 @@ impl Daemon {
         let mut wake_word_detector: Option<WakeWordDetector> = if wake_word_enabled {
             if !WakeWordDetector::models_available() {
                 warn!("Wake word models not found. Starting background download...");
-                spawn_wake_word_download();
+                spawn_wake_word_download(self.config.models.clone());
                 None
             } else {
                 match WakeWordDetector::new(&self.config.wake_word) {
                     Ok(detector) => {
                         info!(
-                            "Wake word detection enabled (threshold: {:.2})",
-                            self.config.wake_word.threshold
+                            "Wake word detection enabled ({})",
+                            detector.names().join(", ")
                         );
                         Some(detector)
                     }
@@ -1114,6 +2190,15 @@ impl Daemon {
         // Wake word processing position tracker
         let mut wake_word_last_pos: usize = 0;
 
+        // Meeting recording started by a wake word
+        let mut meeting: Option<std::process::Child> = None;
+
+        // Hands-free session whose single utterance gets translated
+        // (sequence ID, translator), started by a "translate" wake word
+        let mut translate_once: Option<(u64, Arc<Translator>)> = None;
+        // Translators of recordings whose refinement is still to come
+        let mut refine_translators: HashMap<u64, Arc<Translator>> = HashMap::new();
+
         // Initialize vocabulary manager if enabled
         let vocabulary_manager = init_vocabulary(&self.config.vocabulary).await;
 
@@ -1134,11 +2219,11 @@ impl Daemon {
             });
 
         // Initialize text corrector if enabled
-        let text_corrector = init_corrector(&self.config.correction).await;
+        let mut text_corrector = init_corrector(&self.config.correction).await;
 
         // Initialize translator if enabled
-        let translator = init_translator(&self.config.translation).await;
-        let translation_config = self.config.translation.clone();
+        let mut translator = init_translator(&self.config.translation, &self.config.models).await;
+        let mut translation_config = self.config.translation.clone();
 
         // Sentence buffer for translation (accumulates until complete sentences)
         let mut sentence_buffer = SentenceBuffer::new();
@@ -1147,16 +2232,90 @@ impl Daemon {
         let (command_tx, command_rx) = mpsc::channel::<WorkerCommand>(CHANNEL_BUFFER_SIZE);
         let (result_tx, mut result_rx) = mpsc::channel(CHANNEL_BUFFER_SIZE);
 
-        // Spawn transcription worker in dedicated thread
+        // Spawn transcription workers in dedicated threads
         let audio_config = self.config.audio.clone();
-        let worker_handle = spawn_worker(initial_engine, command_rx, result_tx, audio_config)?;
-        info!("Transcription worker started");
+        let queue_config = &self.config.queue;
+        let spill_dir = if queue_config.backpressure_strategy == BackpressureStrategy::SpillToDisk
+            && queue_config.max_pending > 0
+        {
+            match SpillStore::default_dir() {
+                Ok(dir) => Some(dir),
+                Err(e) => {
+                    warn!("Spill to disk unavailable, keeping jobs in memory: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        let pool_config = PoolConfig {
+            workers: queue_config.worker_count(),
+            memory_budget_bytes: self.config.transcription.engine_memory_budget_mb * 1_000_000,
+            max_queued: queue_config.max_pending as usize,
+            spill_dir,
+        };
+        let worker_pool = spawn_pool(
+            pool_config,
+            initial_engine,
+            command_rx,
+            result_tx,
+            audio_config,
+        )?;
+        let worker_stats = worker_pool.stats();
+
+        // Route jobs to a remote server for this preset or app profiles, with local fallback
+        let remote_fallback = self.config.transcription.remote.fallback_to_local;
+        if let Some(backend) = create_remote_backend(&self.config) {
+            command_tx
+                .send(WorkerCommand::SetRemote(Some(backend), remote_fallback))
+                .await
+                .map_err(|_| DaemonError::WorkerFailed)?;
+        }
+
+        // Keep the accurate model warm next to the live one
+        let accurate_engine = Arc::new(AccurateEngineState::new(worker_stats.clone()));
+        if preload {
+            match self.create_accurate_engine() {
+                Some(Ok(engine)) => {
+                    let loaded = accurate_engine.send(engine, &command_tx).await;
+                    if loaded {
+                        info!(
+                            "Accurate model loaded ({})",
+                            self.config.transcription.effective_accurate_model()
+                        );
+                    }
+                }
+                Some(Err(e)) => {
+                    warn!(
+                        "Failed to load accurate model, using live model instead: {}",
+                        e
+                    );
+                }
+                None => {}
+            }
+        }
+
+        // Two-pass refinement of push-to-talk recordings
+        let refine_mode = if accurate_model_name(&self.config.transcription).is_some() {
+            self.config.transcription.refine
+        } else {
+            RefineMode::Off
+        };
+        if refine_mode != RefineMode::Off {
+            info!("Two-pass refinement enabled ({:?})", refine_mode);
+        } else if self.config.transcription.refine != RefineMode::Off {
+            warn!("Refinement disabled: accurate_model is the same as the live model");
+        }
 
         // Track model loaded state (for IPC clients)
         let mut model_loaded = preload;
 
-        // Result tracker for ordered output
-        let mut tracker = TranscriptionTracker::new();
+        // Result tracker for ordered output (parallel workers may finish out of order)
+        let mut tracker = if worker_pool.size() > 1 {
+            TranscriptionTracker::new_ordered()
+        } else {
+            TranscriptionTracker::new()
+        };
 
         // Idle timeout tracking for model unloading
         let idle_unload_secs = self.config.transcription.idle_unload_secs;
@@ -1166,6 +2325,58 @@ impl Daemon {
             None // No tracking until first transcription when lazy loading
         };
 
+        // Replay dictations a previous run journaled but never output
+        let mut journal = if self.config.queue.journal {
+            match Journal::default_dir().and_then(Journal::open) {
+                Ok(journal) => Some(journal),
+                Err(e) => {
+                    warn!(
+                        "Audio journal unavailable, dictations are not crash-safe: {}",
+                        e
+                    );
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        let recovered_jobs = journal.as_mut().map(Journal::recover).unwrap_or_default();
+        let mut recovered_pending = recovered_jobs.len();
+        let mut recovered_saved = 0;
+
+        // Files submitted for transcription, answered instead of typed
+        let mut file_jobs = FileJobs::new();
+        if !recovered_jobs.is_empty() {
+            if !model_loaded {
+                match self.create_engine() {
+                    Ok(engine) => {
+                        if command_tx
+                            .send(WorkerCommand::LoadEngine(engine))
+                            .await
+                            .is_ok()
+                        {
+                            #[cfg(target_os = "linux")]
+                            {
+                                dbus_status.write().await.model_loaded = true;
+                            }
+                            model_loaded = true;
+                            last_transcription_time = Some(std::time::Instant::now());
+                        }
+                    }
+                    Err(e) => warn!("Failed to load model for recovered dictations: {}", e),
+                }
+            }
+            for job in recovered_jobs {
+                command_tx
+                    .send(WorkerCommand::Job(job))
+                    .await
+                    .map_err(|_| {
+                        error!("Transcription worker failed - channel closed");
+                        DaemonError::WorkerFailed
+                    })?;
+            }
+        }
+
         // Idle check timer (runs every 10 seconds if idle unload is enabled)
         let mut idle_check_timer: Option<tokio::time::Interval> = if idle_unload_secs > 0 {
             let mut timer = tokio::time::interval(tokio::time::Duration::from_secs(10));
@@ -1176,9 +2387,23 @@ impl Daemon {
         };
 
         // Initialize hotkey listener
-        let (hotkey_listener, mut hotkey_rx) = HotkeyListener::new(&self.config.hotkey.key)?;
+        let (mut hotkey_listener, mut hotkey_rx) = HotkeyListener::new(&self.config.hotkey.key)?;
+        if let Some(ref key) = self.config.replay.key {
+            hotkey_listener = hotkey_listener.with_replay_key(key)?;
+            info!(
+                "Press {} to transcribe the last {:.0}s",
+                key, self.config.replay.default_secs
+            );
+        }
+        if let Some(ref key) = self.config.hotkey.language_key {
+            hotkey_listener = hotkey_listener.with_language_key(key)?;
+            info!("Press {} to switch the transcription language", key);
+        }
         hotkey_listener.start()?;
 
+        // Pending request to transcribe buffered audio (seconds)
+        let mut replay_secs: Option<f32> = None;
+
         // Chunk separator (space by default) - cloned to allow config reload
         let mut chunk_separator = self.config.queue.separator.clone();
 
@@ -1242,6 +2467,13 @@ impl Daemon {
         #[cfg(unix)]
         let (mut sigterm, mut sighup) = setup_signal_handlers()?;
 
+        // Recording state seen by the previous loop iteration
+        let mut was_recording = false;
+
+        // Last state pushed to the tray menu, with the history revision it shows
+        #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+        let mut tray_state: Option<(TrayState, i64)> = None;
+
         // Main event loop
         loop {
             // Poll Unix signals (non-blocking, at start of loop)
@@ -1257,6 +2489,7 @@ impl Daemon {
                     _ = sighup.recv() => {
                         info!("SIGHUP received, reloading configuration...");
                         reload_config(&mut self.config, &mut chunk_separator);
+                        self.pinned_language = None;
                         continue; // Don't break, continue with new config
                     }
                     // Immediate timeout to make this non-blocking
@@ -1264,6 +2497,9 @@ impl Daemon {
                 }
             }
 
+            // Commands the tray shares with D-Bus, and where they came from
+            let mut control: Option<(DaemonCommand, &str)> = None;
+
             // Check for tray events (Linux, macOS, and Windows)
             #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
             {
@@ -1282,6 +2518,121 @@ impl Daemon {
                             TrayEvent::StatusClicked => {
                                 debug!("Status clicked");
                             }
+                            TrayEvent::ToggleRecording => {
+                                control = Some((DaemonCommand::ToggleRecording, "tray"));
+                            }
+                            TrayEvent::CopyTranscription(id) => {
+                                let text = history.read().await.get(id).map(|i| i.text.clone());
+                                match text {
+                                    Some(text) => match output_handler.copy_only(&text) {
+                                        Ok(()) => {
+                                            info!("📋 Copied transcription #{} from tray", id)
+                                        }
+                                        Err(e) => warn!("Failed to copy transcription: {}", e),
+                                    },
+                                    None => debug!("Transcription #{} no longer in history", id),
+                                }
+                            }
+                            TrayEvent::SetLanguage(language) => {
+                                control = Some((DaemonCommand::SetLanguage(language), "tray"));
+                            }
+                            TrayEvent::SetPreset(preset) => {
+                                if preset != self.config.transcription.preset {
+                                    let previous = self.config.transcription.preset;
+                                    self.config.transcription.preset = preset;
+                                    let model =
+                                        self.config.transcription.effective_model().to_string();
+                                    info!(
+                                        "Switching preset to {:?} (model: {}) via tray",
+                                        preset, model
+                                    );
+
+                                    #[cfg(target_os = "linux")]
+                                    let loaded = dbus_status.read().await.model_loaded;
+                                    #[cfg(any(target_os = "macos", target_os = "windows"))]
+                                    let loaded = model_loaded;
+
+                                    // Swap the loaded engine; otherwise the new model
+                                    // is picked up by the next (lazy) load
+                                    let switched = if loaded {
+                                        events.model_progress(&model, 0.0, "loading").await;
+                                        match self.create_engine() {
+                                            Ok(engine) => {
+                                                let sent = command_tx
+                                                    .send(WorkerCommand::LoadEngine(engine))
+                                                    .await
+                                                    .is_ok();
+                                                if sent {
+                                                    events
+                                                        .model_progress(&model, 1.0, "loaded")
+                                                        .await;
+                                                }
+                                                sent
+                                            }
+                                            Err(e) => {
+                                                error!("Failed to load model: {}", e);
+                                                events
+                                                    .error("model_load_failed", &e.to_string())
+                                                    .await;
+                                                false
+                                            }
+                                        }
+                                    } else {
+                                        true
+                                    };
+
+                                    if switched {
+                                        let _ = command_tx
+                                            .send(WorkerCommand::SetRemote(
+                                                create_remote_backend(&self.config),
+                                                remote_fallback,
+                                            ))
+                                            .await;
+                                        effective_model = model;
+                                        api_status.write().await.model = effective_model.clone();
+                                        #[cfg(target_os = "linux")]
+                                        {
+                                            dbus_status.write().await.model =
+                                                effective_model.clone();
+                                        }
+                                    } else {
+                                        self.config.transcription.preset = previous;
+                                    }
+                                }
+                            }
+                            TrayEvent::ToggleCorrection => {
+                                self.config.correction.enabled = text_corrector.is_none();
+                                text_corrector = init_corrector(&self.config.correction).await;
+                                info!(
+                                    "LLM correction {} via tray",
+                                    if text_corrector.is_some() {
+                                        "enabled"
+                                    } else {
+                                        "disabled"
+                                    }
+                                );
+                            }
+                            TrayEvent::ToggleTranslation => {
+                                self.config.translation.enabled = translator.is_none();
+                                translator =
+                                    init_translator(&self.config.translation, &self.config.models)
+                                        .await;
+                                translation_config = self.config.translation.clone();
+                                info!(
+                                    "Translation {} via tray",
+                                    if translator.is_some() {
+                                        "enabled"
+                                    } else {
+                                        "disabled"
+                                    }
+                                );
+                            }
+                            TrayEvent::LoadModel => {
+                                control = Some((DaemonCommand::LoadModel, "tray"));
+                            }
+                            TrayEvent::UnloadModel => {
+                                control = Some((DaemonCommand::UnloadModel, "tray"));
+                            }
                         }
                     }
                 }
@@ -1289,139 +2640,229 @@ impl Daemon {
 
             // Check for D-Bus commands (Linux only)
             #[cfg(target_os = "linux")]
-            if let Some(ref mut rx) = dbus_rx {
-                if let Ok(cmd) = rx.try_recv() {
-                    match cmd {
-                        DaemonCommand::StartRecording => {
-                            if matches!(self.state, DaemonState::Idle) {
-                                info!("🎙️ Recording started via D-Bus");
-                                let mark = audio_recorder.mark();
-                                tracker.reset_dedup();
-
-                                // Start chunk timer if streaming enabled
-                                if let Some(interval) = chunk_interval {
-                                    let mut timer = tokio::time::interval(interval);
-                                    timer.set_missed_tick_behavior(
-                                        tokio::time::MissedTickBehavior::Skip,
-                                    );
-                                    chunk_timer = Some(timer);
-                                }
-
-                                self.state = DaemonState::Recording {
-                                    mark,
-                                    last_chunk_pos: audio_recorder.current_position(),
-                                    next_chunk_id: 0,
-                                };
+            {
+                control =
+                    control.or_else(|| dbus_rx.as_mut()?.try_recv().ok().map(|cmd| (cmd, "D-Bus")));
+            }
 
-                                // Update D-Bus status
-                                {
-                                    let mut status = dbus_status.write().await;
-                                    status.is_recording = true;
-                                }
-                                if let Some(ref service) = dbus_service {
-                                    let _ = service.emit_recording_changed().await;
-                                }
-                            }
+            if let Some((cmd, via)) = control {
+                match cmd {
+                    DaemonCommand::StartRecording => {
+                        if matches!(self.state, DaemonState::Idle) {
+                            info!("🎙️ Recording started via {}", via);
+                            self.start_recording(
+                                is_continuous_mode,
+                                chunk_interval,
+                                &audio_recorder,
+                                &mut vad_engine,
+                                &mut vad_state,
+                                &mut tracker,
+                                &mut chunk_timer,
+                                &mut vad_timer,
+                            )
+                            .await;
                         }
-                        DaemonCommand::StopRecording => {
-                            if !matches!(self.state, DaemonState::Idle) {
-                                info!("🛑 Recording stopped via D-Bus");
-                                chunk_timer = None;
-                                vad_timer = None;
-                                self.state = DaemonState::Idle;
-
-                                // Update D-Bus status
-                                {
-                                    let mut status = dbus_status.write().await;
-                                    status.is_recording = false;
-                                }
-                                if let Some(ref service) = dbus_service {
-                                    let _ = service.emit_recording_changed().await;
-                                }
-                            }
+                    }
+                    DaemonCommand::StopRecording => {
+                        if !matches!(self.state, DaemonState::Idle) {
+                            info!("🛑 Recording stopped via {}", via);
+                            chunk_timer = None;
+                            vad_timer = None;
+                            self.state = DaemonState::Idle;
                         }
-                        DaemonCommand::ToggleRecording => {
-                            if matches!(self.state, DaemonState::Idle) {
-                                info!("🎙️ Recording toggled ON via D-Bus");
-                                let mark = audio_recorder.mark();
-                                tracker.reset_dedup();
-
-                                if let Some(interval) = chunk_interval {
-                                    let mut timer = tokio::time::interval(interval);
-                                    timer.set_missed_tick_behavior(
-                                        tokio::time::MissedTickBehavior::Skip,
-                                    );
-                                    chunk_timer = Some(timer);
-                                }
-
-                                self.state = DaemonState::Recording {
-                                    mark,
-                                    last_chunk_pos: audio_recorder.current_position(),
-                                    next_chunk_id: 0,
-                                };
-
-                                {
-                                    let mut status = dbus_status.write().await;
-                                    status.is_recording = true;
-                                }
-                                if let Some(ref service) = dbus_service {
-                                    let _ = service.emit_recording_changed().await;
-                                }
-                            } else {
-                                info!("🛑 Recording toggled OFF via D-Bus");
-                                chunk_timer = None;
-                                vad_timer = None;
-                                self.state = DaemonState::Idle;
-
-                                {
-                                    let mut status = dbus_status.write().await;
-                                    status.is_recording = false;
-                                }
-                                if let Some(ref service) = dbus_service {
-                                    let _ = service.emit_recording_changed().await;
-                                }
-                            }
+                    }
+                    DaemonCommand::ToggleRecording => {
+                        if matches!(self.state, DaemonState::Idle) {
+                            info!("🎙️ Recording toggled ON via {}", via);
+                            self.start_recording(
+                                is_continuous_mode,
+                                chunk_interval,
+                                &audio_recorder,
+                                &mut vad_engine,
+                                &mut vad_state,
+                                &mut tracker,
+                                &mut chunk_timer,
+                                &mut vad_timer,
+                            )
+                            .await;
+                        } else {
+                            info!("🛑 Recording toggled OFF via {}", via);
+                            chunk_timer = None;
+                            vad_timer = None;
+                            self.state = DaemonState::Idle;
                         }
-                        DaemonCommand::LoadModel => {
-                            let status = dbus_status.read().await;
-                            if status.model_loaded {
-                                info!("Model already loaded, ignoring load request");
-                            } else {
-                                drop(status);
-                                info!("Loading Whisper model via D-Bus command...");
-                                match self.create_engine() {
-                                    Ok(engine) => {
-                                        if command_tx
-                                            .send(WorkerCommand::LoadEngine(engine))
-                                            .await
-                                            .is_ok()
+                    }
+                    DaemonCommand::LoadModel => {
+                        #[cfg(target_os = "linux")]
+                        let loaded = dbus_status.read().await.model_loaded;
+                        #[cfg(any(target_os = "macos", target_os = "windows"))]
+                        let loaded = model_loaded;
+
+                        if loaded {
+                            info!("Model already loaded, ignoring load request");
+                        } else {
+                            info!("Loading Whisper model via {}...", via);
+                            events
+                                .model_progress(&effective_model, 0.0, "loading")
+                                .await;
+                            match self.create_engine() {
+                                Ok(engine) => {
+                                    if command_tx
+                                        .send(WorkerCommand::LoadEngine(engine))
+                                        .await
+                                        .is_ok()
+                                    {
+                                        #[cfg(target_os = "linux")]
                                         {
-                                            let mut status = dbus_status.write().await;
-                                            status.model_loaded = true;
-                                            info!("Model loaded successfully");
+                                            dbus_status.write().await.model_loaded = true;
                                         }
+                                        model_loaded = true;
+                                        last_transcription_time = Some(std::time::Instant::now());
+                                        info!("Model loaded successfully");
+                                        events
+                                            .model_progress(&effective_model, 1.0, "loaded")
+                                            .await;
                                     }
-                                    Err(e) => {
-                                        error!("Failed to load model: {}", e);
-                                    }
+                                }
+                                Err(e) => {
+                                    error!("Failed to load model: {}", e);
+                                    events.error("model_load_failed", &e.to_string()).await;
                                 }
                             }
                         }
-                        DaemonCommand::UnloadModel => {
-                            let status = dbus_status.read().await;
-                            if !status.model_loaded {
-                                info!("Model already unloaded, ignoring unload request");
-                            } else {
-                                drop(status);
-                                info!("Unloading Whisper model via D-Bus command...");
-                                if command_tx.send(WorkerCommand::UnloadEngine).await.is_ok() {
-                                    let mut status = dbus_status.write().await;
-                                    status.model_loaded = false;
-                                    info!("Model unloaded successfully");
+                    }
+                    DaemonCommand::UnloadModel => {
+                        #[cfg(target_os = "linux")]
+                        let loaded = dbus_status.read().await.model_loaded;
+                        #[cfg(any(target_os = "macos", target_os = "windows"))]
+                        let loaded = model_loaded;
+
+                        if !loaded {
+                            info!("Model already unloaded, ignoring unload request");
+                        } else {
+                            info!("Unloading Whisper model via {}...", via);
+                            if command_tx.send(WorkerCommand::UnloadEngine).await.is_ok() {
+                                accurate_engine.set_ready(false);
+                                #[cfg(target_os = "linux")]
+                                {
+                                    dbus_status.write().await.model_loaded = false;
                                 }
+                                model_loaded = false;
+                                last_transcription_time = None;
+                                info!("Model unloaded successfully");
+                                events
+                                    .model_progress(&effective_model, 1.0, "unloaded")
+                                    .await;
                             }
                         }
                     }
+                    DaemonCommand::Cancel => {
+                        let discarded = self.cancel_recording(
+                            &mut chunk_timer,
+                            &mut vad_timer,
+                            &mut translate_once,
+                            &mut tracker,
+                            &mut sentence_buffer,
+                            &mut journal,
+                        );
+                        info!(
+                            "✖️ Cancelled via {} ({} pending chunk(s) discarded)",
+                            via, discarded
+                        );
+                    }
+                    DaemonCommand::TranscribeLast(secs) => {
+                        replay_secs = Some(secs.unwrap_or(self.config.replay.default_secs));
+                    }
+                    DaemonCommand::SetLanguage(language) => {
+                        info!(
+                            "Switching transcription language to '{}' via {}",
+                            language, via
+                        );
+                        self.pinned_language = Some(language.clone());
+                        #[cfg(target_os = "linux")]
+                        {
+                            dbus_status.write().await.language = language;
+                        }
+                    }
+                }
+            }
+
+            // React to recording start/stop, whatever triggered it
+            let is_recording = !matches!(self.state, DaemonState::Idle);
+            let hands_free = matches!(self.state, DaemonState::ContinuousRecording { .. });
+            if is_recording != was_recording {
+                was_recording = is_recording;
+                #[cfg(target_os = "linux")]
+                if let Some(ref media) = media_controller {
+                    if is_recording {
+                        media.recording_started();
+                    } else {
+                        media.recording_stopped();
+                    }
+                }
+            }
+
+            // Publish state changes as D-Bus PropertiesChanged signals
+            #[cfg(target_os = "linux")]
+            events
+                .sync_status(is_recording, hands_free, tracker.pending_count())
+                .await;
+
+            // Refresh the tray menu when anything it shows has changed
+            #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+            if let Some(ref tray) = tray {
+                #[cfg(target_os = "linux")]
+                let loaded = dbus_status.read().await.model_loaded;
+                #[cfg(any(target_os = "macos", target_os = "windows"))]
+                let loaded = model_loaded;
+
+                let queue_depth = tracker.pending_count();
+                let status = if hands_free {
+                    TrayStatus::Listening
+                } else if is_recording {
+                    TrayStatus::Recording
+                } else if queue_depth > 0 {
+                    TrayStatus::Processing
+                } else {
+                    TrayStatus::Idle
+                };
+                let revision = history.read().await.revision();
+
+                // Only build the state (and copy the history) when it changed
+                let changed = match &tray_state {
+                    Some((shown, shown_revision)) => {
+                        *shown_revision != revision
+                            || shown.status != status
+                            || shown.queue_depth != queue_depth
+                            || shown.model != effective_model
+                            || shown.model_loaded != loaded
+                            || shown.language != self.language()
+                            || shown.preset != self.config.transcription.preset
+                            || shown.correction_enabled != text_corrector.is_some()
+                            || shown.translation_enabled != translator.is_some()
+                    }
+                    None => true,
+                };
+                if changed {
+                    let state = TrayState {
+                        status,
+                        queue_depth,
+                        model: effective_model.clone(),
+                        model_loaded: loaded,
+                        language: self.language().to_string(),
+                        preset: self.config.transcription.preset,
+                        correction_enabled: text_corrector.is_some(),
+                        translation_enabled: translator.is_some(),
+                        recent: history
+                            .read()
+                            .await
+                            .recent(TRAY_RECENT_LIMIT, 0)
+                            .into_iter()
+                            .map(|item| (item.id, item.text))
+                            .collect(),
+                    };
+                    tray.update_state(state.clone()).await;
+                    tray_state = Some((state, revision));
                 }
             }
 
@@ -1447,12 +2888,82 @@ impl Daemon {
                         IpcCommand::Ping => {
                             responder(IpcResponse::pong());
                         }
-                        IpcCommand::HistoryList {
-                            limit: _,
-                            offset: _,
-                        } => {
-                            // TODO: Implement history storage
-                            responder(IpcResponse::error("History not yet implemented"));
+                        IpcCommand::QueueStats => {
+                            let workers = worker_stats
+                                .snapshot()
+                                .into_iter()
+                                .map(|s| WorkerQueueStats {
+                                    id: s.id,
+                                    in_flight: s.in_flight,
+                                    completed: s.completed,
+                                    failed: s.failed,
+                                    busy_ms: s.busy_ms,
+                                })
+                                .collect();
+                            responder(IpcResponse::queue_stats(
+                                tracker.pending_count(),
+                                tracker.waiting_count(),
+                                workers,
+                            ));
+                        }
+                        IpcCommand::TranscribeLast { secs } => {
+                            if secs.is_some_and(|secs| !secs.is_finite() || secs <= 0.0) {
+                                responder(IpcResponse::error("secs must be a positive number"));
+                            } else if !matches!(self.state, DaemonState::Idle) {
+                                responder(IpcResponse::error("Cannot replay while recording"));
+                            } else {
+                                replay_secs = Some(secs.unwrap_or(self.config.replay.default_secs));
+                                responder(IpcResponse::ok());
+                            }
+                        }
+                        IpcCommand::TranscribeFile { path } => {
+                            // Decode off the main loop, then queue it like an API upload
+                            let api_cmd_tx = api_cmd_tx.clone();
+                            let quality = self.config.audio.resampling_quality;
+                            tokio::spawn(async move {
+                                let decoded = tokio::task::spawn_blocking(move || {
+                                    load_wav_file(std::path::Path::new(&path), quality)
+                                })
+                                .await;
+                                let audio = match decoded {
+                                    Ok(Ok(audio)) => audio,
+                                    Ok(Err(e)) => {
+                                        return responder(IpcResponse::error(&e.to_string()))
+                                    }
+                                    Err(e) => return responder(IpcResponse::error(&e.to_string())),
+                                };
+
+                                let (reply, mut result_rx) = mpsc::channel(1);
+                                if api_cmd_tx
+                                    .send(ApiCommand::Transcribe { audio, reply })
+                                    .await
+                                    .is_err()
+                                {
+                                    return responder(IpcResponse::error(
+                                        "Daemon is shutting down",
+                                    ));
+                                }
+                                responder(match result_rx.recv().await {
+                                    Some(TranscriptionResult { error: Some(e), .. }) => {
+                                        IpcResponse::error(&e)
+                                    }
+                                    Some(result) => IpcResponse::transcription(
+                                        result.text,
+                                        result.duration_secs,
+                                        result.language,
+                                    ),
+                                    None => IpcResponse::error(
+                                        "Daemon stopped before the transcription finished",
+                                    ),
+                                });
+                            });
+                        }
+                        IpcCommand::HistoryList { limit, offset } => {
+                            let history = history.read().await;
+                            responder(IpcResponse::history(
+                                history.recent(limit, offset),
+                                history.len(),
+                            ));
                         }
                         IpcCommand::ConfigGet { key: _ } => {
                             responder(IpcResponse::error("Config get not yet implemented"));
@@ -1471,6 +2982,9 @@ impl Daemon {
                                 responder(IpcResponse::ok());
                             } else {
                                 info!("Loading Whisper model via IPC command...");
+                                events
+                                    .model_progress(&effective_model, 0.0, "loading")
+                                    .await;
                                 match self.create_engine() {
                                     Ok(engine) => {
                                         if command_tx
@@ -1480,6 +2994,9 @@ impl Daemon {
                                         {
                                             model_loaded = true;
                                             info!("Model loaded successfully");
+                                            events
+                                                .model_progress(&effective_model, 1.0, "loaded")
+                                                .await;
                                             responder(IpcResponse::ok());
                                         } else {
                                             responder(IpcResponse::error(
@@ -1489,6 +3006,7 @@ impl Daemon {
                                     }
                                     Err(e) => {
                                         error!("Failed to load model: {}", e);
+                                        events.error("model_load_failed", &e.to_string()).await;
                                         responder(IpcResponse::error(&format!(
                                             "Failed to load model: {}",
                                             e
@@ -1504,8 +3022,12 @@ impl Daemon {
                             } else {
                                 info!("Unloading Whisper model via IPC command...");
                                 if command_tx.send(WorkerCommand::UnloadEngine).await.is_ok() {
+                                    accurate_engine.set_ready(false);
                                     model_loaded = false;
                                     info!("Model unloaded successfully");
+                                    events
+                                        .model_progress(&effective_model, 1.0, "unloaded")
+                                        .await;
                                     responder(IpcResponse::ok());
                                 } else {
                                     responder(IpcResponse::error("Failed to send to worker"));
@@ -1515,22 +3037,17 @@ impl Daemon {
                         IpcCommand::StartRecording => {
                             if matches!(self.state, DaemonState::Idle) {
                                 info!("🎙️ Recording started via IPC");
-                                let mark = audio_recorder.mark();
-                                tracker.reset_dedup();
-
-                                if let Some(interval) = chunk_interval {
-                                    let mut timer = tokio::time::interval(interval);
-                                    timer.set_missed_tick_behavior(
-                                        tokio::time::MissedTickBehavior::Skip,
-                                    );
-                                    chunk_timer = Some(timer);
-                                }
-
-                                self.state = DaemonState::Recording {
-                                    mark,
-                                    last_chunk_pos: audio_recorder.current_position(),
-                                    next_chunk_id: 0,
-                                };
+                                self.start_recording(
+                                    is_continuous_mode,
+                                    chunk_interval,
+                                    &audio_recorder,
+                                    &mut vad_engine,
+                                    &mut vad_state,
+                                    &mut tracker,
+                                    &mut chunk_timer,
+                                    &mut vad_timer,
+                                )
+                                .await;
                                 responder(IpcResponse::ok());
                             } else {
                                 responder(IpcResponse::error("Already recording"));
@@ -1550,22 +3067,17 @@ impl Daemon {
                         IpcCommand::ToggleRecording => {
                             if matches!(self.state, DaemonState::Idle) {
                                 info!("🎙️ Recording toggled ON via IPC");
-                                let mark = audio_recorder.mark();
-                                tracker.reset_dedup();
-
-                                if let Some(interval) = chunk_interval {
-                                    let mut timer = tokio::time::interval(interval);
-                                    timer.set_missed_tick_behavior(
-                                        tokio::time::MissedTickBehavior::Skip,
-                                    );
-                                    chunk_timer = Some(timer);
-                                }
-
-                                self.state = DaemonState::Recording {
-                                    mark,
-                                    last_chunk_pos: audio_recorder.current_position(),
-                                    next_chunk_id: 0,
-                                };
+                                self.start_recording(
+                                    is_continuous_mode,
+                                    chunk_interval,
+                                    &audio_recorder,
+                                    &mut vad_engine,
+                                    &mut vad_state,
+                                    &mut tracker,
+                                    &mut chunk_timer,
+                                    &mut vad_timer,
+                                )
+                                .await;
                                 responder(IpcResponse::ok());
                             } else {
                                 info!("🛑 Recording toggled OFF via IPC");
@@ -1603,22 +3115,17 @@ impl Daemon {
                     ApiCommand::StartRecording => {
                         if matches!(self.state, DaemonState::Idle) {
                             info!("🎙️ Recording started via API");
-                            let mark = audio_recorder.mark();
-                            tracker.reset_dedup();
-
-                            if let Some(interval) = chunk_interval {
-                                let mut timer = tokio::time::interval(interval);
-                                timer.set_missed_tick_behavior(
-                                    tokio::time::MissedTickBehavior::Skip,
-                                );
-                                chunk_timer = Some(timer);
-                            }
-
-                            self.state = DaemonState::Recording {
-                                mark,
-                                last_chunk_pos: audio_recorder.current_position(),
-                                next_chunk_id: 0,
-                            };
+                            self.start_recording(
+                                is_continuous_mode,
+                                chunk_interval,
+                                &audio_recorder,
+                                &mut vad_engine,
+                                &mut vad_state,
+                                &mut tracker,
+                                &mut chunk_timer,
+                                &mut vad_timer,
+                            )
+                            .await;
 
                             // Update API status
                             {
@@ -1644,22 +3151,17 @@ impl Daemon {
                     ApiCommand::ToggleRecording => {
                         if matches!(self.state, DaemonState::Idle) {
                             info!("🎙️ Recording toggled ON via API");
-                            let mark = audio_recorder.mark();
-                            tracker.reset_dedup();
-
-                            if let Some(interval) = chunk_interval {
-                                let mut timer = tokio::time::interval(interval);
-                                timer.set_missed_tick_behavior(
-                                    tokio::time::MissedTickBehavior::Skip,
-                                );
-                                chunk_timer = Some(timer);
-                            }
-
-                            self.state = DaemonState::Recording {
-                                mark,
-                                last_chunk_pos: audio_recorder.current_position(),
-                                next_chunk_id: 0,
-                            };
+                            self.start_recording(
+                                is_continuous_mode,
+                                chunk_interval,
+                                &audio_recorder,
+                                &mut vad_engine,
+                                &mut vad_state,
+                                &mut tracker,
+                                &mut chunk_timer,
+                                &mut vad_timer,
+                            )
+                            .await;
 
                             {
                                 let mut status = api_status.write().await;
@@ -1677,6 +3179,101 @@ impl Daemon {
                             }
                         }
                     }
+                    ApiCommand::TranscribeLast(secs) => {
+                        replay_secs = Some(secs.unwrap_or(self.config.replay.default_secs));
+                    }
+                    ApiCommand::Transcribe { audio, reply } => {
+                        self.auto_load_model(
+                            "file transcription",
+                            &command_tx,
+                            &events,
+                            &mut model_loaded,
+                            &mut last_transcription_time,
+                        )
+                        .await;
+
+                        let duration = audio.duration_secs();
+                        let job = file_jobs.submit(
+                            audio,
+                            Some(self.language().to_string()),
+                            self.config.transcription.effective_backend(),
+                            Box::new(move |result| {
+                                let _ = reply.try_send(result);
+                            }),
+                        );
+                        let sequence_id = job.sequence_id;
+                        info!(
+                            "📄 Transcribing file ({:.1}s, seq {})",
+                            duration, sequence_id
+                        );
+                        if command_tx.send(WorkerCommand::Job(job)).await.is_err() {
+                            file_jobs.fail(sequence_id, "Transcription worker is not running");
+                        }
+                    }
+                }
+            }
+
+            // Transcribe audio already in the ring buffer ("what was just said?")
+            if let Some(secs) = replay_secs.take() {
+                if !matches!(self.state, DaemonState::Idle) {
+                    warn!("Ignoring replay request while recording");
+                } else if let Some(buffer) = audio_recorder.extract_last(secs) {
+                    let mark = audio_recorder.mark();
+                    info!(
+                        "⏪ Transcribing last {:.1}s from the buffer (seq {})",
+                        buffer.duration_secs(),
+                        mark.sequence_id
+                    );
+                    if buffer.duration_secs() + 0.5 < secs {
+                        info!(
+                            "Requested {:.0}s but only {:.1}s are buffered",
+                            secs,
+                            buffer.duration_secs()
+                        );
+                    }
+                    tracker.reset_dedup();
+
+                    let accepted = bp.admit(&mut tracker, &mut journal, mark.sequence_id, 0);
+                    if !accepted {
+                        warn!(
+                            "Replay rejected due to backpressure (seq {})",
+                            mark.sequence_id
+                        );
+                        #[cfg(unix)]
+                        notify_backpressure(bp.notify);
+                    } else {
+                        // Auto-load model if not loaded (lazy loading)
+                        self.auto_load_model(
+                            "replay",
+                            &command_tx,
+                            &events,
+                            &mut model_loaded,
+                            &mut last_transcription_time,
+                        )
+                        .await;
+
+                        let settings = self.recording_settings().await;
+                        let job = TranscriptionJob {
+                            buffer,
+                            sequence_id: mark.sequence_id,
+                            chunk_id: 0,
+                            is_final: true,
+                            role: EngineRole::Fast,
+                            priority: JobPriority::Interactive,
+                            language: Some(settings.language),
+                            backend: settings.backend,
+                        };
+                        journal_job(&mut journal, &job);
+                        command_tx
+                            .send(WorkerCommand::Job(job))
+                            .await
+                            .map_err(|_| {
+                                error!("Transcription worker failed - channel closed");
+                                DaemonError::WorkerFailed
+                            })?;
+                    }
+                } else {
+                    warn!("Nothing to replay: the audio buffer is empty");
                 }
             }
 
@@ -1684,70 +3281,45 @@ impl Daemon {
                 // Handle hotkey events
                 Some(event) = hotkey_rx.recv() => {
                     match event {
-                        HotkeyEvent::Pressed => {
-                            if is_continuous_mode {
-                                // Continuous mode: toggle recording on/off
-                                match &self.state {
-                                    DaemonState::Idle => {
-                                        let mark = audio_recorder.mark();
-                                        let start_pos = audio_recorder.current_position();
-                                        info!("🎙️ Continuous recording started (sequence_id: {})", mark.sequence_id);
-
-                                        // Start VAD timer for continuous processing
-                                        let mut timer = tokio::time::interval(
-                                            tokio::time::Duration::from_millis(VAD_PROCESS_INTERVAL_MS)
-                                        );
-                                        timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
-                                        vad_timer = Some(timer);
-
-                                        // Reset VAD state for new recording
-                                        if let Some(ref mut state) = vad_state {
-                                            state.reset();
-                                        }
-                                        if let Some(ref mut engine) = vad_engine {
-                                            engine.reset();
-                                        }
-                                        tracker.reset_dedup();
-
-                                        self.state = DaemonState::ContinuousRecording {
-                                            mark,
-                                            speech_start_pos: None,
-                                            last_vad_pos: start_pos,
-                                            next_chunk_id: 0,
-                                        };
-                                    }
-                                    DaemonState::ContinuousRecording { .. } => {
-                                        // Toggle off - stop continuous recording
-                                        info!("🛑 Continuous recording stopped");
-                                        vad_timer = None;
-                                        self.state = DaemonState::Idle;
-                                    }
-                                    _ => {}
-                                }
-                            } else if matches!(self.state, DaemonState::Idle) {
-                                // Push-to-talk mode: start recording on press
-                                let mark = audio_recorder.mark();
-                                let start_pos = audio_recorder.current_position();
-                                debug!(
-                                    "Hotkey pressed, marked position (sequence_id: {}, pos: {})",
-                                    mark.sequence_id, start_pos
-                                );
-
-                                // Start chunk timer if streaming is enabled
-                                if let Some(interval) = chunk_interval {
-                                    let mut timer = tokio::time::interval(interval);
-                                    timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
-                                    chunk_timer = Some(timer);
+                        HotkeyEvent::Pressed => match &self.state {
+                            DaemonState::Idle => {
+                                // Continuous mode toggles, push-to-talk records until release
+                                self.start_recording(
+                                    is_continuous_mode,
+                                    chunk_interval,
+                                    &audio_recorder,
+                                    &mut vad_engine,
+                                    &mut vad_state,
+                                    &mut tracker,
+                                    &mut chunk_timer,
+                                    &mut vad_timer,
+                                )
+                                .await;
+                            }
+                            DaemonState::ContinuousRecording { .. } if is_continuous_mode => {
+                                // Toggle off - stop continuous recording
+                                info!("🛑 Hands-free dictation stopped");
+                                vad_timer = None;
+                                self.state = DaemonState::Idle;
+                            }
+                            _ => {}
+                        },
+                        HotkeyEvent::Replay => {
+                            replay_secs = Some(self.config.replay.default_secs);
+                        }
+                        HotkeyEvent::CycleLanguage => {
+                            let language = self.config.transcription.next_language(self.language());
+                            info!("🌐 Switching transcription language to '{}' via hotkey", language);
+                            self.pinned_language = Some(language.clone());
+                            if self.config.feedback.visual {
+                                let message = format!("Transcription language: {}", language);
+                                if let Err(e) = self.platform.notify("OpenHush", &message) {
+                                    debug!("Failed to show language notification: {}", e);
                                 }
-
-                                // Reset deduplication state for new recording
-                                tracker.reset_dedup();
-
-                                self.state = DaemonState::Recording {
-                                    mark,
-                                    last_chunk_pos: start_pos,
-                                    next_chunk_id: 0,
-                                };
+                            }
+                            #[cfg(target_os = "linux")]
+                            {
+                                dbus_status.write().await.language = language;
                             }
                         }
                         HotkeyEvent::Released => {
@@ -1755,7 +3327,7 @@ impl Daemon {
                             if is_continuous_mode {
                                 continue;
                             }
-                            if let DaemonState::Recording { mark, last_chunk_pos, next_chunk_id } = std::mem::replace(
+                            if let DaemonState::Recording { mark, last_chunk_pos, next_chunk_id, settings } = std::mem::replace(
                                 &mut self.state,
                                 DaemonState::Idle,
                             ) {
@@ -1768,6 +3340,9 @@ impl Daemon {
                                     mark.sequence_id, next_chunk_id, last_chunk_pos, current_pos
                                 );
 
+                                // Whether any audio of this recording was queued
+                                let mut submitted = next_chunk_id > 0;
+
                                 // Extract final chunk from last position to current
                                 if let Some(buffer) = audio_recorder.extract_chunk(last_chunk_pos, current_pos) {
                                     info!(
@@ -1778,13 +3353,7 @@ impl Daemon {
                                     );
 
                                     // Track pending transcription with backpressure
-                                    let accepted = tracker.add_pending_with_config(
-                                        mark.sequence_id,
-                                        next_chunk_id,
-                                        bp.max_pending,
-                                        bp.high_water_mark,
-                                        bp.strategy,
-                                    );
+                                    let accepted = bp.admit(&mut tracker, &mut journal, mark.sequence_id, next_chunk_id);
                                     if !accepted {
                                         warn!(
                                             "Final chunk rejected due to backpressure (seq {}.{})",
@@ -1794,43 +3363,14 @@ impl Daemon {
                                         notify_backpressure(bp.notify);
                                     } else {
                                         // Auto-load model if not loaded (lazy loading)
-                                        #[cfg(target_os = "linux")]
-                                        {
-                                            let status = dbus_status.read().await;
-                                            if !status.model_loaded {
-                                                drop(status);
-                                                info!("Auto-loading model for transcription...");
-                                                match self.create_engine() {
-                                                    Ok(engine) => {
-                                                        if command_tx.send(WorkerCommand::LoadEngine(engine)).await.is_ok() {
-                                                            let mut status = dbus_status.write().await;
-                                                            status.model_loaded = true;
-                                                            last_transcription_time = Some(std::time::Instant::now());
-                                                            info!("Model auto-loaded successfully");
-                                                        }
-                                                    }
-                                                    Err(e) => {
-                                                        error!("Failed to auto-load model: {}", e);
-                                                    }
-                                                }
-                                            }
-                                        }
-                                        #[cfg(any(target_os = "macos", target_os = "windows"))]
-                                        if !model_loaded {
-                                            info!("Auto-loading model for transcription...");
-                                            match self.create_engine() {
-                                                Ok(engine) => {
-                                                    if command_tx.send(WorkerCommand::LoadEngine(engine)).await.is_ok() {
-                                                        model_loaded = true;
-                                                        last_transcription_time = Some(std::time::Instant::now());
-                                                        info!("Model auto-loaded successfully");
-                                                    }
-                                                }
-                                                Err(e) => {
-                                                    error!("Failed to auto-load model: {}", e);
-                                                }
-                                            }
-                                        }
+                                        self.auto_load_model(
+                                            "transcription",
+                                            &command_tx,
+                                            &events,
+                                            &mut model_loaded,
+                                            &mut last_transcription_time,
+                                        )
+                                        .await;
 
                                         // Submit final job only if accepted
                                         let job = TranscriptionJob {
@@ -1838,11 +3378,17 @@ impl Daemon {
                                             sequence_id: mark.sequence_id,
                                             chunk_id: next_chunk_id,
                                             is_final: true,
+                                            role: EngineRole::Fast,
+                                            priority: JobPriority::Interactive,
+                                            language: Some(settings.language.clone()),
+                                            backend: settings.backend,
                                         };
+                                        journal_job(&mut journal, &job);
                                         command_tx.send(WorkerCommand::Job(job)).await.map_err(|_| {
                                             error!("Transcription worker failed - channel closed");
                                             DaemonError::WorkerFailed
                                         })?;
+                                        submitted = true;
                                     }
                                 } else if next_chunk_id == 0 {
                                     // No chunks emitted and final chunk too short
@@ -1851,9 +3397,39 @@ impl Daemon {
                                     debug!("Final chunk too short, but {} chunks already emitted", next_chunk_id);
                                 }
 
+                                // Queue a second pass of the whole recording on the accurate engine
+                                if submitted && refine_mode != RefineMode::Off {
+                                    if !accurate_engine.is_ready() {
+                                        debug!("Accurate model not loaded, skipping refinement (seq {})", mark.sequence_id);
+                                        accurate_engine.load_in_background(self.config.transcription.clone(), command_tx.clone());
+                                    } else if let Some(buffer) = audio_recorder.extract_chunk(mark.position(), current_pos) {
+                                        tracker.expect_refinement(mark.sequence_id);
+                                        if let Some(ref translator) = translator {
+                                            refine_translators.insert(mark.sequence_id, Arc::clone(translator));
+                                        }
+                                        let job = TranscriptionJob {
+                                            buffer,
+                                            sequence_id: mark.sequence_id,
+                                            chunk_id: 0,
+                                            is_final: true,
+                                            role: EngineRole::Accurate,
+                                            priority: JobPriority::Batch,
+                                            language: Some(settings.language.clone()),
+                                            // The recording is never uploaded a second time
+                                            backend: TranscriptionBackendKind::Local,
+                                        };
+                                        command_tx.send(WorkerCommand::Job(job)).await.map_err(|_| {
+                                            error!("Transcription worker failed - channel closed");
+                                            DaemonError::WorkerFailed
+                                        })?;
+                                    }
+                                }
+
                                 // Flush any buffered results now that hotkey is released
                                 for ready in tracker.take_ready() {
-                                    process_and_output(
+                                    let (sequence_id, chunk_id, duration_secs) = (ready.sequence_id, ready.chunk_id, ready.duration_secs);
+                                    let language = ready.language.clone();
+                                    let outputs = process_and_output(
                                         ready,
                                         &chunk_separator,
                                         &vocabulary_manager,
@@ -1863,9 +3439,29 @@ impl Daemon {
                                         &mut sentence_buffer,
                                         &output_handler,
                                         &action_runner,
+                                        &follow_ups,
                                         &effective_model,
                                     ).await;
+                                    for text in &outputs {
+                                        tracker.record_draft(sequence_id, text);
+                                    }
+                                    for text in outputs.iter().filter(|t| !t.trim().is_empty()) {
+                                        events.transcription(text, duration_secs, text_corrector.is_some(), sequence_id, language.as_deref()).await;
+                                    }
+                                    if let Some(journal) = journal.as_mut() {
+                                        journal.complete(sequence_id, chunk_id);
+                                    }
                                 }
+                                output_refinements(
+                                    &mut tracker,
+                                    refine_mode,
+                                    &vocabulary_manager,
+                                    &text_corrector,
+                                    &mut refine_translators,
+                                    &translation_config,
+                                    &output_handler,
+                                    &events,
+                                ).await;
                             }
                         }
                     }
@@ -1883,26 +3479,96 @@ impl Daemon {
                     // Update last transcription time for idle timeout tracking
                     last_transcription_time = Some(std::time::Instant::now());
 
-                    // Add to tracker
-                    tracker.add_result(result);
+                    // File jobs go back to their submitter, not to the output
+                    let Some(result) = file_jobs.complete(result) else {
+                        continue;
+                    };
+
+                    if let Some(ref error) = result.error {
+                        events.error("transcription_failed", error).await;
+                    }
+
+                    // Add to tracker (accurate results refine an earlier draft)
+                    if journal.as_ref().is_some_and(|j| j.is_recovered(result.sequence_id)) {
+                        if output_recovered(result, &mut journal, &vocabulary_manager, &text_corrector, &events).await {
+                            recovered_saved += 1;
+                        }
+                        recovered_pending = recovered_pending.saturating_sub(1);
+                        if recovered_pending == 0 && recovered_saved > 0 {
+                            let message = format!("Recovered {} unfinished dictation(s) into history", recovered_saved);
+                            info!("{}", message);
+                            let _ = self.platform.notify("OpenHush", &message);
+                            recovered_saved = 0;
+                        }
+                    } else if result.role == EngineRole::Accurate {
+                        tracker.add_refinement(result);
+                    } else {
+                        tracker.add_result(result);
+                    }
+
+                    // Only output when the hotkey is not held: this prevents
+                    // AltGr/modifier keys from affecting typed output. Hands-free
+                    // dictation outputs each utterance as soon as it is transcribed.
+                    if !matches!(self.state, DaemonState::Recording { .. }) {
+                        for mut ready in tracker.take_ready() {
+                            let (sequence_id, chunk_id, duration_secs) = (ready.sequence_id, ready.chunk_id, ready.duration_secs);
+                            let language = ready.language.clone();
+
+                            // A stop phrase ends hands-free dictation and is not typed
+                            if let Some(ref phrase) = self.config.wake_word.stop_phrase {
+                                if let Some(rest) = strip_stop_phrase(&ready.text, phrase) {
+                                    ready.text = rest;
+                                    if matches!(&self.state, DaemonState::ContinuousRecording { mark, .. } if mark.sequence_id == sequence_id) {
+                                        info!("🛑 Hands-free dictation stopped by stop phrase");
+                                        vad_timer = None;
+                                        self.state = DaemonState::Idle;
+                                    }
+                                }
+                            }
+
+                            // Translate the utterance of a "translate" wake word session
+                            let once = translate_once
+                                .as_ref()
+                                .filter(|(seq, _)| *seq == sequence_id)
+                                .map(|(_, translator)| translator.clone());
+                            if once.is_some() && ready.is_final {
+                                translate_once = None;
+                            }
+                            let active_translator = once.or_else(|| translator.clone());
 
-                    // Only output when NOT recording (hotkey released)
-                    // This prevents AltGr/modifier key from affecting typed output
-                    if matches!(self.state, DaemonState::Idle) {
-                        for ready in tracker.take_ready() {
-                            process_and_output(
+                            let outputs = process_and_output(
                                 ready,
                                 &chunk_separator,
                                 &vocabulary_manager,
                                 &text_corrector,
-                                &translator,
+                                &active_translator,
                                 &translation_config,
                                 &mut sentence_buffer,
                                 &output_handler,
                                 &action_runner,
+                                &follow_ups,
                                 &effective_model,
                             ).await;
+                            for text in &outputs {
+                                tracker.record_draft(sequence_id, text);
+                            }
+                            for text in outputs.iter().filter(|t| !t.trim().is_empty()) {
+                                events.transcription(text, duration_secs, text_corrector.is_some(), sequence_id, language.as_deref()).await;
+                            }
+                            if let Some(journal) = journal.as_mut() {
+                                journal.complete(sequence_id, chunk_id);
+                            }
                         }
+                        output_refinements(
+                            &mut tracker,
+                            refine_mode,
+                            &vocabulary_manager,
+                            &text_corrector,
+                            &mut refine_translators,
+                            &translation_config,
+                            &output_handler,
+                            &events,
+                        ).await;
                     } else {
                         debug!("Buffering result while recording (will output on release)");
                     }
@@ -1917,45 +3583,16 @@ impl Daemon {
                     }
                 } => {
                     // Auto-load model before checking state (avoids borrow conflict)
-                    #[cfg(target_os = "linux")]
-                    {
-                        let status = dbus_status.read().await;
-                        if !status.model_loaded {
-                            drop(status);
-                            info!("Auto-loading model for streaming transcription...");
-                            match self.create_engine() {
-                                Ok(engine) => {
-                                    if command_tx.send(WorkerCommand::LoadEngine(engine)).await.is_ok() {
-                                        let mut status = dbus_status.write().await;
-                                        status.model_loaded = true;
-                                        last_transcription_time = Some(std::time::Instant::now());
-                                        info!("Model auto-loaded successfully");
-                                    }
-                                }
-                                Err(e) => {
-                                    error!("Failed to auto-load model: {}", e);
-                                }
-                            }
-                        }
-                    }
-                    #[cfg(any(target_os = "macos", target_os = "windows"))]
-                    if !model_loaded {
-                        info!("Auto-loading model for streaming transcription...");
-                        match self.create_engine() {
-                            Ok(engine) => {
-                                if command_tx.send(WorkerCommand::LoadEngine(engine)).await.is_ok() {
-                                    model_loaded = true;
-                                    last_transcription_time = Some(std::time::Instant::now());
-                                    info!("Model auto-loaded successfully");
-                                }
-                            }
-                            Err(e) => {
-                                error!("Failed to auto-load model: {}", e);
-                            }
-                        }
-                    }
-
-                    if let DaemonState::Recording { ref mark, ref mut last_chunk_pos, ref mut next_chunk_id } = self.state {
+                    self.auto_load_model(
+                        "streaming transcription",
+                        &command_tx,
+                        &events,
+                        &mut model_loaded,
+                        &mut last_transcription_time,
+                    )
+                    .await;
+
+                    if let DaemonState::Recording { ref mark, ref mut last_chunk_pos, ref mut next_chunk_id, ref settings } = self.state {
                         let current_pos = audio_recorder.current_position();
                         debug!(
                             "Chunk timer tick (seq {}.{}, pos: {} -> {})",
@@ -1972,13 +3609,7 @@ impl Daemon {
                             );
 
                             // Track pending transcription with backpressure
-                            let accepted = tracker.add_pending_with_config(
-                                mark.sequence_id,
-                                *next_chunk_id,
-                                bp.max_pending,
-                                bp.high_water_mark,
-                                bp.strategy,
-                            );
+                            let accepted = bp.admit(&mut tracker, &mut journal, mark.sequence_id, *next_chunk_id);
                             if !accepted {
                                 warn!(
                                     "Streaming chunk rejected due to backpressure (seq {}.{})",
@@ -1996,7 +3627,12 @@ impl Daemon {
                                     sequence_id: mark.sequence_id,
                                     chunk_id: *next_chunk_id,
                                     is_final: false,
+                                    role: EngineRole::Fast,
+                                    priority: JobPriority::Recording,
+                                    language: Some(settings.language.clone()),
+                                    backend: settings.backend,
                                 };
+                                journal_job(&mut journal, &job);
                                 command_tx.send(WorkerCommand::Job(job)).await.map_err(|_| {
                                     error!("Transcription worker failed - channel closed");
                                     DaemonError::WorkerFailed
@@ -2021,59 +3657,31 @@ impl Daemon {
                     }
                 } => {
                     // Auto-load model before checking state (avoids borrow conflict)
-                    #[cfg(target_os = "linux")]
-                    {
-                        let status = dbus_status.read().await;
-                        if !status.model_loaded {
-                            drop(status);
-                            info!("Auto-loading model for VAD transcription...");
-                            match self.create_engine() {
-                                Ok(engine) => {
-                                    if command_tx.send(WorkerCommand::LoadEngine(engine)).await.is_ok() {
-                                        let mut status = dbus_status.write().await;
-                                        status.model_loaded = true;
-                                        last_transcription_time = Some(std::time::Instant::now());
-                                        info!("Model auto-loaded successfully");
-                                    }
-                                }
-                                Err(e) => {
-                                    error!("Failed to auto-load model: {}", e);
-                                }
-                            }
-                        }
-                    }
-                    #[cfg(any(target_os = "macos", target_os = "windows"))]
-                    if !model_loaded {
-                        info!("Auto-loading model for VAD transcription...");
-                        match self.create_engine() {
-                            Ok(engine) => {
-                                if command_tx.send(WorkerCommand::LoadEngine(engine)).await.is_ok() {
-                                    model_loaded = true;
-                                    last_transcription_time = Some(std::time::Instant::now());
-                                    info!("Model auto-loaded successfully");
-                                }
-                            }
-                            Err(e) => {
-                                error!("Failed to auto-load model: {}", e);
-                            }
-                        }
-                    }
-
+                    self.auto_load_model(
+                        "VAD transcription",
+                        &command_tx,
+                        &events,
+                        &mut model_loaded,
+                        &mut last_transcription_time,
+                    )
+                    .await;
+
+                    let mut auto_stopped = None;
+                    let mut single_done = false;
                     if let DaemonState::ContinuousRecording {
                         ref mark,
                         ref mut speech_start_pos,
                         ref mut last_vad_pos,
                         ref mut next_chunk_id,
+                        ref settings,
                     } = self.state {
-                        let current_pos = audio_recorder.current_position();
-
-                        // Extract audio from last VAD position to current for VAD processing
-                        if let Some(buffer) = audio_recorder.extract_chunk(*last_vad_pos, current_pos) {
-                            let samples = &buffer.samples;
+                        if let (Some(ref mut engine), Some(ref mut state)) = (&mut vad_engine, &mut vad_state) {
+                            let current_pos = audio_recorder.current_position();
 
-                            // Process through VAD
-                            if let (Some(ref mut engine), Some(ref mut state)) = (&mut vad_engine, &mut vad_state) {
-                                match engine.process(samples) {
+                            // Feed the audio since the last tick once a full VAD window has arrived
+                            let samples = audio_recorder.extract_samples(*last_vad_pos, current_pos);
+                            if samples.len() >= engine.chunk_size() {
+                                match engine.process(&samples) {
                                     Ok(result) => {
                                         // Track speech start position
                                         if result.is_speech && speech_start_pos.is_none() {
@@ -2082,59 +3690,97 @@ impl Daemon {
                                         }
 
                                         // Update state and check for speech segment completion
-                                        if let Some(segment) = state.update(&result, samples.len()) {
-                                            // Speech segment detected - extract and transcribe
-                                            let segment_start = speech_start_pos.take().unwrap_or(segment.start);
-                                            let segment_end = current_pos;
-
-                                            if let Some(segment_buffer) = audio_recorder.extract_chunk(segment_start, segment_end) {
-                                                info!(
-                                                    "🎤 VAD speech segment {:.2}s (seq {}.{}, prob: {:.2})",
-                                                    segment_buffer.duration_secs(),
-                                                    mark.sequence_id,
-                                                    *next_chunk_id,
-                                                    segment.avg_probability
-                                                );
-
-                                                // Track pending transcription with backpressure
-                                                let accepted = tracker.add_pending_with_config(
-                                                    mark.sequence_id,
-                                                    *next_chunk_id,
-                                                    bp.max_pending,
-                                                    bp.high_water_mark,
-                                                    bp.strategy,
-                                                );
-                                                if !accepted {
-                                                    warn!(
-                                                        "VAD segment rejected due to backpressure (seq {}.{})",
-                                                        mark.sequence_id, *next_chunk_id
+                                        match state.update(&result, samples.len()) {
+                                            Some(segment) => {
+                                                // Speech segment detected - extract and transcribe
+                                                let segment_start = speech_start_pos.take().unwrap_or(segment.start);
+                                                let segment_end = current_pos;
+                                                if segment.truncated {
+                                                    // Still speaking: the next piece starts here
+                                                    *speech_start_pos = Some(current_pos);
+                                                }
+
+                                                if let Some(segment_buffer) = audio_recorder.extract_chunk(segment_start, segment_end) {
+                                                    info!(
+                                                        "🎤 VAD speech segment {:.2}s (seq {}.{}, prob: {:.2}{})",
+                                                        segment_buffer.duration_secs(),
+                                                        mark.sequence_id,
+                                                        *next_chunk_id,
+                                                        segment.avg_probability,
+                                                        if segment.truncated { ", max length" } else { "" }
                                                     );
-                                                    #[cfg(unix)]
-                                                    notify_backpressure(bp.notify);
-                                                } else {
-                                                    // Submit transcription job
-                                                    let job = TranscriptionJob {
-                                                        buffer: segment_buffer,
-                                                        sequence_id: mark.sequence_id,
-                                                        chunk_id: *next_chunk_id,
-                                                        is_final: false, // Continuous mode, more may come
-                                                    };
-                                                    command_tx.send(WorkerCommand::Job(job)).await.map_err(|_| {
-                                                        error!("Transcription worker failed - channel closed");
-                                                        DaemonError::WorkerFailed
-                                                    })?;
+
+                                                    // Track pending transcription with backpressure
+                                                    let accepted = bp.admit(&mut tracker, &mut journal, mark.sequence_id, *next_chunk_id);
+                                                    if !accepted {
+                                                        warn!(
+                                                            "VAD segment rejected due to backpressure (seq {}.{})",
+                                                            mark.sequence_id, *next_chunk_id
+                                                        );
+                                                        #[cfg(unix)]
+                                                        notify_backpressure(bp.notify);
+                                                    } else {
+                                                        // Submit transcription job; a translate
+                                                        // session ends after its one utterance
+                                                        single_done = translate_once
+                                                            .as_ref()
+                                                            .is_some_and(|(seq, _)| *seq == mark.sequence_id);
+                                                        let job = TranscriptionJob {
+                                                            buffer: segment_buffer,
+                                                            sequence_id: mark.sequence_id,
+                                                            chunk_id: *next_chunk_id,
+                                                            is_final: single_done, // Continuous mode, more may come
+                                                            role: EngineRole::Fast,
+                                                            priority: JobPriority::Interactive,
+                                                            language: Some(settings.language.clone()),
+                                                            backend: settings.backend,
+                                                        };
+                                                        journal_job(&mut journal, &job);
+                                                        command_tx.send(WorkerCommand::Job(job)).await.map_err(|_| {
+                                                            error!("Transcription worker failed - channel closed");
+                                                            DaemonError::WorkerFailed
+                                                        })?;
+                                                    }
+                                                    *next_chunk_id += 1;
                                                 }
-                                                *next_chunk_id += 1;
                                             }
+                                            // Speech too short to keep: forget where it started
+                                            None if !state.is_speech() => *speech_start_pos = None,
+                                            None => {}
+                                        }
+
+                                        // Stop listening after a long stretch without speech
+                                        let auto_stop_secs = vad_config.auto_stop_secs;
+                                        if auto_stop_secs > 0
+                                            && !state.is_speech()
+                                            && state.silence_secs() >= auto_stop_secs as f32
+                                        {
+                                            auto_stopped = Some(auto_stop_secs);
                                         }
                                     }
                                     Err(e) => {
                                         warn!("VAD processing error: {}", e);
                                     }
                                 }
+                                *last_vad_pos = current_pos;
+                            }
+                        }
+                    }
+                    if single_done {
+                        info!("🛑 Hands-free dictation stopped after the utterance to translate");
+                        vad_timer = None;
+                        self.state = DaemonState::Idle;
+                    } else if let Some(secs) = auto_stopped {
+                        translate_once = None;
+                        info!("🛑 Hands-free dictation stopped after {}s of silence", secs);
+                        vad_timer = None;
+                        self.state = DaemonState::Idle;
+                        if self.config.feedback.visual {
+                            let message = format!("Hands-free dictation stopped after {}s of silence", secs);
+                            if let Err(e) = self.platform.notify("OpenHush", &message) {
+                                debug!("Failed to show hands-free notification: {}", e);
                             }
                         }
-                        *last_vad_pos = current_pos;
                     }
                 }
 
@@ -2170,71 +3816,162 @@ impl Daemon {
                         std::future::pending::<()>().await;
                     }
                 } => {
-                    // Only process wake word when in Idle state
-                    if matches!(self.state, DaemonState::Idle) {
-                        if let Some(ref mut detector) = wake_word_detector {
-                            let current_pos = audio_recorder.current_position();
-
-                            // Extract new audio since last processed position
-                            if current_pos > wake_word_last_pos {
-                                if let Some(buffer) = audio_recorder.extract_chunk(wake_word_last_pos, current_pos) {
-                                    // Feed samples to wake word detector
-                                    if let Some(event) = detector.process(&buffer.samples) {
-                                        info!(
-                                            "🗣️ Wake word detected: \"{}\" (score: {:.2})",
-                                            event.name, event.score
-                                        );
-
-                                        // Play beep and/or show notification if enabled
-                                        if detector.beep_enabled() {
-                                            if let Err(e) = self.platform.play_start_sound() {
-                                                debug!("Failed to play wake word beep: {}", e);
-                                            }
-                                        }
-                                        if detector.notify_enabled() {
-                                            if let Err(e) = self.platform.notify("OpenHush", "Wake word detected - listening...") {
-                                                debug!("Failed to show wake word notification: {}", e);
-                                            }
-                                        }
-
-                                        // Reset detector to avoid repeated detections
-                                        detector.reset();
+                    // Wake words act while idle; while recording only "cancel" ones are heard
+                    let idle = matches!(self.state, DaemonState::Idle);
+                    let mut detected = None;
+                    if let Some(ref mut detector) = wake_word_detector {
+                        let current_pos = audio_recorder.current_position();
 
-                                        // Start recording with VAD-based termination
-                                        let mark = audio_recorder.mark();
-                                        let start_pos = audio_recorder.current_position();
-                                        tracker.reset_dedup();
+                        if !idle && !detector.has_action(WakeWordAction::Cancel) {
+                            wake_word_last_pos = current_pos;
+                        } else if current_pos > wake_word_last_pos {
+                            // Feed new audio since last processed position
+                            let samples = audio_recorder.extract_samples(wake_word_last_pos, current_pos);
+                            wake_word_last_pos = current_pos;
+                            if let Some(event) = detector.process(&samples) {
+                                // Reset detector to avoid repeated detections
+                                detector.reset();
+                                detected = Some((event, detector.beep_enabled(), detector.notify_enabled()));
+                            }
+                        }
+                    }
 
-                                        // Start VAD timer for speech detection
-                                        let mut timer = tokio::time::interval(
-                                            tokio::time::Duration::from_millis(VAD_PROCESS_INTERVAL_MS)
-                                        );
-                                        timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
-                                        vad_timer = Some(timer);
+                    if let Some((event, beep, notify)) = detected {
+                        info!(
+                            "🗣️ Wake word detected: \"{}\" (score: {:.2}, action: {:?})",
+                            event.name, event.score, event.action
+                        );
 
-                                        // Reset VAD state for new recording
-                                        if let Some(ref mut state) = vad_state {
-                                            state.reset();
+                        // Play beep and/or show notification if enabled
+                        let feedback = |platform: &CurrentPlatform, message: &str| {
+                            if beep {
+                                if let Err(e) = platform.play_start_sound() {
+                                    debug!("Failed to play wake word beep: {}", e);
+                                }
+                            }
+                            if notify {
+                                if let Err(e) = platform.notify("OpenHush", message) {
+                                    debug!("Failed to show wake word notification: {}", e);
+                                }
+                            }
+                        };
+
+                        match (event.action, idle) {
+                            (WakeWordAction::Cancel, false) => {
+                                let discarded = self.cancel_recording(
+                                    &mut chunk_timer,
+                                    &mut vad_timer,
+                                    &mut translate_once,
+                                    &mut tracker,
+                                    &mut sentence_buffer,
+                                    &mut journal,
+                                );
+                                info!(
+                                    "🛑 Recording cancelled by wake word ({} pending chunk(s) discarded)",
+                                    discarded
+                                );
+                                feedback(&self.platform, "Recording cancelled");
+                            }
+                            (WakeWordAction::Cancel, true) => {
+                                if let Some(child) = meeting.take() {
+                                    info!("🛑 Meeting recording stopped by wake word");
+                                    stop_meeting_recording(child);
+                                    feedback(&self.platform, "Meeting recording stopped");
+                                } else {
+                                    debug!("Nothing to cancel");
+                                }
+                            }
+                            // Other wake words are ignored while recording
+                            (_, false) => {}
+                            (WakeWordAction::Dictate, true) => {
+                                feedback(&self.platform, "Wake word detected - listening...");
+                                let settings = self.recording_settings().await;
+                                vad_timer = Some(self.start_hands_free(
+                                    &audio_recorder,
+                                    &mut vad_engine,
+                                    &mut vad_state,
+                                    &mut tracker,
+                                    settings,
+                                ));
+                            }
+                            (WakeWordAction::Translate, true) => {
+                                // Use the configured translator, or bring one up for this utterance
+                                let once = match translator {
+                                    Some(ref translator) => Some(translator.clone()),
+                                    None => {
+                                        let mut config = self.config.translation.clone();
+                                        config.enabled = true;
+                                        init_translator(&config, &self.config.models).await
+                                    }
+                                };
+                                match once {
+                                    Some(once) => {
+                                        feedback(&self.platform, "Listening - your next sentence will be translated");
+                                        let settings = self.recording_settings().await;
+                                        vad_timer = Some(self.start_hands_free(
+                                            &audio_recorder,
+                                            &mut vad_engine,
+                                            &mut vad_state,
+                                            &mut tracker,
+                                            settings,
+                                        ));
+                                        if let DaemonState::ContinuousRecording { ref mark, .. } = self.state {
+                                            translate_once = Some((mark.sequence_id, once));
                                         }
-                                        if let Some(ref mut engine) = vad_engine {
-                                            engine.reset();
+                                    }
+                                    None => warn!("No translation engine available, ignoring wake word"),
+                                }
+                            }
+                            (WakeWordAction::Meeting, true) => {
+                                if let Some(child) = meeting.take() {
+                                    info!("🛑 Meeting recording stopped by wake word");
+                                    stop_meeting_recording(child);
+                                    feedback(&self.platform, "Meeting recording stopped");
+                                } else {
+                                    match spawn_meeting_recording() {
+                                        Ok((child, path)) => {
+                                            info!("🎙️ Meeting recording started: {}", path.display());
+                                            meeting = Some(child);
+                                            feedback(&self.platform, "Meeting recording started");
                                         }
+                                        Err(e) => error!("Failed to start meeting recording: {}", e),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
 
-                                        // Transition to continuous recording mode with VAD
-                                        self.state = DaemonState::ContinuousRecording {
-                                            mark,
-                                            speech_start_pos: None,
-                                            last_vad_pos: start_pos,
-                                            next_chunk_id: 0,
-                                        };
-
-                                        // Track timeout for wake word recording
-                                        // TODO: Add timeout handling based on detector.timeout_secs()
+                // Handle input devices being added, removed or the default changing
+                Some(snapshot) = device_rx.recv() => {
+                    let current = audio_recorder
+                        .device_id()
+                        .unwrap_or_else(|| audio_recorder.device_name());
+                    match snapshot.select(
+                        &self.config.audio.preferred_devices,
+                        self.config.audio.input_device.as_deref(),
+                    ) {
+                        Some(device) if device.id != current => {
+                            match audio_recorder.switch_device(Some(&device.id)) {
+                                Ok(name) => {
+                                    info!("🎤 Audio input switched to {}", name);
+                                    events.device_changed(&device.id, &name);
+                                    if self.config.feedback.visual {
+                                        if let Err(e) = self
+                                            .platform
+                                            .notify("OpenHush", &format!("Microphone: {}", name))
+                                        {
+                                            debug!("Failed to show device notification: {}", e);
+                                        }
                                     }
                                 }
-                                wake_word_last_pos = current_pos;
+                                Err(e) => {
+                                    warn!("Failed to switch audio input to {}: {}", device.name, e)
+                                }
                             }
                         }
+                        Some(_) => {}
+                        None => warn!("No audio input device available"),
                     }
                 }
 
@@ -2265,6 +4002,7 @@ impl Daemon {
                                     elapsed
                                 );
                                 if command_tx.send(WorkerCommand::UnloadEngine).await.is_ok() {
+                                    accurate_engine.set_ready(false);
                                     #[cfg(target_os = "linux")]
                                     {
                                         let mut status = dbus_status.write().await;
@@ -2296,14 +4034,33 @@ impl Daemon {
 
         // Cleanup
         hotkey_listener.stop();
+        if let Some(child) = meeting.take() {
+            stop_meeting_recording(child);
+        }
+        #[cfg(target_os = "linux")]
+        if let Some(ref media) = media_controller {
+            media.restore().await;
+        }
         drop(command_tx); // Signal worker to stop by closing the channel
 
         // Wait for worker thread to finish (with timeout)
         info!("Waiting for transcription worker to finish...");
-        match worker_handle.join() {
+        match worker_pool.join() {
             Ok(()) => info!("Transcription worker stopped cleanly"),
             Err(_) => warn!("Transcription worker thread panicked during shutdown"),
         }
+        if !file_jobs.is_empty() {
+            warn!(
+                "{} file transcription(s) abandoned at shutdown",
+                file_jobs.len()
+            );
+        }
+        if let Some(journal) = journal.as_ref().filter(|j| !j.is_empty()) {
+            info!(
+                "{} unfinished chunk(s) kept in the journal for the next start",
+                journal.len()
+            );
+        }
 
         info!("Daemon stopped");
         Ok(())
@@ -2723,6 +4480,37 @@ pub async fn stop() -> Result<(), DaemonError> {
     Ok(())
 }
 
+/// Print per-worker queue statistics of the running daemon (best effort).
+fn print_queue_stats() {
+    use crate::ipc::{IpcClient, IpcCommand, IpcResponseData};
+
+    let response = match IpcClient::connect().and_then(|mut c| c.send(IpcCommand::QueueStats)) {
+        Ok(response) => response,
+        Err(e) => {
+            debug!("Queue statistics unavailable: {}", e);
+            return;
+        }
+    };
+    if let Some(IpcResponseData::Queue {
+        pending,
+        waiting,
+        workers,
+    }) = response.data
+    {
+        println!("  Queue: {} pending, {} waiting", pending, waiting);
+        for worker in workers {
+            println!(
+                "    Worker {}: {} in flight, {} done, {} failed, {:.1}s busy",
+                worker.id,
+                worker.in_flight,
+                worker.completed,
+                worker.failed,
+                worker.busy_ms as f64 / 1000.0
+            );
+        }
+    }
+}
+
 /// Check daemon status
 pub async fn status() -> Result<(), DaemonError> {
     // Try IPC first on macOS and Windows for detailed status
@@ -2753,6 +4541,7 @@ pub async fn status() -> Result<(), DaemonError> {
                                     "not loaded"
                                 }
                             );
+                            print_queue_stats();
                             return Ok(());
                         }
                     }
@@ -2777,6 +4566,7 @@ pub async fn status() -> Result<(), DaemonError> {
         if let Ok(pid_str) = std::fs::read_to_string(&path) {
             println!("OpenHush daemon is running (PID: {})", pid_str.trim());
         }
+        print_queue_stats();
     } else {
         println!("OpenHush daemon is not running");
     }
@@ -1,31 +1,62 @@
 //! D-Bus interface definition for OpenHush daemon.
 //!
 //! Provides the `org.openhush.Daemon1` interface for remote control.
+//!
+//! Besides methods, the interface exposes properties (with
+//! `PropertiesChanged` notifications) and the signals `TranscriptionComplete`,
+//! `ErrorOccurred` and `ModelProgress`, so desktop extensions can integrate
+//! without polling.
 
 use std::sync::Arc;
 use tokio::sync::{mpsc, RwLock};
 use zbus::interface;
+use zbus::object_server::SignalEmitter;
 
-/// Commands that can be sent to the daemon via D-Bus.
-#[derive(Debug, Clone)]
-#[allow(clippy::enum_variant_names)]
-pub enum DaemonCommand {
-    StartRecording,
-    StopRecording,
-    ToggleRecording,
-    /// Load the Whisper model into GPU memory
-    LoadModel,
-    /// Unload the Whisper model to free GPU memory
-    UnloadModel,
-}
+use crate::daemon::DaemonCommand;
+use crate::engine::whisper::is_supported_language;
+use crate::history::TranscriptionHistory;
+
+/// Maximum number of entries returned by a single `GetHistory` call.
+const MAX_HISTORY_ENTRIES: u32 = 100;
 
 /// Shared state exposed via D-Bus properties.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct DaemonStatus {
     pub is_recording: bool,
+    /// Recording is hands-free dictation rather than push-to-talk
+    pub hands_free: bool,
     pub queue_depth: u32,
     /// Whether the Whisper model is currently loaded
     pub model_loaded: bool,
+    /// Active Whisper model name
+    pub model: String,
+    /// Transcription language ("auto" for detection)
+    pub language: String,
+    /// Text of the most recent transcription
+    pub last_transcription: String,
+}
+
+impl DaemonStatus {
+    /// Daemon state as reported by `GetStatus` and the `State` property.
+    pub fn state(&self) -> &'static str {
+        if self.is_recording && self.hands_free {
+            "listening"
+        } else if self.is_recording {
+            "recording"
+        } else if self.queue_depth > 0 {
+            "processing"
+        } else if self.model_loaded {
+            "idle"
+        } else {
+            "standby"
+        }
+    }
+}
+
+/// Check that a language code is "auto" or one Whisper knows, such as
+/// "en" or "haw".
+pub fn is_valid_language(language: &str) -> bool {
+    language == "auto" || is_supported_language(language)
 }
 
 /// D-Bus interface implementation for the daemon.
@@ -37,12 +68,30 @@ pub struct DaemonInterface {
     command_tx: mpsc::Sender<DaemonCommand>,
     /// Shared state for reading properties.
     status: Arc<RwLock<DaemonStatus>>,
+    /// Recent transcriptions for `GetHistory`.
+    history: Arc<RwLock<TranscriptionHistory>>,
 }
 
 impl DaemonInterface {
     /// Create a new D-Bus interface.
-    pub fn new(command_tx: mpsc::Sender<DaemonCommand>, status: Arc<RwLock<DaemonStatus>>) -> Self {
-        Self { command_tx, status }
+    pub fn new(
+        command_tx: mpsc::Sender<DaemonCommand>,
+        status: Arc<RwLock<DaemonStatus>>,
+        history: Arc<RwLock<TranscriptionHistory>>,
+    ) -> Self {
+        Self {
+            command_tx,
+            status,
+            history,
+        }
+    }
+
+    /// Forward a command to the daemon's main loop.
+    async fn send(&self, command: DaemonCommand) -> zbus::fdo::Result<()> {
+        self.command_tx
+            .send(command)
+            .await
+            .map_err(|e| zbus::fdo::Error::Failed(format!("Failed to send command: {}", e)))
     }
 }
 
@@ -50,59 +99,79 @@ impl DaemonInterface {
 impl DaemonInterface {
     /// Start recording audio.
     async fn start_recording(&self) -> zbus::fdo::Result<()> {
-        self.command_tx
-            .send(DaemonCommand::StartRecording)
-            .await
-            .map_err(|e| zbus::fdo::Error::Failed(format!("Failed to send command: {}", e)))?;
-        Ok(())
+        self.send(DaemonCommand::StartRecording).await
     }
 
     /// Stop recording audio.
     async fn stop_recording(&self) -> zbus::fdo::Result<()> {
-        self.command_tx
-            .send(DaemonCommand::StopRecording)
-            .await
-            .map_err(|e| zbus::fdo::Error::Failed(format!("Failed to send command: {}", e)))?;
-        Ok(())
+        self.send(DaemonCommand::StopRecording).await
     }
 
     /// Toggle recording state.
     async fn toggle_recording(&self) -> zbus::fdo::Result<()> {
-        self.command_tx
-            .send(DaemonCommand::ToggleRecording)
-            .await
-            .map_err(|e| zbus::fdo::Error::Failed(format!("Failed to send command: {}", e)))?;
-        Ok(())
+        self.send(DaemonCommand::ToggleRecording).await
     }
 
     /// Load the Whisper model into GPU memory.
     async fn load_model(&self) -> zbus::fdo::Result<()> {
-        self.command_tx
-            .send(DaemonCommand::LoadModel)
-            .await
-            .map_err(|e| zbus::fdo::Error::Failed(format!("Failed to send command: {}", e)))?;
-        Ok(())
+        self.send(DaemonCommand::LoadModel).await
     }
 
     /// Unload the Whisper model to free GPU memory.
     async fn unload_model(&self) -> zbus::fdo::Result<()> {
-        self.command_tx
-            .send(DaemonCommand::UnloadModel)
+        self.send(DaemonCommand::UnloadModel).await
+    }
+
+    /// Abort the current recording without transcribing it and discard
+    /// queued transcriptions.
+    async fn cancel(&self) -> zbus::fdo::Result<()> {
+        self.send(DaemonCommand::Cancel).await
+    }
+
+    /// Switch the transcription language ("auto" for detection).
+    async fn set_language(&self, language: &str) -> zbus::fdo::Result<()> {
+        let language = language.trim().to_lowercase();
+        if !is_valid_language(&language) {
+            return Err(zbus::fdo::Error::InvalidArgs(format!(
+                "Invalid language code '{}' (expected 'auto' or a code like 'en')",
+                language
+            )));
+        }
+        self.send(DaemonCommand::SetLanguage(language)).await
+    }
+
+    /// Transcribe audio already in the ring buffer ("what was just said?").
+    ///
+    /// `secs` of 0 uses the configured default duration.
+    async fn transcribe_last(&self, secs: f64) -> zbus::fdo::Result<()> {
+        if !secs.is_finite() || secs < 0.0 {
+            return Err(zbus::fdo::Error::InvalidArgs(format!(
+                "Invalid duration {} (expected seconds, or 0 for the default)",
+                secs
+            )));
+        }
+        let secs = (secs > 0.0).then_some(secs as f32);
+        self.send(DaemonCommand::TranscribeLast(secs)).await
+    }
+
+    /// Get recent transcriptions, newest first.
+    ///
+    /// Returns `(id, timestamp, text, duration_secs)` tuples; at most 100
+    /// entries are returned per call.
+    async fn get_history(&self, limit: u32, offset: u32) -> Vec<(i64, String, String, f64)> {
+        let limit = limit.min(MAX_HISTORY_ENTRIES) as usize;
+        self.history
+            .read()
             .await
-            .map_err(|e| zbus::fdo::Error::Failed(format!("Failed to send command: {}", e)))?;
-        Ok(())
+            .recent(limit, offset as usize)
+            .into_iter()
+            .map(|item| (item.id, item.timestamp, item.text, item.duration_secs))
+            .collect()
     }
 
     /// Get current daemon status as a string.
     async fn get_status(&self) -> String {
-        let status = self.status.read().await;
-        if status.is_recording {
-            "recording".to_string()
-        } else if status.model_loaded {
-            "idle".to_string()
-        } else {
-            "standby".to_string()
-        }
+        self.status.read().await.state().to_string()
     }
 
     /// Whether the daemon is currently recording.
@@ -123,12 +192,95 @@ impl DaemonInterface {
         self.status.read().await.model_loaded
     }
 
+    /// Daemon state: recording, processing, idle or standby.
+    #[zbus(property)]
+    async fn state(&self) -> String {
+        self.status.read().await.state().to_string()
+    }
+
+    /// Active Whisper model name.
+    #[zbus(property)]
+    async fn model(&self) -> String {
+        self.status.read().await.model.clone()
+    }
+
+    /// Transcription language ("auto" for detection).
+    #[zbus(property)]
+    async fn language(&self) -> String {
+        self.status.read().await.language.clone()
+    }
+
+    /// Text of the most recent transcription.
+    #[zbus(property)]
+    async fn last_transcription(&self) -> String {
+        self.status.read().await.last_transcription.clone()
+    }
+
     /// Daemon version.
     #[zbus(property)]
     fn version(&self) -> &str {
         env!("CARGO_PKG_VERSION")
     }
 
-    // Signals are emitted via SignalContext, not defined here.
-    // See service.rs for signal emission.
+    /// Emitted after a transcription has been output.
+    #[zbus(signal)]
+    pub async fn transcription_complete(
+        emitter: &SignalEmitter<'_>,
+        id: i64,
+        text: &str,
+        duration_secs: f64,
+    ) -> zbus::Result<()>;
+
+    /// Emitted when an operation fails (model load, transcription, output).
+    #[zbus(signal)]
+    pub async fn error_occurred(
+        emitter: &SignalEmitter<'_>,
+        code: &str,
+        message: &str,
+    ) -> zbus::Result<()>;
+
+    /// Emitted while a model is loaded or unloaded.
+    ///
+    /// `progress` is in the range 0.0 to 1.0.
+    #[zbus(signal)]
+    pub async fn model_progress(
+        emitter: &SignalEmitter<'_>,
+        model: &str,
+        progress: f64,
+        status: &str,
+    ) -> zbus::Result<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_state() {
+        let mut status = DaemonStatus::default();
+        assert_eq!(status.state(), "standby");
+
+        status.model_loaded = true;
+        assert_eq!(status.state(), "idle");
+
+        status.queue_depth = 2;
+        assert_eq!(status.state(), "processing");
+
+        status.is_recording = true;
+        assert_eq!(status.state(), "recording");
+
+        status.hands_free = true;
+        assert_eq!(status.state(), "listening");
+    }
+
+    #[test]
+    fn test_is_valid_language() {
+        assert!(is_valid_language("auto"));
+        assert!(is_valid_language("en"));
+        assert!(is_valid_language("haw"));
+        assert!(!is_valid_language("xx"));
+        assert!(!is_valid_language("english"));
+        assert!(!is_valid_language("EN"));
+        assert!(!is_valid_language(""));
+    }
 }
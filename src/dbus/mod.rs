@@ -3,6 +3,9 @@
 //! Provides the `org.openhush.Daemon1` interface for remote control of the daemon,
 //! enabling commands like `openhush recording start|stop|status`.
 //!
+//! The [`mpris`] module talks to media players on the same bus to pause them
+//! while recording.
+//!
 //! # Architecture
 //!
 //! ```text
@@ -19,7 +22,8 @@
 //!
 //! ```ignore
 //! let status = Arc::new(RwLock::new(DaemonStatus::default()));
-//! let (dbus_service, mut dbus_rx) = DbusService::start(status.clone()).await?;
+//! let history = Arc::new(RwLock::new(TranscriptionHistory::default()));
+//! let (dbus_service, mut dbus_rx) = DbusService::start(status.clone(), history).await?;
 //!
 //! // In event loop:
 //! if let Some(cmd) = dbus_rx.try_recv().ok() {
@@ -27,8 +31,12 @@
 //!         DaemonCommand::StartRecording => { /* ... */ }
 //!         DaemonCommand::StopRecording => { /* ... */ }
 //!         DaemonCommand::ToggleRecording => { /* ... */ }
+//!         _ => { /* ... */ }
 //!     }
 //! }
+//!
+//! // Publish state changes as PropertiesChanged signals
+//! dbus_service.sync_properties(&status.read().await.clone()).await?;
 //! ```
 //!
 //! ## Client (CLI side)
@@ -40,7 +48,9 @@
 //! ```
 
 mod interface;
+pub mod mpris;
 mod service;
 
-pub use interface::{DaemonCommand, DaemonStatus};
+pub use interface::DaemonStatus;
+pub use mpris::MediaController;
 pub use service::{DbusClient, DbusService};
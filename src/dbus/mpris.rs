@@ -0,0 +1,362 @@
+//! Pause or duck MPRIS media players while recording.
+//!
+//! Players are discovered on the session bus by their
+//! `org.mpris.MediaPlayer2.*` name. Only players that were playing when
+//! recording started are touched, and only those are resumed (or have their
+//! volume restored) when recording stops. Start and stop requests are carried
+//! out one after another by a single task, in the order they were made.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot, Mutex};
+use tracing::{debug, info};
+use zbus::proxy::CacheProperties;
+use zbus::Connection;
+
+use crate::config::{FeedbackConfig, MediaControl};
+
+/// Bus name prefix of MPRIS media players.
+const MPRIS_PREFIX: &str = "org.mpris.MediaPlayer2.";
+
+/// Upper bound for talking to a single player, so a hung player cannot
+/// stall the others.
+const PLAYER_TIMEOUT: Duration = Duration::from_millis(500);
+
+#[zbus::proxy(
+    interface = "org.mpris.MediaPlayer2.Player",
+    default_path = "/org/mpris/MediaPlayer2"
+)]
+trait Player {
+    fn play(&self) -> zbus::Result<()>;
+    fn pause(&self) -> zbus::Result<()>;
+
+    #[zbus(property)]
+    fn playback_status(&self) -> zbus::Result<String>;
+
+    #[zbus(property)]
+    fn volume(&self) -> zbus::Result<f64>;
+
+    #[zbus(property)]
+    fn set_volume(&self, volume: f64) -> zbus::Result<()>;
+}
+
+/// Requests handled by the media control task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MediaCommand {
+    Suspend,
+    Restore,
+}
+
+/// A command and who to tell once it is done.
+type MediaRequest = (MediaCommand, Option<oneshot::Sender<()>>);
+
+/// A player changed by us, to be restored when recording stops.
+#[derive(Debug)]
+struct AffectedPlayer {
+    bus_name: String,
+    /// Volume before ducking (duck mode only)
+    original_volume: Option<f64>,
+}
+
+struct Inner {
+    connection: Connection,
+    mode: MediaControl,
+    duck_volume: f64,
+    /// Held for the whole suspend/restore operation so they never interleave
+    affected: Mutex<Vec<AffectedPlayer>>,
+}
+
+/// Pauses or ducks playing media players around recordings.
+///
+/// Cheap to clone; all clones share the list of affected players and the
+/// task applying requests.
+#[derive(Clone)]
+pub struct MediaController {
+    commands: mpsc::UnboundedSender<MediaRequest>,
+}
+
+impl MediaController {
+    /// Connect to the session bus.
+    ///
+    /// Returns `Ok(None)` if media control is disabled in the config.
+    pub async fn new(config: &FeedbackConfig) -> zbus::Result<Option<Self>> {
+        if config.media_control == MediaControl::Off {
+            return Ok(None);
+        }
+
+        let connection = Connection::session().await?;
+        info!(
+            "Media control enabled ({})",
+            config.media_control.display_name()
+        );
+
+        let inner = Arc::new(Inner {
+            connection,
+            mode: config.media_control,
+            duck_volume: config.duck_volume.clamp(0.0, 1.0),
+            affected: Mutex::new(Vec::new()),
+        });
+
+        // Ends once the last controller is dropped
+        let (commands, command_rx) = mpsc::unbounded_channel();
+        tokio::spawn(run_commands(command_rx, move |command| {
+            let inner = Arc::clone(&inner);
+            async move {
+                match command {
+                    MediaCommand::Suspend => inner.suspend().await,
+                    MediaCommand::Restore => inner.restore().await,
+                }
+            }
+        }));
+
+        Ok(Some(Self { commands }))
+    }
+
+    /// Pause or duck playing players in the background.
+    pub fn recording_started(&self) {
+        let _ = self.commands.send((MediaCommand::Suspend, None));
+    }
+
+    /// Resume players affected by [`recording_started`](Self::recording_started)
+    /// in the background, once that finished.
+    pub fn recording_stopped(&self) {
+        let _ = self.commands.send((MediaCommand::Restore, None));
+    }
+
+    /// Resume affected players after any pending request and wait until
+    /// that is done (used at shutdown).
+    pub async fn restore(&self) {
+        let (done, finished) = oneshot::channel();
+        if self
+            .commands
+            .send((MediaCommand::Restore, Some(done)))
+            .is_ok()
+        {
+            let _ = finished.await;
+        }
+    }
+}
+
+/// Apply commands one at a time, in the order they were sent.
+async fn run_commands<F, Fut>(mut requests: mpsc::UnboundedReceiver<MediaRequest>, mut apply: F)
+where
+    F: FnMut(MediaCommand) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    while let Some((command, done)) = requests.recv().await {
+        apply(command).await;
+        if let Some(done) = done {
+            let _ = done.send(());
+        }
+    }
+}
+
+impl Inner {
+    /// Pause or duck all currently playing players.
+    async fn suspend(&self) {
+        let mut affected = self.affected.lock().await;
+        if !affected.is_empty() {
+            // Already suspended (e.g. a restore has not run yet)
+            return;
+        }
+
+        for bus_name in self.player_names().await {
+            let result = tokio::time::timeout(PLAYER_TIMEOUT, self.suspend_player(&bus_name)).await;
+            match result {
+                Ok(Ok(Some(player))) => affected.push(player),
+                Ok(Ok(None)) => {}
+                Ok(Err(e)) => debug!("Media control: {} failed: {}", bus_name, e),
+                Err(_) => debug!("Media control: {} timed out", bus_name),
+            }
+        }
+
+        if !affected.is_empty() {
+            info!(
+                "{} {} media player(s) while recording",
+                match self.mode {
+                    MediaControl::Duck => "Ducked",
+                    _ => "Paused",
+                },
+                affected.len()
+            );
+        }
+    }
+
+    /// Resume or restore the volume of players changed by [`suspend`](Self::suspend).
+    async fn restore(&self) {
+        let mut affected = self.affected.lock().await;
+        for player in affected.drain(..) {
+            let result = tokio::time::timeout(PLAYER_TIMEOUT, self.restore_player(&player)).await;
+            match result {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => debug!("Media control: {} failed: {}", player.bus_name, e),
+                Err(_) => debug!("Media control: {} timed out", player.bus_name),
+            }
+        }
+    }
+
+    /// Bus names of all MPRIS players on the session bus.
+    async fn player_names(&self) -> Vec<String> {
+        let proxy = match zbus::fdo::DBusProxy::new(&self.connection).await {
+            Ok(proxy) => proxy,
+            Err(e) => {
+                debug!("Media control: cannot list bus names: {}", e);
+                return Vec::new();
+            }
+        };
+
+        match proxy.list_names().await {
+            Ok(names) => names
+                .into_iter()
+                .map(|name| name.to_string())
+                .filter(|name| name.starts_with(MPRIS_PREFIX))
+                .collect(),
+            Err(e) => {
+                debug!("Media control: cannot list bus names: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    async fn player(&self, bus_name: &str) -> zbus::Result<PlayerProxy<'static>> {
+        PlayerProxy::builder(&self.connection)
+            .destination(bus_name.to_string())?
+            .cache_properties(CacheProperties::No)
+            .build()
+            .await
+    }
+
+    /// Pause or duck one player if it is playing.
+    async fn suspend_player(&self, bus_name: &str) -> zbus::Result<Option<AffectedPlayer>> {
+        let player = self.player(bus_name).await?;
+        if player.playback_status().await? != "Playing" {
+            return Ok(None);
+        }
+
+        let original_volume = match self.mode {
+            MediaControl::Duck => {
+                let volume = player.volume().await?;
+                player.set_volume(volume * self.duck_volume).await?;
+                Some(volume)
+            }
+            _ => {
+                player.pause().await?;
+                None
+            }
+        };
+
+        debug!("Media control: suspended {}", bus_name);
+        Ok(Some(AffectedPlayer {
+            bus_name: bus_name.to_string(),
+            original_volume,
+        }))
+    }
+
+    /// Undo [`suspend_player`](Self::suspend_player).
+    async fn restore_player(&self, affected: &AffectedPlayer) -> zbus::Result<()> {
+        let player = self.player(&affected.bus_name).await?;
+
+        match affected.original_volume {
+            Some(volume) => player.set_volume(volume).await?,
+            None => {
+                // Leave players alone that the user stopped or restarted meanwhile
+                if player.playback_status().await? == "Paused" {
+                    player.play().await?;
+                }
+            }
+        }
+
+        debug!("Media control: restored {}", affected.bus_name);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ===================
+    // Command Ordering Tests
+    // ===================
+
+    #[tokio::test]
+    async fn test_commands_run_in_order_without_overlap() {
+        let log = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let (commands, command_rx) = mpsc::unbounded_channel();
+
+        // A quick start/stop: suspending is slow, restoring is instant
+        for command in [
+            MediaCommand::Suspend,
+            MediaCommand::Restore,
+            MediaCommand::Suspend,
+            MediaCommand::Restore,
+        ] {
+            commands.send((command, None)).unwrap();
+        }
+        drop(commands);
+
+        let task_log = Arc::clone(&log);
+        run_commands(command_rx, move |command| {
+            let log = Arc::clone(&task_log);
+            async move {
+                log.lock().unwrap().push(format!("{:?} start", command));
+                if command == MediaCommand::Suspend {
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                }
+                log.lock().unwrap().push(format!("{:?} end", command));
+            }
+        })
+        .await;
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "Suspend start",
+                "Suspend end",
+                "Restore start",
+                "Restore end",
+                "Suspend start",
+                "Suspend end",
+                "Restore start",
+                "Restore end",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_restore_waits_for_pending_commands() {
+        let log = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let (commands, command_rx) = mpsc::unbounded_channel();
+        let controller = MediaController { commands };
+
+        let task_log = Arc::clone(&log);
+        tokio::spawn(run_commands(command_rx, move |command| {
+            let log = Arc::clone(&task_log);
+            async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                log.lock().unwrap().push(command);
+            }
+        }));
+
+        controller.recording_started();
+        controller.restore().await;
+        assert_eq!(
+            *log.lock().unwrap(),
+            [MediaCommand::Suspend, MediaCommand::Restore]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_command_task_ends_with_last_sender() {
+        let (commands, command_rx) = mpsc::unbounded_channel();
+        let task = tokio::spawn(run_commands(command_rx, |_| async {}));
+
+        commands.send((MediaCommand::Suspend, None)).unwrap();
+        drop(commands);
+        tokio::time::timeout(Duration::from_secs(1), task)
+            .await
+            .expect("task should end")
+            .unwrap();
+    }
+}
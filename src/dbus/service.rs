@@ -1,11 +1,14 @@
 //! D-Bus service setup and client for OpenHush daemon.
 
 use std::sync::Arc;
-use tokio::sync::{mpsc, RwLock};
+use tokio::sync::{mpsc, Mutex, RwLock};
 use tracing::{debug, info};
 use zbus::{connection::Builder, Connection, Result};
 
-use super::interface::{DaemonCommand, DaemonInterface, DaemonStatus};
+use super::interface::{DaemonInterface, DaemonStatus};
+use crate::daemon::DaemonCommand;
+use crate::history::TranscriptionHistory;
+use crate::ipc::HistoryItem;
 
 /// Well-known bus name for the OpenHush daemon.
 pub const BUS_NAME: &str = "org.openhush.Daemon1";
@@ -18,6 +21,8 @@ pub const OBJECT_PATH: &str = "/org/openhush/Daemon1";
 /// Keeps the D-Bus connection alive and provides methods for emitting signals.
 pub struct DbusService {
     connection: Connection,
+    /// Property values last announced via `PropertiesChanged`.
+    announced: Mutex<DaemonStatus>,
 }
 
 impl DbusService {
@@ -27,10 +32,12 @@ impl DbusService {
     /// Returns a channel receiver for commands from D-Bus clients.
     pub async fn start(
         status: Arc<RwLock<DaemonStatus>>,
+        history: Arc<RwLock<TranscriptionHistory>>,
     ) -> Result<(Self, mpsc::Receiver<DaemonCommand>)> {
         let (command_tx, command_rx) = mpsc::channel(32);
 
-        let interface = DaemonInterface::new(command_tx, status);
+        let announced = Mutex::new(status.read().await.clone());
+        let interface = DaemonInterface::new(command_tx, status, history);
 
         let connection = Builder::session()?
             .name(BUS_NAME)?
@@ -40,26 +47,104 @@ impl DbusService {
 
         info!("D-Bus service started: {} at {}", BUS_NAME, OBJECT_PATH);
 
-        Ok((Self { connection }, command_rx))
+        Ok((
+            Self {
+                connection,
+                announced,
+            },
+            command_rx,
+        ))
     }
 
-    /// Emit a signal that recording state has changed.
-    pub async fn emit_recording_changed(&self) -> Result<()> {
-        debug!("Emitting IsRecording property change");
+    /// Emit `PropertiesChanged` for every property that differs from the
+    /// last announced state.
+    ///
+    /// Cheap when nothing changed, so the daemon calls it once per loop
+    /// iteration. `status` must be a snapshot: the shared status lock must
+    /// not be held, as property getters read it.
+    pub async fn sync_properties(&self, status: &DaemonStatus) -> Result<()> {
+        let mut announced = self.announced.lock().await;
+        if *announced == *status {
+            return Ok(());
+        }
+
         let iface_ref = self
             .connection
             .object_server()
             .interface::<_, DaemonInterface>(OBJECT_PATH)
             .await?;
-
-        // Emit property change signal
         let iface = iface_ref.get().await;
-        iface
-            .is_recording_changed(iface_ref.signal_emitter())
-            .await?;
+        let emitter = iface_ref.signal_emitter();
+
+        if announced.is_recording != status.is_recording {
+            debug!("Emitting IsRecording property change");
+            iface.is_recording_changed(emitter).await?;
+        }
+        if announced.queue_depth != status.queue_depth {
+            iface.queue_depth_changed(emitter).await?;
+        }
+        if announced.model_loaded != status.model_loaded {
+            iface.model_loaded_changed(emitter).await?;
+        }
+        if announced.state() != status.state() {
+            iface.state_changed(emitter).await?;
+        }
+        if announced.model != status.model {
+            iface.model_changed(emitter).await?;
+        }
+        if announced.language != status.language {
+            iface.language_changed(emitter).await?;
+        }
+        if announced.last_transcription != status.last_transcription {
+            iface.last_transcription_changed(emitter).await?;
+        }
+
+        *announced = status.clone();
         Ok(())
     }
 
+    /// Emit the `TranscriptionComplete` signal.
+    pub async fn emit_transcription_complete(&self, item: &HistoryItem) -> Result<()> {
+        let iface_ref = self
+            .connection
+            .object_server()
+            .interface::<_, DaemonInterface>(OBJECT_PATH)
+            .await?;
+        DaemonInterface::transcription_complete(
+            iface_ref.signal_emitter(),
+            item.id,
+            &item.text,
+            item.duration_secs,
+        )
+        .await
+    }
+
+    /// Emit the `ErrorOccurred` signal.
+    pub async fn emit_error(&self, code: &str, message: &str) -> Result<()> {
+        debug!("Emitting ErrorOccurred ({}): {}", code, message);
+        let iface_ref = self
+            .connection
+            .object_server()
+            .interface::<_, DaemonInterface>(OBJECT_PATH)
+            .await?;
+        DaemonInterface::error_occurred(iface_ref.signal_emitter(), code, message).await
+    }
+
+    /// Emit the `ModelProgress` signal.
+    pub async fn emit_model_progress(
+        &self,
+        model: &str,
+        progress: f64,
+        status: &str,
+    ) -> Result<()> {
+        let iface_ref = self
+            .connection
+            .object_server()
+            .interface::<_, DaemonInterface>(OBJECT_PATH)
+            .await?;
+        DaemonInterface::model_progress(iface_ref.signal_emitter(), model, progress, status).await
+    }
+
     /// Get a reference to the connection for advanced usage.
     #[allow(dead_code)]
     pub fn connection(&self) -> &Connection {
@@ -126,6 +211,34 @@ impl DbusClient {
         proxy.unload_model().await
     }
 
+    /// Cancel the current recording and pending transcriptions.
+    pub async fn cancel(&self) -> Result<()> {
+        let proxy = DaemonProxy::new(&self.connection).await?;
+        proxy.cancel().await
+    }
+
+    /// Switch the transcription language.
+    pub async fn set_language(&self, language: &str) -> Result<()> {
+        let proxy = DaemonProxy::new(&self.connection).await?;
+        proxy.set_language(language).await
+    }
+
+    /// Transcribe the last `secs` seconds of buffered audio (0 = default).
+    pub async fn transcribe_last(&self, secs: f64) -> Result<()> {
+        let proxy = DaemonProxy::new(&self.connection).await?;
+        proxy.transcribe_last(secs).await
+    }
+
+    /// Get recent transcriptions as `(id, timestamp, text, duration_secs)`.
+    pub async fn get_history(
+        &self,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<(i64, String, String, f64)>> {
+        let proxy = DaemonProxy::new(&self.connection).await?;
+        proxy.get_history(limit, offset).await
+    }
+
     /// Get current status.
     pub async fn get_status(&self) -> Result<String> {
         let proxy = DaemonProxy::new(&self.connection).await?;
@@ -150,6 +263,18 @@ impl DbusClient {
         proxy.model_loaded().await
     }
 
+    /// Get transcription language.
+    pub async fn language(&self) -> Result<String> {
+        let proxy = DaemonProxy::new(&self.connection).await?;
+        proxy.language().await
+    }
+
+    /// Get the most recent transcription.
+    pub async fn last_transcription(&self) -> Result<String> {
+        let proxy = DaemonProxy::new(&self.connection).await?;
+        proxy.last_transcription().await
+    }
+
     /// Get daemon version.
     pub async fn version(&self) -> Result<String> {
         let proxy = DaemonProxy::new(&self.connection).await?;
@@ -169,6 +294,11 @@ trait Daemon {
     fn toggle_recording(&self) -> zbus::Result<()>;
     fn load_model(&self) -> zbus::Result<()>;
     fn unload_model(&self) -> zbus::Result<()>;
+    fn cancel(&self) -> zbus::Result<()>;
+    fn set_language(&self, language: &str) -> zbus::Result<()>;
+    fn transcribe_last(&self, secs: f64) -> zbus::Result<()>;
+    fn get_history(&self, limit: u32, offset: u32)
+        -> zbus::Result<Vec<(i64, String, String, f64)>>;
     fn get_status(&self) -> zbus::Result<String>;
 
     #[zbus(property)]
@@ -180,6 +310,27 @@ trait Daemon {
     #[zbus(property)]
     fn model_loaded(&self) -> zbus::Result<bool>;
 
+    #[zbus(property)]
+    fn state(&self) -> zbus::Result<String>;
+
+    #[zbus(property)]
+    fn model(&self) -> zbus::Result<String>;
+
+    #[zbus(property)]
+    fn language(&self) -> zbus::Result<String>;
+
+    #[zbus(property)]
+    fn last_transcription(&self) -> zbus::Result<String>;
+
     #[zbus(property)]
     fn version(&self) -> zbus::Result<String>;
+
+    #[zbus(signal)]
+    fn transcription_complete(&self, id: i64, text: &str, duration_secs: f64) -> zbus::Result<()>;
+
+    #[zbus(signal)]
+    fn error_occurred(&self, code: &str, message: &str) -> zbus::Result<()>;
+
+    #[zbus(signal)]
+    fn model_progress(&self, model: &str, progress: f64, status: &str) -> zbus::Result<()>;
 }
@@ -8,7 +8,8 @@
 
 #![allow(dead_code)] // Integration with recording module in Phase 5
 
-use crate::config::Config;
+use crate::config::{Config, ModelFamily, ModelsConfig};
+use crate::manifest;
 use pyannote_rs::{EmbeddingExtractor, EmbeddingManager};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
@@ -21,8 +22,10 @@ pub const SAMPLE_RATE: u32 = 16000;
 pub const DEFAULT_SIMILARITY_THRESHOLD: f32 = 0.5;
 
 /// Model file names
-pub const SEGMENTATION_MODEL: &str = "segmentation-3.0.onnx";
-pub const EMBEDDING_MODEL: &str = "wespeaker_en_voxceleb_CAM++.onnx";
+pub use crate::model_store::{
+    DIARIZATION_EMBEDDING_MODEL as EMBEDDING_MODEL,
+    DIARIZATION_SEGMENTATION_MODEL as SEGMENTATION_MODEL,
+};
 
 /// Model download URLs
 const SEGMENTATION_URL: &str =
@@ -98,12 +101,15 @@ impl DiarizationEngine {
     /// Create a new diarization engine.
     ///
     /// Downloads models if not present.
-    pub async fn new(config: DiarizationConfig) -> Result<Self, DiarizationError> {
+    pub async fn new(
+        config: DiarizationConfig,
+        models: &ModelsConfig,
+    ) -> Result<Self, DiarizationError> {
         let models_dir = Self::models_dir()?;
         std::fs::create_dir_all(&models_dir)?;
 
         // Ensure models are downloaded
-        Self::ensure_models(&models_dir).await?;
+        Self::ensure_models(&models_dir, models).await?;
 
         let segmentation_path = models_dir.join(SEGMENTATION_MODEL);
         let embedding_path = models_dir.join(EMBEDDING_MODEL);
@@ -144,18 +150,31 @@ impl DiarizationEngine {
     }
 
     /// Ensure models are downloaded
-    async fn ensure_models(models_dir: &Path) -> Result<(), DiarizationError> {
+    async fn ensure_models(
+        models_dir: &Path,
+        models: &ModelsConfig,
+    ) -> Result<(), DiarizationError> {
         let segmentation_path = models_dir.join(SEGMENTATION_MODEL);
         let embedding_path = models_dir.join(EMBEDDING_MODEL);
 
         if !segmentation_path.exists() {
             info!("Downloading segmentation model...");
-            Self::download_model(SEGMENTATION_URL, &segmentation_path).await?;
+            let url = models.mirrors.url(
+                ModelFamily::Diarization,
+                SEGMENTATION_MODEL,
+                SEGMENTATION_URL.to_string(),
+            );
+            Self::download_model(&url, &segmentation_path).await?;
         }
 
         if !embedding_path.exists() {
             info!("Downloading speaker embedding model...");
-            Self::download_model(EMBEDDING_URL, &embedding_path).await?;
+            let url = models.mirrors.url(
+                ModelFamily::Diarization,
+                EMBEDDING_MODEL,
+                EMBEDDING_URL.to_string(),
+            );
+            Self::download_model(&url, &embedding_path).await?;
         }
 
         Ok(())
@@ -233,6 +252,11 @@ impl DiarizationEngine {
                 debug!("Download progress: {}%", percent);
             }
         }
+        drop(file);
+
+        manifest::verify_download(temp_path.clone(), path.to_path_buf())
+            .await
+            .map_err(|e| DiarizationError::DownloadFailed(e.to_string()))?;
 
         // Rename temp to final
         std::fs::rename(&temp_path, path)?;
@@ -350,10 +374,10 @@ impl DiarizationEngine {
 }
 
 /// Download diarization models (CLI command support)
-pub async fn download_models() -> Result<(), DiarizationError> {
+pub async fn download_models(models: &ModelsConfig) -> Result<(), DiarizationError> {
     let models_dir = DiarizationEngine::models_dir()?;
     std::fs::create_dir_all(&models_dir)?;
-    DiarizationEngine::ensure_models(&models_dir).await?;
+    DiarizationEngine::ensure_models(&models_dir, models).await?;
     println!("Diarization models downloaded to: {}", models_dir.display());
     Ok(())
 }
@@ -0,0 +1,102 @@
+//! Transcription backend abstraction.
+//!
+//! Defines the `TranscriptionBackend` trait implemented by the local
+//! whisper-rs engine and by remote OpenAI-compatible transcription servers.
+
+use super::whisper::{TranscriptionResult, WhisperEngine, WhisperError};
+use crate::input::AudioBuffer;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Errors from transcription backends.
+#[derive(Error, Debug)]
+pub enum BackendError {
+    #[error(transparent)]
+    Whisper(#[from] WhisperError),
+
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("Server returned error: {0}")]
+    ApiError(String),
+
+    #[error("Authentication failed: {0}")]
+    AuthError(String),
+
+    #[error("Configuration error: {0}")]
+    ConfigError(String),
+
+    #[error("Invalid response: {0}")]
+    InvalidResponse(String),
+
+    #[error("Failed to encode audio: {0}")]
+    Encoding(#[from] hound::Error),
+}
+
+/// Trait for transcription backends.
+///
+/// Calls block until the transcription is done; backends are driven from
+/// the dedicated worker threads.
+pub trait TranscriptionBackend: Send {
+    /// Get the backend name (e.g., "whisper", "http").
+    fn name(&self) -> &'static str;
+
+    /// Get the model name being used.
+    fn model(&self) -> &str;
+
+    /// Check if the backend can take jobs right now.
+    fn is_available(&self) -> bool;
+
+    /// Transcribe an audio buffer.
+    ///
+    /// `language` ("auto" or an ISO code) overrides the backend's configured
+    /// language for this call only.
+    fn transcribe(
+        &self,
+        audio: &AudioBuffer,
+        language: Option<&str>,
+    ) -> Result<TranscriptionResult, BackendError>;
+}
+
+/// A backend shared by all workers of a pool.
+pub type SharedBackend = Arc<dyn TranscriptionBackend + Sync>;
+
+impl TranscriptionBackend for WhisperEngine {
+    fn name(&self) -> &'static str {
+        "whisper"
+    }
+
+    fn model(&self) -> &str {
+        self.model_name()
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn transcribe(
+        &self,
+        audio: &AudioBuffer,
+        language: Option<&str>,
+    ) -> Result<TranscriptionResult, BackendError> {
+        Ok(match language {
+            Some(language) => self.transcribe_in(audio, language)?,
+            None => WhisperEngine::transcribe(self, audio)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_error_display() {
+        let err = BackendError::AuthError("invalid key".to_string());
+        assert!(err.to_string().contains("Authentication failed"));
+        assert!(err.to_string().contains("invalid key"));
+
+        let err = BackendError::Whisper(WhisperError::LoadFailed("oops".to_string()));
+        assert_eq!(err.to_string(), "Failed to load model: oops");
+    }
+}
@@ -0,0 +1,467 @@
+//! Model catalog: downloadable Whisper variants and local custom models.
+//!
+//! Built-in models are named `<model>[-q5|-q8]`, e.g. `small.en` or
+//! `large-v3-turbo-q8`. A name matching an entry in
+//! `transcription.custom_models` refers to a local ggml file instead and is
+//! never downloaded.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::config::TranscriptionConfig;
+use crate::engine::whisper::{all_models, models_dir, WhisperError, WhisperModel};
+
+/// ggml file magic ("ggml" read as a little-endian u32)
+const GGML_MAGIC: u32 = 0x6767_6d6c;
+
+/// whisper.cpp stores the weight type as `ftype + GGML_QNT_VERSION * 1000`
+const GGML_QNT_VERSION_FACTOR: u32 = 1000;
+
+/// Header size up to and including `ftype`: magic plus 11 hyperparameters
+const GGML_HEADER_LEN: usize = 48;
+
+/// Weight quantization of a downloadable model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Quantization {
+    /// Unquantized (f16) weights
+    #[default]
+    F16,
+    /// 5-bit weights, about a third of the f16 size
+    Q5,
+    /// 8-bit weights, about half the f16 size
+    Q8,
+}
+
+impl Quantization {
+    /// Suffix appended to model names ("" for f16)
+    #[must_use]
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Self::F16 => "",
+            Self::Q5 => "-q5",
+            Self::Q8 => "-q8",
+        }
+    }
+
+    /// Approximate file size relative to f16 weights, in percent
+    fn size_percent(&self) -> u64 {
+        match self {
+            Self::F16 => 100,
+            Self::Q5 => 36,
+            Self::Q8 => 55,
+        }
+    }
+}
+
+/// A downloadable model: a Whisper model in a given quantization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModelSpec {
+    pub model: WhisperModel,
+    pub quantization: Quantization,
+}
+
+impl From<WhisperModel> for ModelSpec {
+    fn from(model: WhisperModel) -> Self {
+        Self {
+            model,
+            quantization: Quantization::F16,
+        }
+    }
+}
+
+impl std::str::FromStr for ModelSpec {
+    type Err = ();
+
+    /// Parse `<model>[-q5|-q8]`; the exact ggml type (`-q5_0`, `-q8_0`, ...)
+    /// is accepted as well.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.to_lowercase();
+        let (base, quantization) = [
+            ("-q5_0", Quantization::Q5),
+            ("-q5_1", Quantization::Q5),
+            ("-q5", Quantization::Q5),
+            ("-q8_0", Quantization::Q8),
+            ("-q8", Quantization::Q8),
+        ]
+        .iter()
+        .find_map(|(suffix, q)| lower.strip_suffix(suffix).map(|base| (base, *q)))
+        .unwrap_or((lower.as_str(), Quantization::F16));
+
+        let spec = Self {
+            model: base.parse()?,
+            quantization,
+        };
+        if spec.is_published() {
+            Ok(spec)
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl std::fmt::Display for ModelSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.model.name(), self.quantization.suffix())
+    }
+}
+
+impl ModelSpec {
+    /// ggml weight type of the published file, `None` if this model is not
+    /// published in this quantization.
+    fn ggml_type(&self) -> Option<&'static str> {
+        use WhisperModel::*;
+        match (self.quantization, self.model) {
+            (Quantization::F16, _) => Some("f16"),
+            (_, DistilLargeV3) => None,
+            (Quantization::Q5, Tiny | TinyEn | Base | BaseEn | Small | SmallEn) => Some("q5_1"),
+            (Quantization::Q5, _) => Some("q5_0"),
+            (Quantization::Q8, LargeV3) => None,
+            (Quantization::Q8, _) => Some("q8_0"),
+        }
+    }
+
+    /// Whether this model/quantization combination can be downloaded
+    #[must_use]
+    pub fn is_published(&self) -> bool {
+        self.ggml_type().is_some()
+    }
+
+    /// Canonical name, e.g. "medium-q5"
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.to_string()
+    }
+
+    /// Get the model filename, e.g. "ggml-medium-q5_0.bin"
+    #[must_use]
+    pub fn filename(&self) -> String {
+        match (self.quantization, self.ggml_type()) {
+            (Quantization::F16, _) | (_, None) => self.model.filename().to_string(),
+            (_, Some(ggml_type)) => format!("ggml-{}-{}.bin", self.model.name(), ggml_type),
+        }
+    }
+
+    /// Quantization label for listings ("f16", "q5_0", ...)
+    #[must_use]
+    pub fn quantization_label(&self) -> &'static str {
+        self.ggml_type().unwrap_or("f16")
+    }
+
+    /// Get model size in bytes (approximate)
+    #[must_use]
+    pub fn size_bytes(&self) -> u64 {
+        self.model.size_bytes() / 100 * self.quantization.size_percent()
+    }
+
+    /// Project that publishes the model
+    #[must_use]
+    pub fn origin(&self) -> &'static str {
+        match self.model {
+            WhisperModel::DistilLargeV3 => "distil-whisper",
+            _ => "whisper.cpp",
+        }
+    }
+
+    /// Get Hugging Face download URL
+    #[must_use]
+    pub fn download_url(&self) -> String {
+        format!(
+            "https://huggingface.co/{}/resolve/main/{}",
+            self.model.repository(),
+            self.filename()
+        )
+    }
+}
+
+/// All downloadable models in every published quantization.
+#[must_use]
+pub fn all_specs() -> Vec<ModelSpec> {
+    all_models()
+        .into_iter()
+        .flat_map(|model| {
+            [Quantization::F16, Quantization::Q5, Quantization::Q8]
+                .into_iter()
+                .map(move |quantization| ModelSpec {
+                    model,
+                    quantization,
+                })
+        })
+        .filter(ModelSpec::is_published)
+        .collect()
+}
+
+/// A configured model name resolved to a concrete model.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolvedModel {
+    /// Built-in model stored in the models directory
+    Catalog(ModelSpec),
+    /// Local model file registered in `transcription.custom_models`
+    Custom(PathBuf),
+}
+
+impl ResolvedModel {
+    /// Path of the model file
+    pub fn path(&self) -> Result<PathBuf, WhisperError> {
+        match self {
+            Self::Catalog(spec) => Ok(models_dir()?.join(spec.filename())),
+            Self::Custom(path) => Ok(path.clone()),
+        }
+    }
+
+    /// Downloadable model, `None` for custom models
+    #[must_use]
+    pub fn spec(&self) -> Option<ModelSpec> {
+        match self {
+            Self::Catalog(spec) => Some(*spec),
+            Self::Custom(_) => None,
+        }
+    }
+}
+
+/// Resolve a model name from config or the command line.
+///
+/// Custom models take precedence over built-in models of the same name.
+pub fn resolve_model(
+    name: &str,
+    config: &TranscriptionConfig,
+) -> Result<ResolvedModel, WhisperError> {
+    if let Some(custom) = config.custom_model(name) {
+        return Ok(ResolvedModel::Custom(expand_home(&custom.path)));
+    }
+
+    name.parse::<ModelSpec>()
+        .map(ResolvedModel::Catalog)
+        .map_err(|()| WhisperError::UnknownModel(name.to_string()))
+}
+
+/// Expand a leading `~` to the home directory.
+#[must_use]
+pub fn expand_home(path: &str) -> PathBuf {
+    let rest = if path == "~" {
+        Some("")
+    } else {
+        path.strip_prefix("~/")
+    };
+
+    match (rest, dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Read the weight type ("f16", "q5_0", ...) from a whisper ggml file header.
+///
+/// Returns `None` if the file cannot be read or is not a ggml model.
+#[must_use]
+pub fn read_weight_type(path: &Path) -> Option<&'static str> {
+    let mut header = [0u8; GGML_HEADER_LEN];
+    File::open(path).ok()?.read_exact(&mut header).ok()?;
+    parse_weight_type(&header)
+}
+
+fn parse_weight_type(header: &[u8]) -> Option<&'static str> {
+    let field = |index: usize| {
+        header
+            .get(index * 4..index * 4 + 4)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u32::from_le_bytes)
+    };
+
+    if field(0)? != GGML_MAGIC {
+        return None;
+    }
+
+    // Hyperparameters: n_vocab, n_audio_ctx, n_audio_state, n_audio_head,
+    // n_audio_layer, n_text_ctx, n_text_state, n_text_head, n_text_layer,
+    // n_mels, ftype
+    let ftype = field(11)? % GGML_QNT_VERSION_FACTOR;
+    Some(match ftype {
+        0 => "f32",
+        1 => "f16",
+        2 => "q4_0",
+        3 => "q4_1",
+        7 => "q8_0",
+        8 => "q5_0",
+        9 => "q5_1",
+        10 => "q2_k",
+        11 => "q3_k",
+        12 => "q4_k",
+        13 => "q5_k",
+        14 => "q6_k",
+        _ => "unknown",
+    })
+}
+
+/// A row of `openhush model list`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelListing {
+    pub name: String,
+    /// File size if installed, otherwise the approximate download size
+    pub size_bytes: Option<u64>,
+    pub quantization: String,
+    /// "whisper.cpp", "distil-whisper" or "custom"
+    pub origin: String,
+    pub installed: bool,
+    pub description: String,
+    /// Model file of custom models
+    pub path: Option<PathBuf>,
+}
+
+/// List built-in models, installed quantized variants and custom models.
+///
+/// Quantized variants that are not installed are left out to keep the
+/// list readable.
+#[must_use]
+pub fn list_models(config: &TranscriptionConfig) -> Vec<ModelListing> {
+    let dir = models_dir().ok();
+    let installed_size = |filename: &str| {
+        dir.as_ref()
+            .and_then(|dir| std::fs::metadata(dir.join(filename)).ok())
+            .map(|meta| meta.len())
+    };
+
+    let mut listings: Vec<ModelListing> = all_specs()
+        .into_iter()
+        .filter_map(|spec| {
+            let file_size = installed_size(&spec.filename());
+            if spec.quantization != Quantization::F16 && file_size.is_none() {
+                return None;
+            }
+            Some(ModelListing {
+                name: spec.name(),
+                size_bytes: file_size.or(Some(spec.size_bytes())),
+                quantization: spec.quantization_label().to_string(),
+                origin: spec.origin().to_string(),
+                installed: file_size.is_some(),
+                description: spec.model.description().to_string(),
+                path: None,
+            })
+        })
+        .collect();
+
+    for custom in &config.custom_models {
+        let path = expand_home(&custom.path);
+        let file_size = std::fs::metadata(&path).ok().map(|meta| meta.len());
+        listings.push(ModelListing {
+            name: custom.name.clone(),
+            size_bytes: file_size,
+            quantization: read_weight_type(&path).unwrap_or("-").to_string(),
+            origin: "custom".to_string(),
+            installed: file_size.is_some(),
+            description: custom.description.clone().unwrap_or_default(),
+            path: Some(path),
+        });
+    }
+
+    listings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CustomModelConfig;
+
+    fn ggml_header(ftype: u32) -> Vec<u8> {
+        let mut header = GGML_MAGIC.to_le_bytes().to_vec();
+        for value in [51865u32, 1500, 384, 6, 4, 448, 384, 6, 4, 80, ftype] {
+            header.extend_from_slice(&value.to_le_bytes());
+        }
+        header
+    }
+
+    #[test]
+    fn test_spec_parse_quantized() {
+        let spec: ModelSpec = "large-v3-turbo-q8".parse().unwrap();
+        assert_eq!(spec.model, WhisperModel::LargeV3Turbo);
+        assert_eq!(spec.quantization, Quantization::Q8);
+        assert_eq!(spec.filename(), "ggml-large-v3-turbo-q8_0.bin");
+
+        let spec: ModelSpec = "small.en-q5_1".parse().unwrap();
+        assert_eq!(spec.name(), "small.en-q5");
+        assert_eq!(spec.filename(), "ggml-small.en-q5_1.bin");
+
+        let spec: ModelSpec = "medium".parse().unwrap();
+        assert_eq!(spec, ModelSpec::from(WhisperModel::Medium));
+        assert_eq!(spec.filename(), "ggml-medium.bin");
+    }
+
+    #[test]
+    fn test_spec_rejects_unpublished() {
+        assert!("distil-large-v3-q5".parse::<ModelSpec>().is_err());
+        assert!("large-v3-q8".parse::<ModelSpec>().is_err());
+        assert!("bogus-q5".parse::<ModelSpec>().is_err());
+    }
+
+    #[test]
+    fn test_all_specs_round_trip() {
+        let specs = all_specs();
+        assert!(specs.len() > all_models().len());
+        for spec in specs {
+            assert_eq!(spec.name().parse(), Ok(spec));
+            assert!(spec.download_url().ends_with(&spec.filename()));
+        }
+    }
+
+    #[test]
+    fn test_quantized_size_is_smaller() {
+        let f16 = ModelSpec::from(WhisperModel::Medium);
+        let q5: ModelSpec = "medium-q5".parse().unwrap();
+        let q8: ModelSpec = "medium-q8".parse().unwrap();
+        assert!(q5.size_bytes() < q8.size_bytes());
+        assert!(q8.size_bytes() < f16.size_bytes());
+    }
+
+    #[test]
+    fn test_resolve_custom_model_first() {
+        let config = TranscriptionConfig {
+            custom_models: vec![CustomModelConfig {
+                name: "legal".into(),
+                path: "/opt/models/ggml-legal.bin".into(),
+                description: None,
+            }],
+            ..Default::default()
+        };
+
+        let resolved = resolve_model("legal", &config).unwrap();
+        assert_eq!(resolved.spec(), None);
+        assert_eq!(
+            resolved.path().unwrap(),
+            PathBuf::from("/opt/models/ggml-legal.bin")
+        );
+
+        let resolved = resolve_model("base-q8", &config).unwrap();
+        assert_eq!(resolved.spec().unwrap().filename(), "ggml-base-q8_0.bin");
+
+        assert!(matches!(
+            resolve_model("nonexistent", &config),
+            Err(WhisperError::UnknownModel(_))
+        ));
+    }
+
+    #[test]
+    fn test_expand_home() {
+        assert_eq!(expand_home("/abs/path"), PathBuf::from("/abs/path"));
+        if let Some(home) = dirs::home_dir() {
+            assert_eq!(expand_home("~/models/a.bin"), home.join("models/a.bin"));
+        }
+    }
+
+    #[test]
+    fn test_parse_weight_type() {
+        assert_eq!(parse_weight_type(&ggml_header(1)), Some("f16"));
+        // Quantized files carry the quantization version in the upper digits
+        assert_eq!(parse_weight_type(&ggml_header(2008)), Some("q5_0"));
+        assert_eq!(parse_weight_type(&ggml_header(2007)), Some("q8_0"));
+        assert_eq!(parse_weight_type(b"RIFF....WAVE"), None);
+    }
+
+    #[test]
+    fn test_read_weight_type_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ggml-custom.bin");
+        std::fs::write(&path, ggml_header(2009)).unwrap();
+        assert_eq!(read_weight_type(&path), Some("q5_1"));
+        assert_eq!(read_weight_type(&dir.path().join("missing.bin")), None);
+    }
+}
@@ -1,8 +1,17 @@
 //! Transcription engine using Whisper.
 
+pub mod backend;
+pub mod catalog;
+pub mod recommend;
+pub mod remote;
 pub mod validation;
 pub mod whisper;
 
+#[allow(unused_imports)]
+pub use backend::{BackendError, SharedBackend, TranscriptionBackend};
+#[allow(unused_imports)]
+pub use remote::{HttpBackend, HttpBackendConfig};
+
 #[allow(unused_imports)]
 pub use validation::{validate_audio, AudioValidationError, AudioValidationInfo};
 #[allow(unused_imports)]
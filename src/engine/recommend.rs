@@ -0,0 +1,327 @@
+//! Model recommendation from a hardware benchmark.
+//!
+//! Each downloaded model is loaded and run on a test clip to measure its
+//! latency, real-time factor and memory use. The most accurate model that
+//! fits the latency (and optionally memory) budget is recommended.
+//!
+//! Memory is read from `/proc/self/status` and is only measured on Linux;
+//! elsewhere the model's file size stands in for it.
+
+use std::time::Instant;
+use tracing::info;
+
+use crate::config::{ResamplingQuality, TranscriptionPreset};
+use crate::engine::catalog::{ModelSpec, Quantization};
+use crate::engine::whisper::{models_dir, WhisperEngine, WhisperError, WhisperModel};
+use crate::input::{load_wav_bytes, AudioBuffer};
+
+/// Default latency budget: time to transcribe one dictation utterance
+pub const DEFAULT_LATENCY_BUDGET_SECS: f32 = 1.5;
+
+/// Timed runs per model (after one warm-up run)
+const BENCHMARK_RUNS: u32 = 2;
+
+/// Eight seconds of read English speech, 16kHz mono (see
+/// `benchmark_speech.wav.license` for its source)
+const TEST_CLIP_WAV: &[u8] = include_bytes!("benchmark_speech.wav");
+
+/// Benchmark result for one model.
+#[derive(Debug, Clone)]
+pub struct ModelBenchmark {
+    pub spec: ModelSpec,
+    /// Time to load the model
+    pub load_secs: f32,
+    /// Average time to transcribe the test clip
+    pub latency_secs: f32,
+    /// Real-time factor (processing time / audio duration)
+    pub rtf: f32,
+    /// Resident memory added by the model (RAM only; VRAM is not included)
+    pub memory_bytes: u64,
+    /// True if memory could not be measured (outside Linux) and the file
+    /// size is used instead
+    pub memory_estimated: bool,
+}
+
+/// Limits a recommended model must stay within.
+#[derive(Debug, Clone, Copy)]
+pub struct Budget {
+    pub latency_secs: f32,
+    pub max_memory_bytes: Option<u64>,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self {
+            latency_secs: DEFAULT_LATENCY_BUDGET_SECS,
+            max_memory_bytes: None,
+        }
+    }
+}
+
+impl Budget {
+    /// Whether a benchmarked model fits this budget
+    #[must_use]
+    pub fn fits(&self, result: &ModelBenchmark) -> bool {
+        result.latency_secs <= self.latency_secs
+            && self
+                .max_memory_bytes
+                .is_none_or(|max| result.memory_bytes <= max)
+    }
+}
+
+/// The chosen model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Recommendation {
+    /// Index into the benchmark results
+    pub index: usize,
+    /// False if no model fits the budget and the fastest one was picked
+    pub within_budget: bool,
+}
+
+/// Built-in test clip: a short recording of real speech.
+///
+/// Whisper's cost depends on the number of decoded tokens, so silence or
+/// synthetic audio would understate it.
+#[must_use]
+pub fn test_clip() -> AudioBuffer {
+    load_wav_bytes(TEST_CLIP_WAV, ResamplingQuality::Low)
+        .expect("built-in benchmark clip is a valid 16kHz WAV")
+}
+
+/// Load a downloaded model and time it on `clip`.
+pub fn benchmark_model(
+    spec: ModelSpec,
+    clip: &AudioBuffer,
+    language: &str,
+    use_gpu: bool,
+) -> Result<ModelBenchmark, WhisperError> {
+    info!("Benchmarking {}...", spec);
+    let path = models_dir()?.join(spec.filename());
+    let memory_before = resident_memory_bytes();
+
+    let start = Instant::now();
+    let engine = WhisperEngine::new(&path, language, false, use_gpu)?;
+    let load_secs = start.elapsed().as_secs_f32();
+
+    // The chunk interval is not used here, so no safety margin
+    let timing = engine.benchmark_audio(clip, BENCHMARK_RUNS, 0.0)?;
+
+    let (memory_bytes, memory_estimated) = match (memory_before, resident_memory_bytes()) {
+        (Some(before), Some(after)) if after > before => (after - before, false),
+        _ => (spec.size_bytes(), true),
+    };
+    drop(engine);
+
+    Ok(ModelBenchmark {
+        spec,
+        load_secs,
+        latency_secs: timing.overhead_secs,
+        rtf: timing.rtf(),
+        memory_bytes,
+        memory_estimated,
+    })
+}
+
+/// Accuracy ordering: larger models first, English-only variants preferred
+/// for English, f16 over q8 over q5. `None` if unsuitable for the language.
+fn accuracy_key(spec: &ModelSpec, language: &str) -> Option<(u8, bool, u8)> {
+    use WhisperModel::*;
+
+    let english = language == "en";
+    let english_focused = spec.model.is_english_only() || spec.model == DistilLargeV3;
+    if english_focused && !english {
+        return None;
+    }
+
+    let size = match spec.model {
+        Tiny | TinyEn => 1,
+        Base | BaseEn => 2,
+        Small | SmallEn => 3,
+        Medium | MediumEn => 4,
+        DistilLargeV3 => 5,
+        LargeV3Turbo => 6,
+        LargeV3 => 7,
+    };
+    let quantization = match spec.quantization {
+        Quantization::F16 => 2,
+        Quantization::Q8 => 1,
+        Quantization::Q5 => 0,
+    };
+    Some((size, spec.model.is_english_only(), quantization))
+}
+
+/// Pick the most accurate model within the budget, or the fastest suitable
+/// model if none fits.
+#[must_use]
+pub fn recommend(
+    results: &[ModelBenchmark],
+    budget: &Budget,
+    language: &str,
+) -> Option<Recommendation> {
+    let suitable: Vec<(usize, (u8, bool, u8))> = results
+        .iter()
+        .enumerate()
+        .filter_map(|(i, r)| accuracy_key(&r.spec, language).map(|key| (i, key)))
+        .collect();
+
+    let best = suitable
+        .iter()
+        .filter(|(i, _)| budget.fits(&results[*i]))
+        .max_by(|(a, key_a), (b, key_b)| {
+            key_a.cmp(key_b).then(
+                // Equally accurate: prefer the faster one
+                results[*b]
+                    .latency_secs
+                    .total_cmp(&results[*a].latency_secs),
+            )
+        });
+    if let Some((index, _)) = best {
+        return Some(Recommendation {
+            index: *index,
+            within_budget: true,
+        });
+    }
+
+    suitable
+        .iter()
+        .min_by(|(a, _), (b, _)| {
+            results[*a]
+                .latency_secs
+                .total_cmp(&results[*b].latency_secs)
+        })
+        .map(|(index, _)| Recommendation {
+            index: *index,
+            within_budget: false,
+        })
+}
+
+/// Preset that selects `spec`, or `Custom` if no preset uses it.
+#[must_use]
+pub fn preset_for(spec: &ModelSpec) -> TranscriptionPreset {
+    let name = spec.name();
+    [
+        TranscriptionPreset::Instant,
+        TranscriptionPreset::Balanced,
+        TranscriptionPreset::Quality,
+    ]
+    .into_iter()
+    .find(|preset| preset.model() == name)
+    .unwrap_or(TranscriptionPreset::Custom)
+}
+
+/// Resident memory of this process, if available (Linux only: other
+/// platforms have no cheap equivalent of `VmRSS` without extra crates).
+fn resident_memory_bytes() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let line = status.lines().find(|l| l.starts_with("VmRSS:"))?;
+        let kb: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+        Some(kb * 1024)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::validation::validate_audio;
+
+    /// Length of the built-in test clip (a typical dictation utterance)
+    const TEST_CLIP_SECS: f32 = 8.0;
+
+    fn result(name: &str, latency_secs: f32, memory_mb: u64) -> ModelBenchmark {
+        ModelBenchmark {
+            spec: name.parse().unwrap(),
+            load_secs: 1.0,
+            latency_secs,
+            rtf: latency_secs / TEST_CLIP_SECS,
+            memory_bytes: memory_mb * 1024 * 1024,
+            memory_estimated: false,
+        }
+    }
+
+    #[test]
+    fn test_clip_is_valid_speech_level_audio() {
+        let clip = test_clip();
+        assert_eq!(clip.sample_rate, 16000);
+        assert!((clip.duration_secs() - TEST_CLIP_SECS).abs() < 0.01);
+
+        let info = validate_audio(&clip.samples, clip.sample_rate).unwrap();
+        assert!(info.rms > 0.01, "rms too low: {}", info.rms);
+        assert!(info.max_value <= 1.0 && info.min_value >= -1.0);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_resident_memory_is_measured_on_linux() {
+        assert!(resident_memory_bytes().is_some_and(|bytes| bytes > 0));
+    }
+
+    #[test]
+    fn test_recommend_most_accurate_within_budget() {
+        let results = vec![
+            result("base", 0.2, 200),
+            result("small", 0.6, 600),
+            result("medium", 1.4, 1600),
+            result("large-v3", 3.0, 3500),
+        ];
+
+        let rec = recommend(&results, &Budget::default(), "de").unwrap();
+        assert_eq!(results[rec.index].spec.name(), "medium");
+        assert!(rec.within_budget);
+
+        let budget = Budget {
+            latency_secs: 1.5,
+            max_memory_bytes: Some(1024 * 1024 * 1024),
+        };
+        let rec = recommend(&results, &budget, "de").unwrap();
+        assert_eq!(results[rec.index].spec.name(), "small");
+    }
+
+    #[test]
+    fn test_recommend_falls_back_to_fastest() {
+        let results = vec![result("medium", 4.0, 1600), result("small", 2.0, 600)];
+        let rec = recommend(&results, &Budget::default(), "en").unwrap();
+        assert_eq!(results[rec.index].spec.name(), "small");
+        assert!(!rec.within_budget);
+
+        assert!(recommend(&[], &Budget::default(), "en").is_none());
+    }
+
+    #[test]
+    fn test_recommend_respects_language() {
+        let results = vec![
+            result("small", 0.6, 600),
+            result("small.en", 0.6, 600),
+            result("distil-large-v3", 1.0, 1500),
+        ];
+
+        let rec = recommend(&results, &Budget::default(), "en").unwrap();
+        assert_eq!(results[rec.index].spec.name(), "distil-large-v3");
+
+        // English-focused models are skipped for other languages
+        let rec = recommend(&results, &Budget::default(), "auto").unwrap();
+        assert_eq!(results[rec.index].spec.name(), "small");
+    }
+
+    #[test]
+    fn test_recommend_prefers_unquantized() {
+        let results = vec![result("medium-q5", 0.8, 600), result("medium", 1.2, 1500)];
+        let rec = recommend(&results, &Budget::default(), "en").unwrap();
+        assert_eq!(results[rec.index].spec.name(), "medium");
+    }
+
+    #[test]
+    fn test_preset_for() {
+        let spec = |name: &str| name.parse::<ModelSpec>().unwrap();
+        assert_eq!(preset_for(&spec("small")), TranscriptionPreset::Instant);
+        assert_eq!(preset_for(&spec("medium")), TranscriptionPreset::Balanced);
+        assert_eq!(preset_for(&spec("large-v3")), TranscriptionPreset::Quality);
+        assert_eq!(preset_for(&spec("tiny")), TranscriptionPreset::Custom);
+        assert_eq!(preset_for(&spec("small-q5")), TranscriptionPreset::Custom);
+    }
+}
@@ -0,0 +1,381 @@
+//! OpenAI-compatible HTTP transcription backend.
+//!
+//! Sends audio to a server implementing the OpenAI `/audio/transcriptions`
+//! API (OpenAI itself, faster-whisper-server, a team whisper server on the
+//! LAN, ...). Requests run on the daemon's tokio runtime; the calling worker
+//! thread blocks until the response arrives.
+
+use super::backend::{BackendError, TranscriptionBackend};
+use super::whisper::TranscriptionResult;
+use crate::input::AudioBuffer;
+use reqwest::multipart::{Form, Part};
+use reqwest::Client;
+use serde::Deserialize;
+use std::io::Cursor;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::runtime::Handle;
+use tracing::{debug, info, warn};
+
+/// How long a server that could not be reached is skipped.
+const UNAVAILABLE_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// OpenAI transcription response format.
+#[derive(Debug, Deserialize)]
+struct TranscriptionResponse {
+    text: String,
+    #[serde(default)]
+    language: Option<String>,
+}
+
+/// Configuration for the HTTP backend.
+#[derive(Debug, Clone)]
+pub struct HttpBackendConfig {
+    /// Base URL (e.g., `http://whisper.lan:8000/v1`)
+    pub base_url: String,
+    /// API key (resolved, not the keyring: reference); empty for none
+    pub api_key: String,
+    /// Model name sent to the server (e.g., "whisper-1")
+    pub model: String,
+    /// Language: "auto" or ISO code
+    pub language: String,
+    /// Translate to English instead of transcribing
+    pub translate: bool,
+    /// Request timeout in seconds
+    pub timeout_secs: u32,
+}
+
+impl Default for HttpBackendConfig {
+    fn default() -> Self {
+        Self {
+            base_url: String::new(),
+            api_key: String::new(),
+            model: "whisper-1".to_string(),
+            language: "auto".to_string(),
+            translate: false,
+            timeout_secs: 30,
+        }
+    }
+}
+
+/// OpenAI-compatible transcription backend.
+pub struct HttpBackend {
+    client: Client,
+    runtime: Handle,
+    config: HttpBackendConfig,
+    /// Set after connection failures so jobs fall back without waiting
+    unavailable_until: Mutex<Option<Instant>>,
+}
+
+impl HttpBackend {
+    /// Create a new HTTP backend with the given configuration.
+    ///
+    /// Must be called from within a tokio runtime, which runs the requests.
+    pub fn new(config: HttpBackendConfig) -> Result<Self, BackendError> {
+        if config.base_url.is_empty() {
+            return Err(BackendError::ConfigError(
+                "Remote transcription URL is required".to_string(),
+            ));
+        }
+        let runtime = Handle::try_current()
+            .map_err(|e| BackendError::ConfigError(format!("No async runtime: {}", e)))?;
+
+        let client = Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs as u64))
+            .build()
+            .map_err(|e| BackendError::ConfigError(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            runtime,
+            config,
+            unavailable_until: Mutex::new(None),
+        })
+    }
+
+    fn endpoint(&self) -> String {
+        let path = if self.config.translate {
+            "audio/translations"
+        } else {
+            "audio/transcriptions"
+        };
+        format!("{}/{}", self.config.base_url.trim_end_matches('/'), path)
+    }
+
+    fn mark_unavailable(&self) {
+        if let Ok(mut until) = self.unavailable_until.lock() {
+            *until = Some(Instant::now() + UNAVAILABLE_COOLDOWN);
+        }
+    }
+
+    async fn request(
+        &self,
+        wav: Vec<u8>,
+        language: &str,
+    ) -> Result<TranscriptionResponse, BackendError> {
+        let file = Part::bytes(wav)
+            .file_name("audio.wav")
+            .mime_str("audio/wav")?;
+        let mut form = Form::new()
+            .part("file", file)
+            .text("model", self.config.model.clone())
+            .text("response_format", "json");
+        if !self.config.translate && !language.is_empty() && language != "auto" {
+            form = form.text("language", language.to_string());
+        }
+
+        let url = self.endpoint();
+        debug!("Sending transcription request to {}", url);
+        let mut request = self.client.post(&url).multipart(form);
+        if !self.config.api_key.is_empty() {
+            request = request.bearer_auth(&self.config.api_key);
+        }
+        let response = request.send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            if status.as_u16() == 401 {
+                return Err(BackendError::AuthError(body));
+            }
+            if status.is_server_error() {
+                self.mark_unavailable();
+            }
+            return Err(BackendError::ApiError(format!("HTTP {}: {}", status, body)));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| BackendError::InvalidResponse(e.to_string()))
+    }
+}
+
+impl TranscriptionBackend for HttpBackend {
+    fn name(&self) -> &'static str {
+        "http"
+    }
+
+    fn model(&self) -> &str {
+        &self.config.model
+    }
+
+    fn is_available(&self) -> bool {
+        self.unavailable_until
+            .lock()
+            .map(|until| until.is_none_or(|until| Instant::now() >= until))
+            .unwrap_or(true)
+    }
+
+    fn transcribe(
+        &self,
+        audio: &AudioBuffer,
+        language: Option<&str>,
+    ) -> Result<TranscriptionResult, BackendError> {
+        let start = Instant::now();
+        let wav = encode_wav(audio)?;
+        let language = language.unwrap_or(&self.config.language);
+
+        let response = match self.runtime.block_on(self.request(wav, language)) {
+            Ok(response) => response,
+            Err(BackendError::Http(e)) => {
+                if e.is_connect() || e.is_timeout() {
+                    warn!(
+                        "Remote transcription server unreachable, skipping it for {}s",
+                        UNAVAILABLE_COOLDOWN.as_secs()
+                    );
+                    self.mark_unavailable();
+                }
+                return Err(BackendError::Http(e));
+            }
+            Err(e) => return Err(e),
+        };
+        if let Ok(mut until) = self.unavailable_until.lock() {
+            *until = None;
+        }
+
+        let duration_ms = start.elapsed().as_millis() as u64;
+        info!(
+            "Remote transcription completed in {}ms (model: {})",
+            duration_ms, self.config.model
+        );
+        let language = response.language.unwrap_or_else(|| language.to_string());
+        Ok(TranscriptionResult {
+            text: response.text.trim().to_string(),
+            language,
+            duration_ms,
+        })
+    }
+}
+
+/// Encode audio as 16-bit PCM WAV, the format every server accepts.
+fn encode_wav(audio: &AudioBuffer) -> Result<Vec<u8>, BackendError> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: audio.sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = hound::WavWriter::new(&mut cursor, spec)?;
+    for &sample in &audio.samples {
+        writer.write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)?;
+    }
+    writer.finalize()?;
+    Ok(cursor.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(base_url: &str) -> HttpBackendConfig {
+        HttpBackendConfig {
+            base_url: base_url.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_encode_wav() {
+        let audio = AudioBuffer {
+            samples: vec![0.0, 0.5, -1.0, 2.0],
+            sample_rate: 16000,
+        };
+        let wav = encode_wav(&audio).unwrap();
+        let mut reader = hound::WavReader::new(Cursor::new(wav)).unwrap();
+        assert_eq!(reader.spec().sample_rate, 16000);
+        let samples: Vec<i16> = reader.samples::<i16>().map(Result::unwrap).collect();
+        assert_eq!(samples, vec![0, 16383, -32767, 32767]);
+    }
+
+    #[test]
+    fn test_new_requires_url() {
+        let result = HttpBackend::new(config(""));
+        assert!(matches!(result, Err(BackendError::ConfigError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_endpoint() {
+        let backend = HttpBackend::new(config("http://whisper.lan:8000/v1/")).unwrap();
+        assert_eq!(
+            backend.endpoint(),
+            "http://whisper.lan:8000/v1/audio/transcriptions"
+        );
+
+        let backend = HttpBackend::new(HttpBackendConfig {
+            translate: true,
+            ..config("http://whisper.lan:8000/v1")
+        })
+        .unwrap();
+        assert_eq!(
+            backend.endpoint(),
+            "http://whisper.lan:8000/v1/audio/translations"
+        );
+    }
+
+    /// Requests seen by the mock server: (authorization header, body).
+    type Seen = std::sync::Arc<Mutex<Vec<(String, String)>>>;
+
+    /// Serve a fake OpenAI transcription endpoint on a free local port.
+    ///
+    /// Returns the base URL and the requests it received.
+    async fn mock_server(status: u16, body: &'static str) -> (String, Seen) {
+        use axum::http::{HeaderMap, StatusCode};
+
+        let seen = Seen::default();
+        let requests = std::sync::Arc::clone(&seen);
+        let app = axum::Router::new().route(
+            "/v1/audio/transcriptions",
+            axum::routing::post(move |headers: HeaderMap, request: axum::body::Bytes| {
+                let auth = headers
+                    .get("authorization")
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string();
+                let request = String::from_utf8_lossy(&request).into_owned();
+                requests.lock().unwrap().push((auth, request));
+                async move {
+                    (
+                        StatusCode::from_u16(status).unwrap(),
+                        [("content-type", "application/json")],
+                        body,
+                    )
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/v1", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (url, seen)
+    }
+
+    /// Transcribe a short silence on a blocking thread, like a worker does.
+    async fn transcribe_on_worker(
+        backend: &std::sync::Arc<HttpBackend>,
+        language: Option<&'static str>,
+    ) -> Result<TranscriptionResult, BackendError> {
+        let worker = std::sync::Arc::clone(backend);
+        tokio::task::spawn_blocking(move || {
+            worker.transcribe(
+                &AudioBuffer {
+                    samples: vec![0.0; 1600],
+                    sample_rate: 16000,
+                },
+                language,
+            )
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_transcribe_with_mock_server() {
+        let (url, seen) = mock_server(200, r#"{"text":"  Hallo Welt "}"#).await;
+        let backend = std::sync::Arc::new(
+            HttpBackend::new(HttpBackendConfig {
+                api_key: "secret".to_string(),
+                ..config(&url)
+            })
+            .unwrap(),
+        );
+
+        let result = transcribe_on_worker(&backend, Some("de")).await.unwrap();
+        assert_eq!(result.text, "Hallo Welt");
+        // The server did not report a language, so the requested one is kept
+        assert_eq!(result.language, "de");
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 1);
+        let (auth, body) = &seen[0];
+        assert_eq!(auth, "Bearer secret");
+        assert!(body.contains("name=\"model\"\r\n\r\nwhisper-1"));
+        assert!(body.contains("name=\"language\"\r\n\r\nde"));
+        assert!(body.contains("audio/wav"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_server_error_skips_server() {
+        let (url, _) = mock_server(503, r#"{"error":"overloaded"}"#).await;
+        let backend = std::sync::Arc::new(HttpBackend::new(config(&url)).unwrap());
+
+        let result = transcribe_on_worker(&backend, None).await;
+        assert!(matches!(result, Err(BackendError::ApiError(_))));
+        assert!(!backend.is_available());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_unreachable_server_is_skipped() {
+        // A port that was just free is closed, so connecting is refused
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/v1", listener.local_addr().unwrap());
+        drop(listener);
+
+        let backend = std::sync::Arc::new(HttpBackend::new(config(&url)).unwrap());
+        assert!(backend.is_available());
+
+        let result = transcribe_on_worker(&backend, None).await;
+        assert!(result.is_err());
+        assert!(!backend.is_available());
+    }
+}
@@ -1,10 +1,13 @@
 //! Whisper transcription engine using whisper-rs.
 
-use crate::config::Config;
+use crate::config::{Config, ModelFamily, ModelsConfig};
+use crate::engine::catalog::{self, ModelSpec};
 use crate::engine::validation::{self, AudioValidationError};
 use crate::input::AudioBuffer;
+use crate::manifest::{self, IntegrityError, ModelVerifier};
 use std::cell::RefCell;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use thiserror::Error;
 use tracing::{debug, info, warn};
 use whisper_rs::{
@@ -16,6 +19,9 @@ pub enum WhisperError {
     #[error("Model not found at {0}. Run 'openhush model download {1}'")]
     ModelNotFound(PathBuf, String),
 
+    #[error("Unknown model '{0}'. Run 'openhush model list' to see available models")]
+    UnknownModel(String),
+
     #[error("Failed to load model: {0}")]
     LoadFailed(String),
 
@@ -24,6 +30,9 @@ pub enum WhisperError {
 
     #[error("Audio validation failed: {0}")]
     ValidationFailed(#[from] AudioValidationError),
+
+    #[error(transparent)]
+    Integrity(#[from] IntegrityError),
 }
 
 /// Result of transcription
@@ -32,7 +41,6 @@ pub struct TranscriptionResult {
     /// Transcribed text
     pub text: String,
     /// Language detected or used
-    #[allow(dead_code)]
     pub language: String,
     /// Processing time in milliseconds
     #[allow(dead_code)]
@@ -40,14 +48,23 @@ pub struct TranscriptionResult {
 }
 
 /// Available Whisper models
+///
+/// Each model can be downloaded unquantized or, where published, as a
+/// quantized ggml file (see [`crate::engine::catalog::ModelSpec`]).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(dead_code)]
 pub enum WhisperModel {
     Tiny,
+    TinyEn,
     Base,
+    BaseEn,
     Small,
+    SmallEn,
     Medium,
+    MediumEn,
     LargeV3,
+    LargeV3Turbo,
+    DistilLargeV3,
 }
 
 impl std::str::FromStr for WhisperModel {
@@ -56,25 +73,55 @@ impl std::str::FromStr for WhisperModel {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "tiny" => Ok(Self::Tiny),
+            "tiny.en" | "tiny-en" => Ok(Self::TinyEn),
             "base" => Ok(Self::Base),
+            "base.en" | "base-en" => Ok(Self::BaseEn),
             "small" => Ok(Self::Small),
+            "small.en" | "small-en" => Ok(Self::SmallEn),
             "medium" => Ok(Self::Medium),
+            "medium.en" | "medium-en" => Ok(Self::MediumEn),
             "large" | "large-v3" | "largev3" => Ok(Self::LargeV3),
+            "large-v3-turbo" | "large-turbo" | "turbo" => Ok(Self::LargeV3Turbo),
+            "distil-large-v3" | "distil-large" => Ok(Self::DistilLargeV3),
             _ => Err(()),
         }
     }
 }
 
 impl WhisperModel {
+    /// Canonical model name, as used in config and on the command line
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Tiny => "tiny",
+            Self::TinyEn => "tiny.en",
+            Self::Base => "base",
+            Self::BaseEn => "base.en",
+            Self::Small => "small",
+            Self::SmallEn => "small.en",
+            Self::Medium => "medium",
+            Self::MediumEn => "medium.en",
+            Self::LargeV3 => "large-v3",
+            Self::LargeV3Turbo => "large-v3-turbo",
+            Self::DistilLargeV3 => "distil-large-v3",
+        }
+    }
+
     /// Get the model filename
     #[must_use]
     pub fn filename(&self) -> &'static str {
         match self {
             Self::Tiny => "ggml-tiny.bin",
+            Self::TinyEn => "ggml-tiny.en.bin",
             Self::Base => "ggml-base.bin",
+            Self::BaseEn => "ggml-base.en.bin",
             Self::Small => "ggml-small.bin",
+            Self::SmallEn => "ggml-small.en.bin",
             Self::Medium => "ggml-medium.bin",
+            Self::MediumEn => "ggml-medium.en.bin",
             Self::LargeV3 => "ggml-large-v3.bin",
+            Self::LargeV3Turbo => "ggml-large-v3-turbo.bin",
+            Self::DistilLargeV3 => "ggml-distil-large-v3.bin",
         }
     }
 
@@ -83,11 +130,48 @@ impl WhisperModel {
     #[allow(dead_code)]
     pub fn size_bytes(&self) -> u64 {
         match self {
-            Self::Tiny => 75_000_000,
-            Self::Base => 142_000_000,
-            Self::Small => 466_000_000,
-            Self::Medium => 1_500_000_000,
+            Self::Tiny | Self::TinyEn => 75_000_000,
+            Self::Base | Self::BaseEn => 142_000_000,
+            Self::Small | Self::SmallEn => 466_000_000,
+            Self::Medium | Self::MediumEn => 1_500_000_000,
             Self::LargeV3 => 3_000_000_000,
+            Self::LargeV3Turbo => 1_600_000_000,
+            Self::DistilLargeV3 => 1_520_000_000,
+        }
+    }
+
+    /// Whether the model only transcribes English
+    #[must_use]
+    pub fn is_english_only(&self) -> bool {
+        matches!(
+            self,
+            Self::TinyEn | Self::BaseEn | Self::SmallEn | Self::MediumEn
+        )
+    }
+
+    /// Hugging Face repository the model is published in
+    #[must_use]
+    pub fn repository(&self) -> &'static str {
+        match self {
+            Self::DistilLargeV3 => "distil-whisper/distil-large-v3-ggml",
+            _ => "ggerganov/whisper.cpp",
+        }
+    }
+
+    /// One-line description for model listings
+    #[must_use]
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Tiny => "Fastest, lowest accuracy",
+            Self::Base => "Fast, good for simple audio",
+            Self::Small => "Balanced speed/accuracy",
+            Self::Medium => "Good accuracy, slower",
+            Self::LargeV3 => "Best accuracy, slowest",
+            Self::LargeV3Turbo => "Near large-v3 accuracy, much faster",
+            Self::DistilLargeV3 => "Distilled large-v3, fast (English-focused)",
+            Self::TinyEn | Self::BaseEn | Self::SmallEn | Self::MediumEn => {
+                "English-only, more accurate for English"
+            }
         }
     }
 
@@ -96,7 +180,8 @@ impl WhisperModel {
     pub fn download_url(&self) -> String {
         let filename = self.filename();
         format!(
-            "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/{}",
+            "https://huggingface.co/{}/resolve/main/{}",
+            self.repository(),
             filename
         )
     }
@@ -108,15 +193,18 @@ impl WhisperModel {
 /// internally by whisper-rs (via Arc), so the state can safely outlive calls
 /// that might otherwise drop the context reference.
 pub struct WhisperEngine {
-    /// The Whisper context (model). Kept for potential future use and clear ownership.
-    /// Note: WhisperState internally holds an Arc to the context, so this field
-    /// could technically be dropped after state creation, but we keep it for clarity.
-    #[allow(dead_code)]
-    context: WhisperContext,
+    /// The Whisper context (model), shared with engines created by [`fork`](Self::fork).
+    context: Arc<WhisperContext>,
     /// Cached state for reuse across transcriptions (avoids GPU buffer reallocation)
     state: RefCell<WhisperState>,
     language: String,
+    /// Languages automatic detection may choose from (empty = any)
+    allowed_languages: Vec<String>,
     translate: bool,
+    /// Size of the model file, used to account warm engines against a memory budget
+    model_bytes: u64,
+    /// Model name derived from the file name (e.g. "large-v3")
+    model_name: String,
 }
 
 impl WhisperEngine {
@@ -153,6 +241,8 @@ impl WhisperEngine {
             ));
         }
 
+        ModelVerifier::new()?.verify_on_load(model_path)?;
+
         let mut params = WhisperContextParameters::default();
         params.use_gpu(use_gpu);
 
@@ -170,38 +260,96 @@ impl WhisperEngine {
 
         info!("Whisper model loaded and GPU buffers allocated");
 
+        let model_bytes = std::fs::metadata(model_path).map_or(0, |m| m.len());
+        let model_name = model_path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .map(|stem| stem.strip_prefix("ggml-").unwrap_or(stem))
+            .unwrap_or("unknown")
+            .to_string();
+
         Ok(Self {
-            context: ctx,
+            context: Arc::new(ctx),
             state: RefCell::new(state),
             language: language.to_string(),
+            allowed_languages: Vec::new(),
             translate,
+            model_bytes,
+            model_name,
         })
     }
 
     /// Load engine from config
     #[allow(dead_code)]
     pub fn from_config(config: &Config) -> Result<Self, WhisperError> {
-        let data_dir = Config::data_dir().map_err(|e| WhisperError::LoadFailed(e.to_string()))?;
-
-        let model = config
-            .transcription
-            .effective_model()
-            .parse()
-            .unwrap_or(WhisperModel::Base);
-
-        let model_path = data_dir.join("models").join(model.filename());
+        let model_path = catalog::resolve_model(
+            config.transcription.effective_model(),
+            &config.transcription,
+        )?
+        .path()?;
         let use_gpu = config.transcription.device.to_lowercase() != "cpu";
 
-        Self::new(
+        let mut engine = Self::new(
             &model_path,
             &config.transcription.language,
             config.transcription.translate,
             use_gpu,
-        )
+        )?;
+        engine.set_allowed_languages(&config.transcription.allowed_languages);
+        Ok(engine)
+    }
+
+    /// Restrict automatic language detection to these codes.
+    ///
+    /// Only applies while the language is "auto"; an empty list lets
+    /// Whisper pick any language.
+    pub fn set_allowed_languages(&mut self, languages: &[String]) {
+        self.allowed_languages = languages.to_vec();
+    }
+
+    /// Create another engine that shares this model but has its own state.
+    ///
+    /// Lets several workers transcribe in parallel without loading the
+    /// model weights more than once.
+    pub fn fork(&self) -> Result<Self, WhisperError> {
+        let state = self
+            .context
+            .create_state()
+            .map_err(|e| WhisperError::LoadFailed(format!("Failed to create state: {:?}", e)))?;
+
+        Ok(Self {
+            context: Arc::clone(&self.context),
+            state: RefCell::new(state),
+            language: self.language.clone(),
+            allowed_languages: self.allowed_languages.clone(),
+            translate: self.translate,
+            model_bytes: self.model_bytes,
+            model_name: self.model_name.clone(),
+        })
+    }
+
+    /// Approximate resident size of the engine (the model file size).
+    pub fn model_bytes(&self) -> u64 {
+        self.model_bytes
+    }
+
+    /// Name of the loaded model.
+    pub fn model_name(&self) -> &str {
+        &self.model_name
     }
 
     /// Transcribe audio buffer to text
     pub fn transcribe(&self, audio: &AudioBuffer) -> Result<TranscriptionResult, WhisperError> {
+        self.transcribe_in(audio, &self.language)
+    }
+
+    /// Transcribe audio buffer to text in `language` ("auto" for detection),
+    /// regardless of the engine's own language.
+    pub fn transcribe_in(
+        &self,
+        audio: &AudioBuffer,
+        language: &str,
+    ) -> Result<TranscriptionResult, WhisperError> {
         // Validate audio before FFI boundary
         let validation_info = validation::validate_audio(&audio.samples, audio.sample_rate)?;
 
@@ -239,12 +387,21 @@ impl WhisperEngine {
         // Use cached state (GPU buffers already allocated)
         let mut state = self.state.borrow_mut();
 
+        // Pick the language up front when detection is restricted
+        let language = if language == "auto" && !self.allowed_languages.is_empty() {
+            Some(self.detect_allowed_language(&mut state, &audio.samples)?)
+        } else if language != "auto" {
+            Some(language.to_string())
+        } else {
+            None
+        };
+
         // Configure parameters
         let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
 
         // Set language
-        if self.language != "auto" {
-            params.set_language(Some(&self.language));
+        if let Some(ref language) = language {
+            params.set_language(Some(language.as_str()));
         }
 
         // Set translate mode
@@ -285,14 +442,13 @@ impl WhisperEngine {
         let duration_ms = start_time.elapsed().as_millis() as u64;
 
         // Get detected/used language
-        let detected_lang = if self.language == "auto" {
+        let detected_lang = match language {
+            Some(language) => language,
             // Get detected language ID from whisper state
-            match state.full_lang_id_from_state() {
+            None => match state.full_lang_id_from_state() {
                 Ok(lang_id) => lang_id_to_code(lang_id).to_string(),
                 Err(_) => "auto".to_string(),
-            }
-        } else {
-            self.language.clone()
+            },
         };
 
         info!(
@@ -308,20 +464,83 @@ impl WhisperEngine {
             duration_ms,
         })
     }
+
+    /// Detect the most likely language among `allowed_languages`.
+    ///
+    /// Runs Whisper's language detection on the start of the audio and
+    /// ignores the probabilities of every other language.
+    fn detect_allowed_language(
+        &self,
+        state: &mut WhisperState,
+        samples: &[f32],
+    ) -> Result<String, WhisperError> {
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get().min(4));
+        state
+            .pcm_to_mel(samples, threads)
+            .map_err(|e| WhisperError::TranscriptionFailed(format!("{:?}", e)))?;
+        let (_, probs) = state
+            .lang_detect(0, threads)
+            .map_err(|e| WhisperError::TranscriptionFailed(format!("{:?}", e)))?;
+
+        let language = pick_allowed_language(&probs, &self.allowed_languages)
+            .unwrap_or(&self.allowed_languages[0]);
+        debug!(
+            "Detected language '{}' (allowed: {})",
+            language,
+            self.allowed_languages.join(", ")
+        );
+        Ok(language.to_string())
+    }
+}
+
+/// Most probable language of `allowed` given Whisper's per-language
+/// probabilities (indexed by language ID).
+///
+/// Returns `None` if no allowed language is known to Whisper.
+fn pick_allowed_language<'a>(probs: &[f32], allowed: &'a [String]) -> Option<&'a str> {
+    allowed
+        .iter()
+        .filter_map(|code| {
+            let id = code_to_lang_id(code)?;
+            Some((
+                code.as_str(),
+                probs.get(usize::try_from(id).ok()?).copied()?,
+            ))
+        })
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(code, _)| code)
+}
+
+/// Convert an ISO 639-1 language code to its whisper language ID.
+fn code_to_lang_id(code: &str) -> Option<std::ffi::c_int> {
+    (0..=98).find(|&id| lang_id_to_code(id) == code)
+}
+
+/// Whether Whisper knows the language code (e.g. "en" or "haw").
+pub fn is_supported_language(code: &str) -> bool {
+    code_to_lang_id(code).is_some()
 }
 
 /// Result of GPU benchmark
 #[derive(Debug, Clone)]
 pub struct BenchmarkResult {
-    /// Fixed overhead in seconds (time to process ~2s of audio)
+    /// Average time in seconds to transcribe the test audio (the fixed
+    /// overhead when it is ~2s of silence)
     pub overhead_secs: f32,
     /// Recommended minimum chunk interval in seconds
     pub recommended_chunk_interval: f32,
     /// Audio duration used for benchmark
-    #[allow(dead_code)]
     pub test_audio_secs: f32,
 }
 
+impl BenchmarkResult {
+    /// Real-time factor (processing time / audio duration)
+    #[must_use]
+    pub fn rtf(&self) -> f32 {
+        self.overhead_secs / self.test_audio_secs
+    }
+}
+
 impl WhisperEngine {
     /// Benchmark GPU transcription to determine optimal chunk interval.
     ///
@@ -349,40 +568,48 @@ impl WhisperEngine {
             sample_rate,
         };
 
-        // Warm-up run (first run may have additional JIT overhead)
-        let _ = self.transcribe(&audio);
+        // Average of 3 runs for stability
+        let result = self.benchmark_audio(&audio, 3, safety_margin)?;
+
+        info!(
+            "Benchmark complete: {:.2}s overhead, recommended chunk interval: {:.2}s (with {:.0}% margin)",
+            result.overhead_secs,
+            result.recommended_chunk_interval,
+            safety_margin * 100.0
+        );
 
-        // Benchmark run (average of 3 runs for stability)
-        let mut total_ms: u64 = 0;
-        const BENCHMARK_RUNS: u32 = 3;
+        Ok(result)
+    }
 
-        for i in 0..BENCHMARK_RUNS {
+    /// Time the transcription of `audio`: one warm-up run, then the average
+    /// of `runs` runs.
+    pub fn benchmark_audio(
+        &self,
+        audio: &crate::input::AudioBuffer,
+        runs: u32,
+        safety_margin: f32,
+    ) -> Result<BenchmarkResult, WhisperError> {
+        // Warm-up run (first run may have additional JIT overhead)
+        self.transcribe(audio)?;
+
+        let runs = runs.max(1);
+        let mut total_secs = 0.0;
+        for i in 0..runs {
             let start = std::time::Instant::now();
-            let _ = self.transcribe(&audio);
-            let elapsed = start.elapsed().as_millis() as u64;
-            total_ms += elapsed;
-            debug!("Benchmark run {}: {}ms", i + 1, elapsed);
+            self.transcribe(audio)?;
+            let elapsed = start.elapsed().as_secs_f32();
+            total_secs += elapsed;
+            debug!("Benchmark run {}: {:.0}ms", i + 1, elapsed * 1000.0);
         }
-
-        let avg_ms = total_ms / BENCHMARK_RUNS as u64;
-        let overhead_secs = avg_ms as f32 / 1000.0;
+        let overhead_secs = total_secs / runs as f32;
 
         // Calculate recommended chunk interval:
         // min_chunk = overhead * (1 + safety_margin)
         // This ensures chunks complete before the next one is ready
-        let recommended = overhead_secs * (1.0 + safety_margin);
-
-        info!(
-            "Benchmark complete: {:.2}s overhead, recommended chunk interval: {:.2}s (with {:.0}% margin)",
-            overhead_secs,
-            recommended,
-            safety_margin * 100.0
-        );
-
         Ok(BenchmarkResult {
             overhead_secs,
-            recommended_chunk_interval: recommended,
-            test_audio_secs: test_duration_secs,
+            recommended_chunk_interval: overhead_secs * (1.0 + safety_margin),
+            test_audio_secs: audio.duration_secs(),
         })
     }
 }
@@ -397,21 +624,21 @@ pub fn models_dir() -> Result<PathBuf, WhisperError> {
 /// Check if a model is downloaded
 #[must_use]
 #[allow(dead_code)]
-pub fn is_model_downloaded(model: WhisperModel) -> bool {
+pub fn is_model_downloaded(model: impl Into<ModelSpec>) -> bool {
     if let Ok(dir) = models_dir() {
-        dir.join(model.filename()).exists()
+        dir.join(model.into().filename()).exists()
     } else {
         false
     }
 }
 
-/// List downloaded models
+/// List downloaded models, including quantized variants
 #[must_use]
 #[allow(dead_code)]
-pub fn list_downloaded_models() -> Vec<WhisperModel> {
-    all_models()
+pub fn list_downloaded_models() -> Vec<ModelSpec> {
+    catalog::all_specs()
         .into_iter()
-        .filter(|m| is_model_downloaded(*m))
+        .filter(|spec| is_model_downloaded(*spec))
         .collect()
 }
 
@@ -420,23 +647,23 @@ pub fn list_downloaded_models() -> Vec<WhisperModel> {
 pub fn all_models() -> Vec<WhisperModel> {
     vec![
         WhisperModel::Tiny,
+        WhisperModel::TinyEn,
         WhisperModel::Base,
+        WhisperModel::BaseEn,
         WhisperModel::Small,
+        WhisperModel::SmallEn,
         WhisperModel::Medium,
+        WhisperModel::MediumEn,
         WhisperModel::LargeV3,
+        WhisperModel::LargeV3Turbo,
+        WhisperModel::DistilLargeV3,
     ]
 }
 
 /// Get model file size in bytes (approximate)
 #[must_use]
-pub fn model_size_bytes(model: WhisperModel) -> u64 {
-    match model {
-        WhisperModel::Tiny => 75_000_000,       // ~75MB
-        WhisperModel::Base => 142_000_000,      // ~142MB
-        WhisperModel::Small => 466_000_000,     // ~466MB
-        WhisperModel::Medium => 1_500_000_000,  // ~1.5GB
-        WhisperModel::LargeV3 => 3_000_000_000, // ~3GB
-    }
+pub fn model_size_bytes(model: impl Into<ModelSpec>) -> u64 {
+    model.into().size_bytes()
 }
 
 /// Format bytes as human-readable size
@@ -457,9 +684,11 @@ pub fn format_size(bytes: u64) -> String {
     }
 }
 
-/// Download a model from Hugging Face with progress callback
+/// Download a model from Hugging Face (or the configured mirror) with
+/// progress callback
 pub async fn download_model<F>(
-    model: WhisperModel,
+    model: ModelSpec,
+    models: &ModelsConfig,
     mut progress_callback: F,
 ) -> Result<PathBuf, WhisperError>
 where
@@ -472,19 +701,26 @@ where
     std::fs::create_dir_all(&dir)
         .map_err(|e| WhisperError::LoadFailed(format!("Cannot create models dir: {}", e)))?;
 
-    let dest_path = dir.join(model.filename());
-    let temp_path = dir.join(format!("{}.tmp", model.filename()));
+    let filename = model.filename();
+    let dest_path = dir.join(&filename);
+    let temp_path = dir.join(format!("{}.tmp", filename));
 
     // Check if already downloaded
     if dest_path.exists() {
         return Err(WhisperError::LoadFailed(format!(
             "Model {} already exists at {}",
-            model.filename(),
+            filename,
             dest_path.display()
         )));
     }
 
-    let url = model.download_url();
+    if let Err(e) = manifest::refresh_pinned(models).await {
+        warn!("Using cached model manifest: {}", e);
+    }
+
+    let url = models
+        .mirrors
+        .url(ModelFamily::Whisper, &filename, model.download_url());
     let client = reqwest::Client::new();
 
     // Check for existing partial download to resume
@@ -493,11 +729,7 @@ where
             Ok(meta) => {
                 let size = meta.len();
                 if size > 0 {
-                    info!(
-                        "Resuming download from byte {} for {}",
-                        size,
-                        model.filename()
-                    );
+                    info!("Resuming download from byte {} for {}", size, filename);
                     size
                 } else {
                     0
@@ -531,7 +763,7 @@ where
             warn!("Server doesn't support resume, starting fresh download");
             // Remove partial file and retry without resume
             let _ = std::fs::remove_file(&temp_path);
-            return Box::pin(download_model(model, progress_callback)).await;
+            return Box::pin(download_model(model, models, progress_callback)).await;
         }
         return Err(WhisperError::LoadFailed(format!(
             "Download failed with status: {}",
@@ -550,7 +782,7 @@ where
             .and_then(|s| s.parse::<u64>().ok())
             .unwrap_or(resume_from + response.content_length().unwrap_or(0))
     } else {
-        response.content_length().unwrap_or(model_size_bytes(model))
+        response.content_length().unwrap_or(model.size_bytes())
     };
 
     // Open file for writing (append if resuming, create if new)
@@ -593,6 +825,9 @@ where
             downloaded, total_size
         )));
     }
+    drop(file);
+
+    manifest::verify_download(temp_path.clone(), dest_path.clone()).await?;
 
     // Rename temp to final
     std::fs::rename(&temp_path, &dest_path)
@@ -603,7 +838,7 @@ where
 }
 
 /// Remove a downloaded model
-pub fn remove_model(model: WhisperModel) -> Result<(), WhisperError> {
+pub fn remove_model(model: ModelSpec) -> Result<(), WhisperError> {
     let dir = models_dir()?;
     let path = dir.join(model.filename());
 
@@ -837,12 +1072,31 @@ mod tests {
     #[test]
     fn test_all_models() {
         let models = all_models();
-        assert_eq!(models.len(), 5);
+        assert_eq!(models.len(), 11);
         assert!(models.contains(&WhisperModel::Tiny));
         assert!(models.contains(&WhisperModel::Base));
         assert!(models.contains(&WhisperModel::Small));
         assert!(models.contains(&WhisperModel::Medium));
         assert!(models.contains(&WhisperModel::LargeV3));
+        assert!(models.contains(&WhisperModel::LargeV3Turbo));
+        assert!(models.contains(&WhisperModel::DistilLargeV3));
+    }
+
+    #[test]
+    fn test_model_name_round_trip() {
+        for model in all_models() {
+            assert_eq!(model.name().parse(), Ok(model));
+        }
+    }
+
+    #[test]
+    fn test_model_from_str_new_variants() {
+        assert_eq!("small.en".parse(), Ok(WhisperModel::SmallEn));
+        assert_eq!("small-en".parse(), Ok(WhisperModel::SmallEn));
+        assert_eq!("turbo".parse(), Ok(WhisperModel::LargeV3Turbo));
+        assert_eq!("distil-large-v3".parse(), Ok(WhisperModel::DistilLargeV3));
+        assert!(WhisperModel::MediumEn.is_english_only());
+        assert!(!WhisperModel::Medium.is_english_only());
     }
 
     // ===================
@@ -858,6 +1112,33 @@ mod tests {
         assert_eq!(lang_id_to_code(15), "it");
     }
 
+    #[test]
+    fn test_code_to_lang_id_round_trip() {
+        assert_eq!(code_to_lang_id("en"), Some(0));
+        assert_eq!(code_to_lang_id("de"), Some(2));
+        assert_eq!(code_to_lang_id("haw"), Some(93));
+        assert_eq!(code_to_lang_id("xx"), None);
+        assert!(is_supported_language("haw"));
+        assert!(!is_supported_language("unknown"));
+    }
+
+    #[test]
+    fn test_pick_allowed_language() {
+        // English 0.2, German 0.3, Dutch 0.4: Dutch wins unrestricted
+        let mut probs = vec![0.0; 99];
+        probs[0] = 0.2;
+        probs[2] = 0.3;
+        probs[12] = 0.4;
+
+        let allowed = vec!["en".to_string(), "de".to_string()];
+        assert_eq!(pick_allowed_language(&probs, &allowed), Some("de"));
+
+        let allowed = vec!["en".to_string(), "xx".to_string()];
+        assert_eq!(pick_allowed_language(&probs, &allowed), Some("en"));
+
+        assert_eq!(pick_allowed_language(&probs, &["xx".to_string()]), None);
+    }
+
     #[test]
     fn test_lang_id_unknown() {
         assert_eq!(lang_id_to_code(999), "unknown");
@@ -888,6 +1169,13 @@ mod tests {
         assert!(url.contains("ggml-tiny.bin"));
     }
 
+    #[test]
+    fn test_download_url_distil() {
+        let url = WhisperModel::DistilLargeV3.download_url();
+        assert!(url.contains("distil-whisper/distil-large-v3-ggml"));
+        assert!(url.ends_with("ggml-distil-large-v3.bin"));
+    }
+
     // ===================
     // TranscriptionResult Tests
     // ===================
@@ -923,6 +1211,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_benchmark_result_rtf() {
+        let result = BenchmarkResult {
+            overhead_secs: 2.0,
+            recommended_chunk_interval: 2.0,
+            test_audio_secs: 8.0,
+        };
+        assert!((result.rtf() - 0.25).abs() < f32::EPSILON);
+    }
+
     // ===================
     // Error Tests
     // ===================
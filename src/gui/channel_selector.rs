@@ -54,6 +54,9 @@ struct ChannelSelectorApp {
     devices: Vec<AudioDeviceInfo>,
     /// Selection state per device
     selections: HashMap<String, DeviceSelection>,
+    /// Current selections for devices not present right now (e.g. an
+    /// application that is not playing), kept unchanged on apply
+    absent: Vec<DeviceChannelSelection>,
     /// Pointer to write result on confirm
     result_ptr: *mut Option<Vec<DeviceChannelSelection>>,
     /// Search/filter text
@@ -100,9 +103,16 @@ impl ChannelSelectorApp {
             selections.insert(device.id.clone(), DeviceSelection { enabled, channels });
         }
 
+        let absent = current_selections
+            .iter()
+            .filter(|s| !devices.iter().any(|d| d.id == s.device_id))
+            .cloned()
+            .collect();
+
         Self {
             devices,
             selections,
+            absent,
             result_ptr,
             filter_text: String::new(),
             show_only_selected: false,
@@ -128,6 +138,7 @@ impl ChannelSelectorApp {
                     enabled: sel.enabled,
                 }
             })
+            .chain(self.absent.iter().cloned())
             .collect()
     }
 
@@ -146,6 +157,7 @@ impl ChannelSelectorApp {
         let device_icon = match device.device_type {
             AudioDeviceType::Microphone => "🎤",
             AudioDeviceType::Monitor => "🔊",
+            AudioDeviceType::Application => "🎵",
         };
 
         let header_text = format!(
@@ -307,6 +319,25 @@ impl eframe::App for ChannelSelectorApp {
                     .map(|(i, _)| i)
                     .collect();
 
+                let app_indices: Vec<usize> = self
+                    .devices
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, d)| d.device_type == AudioDeviceType::Application)
+                    .filter(|(_, d)| filter.is_empty() || d.name.to_lowercase().contains(&filter))
+                    .filter(|(_, d)| {
+                        if self.show_only_selected {
+                            self.selections
+                                .get(&d.id)
+                                .map(|s| s.enabled)
+                                .unwrap_or(false)
+                        } else {
+                            true
+                        }
+                    })
+                    .map(|(i, _)| i)
+                    .collect();
+
                 // Microphones section
                 if !mic_indices.is_empty() {
                     ui.heading("🎤 Microphones");
@@ -326,6 +357,18 @@ impl eframe::App for ChannelSelectorApp {
                     for idx in mon_indices {
                         self.show_device_by_index(ui, idx);
                     }
+                    ui.add_space(10.0);
+                }
+
+                // Single applications (used by `openhush record`)
+                if !app_indices.is_empty() {
+                    ui.heading("🎵 Applications");
+                    ui.label("Enable one to record only its audio with `openhush record`.");
+                    ui.add_space(5.0);
+
+                    for idx in app_indices {
+                        self.show_device_by_index(ui, idx);
+                    }
                 }
             });
         });
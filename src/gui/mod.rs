@@ -11,6 +11,8 @@ mod wizard;
 pub use channel_selector::{run_channel_selector, spawn_channel_selector};
 pub use wizard::{is_first_run, run_wizard};
 
+#[cfg(target_os = "linux")]
+use crate::config::MediaControl;
 use crate::config::{Config, Theme};
 use eframe::egui;
 use tracing::{info, warn};
@@ -141,7 +143,7 @@ impl PreferencesApp {
 
         ui.horizontal(|ui| {
             ui.label("Mode:");
-            let modes = ["push_to_talk", "toggle"];
+            let modes = ["push_to_talk", "toggle", "continuous"];
             egui::ComboBox::from_id_salt("hotkey_mode")
                 .selected_text(&self.config.hotkey.mode)
                 .show_ui(ui, |ui| {
@@ -198,17 +200,29 @@ impl PreferencesApp {
         let effective_model = self.config.transcription.effective_model().to_string();
         ui.horizontal(|ui| {
             ui.label("Model:");
-            let models = ["tiny", "base", "small", "medium", "large-v3"];
+            // Built-in models plus custom models registered in the config;
+            // quantized variants can be set in the config file
+            let models: Vec<String> = crate::engine::whisper::all_models()
+                .iter()
+                .map(|m| m.name().to_string())
+                .chain(
+                    self.config
+                        .transcription
+                        .custom_models
+                        .iter()
+                        .map(|m| m.name.clone()),
+                )
+                .collect();
             ui.add_enabled_ui(is_custom, |ui| {
                 egui::ComboBox::from_id_salt("model")
                     .selected_text(&effective_model)
                     .show_ui(ui, |ui| {
-                        for model in models {
+                        for model in &models {
                             if ui
                                 .selectable_value(
                                     &mut self.config.transcription.model,
-                                    model.to_string(),
-                                    model,
+                                    model.clone(),
+                                    model.as_str(),
                                 )
                                 .changed()
                             {
@@ -461,6 +475,27 @@ impl PreferencesApp {
         {
             self.unsaved_changes = true;
         }
+
+        #[cfg(target_os = "linux")]
+        ui.horizontal(|ui| {
+            ui.label("Media while recording:");
+            egui::ComboBox::from_id_salt("media_control")
+                .selected_text(self.config.feedback.media_control.display_name())
+                .show_ui(ui, |ui| {
+                    for mode in [MediaControl::Off, MediaControl::Pause, MediaControl::Duck] {
+                        if ui
+                            .selectable_value(
+                                &mut self.config.feedback.media_control,
+                                mode,
+                                mode.display_name(),
+                            )
+                            .changed()
+                        {
+                            self.unsaved_changes = true;
+                        }
+                    }
+                });
+        });
     }
 
     fn show_appearance_tab(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
@@ -509,19 +544,38 @@ impl PreferencesApp {
 
     fn build_channel_selections(&self) -> Vec<crate::input::DeviceChannelSelection> {
         // Build DeviceChannelSelection from current config
-        // For now, return empty - the channel selector will enumerate fresh devices
-        Vec::new()
+        // For now, only the recorded application - the channel selector will
+        // enumerate fresh devices
+        self.config
+            .audio
+            .capture_app
+            .iter()
+            .map(|app| crate::input::DeviceChannelSelection {
+                device_id: format!("{}{}", crate::input::APP_DEVICE_PREFIX, app),
+                selected_channels: Vec::new(),
+                enabled: true,
+            })
+            .collect()
     }
 
     fn apply_channel_selections(&mut self, selections: &[crate::input::DeviceChannelSelection]) {
         use crate::config::ChannelSelection;
+        use crate::input::app_from_device_id;
+
+        // An enabled application becomes the `openhush record` target
+        self.config.audio.capture_app = selections
+            .iter()
+            .filter(|s| s.enabled)
+            .find_map(|s| app_from_device_id(&s.device_id))
+            .map(str::to_string);
 
         // For now, store the first enabled device's channels in config
         // TODO: support multiple devices in config
-        if let Some(sel) = selections
-            .iter()
-            .find(|s| s.enabled && !s.selected_channels.is_empty())
-        {
+        if let Some(sel) = selections.iter().find(|s| {
+            s.enabled
+                && !s.selected_channels.is_empty()
+                && app_from_device_id(&s.device_id).is_none()
+        }) {
             self.config.audio.channels = ChannelSelection::Select(sel.selected_channels.clone());
             // Update the input field
             self.channels_input = sel
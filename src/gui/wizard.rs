@@ -4,14 +4,16 @@
 //! 1. Welcome - Introduction and privacy notice
 //! 2. Microphone - Select and test audio input
 //! 3. Model - Choose and download Whisper model
-//! 4. Hotkey - Configure trigger key
-//! 5. Output - Choose clipboard/paste behavior
-//! 6. Ollama - Optional LLM correction setup
-//! 7. Complete - Summary and quick test
+//! 4. Performance - Benchmark downloaded models and pick the best fit
+//! 5. Hotkey - Configure trigger key
+//! 6. Output - Choose clipboard/paste behavior
+//! 7. Ollama - Optional LLM correction setup
+//! 8. Complete - Summary and quick test
 
 #![allow(dead_code)]
 
-use crate::config::{Config, TranscriptionPreset};
+use crate::config::Config;
+use crate::engine::recommend::{self, Budget, ModelBenchmark};
 use crate::input::audio::AudioRecorder;
 use eframe::egui;
 use std::sync::mpsc;
@@ -25,6 +27,7 @@ enum WizardStep {
     Welcome,
     Microphone,
     Model,
+    Performance,
     Hotkey,
     Output,
     Ollama,
@@ -37,6 +40,7 @@ impl WizardStep {
             WizardStep::Welcome => "Welcome to OpenHush",
             WizardStep::Microphone => "Microphone Setup",
             WizardStep::Model => "Model Selection",
+            WizardStep::Performance => "Performance Check",
             WizardStep::Hotkey => "Hotkey Configuration",
             WizardStep::Output => "Output Settings",
             WizardStep::Ollama => "LLM Correction (Optional)",
@@ -49,10 +53,11 @@ impl WizardStep {
             WizardStep::Welcome => 0,
             WizardStep::Microphone => 1,
             WizardStep::Model => 2,
-            WizardStep::Hotkey => 3,
-            WizardStep::Output => 4,
-            WizardStep::Ollama => 5,
-            WizardStep::Complete => 6,
+            WizardStep::Performance => 3,
+            WizardStep::Hotkey => 4,
+            WizardStep::Output => 5,
+            WizardStep::Ollama => 6,
+            WizardStep::Complete => 7,
         }
     }
 
@@ -61,16 +66,17 @@ impl WizardStep {
             0 => Some(WizardStep::Welcome),
             1 => Some(WizardStep::Microphone),
             2 => Some(WizardStep::Model),
-            3 => Some(WizardStep::Hotkey),
-            4 => Some(WizardStep::Output),
-            5 => Some(WizardStep::Ollama),
-            6 => Some(WizardStep::Complete),
+            3 => Some(WizardStep::Performance),
+            4 => Some(WizardStep::Hotkey),
+            5 => Some(WizardStep::Output),
+            6 => Some(WizardStep::Ollama),
+            7 => Some(WizardStep::Complete),
             _ => None,
         }
     }
 
     fn total_steps() -> usize {
-        7
+        8
     }
 }
 
@@ -87,6 +93,19 @@ enum DownloadState {
     Failed(String),
 }
 
+/// Model benchmark state
+#[derive(Clone)]
+enum BenchmarkState {
+    NotStarted,
+    Running {
+        model: String,
+        done: usize,
+        total: usize,
+    },
+    Completed(Vec<ModelBenchmark>),
+    Failed(String),
+}
+
 /// Microphone test state
 struct MicTestState {
     audio_recorder: Option<AudioRecorder>,
@@ -119,6 +138,11 @@ struct OnboardingWizard {
     download_state: Arc<Mutex<DownloadState>>,
     download_receiver: Option<mpsc::Receiver<DownloadState>>,
 
+    // Performance state
+    latency_budget: f32,
+    benchmark_state: BenchmarkState,
+    benchmark_receiver: Option<mpsc::Receiver<BenchmarkState>>,
+
     // Hotkey state
     hotkey_listening: bool,
     hotkey_detected: Option<String>,
@@ -142,6 +166,9 @@ impl OnboardingWizard {
             selected_model: "small".to_string(),
             download_state: Arc::new(Mutex::new(DownloadState::NotStarted)),
             download_receiver: None,
+            latency_budget: recommend::DEFAULT_LATENCY_BUDGET_SECS,
+            benchmark_state: BenchmarkState::NotStarted,
+            benchmark_receiver: None,
             hotkey_listening: false,
             hotkey_detected: None,
             status_message: None,
@@ -173,6 +200,9 @@ impl OnboardingWizard {
                     DownloadState::Completed | DownloadState::NotStarted
                 )
             }
+            WizardStep::Performance => {
+                !matches!(self.benchmark_state, BenchmarkState::Running { .. })
+            }
             WizardStep::Hotkey => !self.config.hotkey.key.is_empty(),
             WizardStep::Output => true,
             WizardStep::Ollama => true,
@@ -181,12 +211,10 @@ impl OnboardingWizard {
     }
 
     fn finish_wizard(&mut self) -> anyhow::Result<()> {
-        // Apply selected model based on preset
-        self.config.transcription.preset = match self.selected_model.as_str() {
-            "tiny" | "base" | "small" => TranscriptionPreset::Instant,
-            "medium" => TranscriptionPreset::Balanced,
-            "large-v3" => TranscriptionPreset::Quality,
-            _ => TranscriptionPreset::Custom,
+        // Use the preset for the selected model, or a custom model setting
+        self.config.transcription.preset = match self.selected_model.parse() {
+            Ok(spec) => recommend::preset_for(&spec),
+            Err(()) => crate::config::TranscriptionPreset::Custom,
         };
         self.config.transcription.model = self.selected_model.clone();
 
@@ -346,6 +374,11 @@ impl OnboardingWizard {
             ),
             ("medium", "Medium (~1.5 GB)", "High accuracy, slower"),
             ("large-v3", "Large V3 (~3 GB)", "Best accuracy, slowest"),
+            (
+                "large-v3-turbo",
+                "Large V3 Turbo (~1.6 GB)",
+                "Near large-v3 accuracy, much faster",
+            ),
         ];
 
         for (id, name, desc) in models {
@@ -413,10 +446,12 @@ impl OnboardingWizard {
             total: 0,
         };
 
+        let models = self.config.models.clone();
         thread::spawn(move || {
-            use crate::engine::whisper::{download_model, WhisperModel};
+            use crate::engine::catalog::ModelSpec;
+            use crate::engine::whisper::download_model;
 
-            let model: WhisperModel = match model_name.parse() {
+            let model: ModelSpec = match model_name.parse() {
                 Ok(m) => m,
                 Err(()) => {
                     let _ = tx.send(DownloadState::Failed(format!(
@@ -440,7 +475,7 @@ impl OnboardingWizard {
             };
 
             let tx_progress = tx.clone();
-            let result = rt.block_on(download_model(model, move |downloaded, total| {
+            let result = rt.block_on(download_model(model, &models, move |downloaded, total| {
                 let progress = if total > 0 {
                     downloaded as f32 / total as f32
                 } else {
@@ -464,6 +499,160 @@ impl OnboardingWizard {
         });
     }
 
+    fn show_performance(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Find the Best Model for This Computer");
+        ui.add_space(10.0);
+
+        ui.label("OpenHush can time each downloaded model on a short test clip and");
+        ui.label("pick the most accurate one that responds fast enough.");
+        ui.add_space(10.0);
+
+        ui.horizontal(|ui| {
+            ui.label("Maximum delay after speaking:");
+            ui.add(
+                egui::Slider::new(&mut self.latency_budget, 0.5..=5.0)
+                    .suffix(" s")
+                    .step_by(0.1),
+            );
+        });
+
+        ui.add_space(10.0);
+
+        // Check for updates from benchmark thread
+        if let Some(ref rx) = self.benchmark_receiver {
+            while let Ok(state) = rx.try_recv() {
+                self.benchmark_state = state;
+            }
+        }
+
+        match self.benchmark_state.clone() {
+            BenchmarkState::NotStarted => {
+                if ui.button("Run Benchmark").clicked() {
+                    self.start_benchmark();
+                }
+                ui.label("This can take a few minutes. You can also skip this step.");
+            }
+            BenchmarkState::Running { model, done, total } => {
+                ui.label(format!(
+                    "Benchmarking {} ({} of {})...",
+                    model,
+                    done + 1,
+                    total
+                ));
+                ui.add(egui::ProgressBar::new(done as f32 / total.max(1) as f32));
+            }
+            BenchmarkState::Completed(results) => {
+                let budget = Budget {
+                    latency_secs: self.latency_budget,
+                    max_memory_bytes: None,
+                };
+                egui::Grid::new("benchmark_results")
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.strong("Model");
+                        ui.strong("Delay");
+                        ui.strong("Speed");
+                        ui.strong("Memory");
+                        ui.end_row();
+                        for result in &results {
+                            ui.label(result.spec.name());
+                            if budget.fits(result) {
+                                ui.label(format!("{:.2} s", result.latency_secs));
+                            } else {
+                                ui.colored_label(
+                                    egui::Color32::YELLOW,
+                                    format!("{:.2} s", result.latency_secs),
+                                );
+                            }
+                            ui.label(format!("{:.1}x real time", 1.0 / result.rtf.max(0.001)));
+                            ui.label(format_bytes(result.memory_bytes));
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(10.0);
+
+                // Recomputed each frame so moving the slider updates it
+                let language = &self.config.transcription.language;
+                match recommend::recommend(&results, &budget, language) {
+                    Some(rec) => {
+                        let name = results[rec.index].spec.name();
+                        if rec.within_budget {
+                            ui.label(format!("Recommended: {}", name));
+                        } else {
+                            ui.label(format!("No model is fast enough; the fastest is {}", name));
+                        }
+                        if self.selected_model == name {
+                            ui.colored_label(egui::Color32::GREEN, "✓ Selected");
+                        } else if ui.button(format!("Use {}", name)).clicked() {
+                            self.selected_model = name;
+                        }
+                    }
+                    None => {
+                        ui.label("No suitable model for the selected language.");
+                    }
+                }
+
+                if ui.button("Run Again").clicked() {
+                    self.start_benchmark();
+                }
+            }
+            BenchmarkState::Failed(err) => {
+                ui.colored_label(egui::Color32::RED, format!("✗ Benchmark failed: {}", err));
+                if ui.button("Retry").clicked() {
+                    self.start_benchmark();
+                }
+            }
+        }
+    }
+
+    fn start_benchmark(&mut self) {
+        let (tx, rx) = mpsc::channel();
+        self.benchmark_receiver = Some(rx);
+
+        let models = crate::engine::whisper::list_downloaded_models();
+        if models.is_empty() {
+            self.benchmark_state = BenchmarkState::Failed("No models downloaded yet".to_string());
+            return;
+        }
+
+        self.benchmark_state = BenchmarkState::Running {
+            model: models[0].name(),
+            done: 0,
+            total: models.len(),
+        };
+
+        let language = self.config.transcription.language.clone();
+        let use_gpu = self.config.transcription.device.to_lowercase() != "cpu";
+
+        thread::spawn(move || {
+            let clip = recommend::test_clip();
+            let total = models.len();
+            let mut results = Vec::new();
+
+            for (done, spec) in models.into_iter().enumerate() {
+                let _ = tx.send(BenchmarkState::Running {
+                    model: spec.name(),
+                    done,
+                    total,
+                });
+                match recommend::benchmark_model(spec, &clip, &language, use_gpu) {
+                    Ok(result) => results.push(result),
+                    Err(e) => error!("Benchmark of {} failed: {}", spec, e),
+                }
+            }
+
+            if results.is_empty() {
+                let _ = tx.send(BenchmarkState::Failed(
+                    "No model could be loaded".to_string(),
+                ));
+                return;
+            }
+
+            let _ = tx.send(BenchmarkState::Completed(results));
+        });
+    }
+
     fn show_hotkey(&mut self, ui: &mut egui::Ui) {
         ui.heading("Configure Trigger Hotkey");
         ui.add_space(10.0);
@@ -483,7 +672,7 @@ impl OnboardingWizard {
 
         ui.horizontal(|ui| {
             ui.label("Mode:");
-            let modes = ["push_to_talk", "toggle"];
+            let modes = ["push_to_talk", "toggle", "continuous"];
             egui::ComboBox::from_id_salt("hotkey_mode")
                 .selected_text(&self.config.hotkey.mode)
                 .show_ui(ui, |ui| {
@@ -496,6 +685,7 @@ impl OnboardingWizard {
         ui.add_space(5.0);
         ui.label("Push-to-talk: Hold key while speaking");
         ui.label("Toggle: Press to start, press again to stop");
+        ui.label("Continuous: Press once, each pause outputs what you said");
     }
 
     fn show_output(&mut self, ui: &mut egui::Ui) {
@@ -695,6 +885,7 @@ impl eframe::App for OnboardingWizard {
                 *self.download_state.lock().unwrap(),
                 DownloadState::Downloading { .. }
             )
+            || matches!(self.benchmark_state, BenchmarkState::Running { .. })
         {
             ctx.request_repaint();
         }
@@ -759,6 +950,7 @@ impl eframe::App for OnboardingWizard {
                 WizardStep::Welcome => self.show_welcome(ui),
                 WizardStep::Microphone => self.show_microphone(ui),
                 WizardStep::Model => self.show_model(ui),
+                WizardStep::Performance => self.show_performance(ui),
                 WizardStep::Hotkey => self.show_hotkey(ui),
                 WizardStep::Output => self.show_output(ui),
                 WizardStep::Ollama => self.show_ollama(ui),
@@ -829,8 +1021,9 @@ mod tests {
     #[test]
     fn test_wizard_step_from_index() {
         assert_eq!(WizardStep::from_index(0), Some(WizardStep::Welcome));
-        assert_eq!(WizardStep::from_index(6), Some(WizardStep::Complete));
-        assert_eq!(WizardStep::from_index(7), None);
+        assert_eq!(WizardStep::from_index(3), Some(WizardStep::Performance));
+        assert_eq!(WizardStep::from_index(7), Some(WizardStep::Complete));
+        assert_eq!(WizardStep::from_index(8), None);
     }
 
     #[test]
@@ -0,0 +1,144 @@
+//! In-memory transcription history.
+//!
+//! Keeps the most recent transcriptions output by the daemon so that
+//! clients (D-Bus, IPC, tray) can list and re-use them. History is not
+//! persisted across daemon restarts.
+
+use std::collections::VecDeque;
+
+use crate::ipc::HistoryItem;
+
+/// Default number of transcriptions kept in memory.
+pub const DEFAULT_HISTORY_CAPACITY: usize = 100;
+
+/// Bounded list of recent transcriptions, oldest entries evicted first.
+#[derive(Debug, Clone)]
+pub struct TranscriptionHistory {
+    entries: VecDeque<HistoryItem>,
+    capacity: usize,
+    next_id: i64,
+}
+
+impl Default for TranscriptionHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_HISTORY_CAPACITY)
+    }
+}
+
+impl TranscriptionHistory {
+    /// Create an empty history holding at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity.min(DEFAULT_HISTORY_CAPACITY)),
+            capacity: capacity.max(1),
+            next_id: 1,
+        }
+    }
+
+    /// Record a transcription and return the stored entry.
+    pub fn push(
+        &mut self,
+        text: &str,
+        duration_secs: f32,
+        llm_corrected: bool,
+        language: Option<&str>,
+    ) -> HistoryItem {
+        let item = HistoryItem {
+            id: self.next_id,
+            timestamp: chrono::Local::now().to_rfc3339(),
+            text: text.to_string(),
+            duration_secs: f64::from(duration_secs),
+            llm_corrected,
+            language: language.map(str::to_string),
+        };
+        self.next_id += 1;
+
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(item.clone());
+        item
+    }
+
+    /// Get up to `limit` entries, newest first, skipping the `offset` newest.
+    pub fn recent(&self, limit: usize, offset: usize) -> Vec<HistoryItem> {
+        self.entries
+            .iter()
+            .rev()
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// Look up an entry by ID.
+    pub fn get(&self, id: i64) -> Option<&HistoryItem> {
+        self.entries.iter().find(|item| item.id == id)
+    }
+
+    /// Number of stored entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Changes whenever an entry is added (and so whenever one is evicted).
+    pub fn revision(&self) -> i64 {
+        self.next_id
+    }
+
+    /// Whether the history is empty.
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_assigns_increasing_ids() {
+        let mut history = TranscriptionHistory::default();
+        let empty = history.revision();
+        let first = history.push("one", 1.0, false, None);
+        let revision = history.revision();
+        let second = history.push("two", 2.0, true, Some("de"));
+
+        assert_ne!(empty, revision);
+        assert_ne!(revision, history.revision());
+
+        assert_eq!(first.id, 1);
+        assert_eq!(second.id, 2);
+        assert!(second.llm_corrected);
+        assert_eq!(first.language, None);
+        assert_eq!(second.language.as_deref(), Some("de"));
+        assert_eq!(history.recent(1, 0)[0].text, "two");
+    }
+
+    #[test]
+    fn test_capacity_evicts_oldest() {
+        let mut history = TranscriptionHistory::new(2);
+        history.push("one", 1.0, false, None);
+        history.push("two", 1.0, false, None);
+        history.push("three", 1.0, false, None);
+
+        assert_eq!(history.len(), 2);
+        let texts: Vec<_> = history.recent(10, 0).into_iter().map(|i| i.text).collect();
+        assert_eq!(texts, vec!["three", "two"]);
+        assert!(history.get(1).is_none());
+        assert_eq!(history.get(3).unwrap().text, "three");
+    }
+
+    #[test]
+    fn test_recent_limit_and_offset() {
+        let mut history = TranscriptionHistory::default();
+        for text in ["a", "b", "c", "d"] {
+            history.push(text, 1.0, false, None);
+        }
+
+        let texts: Vec<_> = history.recent(2, 1).into_iter().map(|i| i.text).collect();
+        assert_eq!(texts, vec!["c", "b"]);
+        assert!(history.recent(5, 10).is_empty());
+    }
+}
@@ -19,8 +19,9 @@ use std::sync::Arc;
 use thiserror::Error;
 use tracing::{debug, error, info, warn};
 
+use super::echo::{EchoReference, EchoThread, ECHO_SAMPLE_RATE};
 use super::ring_buffer::{AudioMark, AudioRingBuffer};
-use crate::config::ResamplingQuality;
+use crate::config::{EchoCancellationConfig, ResamplingQuality};
 
 /// Target sample rate for Whisper (16kHz)
 pub const SAMPLE_RATE: u32 = 16000;
@@ -49,6 +50,9 @@ pub enum AudioRecorderError {
 
     #[error("Failed to start audio stream: {0}")]
     StreamStartFailed(String),
+
+    #[error("Echo cancellation reference unavailable: {0}")]
+    EchoReference(String),
 }
 
 /// Audio buffer containing recorded samples
@@ -349,12 +353,29 @@ pub fn load_wav_file(
     path: &std::path::Path,
     quality: ResamplingQuality,
 ) -> Result<AudioBuffer, AudioRecorderError> {
-    use hound::WavReader;
-
-    let reader = WavReader::open(path).map_err(|e| {
+    let reader = hound::WavReader::open(path).map_err(|e| {
         AudioRecorderError::StreamBuildFailed(format!("Failed to open WAV file: {}", e))
     })?;
+    decode_wav(reader, quality)
+}
+
+/// Decode WAV data already in memory (e.g. an API upload).
+///
+/// Same conversions as [`load_wav_file`].
+pub fn load_wav_bytes(
+    bytes: &[u8],
+    quality: ResamplingQuality,
+) -> Result<AudioBuffer, AudioRecorderError> {
+    let reader = hound::WavReader::new(std::io::Cursor::new(bytes)).map_err(|e| {
+        AudioRecorderError::StreamBuildFailed(format!("Failed to read WAV data: {}", e))
+    })?;
+    decode_wav(reader, quality)
+}
 
+fn decode_wav<R: std::io::Read>(
+    reader: hound::WavReader<R>,
+    quality: ResamplingQuality,
+) -> Result<AudioBuffer, AudioRecorderError> {
     let spec = reader.spec();
     let file_sample_rate = spec.sample_rate;
     let channels = spec.channels as usize;
@@ -454,7 +475,10 @@ pub struct AudioRecorder {
     config: StreamConfig,
     /// Running audio stream
     stream: Option<Stream>,
+    /// Sample rate of the ring buffer (the rate of the first device opened)
     device_sample_rate: u32,
+    /// Sample rate of the device currently streaming into the ring buffer
+    capture_rate: u32,
     /// Ring buffer for continuous capture
     ring_buffer: Arc<AudioRingBuffer>,
     /// Resampling quality setting
@@ -463,6 +487,10 @@ pub struct AudioRecorder {
     device_channels: u16,
     /// Which channels to mix for output
     channel_mix: Arc<ChannelMix>,
+    /// Echo canceller applied to captured audio
+    echo: Option<EchoThread>,
+    /// System audio capture feeding the echo canceller
+    _echo_reference: Option<EchoReference>,
 }
 
 impl AudioRecorder {
@@ -561,10 +589,13 @@ impl AudioRecorder {
             config,
             stream: None,
             device_sample_rate,
+            capture_rate: device_sample_rate,
             ring_buffer,
             resampling_quality,
             device_channels: capture_channels,
             channel_mix: Arc::new(channel_mix),
+            echo: None,
+            _echo_reference: None,
         };
 
         // Start the stream immediately
@@ -573,11 +604,54 @@ impl AudioRecorder {
         Ok(recorder)
     }
 
+    /// Remove speaker bleed from the mic using system audio as reference.
+    ///
+    /// Call right after creating the recorder: the ring buffer is recreated
+    /// at 16kHz (the canceller's rate), discarding audio buffered so far.
+    pub fn enable_echo_cancellation(
+        &mut self,
+        config: &EchoCancellationConfig,
+    ) -> Result<(), AudioRecorderError> {
+        let reference = EchoReference::start(config.reference_source.as_deref())?;
+
+        if self.device_sample_rate != ECHO_SAMPLE_RATE {
+            let prebuffer_secs = self.ring_buffer.duration_secs();
+            self.ring_buffer = Arc::new(AudioRingBuffer::new(prebuffer_secs, ECHO_SAMPLE_RATE));
+            self.device_sample_rate = ECHO_SAMPLE_RATE;
+        }
+
+        let stage = reference.stage(config.tail_ms);
+        info!(
+            "Echo cancellation enabled ({} ms tail, {} taps)",
+            config.tail_ms,
+            stage.taps()
+        );
+        // Stop the stream first: the worker takes over writing the ring buffer
+        self.stream = None;
+        self.echo = None;
+        self.echo = Some(EchoThread::spawn(
+            stage,
+            self.ring_buffer.clone(),
+            self.resampling_quality,
+        ));
+        self._echo_reference = Some(reference);
+
+        // Restart the stream so the callback feeds the canceller
+        self.stream = None;
+        self.start_stream()
+    }
+
     /// Start the audio stream (internal).
     fn start_stream(&mut self) -> Result<(), AudioRecorderError> {
         let ring_buffer = self.ring_buffer.clone();
         let channel_mix = self.channel_mix.clone();
         let device_channels = self.device_channels;
+        let capture_rate = self.capture_rate;
+        // The ring buffer rate differs from the device after a device switch.
+        // With echo cancellation the worker resamples instead.
+        let mut resampler = (self.echo.is_none() && capture_rate != self.device_sample_rate)
+            .then(|| StreamResampler::new(capture_rate, self.device_sample_rate));
+        let echo = self.echo.as_ref().map(EchoThread::input);
         let err_fn = |err| error!("Audio stream error: {}", err);
 
         let stream = self
@@ -593,7 +667,15 @@ impl AudioRecorder {
                         // Mix selected channels to mono
                         mix_channels_to_mono(data, device_channels as usize, &channel_mix)
                     };
-                    ring_buffer.push_samples(&mono_data);
+                    if let Some(ref echo) = echo {
+                        echo.push(capture_rate, mono_data);
+                        return;
+                    }
+                    let samples = match resampler.as_mut() {
+                        Some(resampler) => resampler.process(&mono_data),
+                        None => mono_data,
+                    };
+                    ring_buffer.push_samples(&samples);
                 },
                 err_fn,
                 None,
@@ -723,6 +805,34 @@ impl AudioRecorder {
         self.ring_buffer.current_levels(window_ms)
     }
 
+    /// Extract the last `secs` seconds of audio already in the ring buffer.
+    ///
+    /// Used to transcribe speech retroactively. Returns less audio when the
+    /// buffer holds less, or None if too short.
+    pub fn extract_last(&self, secs: f32) -> Option<AudioBuffer> {
+        let to_pos = self.ring_buffer.current_position();
+        let samples = (secs.max(0.0) * self.device_sample_rate as f32) as usize;
+        let from_pos = self.ring_buffer.position_before(to_pos, samples);
+        self.extract_chunk(from_pos, to_pos)
+    }
+
+    /// Extract audio resampled to 16kHz, without the minimum length and
+    /// padding applied by [`extract_chunk`](Self::extract_chunk).
+    ///
+    /// Used to feed short windows to the VAD.
+    pub fn extract_samples(&self, from_pos: usize, to_pos: usize) -> Vec<f32> {
+        let samples = self.ring_buffer.extract_range(from_pos, to_pos);
+        if samples.is_empty() || self.device_sample_rate == SAMPLE_RATE {
+            return samples;
+        }
+        resample(
+            &samples,
+            self.device_sample_rate,
+            SAMPLE_RATE,
+            self.resampling_quality,
+        )
+    }
+
     /// Extract a chunk of audio from one position to another.
     ///
     /// Used for streaming chunk extraction during recording. Call this
@@ -808,34 +918,39 @@ impl AudioRecorder {
             .unwrap_or_else(|_| "unknown".to_string())
     }
 
-    /// Try to reinitialize with a new default device.
-    ///
-    /// Call this when the current device is disconnected and a new one becomes available.
-    /// Returns the new device name on success.
-    #[allow(dead_code)]
-    pub fn try_reinitialize(&mut self) -> Result<String, AudioRecorderError> {
-        info!("Attempting to reinitialize audio capture...");
-
-        // Stop the current stream
-        self.stream = None;
+    /// Get the current device ID, if the backend reports one
+    pub fn device_id(&self) -> Option<String> {
+        self.device.id().ok().map(|id| id.to_string())
+    }
 
+    /// Switch capture to another input device without losing buffered audio.
+    ///
+    /// The ring buffer, marks and positions are kept, so a recording in
+    /// progress continues on the new device. If the new device runs at a
+    /// different sample rate, its audio is resampled to the ring buffer rate
+    /// as it arrives. Returns the new device name on success.
+    ///
+    /// # Arguments
+    /// * `device_id` - Device ID or name to switch to (None = system default)
+    pub fn switch_device(&mut self, device_id: Option<&str>) -> Result<String, AudioRecorderError> {
         let host = cpal::default_host();
 
-        // Try to get a new default device (skipping monitor sources)
-        let device = Self::pick_default_microphone(&host)?;
+        let device = match device_id {
+            Some(id) => Self::find_device_by_id(&host, id)?,
+            None => Self::pick_default_microphone(&host)?,
+        };
 
         let device_name = device
             .description()
             .map(|d| d.name().to_string())
             .unwrap_or_else(|_| "unknown".to_string());
-        info!("Found new audio device: {}", device_name);
 
-        // Get new config
+        // Query the new device before touching the running stream
         let supported_config = device
             .default_input_config()
             .map_err(|e| AudioRecorderError::NoInputConfig(e.to_string()))?;
 
-        let device_sample_rate = supported_config.sample_rate();
+        let capture_rate = supported_config.sample_rate();
         let device_channels = supported_config.channels();
 
         // Determine if we need multi-channel capture based on current mix setting
@@ -848,35 +963,144 @@ impl AudioRecorder {
         };
 
         info!(
-            "Device: {} Hz, {} channels (capturing {} ch)",
-            device_sample_rate, device_channels, capture_channels
+            "Switching audio input to {} ({} Hz, {} channels, capturing {} ch)",
+            device_name, capture_rate, device_channels, capture_channels
         );
 
-        // Build new config
-        let config = StreamConfig {
+        // Stop the current stream
+        self.stream = None;
+
+        self.device = device;
+        self.config = StreamConfig {
             channels: capture_channels,
-            sample_rate: device_sample_rate,
+            sample_rate: capture_rate,
             buffer_size: cpal::BufferSize::Default,
         };
-
-        // Update fields
-        self.device = device;
-        self.config = config;
-        self.device_sample_rate = device_sample_rate;
+        self.capture_rate = capture_rate;
         self.device_channels = capture_channels;
 
-        // Get prebuffer duration from existing ring buffer
-        let prebuffer_secs = self.ring_buffer.duration_secs();
-
-        // Create new ring buffer at new device sample rate
-        self.ring_buffer = Arc::new(AudioRingBuffer::new(prebuffer_secs, device_sample_rate));
-
-        // Start the new stream
         self.start_stream()?;
-        info!("Audio stream reinitialized successfully");
 
         Ok(device_name)
     }
+
+    /// Try to reinitialize with a new default device.
+    ///
+    /// Call this when the current device is disconnected and a new one becomes available.
+    /// Buffered audio is kept (see [`Self::switch_device`]).
+    /// Returns the new device name on success.
+    #[allow(dead_code)]
+    pub fn try_reinitialize(&mut self) -> Result<String, AudioRecorderError> {
+        info!("Attempting to reinitialize audio capture...");
+        self.switch_device(None)
+    }
+}
+
+/// Streaming linear resampler for the capture callback.
+///
+/// Unlike [`resample_linear`], it keeps its position and last sample between
+/// calls, so consecutive callback buffers join without clicks or drift.
+pub(super) struct StreamResampler {
+    /// Input samples advanced per output sample
+    step: f64,
+    /// Position of the next output sample, relative to `last`
+    pos: f64,
+    /// Last input sample of the previous call
+    last: f32,
+}
+
+impl StreamResampler {
+    fn new(from_rate: u32, to_rate: u32) -> Self {
+        Self {
+            step: from_rate as f64 / to_rate as f64,
+            pos: 0.0,
+            last: 0.0,
+        }
+    }
+
+    /// Resample the next block of input.
+    fn process(&mut self, input: &[f32]) -> Vec<f32> {
+        let Some(&tail) = input.last() else {
+            return Vec::new();
+        };
+
+        let mut output = Vec::with_capacity((input.len() as f64 / self.step) as usize + 1);
+        while self.pos < input.len() as f64 {
+            let idx = self.pos.floor() as usize;
+            let frac = (self.pos - idx as f64) as f32;
+            let a = if idx == 0 { self.last } else { input[idx - 1] };
+            let b = input[idx];
+            output.push(a + (b - a) * frac);
+            self.pos += self.step;
+        }
+
+        self.pos -= input.len() as f64;
+        self.last = tail;
+        output
+    }
+}
+
+/// Input samples per sinc resampler call when streaming (about 10ms at 48kHz)
+const STREAM_SINC_CHUNK: usize = 512;
+
+/// Streaming resampler honouring `audio.resampling_quality`, for audio
+/// arriving in blocks.
+pub(super) enum BlockResampler {
+    /// Rates match
+    Same,
+    Linear(StreamResampler),
+    Sinc {
+        resampler: Box<SincFixedIn<f32>>,
+        /// Input waiting for a full chunk
+        pending: Vec<f32>,
+    },
+}
+
+impl BlockResampler {
+    pub(super) fn new(from_rate: u32, to_rate: u32, quality: ResamplingQuality) -> Self {
+        if from_rate == to_rate {
+            return Self::Same;
+        }
+        match quality {
+            ResamplingQuality::Low => Self::Linear(StreamResampler::new(from_rate, to_rate)),
+            ResamplingQuality::High => {
+                match sinc_resampler(from_rate, to_rate, STREAM_SINC_CHUNK) {
+                    Ok(resampler) => Self::Sinc {
+                        resampler: Box::new(resampler),
+                        pending: Vec::with_capacity(STREAM_SINC_CHUNK * 2),
+                    },
+                    Err(e) => {
+                        warn!(
+                            "Failed to create sinc resampler: {}, falling back to linear",
+                            e
+                        );
+                        Self::Linear(StreamResampler::new(from_rate, to_rate))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Resample the next block of input. The sinc resampler holds input
+    /// back until it has a full chunk.
+    pub(super) fn process(&mut self, input: &[f32]) -> Vec<f32> {
+        match self {
+            Self::Same => input.to_vec(),
+            Self::Linear(resampler) => resampler.process(input),
+            Self::Sinc { resampler, pending } => {
+                pending.extend_from_slice(input);
+                let mut output = Vec::new();
+                while pending.len() >= resampler.input_frames_next() {
+                    let chunk: Vec<f32> = pending.drain(..resampler.input_frames_next()).collect();
+                    match resampler.process(&[chunk], None) {
+                        Ok(resampled) => output.extend_from_slice(&resampled[0]),
+                        Err(e) => warn!("Sinc resampling error: {}", e),
+                    }
+                }
+                output
+            }
+        }
+    }
 }
 
 /// Detect whether a cpal device is actually a monitor/loopback source.
@@ -884,7 +1108,7 @@ impl AudioRecorder {
 /// On PulseAudio/PipeWire these appear as input devices with names ending in
 /// ".monitor" or prefixed with "Monitor of". We never want to pick one as a
 /// microphone — doing so would capture desktop audio instead of the user's voice.
-fn is_monitor_device(device: &Device) -> bool {
+pub(crate) fn is_monitor_device(device: &Device) -> bool {
     let Ok(desc) = device.description() else {
         return false;
     };
@@ -1000,15 +1224,12 @@ fn resample_for_rnnoise(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f3
     resample_linear(samples, from_rate, to_rate)
 }
 
-/// High-quality sinc resampling via rubato
-///
-/// Uses polyphase sinc interpolation which is the standard for professional audio.
-/// This provides better frequency response and less aliasing than linear interpolation.
-fn resample_sinc(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
-    if samples.is_empty() {
-        return Vec::new();
-    }
-
+/// Mono sinc resampler taking `chunk_size` input samples per call.
+fn sinc_resampler(
+    from_rate: u32,
+    to_rate: u32,
+    chunk_size: usize,
+) -> Result<SincFixedIn<f32>, rubato::ResamplerConstructionError> {
     // Configure sinc resampler for high quality audio
     let params = SincInterpolationParameters {
         sinc_len: 256,
@@ -1018,18 +1239,29 @@ fn resample_sinc(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
         window: WindowFunction::BlackmanHarris2,
     };
 
-    let resample_ratio = to_rate as f64 / from_rate as f64;
-
-    // Create resampler for mono audio (1 channel)
-    // chunk_size is how many input samples we process at once
-    let chunk_size = 1024;
-    let mut resampler = match SincFixedIn::<f32>::new(
-        resample_ratio,
+    SincFixedIn::<f32>::new(
+        to_rate as f64 / from_rate as f64,
         2.0, // max relative ratio (allows some flexibility)
         params,
         chunk_size,
         1, // mono
-    ) {
+    )
+}
+
+/// High-quality sinc resampling via rubato
+///
+/// Uses polyphase sinc interpolation which is the standard for professional audio.
+/// This provides better frequency response and less aliasing than linear interpolation.
+fn resample_sinc(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
+    if samples.is_empty() {
+        return Vec::new();
+    }
+
+    let resample_ratio = to_rate as f64 / from_rate as f64;
+
+    // chunk_size is how many input samples we process at once
+    let chunk_size = 1024;
+    let mut resampler = match sinc_resampler(from_rate, to_rate, chunk_size) {
         Ok(r) => r,
         Err(e) => {
             warn!(
@@ -1186,6 +1418,61 @@ mod tests {
         assert!((result.len() as i32 - expected_len as i32).abs() < 100);
     }
 
+    #[test]
+    fn test_load_wav_bytes_downmixes_and_resamples() {
+        // Two seconds of 32kHz stereo
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 32000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut bytes = std::io::Cursor::new(Vec::new());
+        {
+            let mut writer = hound::WavWriter::new(&mut bytes, spec).unwrap();
+            for _ in 0..64000 {
+                writer.write_sample(8192i16).unwrap();
+                writer.write_sample(-8192i16).unwrap();
+            }
+            writer.finalize().unwrap();
+        }
+
+        let buffer = load_wav_bytes(bytes.get_ref(), ResamplingQuality::Low).unwrap();
+        assert_eq!(buffer.sample_rate, SAMPLE_RATE);
+        assert!((buffer.duration_secs() - 2.0).abs() < 0.05);
+        assert!(buffer.samples.iter().all(|s| s.abs() < 0.01));
+
+        assert!(load_wav_bytes(b"not a wav file", ResamplingQuality::Low).is_err());
+    }
+
+    #[test]
+    fn test_stream_resampler_same_rate_is_continuous() {
+        let mut resampler = StreamResampler::new(16000, 16000);
+        let first = resampler.process(&[1.0, 2.0, 3.0]);
+        let second = resampler.process(&[4.0, 5.0]);
+        // One sample of latency, nothing dropped across calls
+        assert_eq!(first, vec![0.0, 1.0, 2.0]);
+        assert_eq!(second, vec![3.0, 4.0]);
+        assert!(resampler.process(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_stream_resampler_downsample_across_blocks() {
+        let mut resampler = StreamResampler::new(48000, 16000);
+        let input: Vec<f32> = (0..4800).map(|i| i as f32).collect();
+
+        let mut output = Vec::new();
+        for block in input.chunks(441) {
+            output.extend(resampler.process(block));
+        }
+
+        assert_eq!(output.len(), 1600);
+        // Evenly spaced: every third input sample after the initial silence
+        assert!(output[1..]
+            .windows(2)
+            .all(|w| (w[1] - w[0] - 3.0).abs() < 1e-3));
+    }
+
     #[test]
     fn test_rms_db_silence() {
         let buffer = AudioBuffer {
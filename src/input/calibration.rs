@@ -0,0 +1,357 @@
+//! Microphone loudness analysis for `openhush device calibrate`.
+//!
+//! Compares a recording of room noise with a spoken sample to measure the
+//! noise floor, speech level, peak and signal-to-noise ratio, and derives
+//! preprocessing, VAD and noise-reduction settings from them.
+
+use thiserror::Error;
+
+use super::audio::AudioBuffer;
+use crate::config::Config;
+
+/// Analysis frame length (20ms at 16kHz)
+const FRAME_SIZE: usize = 320;
+
+/// Frames this far above the noise floor count as speech
+const SPEECH_MARGIN_DB: f32 = 6.0;
+
+/// Minimum share of the spoken sample that must contain speech
+const MIN_SPEECH_RATIO: f32 = 0.1;
+
+/// Crest factor (peak over RMS) above which compression is recommended
+const COMPRESSION_CREST_DB: f32 = 18.0;
+
+/// Raw input peaks above this level are treated as clipping
+const CLIPPING_DB: f32 = -0.5;
+
+/// Speech quieter than this before normalization suggests raising mic gain
+const QUIET_SPEECH_DB: f32 = -45.0;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum CalibrationError {
+    #[error("Recording too short to analyze")]
+    TooShort,
+
+    #[error("No speech detected in the spoken sample (only {0:.0}% above the noise floor)")]
+    NoSpeech(f32),
+}
+
+/// Measured levels of the microphone in the current room.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calibration {
+    /// Median frame level of the silent recording (dBFS)
+    pub noise_floor_db: f32,
+    /// RMS level of the speech frames (dBFS)
+    pub speech_rms_db: f32,
+    /// Peak level of the spoken sample (dBFS)
+    pub peak_db: f32,
+    /// Speech level over the noise floor (dB)
+    pub snr_db: f32,
+}
+
+/// Settings derived from a [`Calibration`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recommendation {
+    /// RMS level speech is normalized to (dBFS)
+    pub normalization_target_db: f32,
+    /// Compression threshold, if the speaker's dynamics call for compression
+    pub compression_threshold_db: Option<f32>,
+    /// Whether normalized peaks would exceed the limiter ceiling
+    pub limiter: bool,
+    /// RNNoise strength, if the room is noisy enough to need it
+    pub noise_reduction_strength: Option<f32>,
+    /// Silero VAD speech probability threshold
+    pub vad_threshold: f32,
+    /// Problems the user should fix at the source
+    pub warnings: Vec<String>,
+}
+
+impl Calibration {
+    /// Measure levels from a silent recording and a spoken sample.
+    ///
+    /// Both buffers are expected at 16kHz mono.
+    pub fn measure(noise: &AudioBuffer, speech: &AudioBuffer) -> Result<Self, CalibrationError> {
+        let noise_frames = frame_levels_db(&noise.samples);
+        let speech_frames = frame_levels_db(&speech.samples);
+        if noise_frames.is_empty() || speech_frames.is_empty() {
+            return Err(CalibrationError::TooShort);
+        }
+
+        let noise_floor_db = median(&noise_frames);
+
+        let active: Vec<f32> = speech_frames
+            .iter()
+            .copied()
+            .filter(|&db| db > noise_floor_db + SPEECH_MARGIN_DB)
+            .collect();
+        let ratio = active.len() as f32 / speech_frames.len() as f32;
+        if ratio < MIN_SPEECH_RATIO {
+            return Err(CalibrationError::NoSpeech(ratio * 100.0));
+        }
+
+        // Average power of the speech frames, so pauses do not drag it down
+        let mean_power =
+            active.iter().map(|&db| db_to_power(db)).sum::<f32>() / active.len() as f32;
+        let speech_rms_db = power_to_db(mean_power);
+
+        let peak = speech
+            .samples
+            .iter()
+            .fold(0.0f32, |max, s| max.max(s.abs()));
+        let peak_db = amplitude_to_db(peak);
+
+        Ok(Self {
+            noise_floor_db,
+            speech_rms_db,
+            peak_db,
+            snr_db: speech_rms_db - noise_floor_db,
+        })
+    }
+
+    /// Crest factor of the speech (peak over RMS, dB)
+    pub fn crest_db(&self) -> f32 {
+        self.peak_db - self.speech_rms_db
+    }
+
+    /// Derive settings for speech normalized to `target_db`.
+    ///
+    /// `limiter_ceiling_db` is the configured limiter ceiling.
+    pub fn recommend(&self, target_db: f32, limiter_ceiling_db: f32) -> Recommendation {
+        let crest = self.crest_db();
+
+        // Compress dynamic speakers a little below the normalized level
+        let compression_threshold_db =
+            (crest > COMPRESSION_CREST_DB).then(|| (target_db - 6.0).round());
+
+        // Clean rooms (30 dB SNR and up) need no denoising, 10 dB and below full strength
+        let strength = round_to_step(((30.0 - self.snr_db) / 20.0).clamp(0.0, 1.0));
+        let noise_reduction_strength = (strength > 0.0).then_some(strength);
+
+        // Noisier rooms need more confidence before audio counts as speech
+        let vad_threshold =
+            round_to_step(0.4 + 0.3 * ((25.0 - self.snr_db) / 15.0).clamp(0.0, 1.0));
+
+        let mut warnings = Vec::new();
+        if self.peak_db >= CLIPPING_DB {
+            warnings.push("Input is clipping - lower the microphone gain".to_string());
+        }
+        if self.speech_rms_db < QUIET_SPEECH_DB {
+            warnings.push(
+                "Speech is very quiet - raise the microphone gain or move closer".to_string(),
+            );
+        }
+        if self.snr_db < 10.0 {
+            warnings.push(
+                "Speech is barely above the background noise - use a closer microphone".to_string(),
+            );
+        }
+
+        Recommendation {
+            normalization_target_db: target_db,
+            compression_threshold_db,
+            limiter: target_db + crest > limiter_ceiling_db,
+            noise_reduction_strength,
+            vad_threshold,
+            warnings,
+        }
+    }
+}
+
+impl Recommendation {
+    /// Write the recommended settings into a config.
+    pub fn apply(&self, config: &mut Config) {
+        let audio = &mut config.audio;
+        audio.preprocessing = true;
+        audio.normalization.enabled = true;
+        audio.normalization.target_db = self.normalization_target_db;
+
+        audio.compression.enabled = self.compression_threshold_db.is_some();
+        if let Some(threshold) = self.compression_threshold_db {
+            audio.compression.threshold_db = threshold;
+        }
+
+        audio.limiter.enabled = self.limiter;
+
+        audio.noise_reduction.enabled = self.noise_reduction_strength.is_some();
+        if let Some(strength) = self.noise_reduction_strength {
+            audio.noise_reduction.strength = strength;
+        }
+
+        config.vad.threshold = self.vad_threshold;
+    }
+}
+
+/// RMS level of each analysis frame in dBFS.
+fn frame_levels_db(samples: &[f32]) -> Vec<f32> {
+    samples
+        .chunks_exact(FRAME_SIZE)
+        .map(|frame| {
+            let power = frame.iter().map(|&s| s * s).sum::<f32>() / frame.len() as f32;
+            power_to_db(power)
+        })
+        .collect()
+}
+
+fn median(values: &[f32]) -> f32 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f32::total_cmp);
+    sorted[sorted.len() / 2]
+}
+
+/// Levels are floored at -120 dBFS so digital silence stays finite.
+fn power_to_db(power: f32) -> f32 {
+    (10.0 * power.log10()).max(-120.0)
+}
+
+fn db_to_power(db: f32) -> f32 {
+    10.0_f32.powf(db / 10.0)
+}
+
+fn amplitude_to_db(amplitude: f32) -> f32 {
+    (20.0 * amplitude.log10()).max(-120.0)
+}
+
+/// Round to the nearest 0.05 (the precision settings are written with).
+fn round_to_step(value: f32) -> f32 {
+    (value * 20.0).round() / 20.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer(samples: Vec<f32>) -> AudioBuffer {
+        AudioBuffer {
+            samples,
+            sample_rate: 16000,
+        }
+    }
+
+    /// Deterministic noise in [-amplitude, amplitude]
+    fn noise(len: usize, amplitude: f32) -> Vec<f32> {
+        let mut state = 0x2545_f491_u32;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state as f32 / u32::MAX as f32 * 2.0 - 1.0) * amplitude
+            })
+            .collect()
+    }
+
+    /// Sine "speech" over background noise
+    fn speech(len: usize, amplitude: f32, noise_amplitude: f32) -> Vec<f32> {
+        noise(len, noise_amplitude)
+            .into_iter()
+            .enumerate()
+            .map(|(i, n)| n + amplitude * (i as f32 * 0.05).sin())
+            .collect()
+    }
+
+    // ===================
+    // Measurement Tests
+    // ===================
+
+    #[test]
+    fn test_measure_levels() {
+        let calibration = Calibration::measure(
+            &buffer(noise(16000, 0.001)),
+            &buffer(speech(32000, 0.1, 0.001)),
+        )
+        .unwrap();
+
+        // A 0.1 sine has an RMS of about -23 dBFS and a peak of -20 dBFS
+        assert!((calibration.speech_rms_db + 23.0).abs() < 1.0);
+        assert!((calibration.peak_db + 20.0).abs() < 0.5);
+        // Uniform noise of 0.001 sits around -65 dBFS
+        assert!((calibration.noise_floor_db + 65.0).abs() < 2.0);
+        assert!(calibration.snr_db > 35.0);
+    }
+
+    #[test]
+    fn test_measure_ignores_pauses() {
+        let mut samples = speech(16000, 0.1, 0.001);
+        samples.extend(noise(16000, 0.001));
+        let with_pause = Calibration::measure(&buffer(noise(16000, 0.001)), &buffer(samples));
+        let without = Calibration::measure(
+            &buffer(noise(16000, 0.001)),
+            &buffer(speech(16000, 0.1, 0.001)),
+        );
+
+        let diff = with_pause.unwrap().speech_rms_db - without.unwrap().speech_rms_db;
+        assert!(diff.abs() < 0.5);
+    }
+
+    #[test]
+    fn test_measure_rejects_silence_and_short_input() {
+        let silence = buffer(noise(16000, 0.001));
+        assert!(matches!(
+            Calibration::measure(&silence, &silence),
+            Err(CalibrationError::NoSpeech(_))
+        ));
+        assert_eq!(
+            Calibration::measure(&buffer(vec![0.0; 100]), &silence),
+            Err(CalibrationError::TooShort)
+        );
+    }
+
+    // ===================
+    // Recommendation Tests
+    // ===================
+
+    fn calibration(noise_floor_db: f32, speech_rms_db: f32, peak_db: f32) -> Calibration {
+        Calibration {
+            noise_floor_db,
+            speech_rms_db,
+            peak_db,
+            snr_db: speech_rms_db - noise_floor_db,
+        }
+    }
+
+    #[test]
+    fn test_recommend_quiet_room() {
+        let rec = calibration(-70.0, -25.0, -12.0).recommend(-18.0, -1.0);
+        assert_eq!(rec.noise_reduction_strength, None);
+        assert_eq!(rec.vad_threshold, 0.4);
+        assert_eq!(rec.compression_threshold_db, None);
+        assert!(!rec.limiter);
+        assert!(rec.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_recommend_noisy_room() {
+        let rec = calibration(-40.0, -25.0, -5.0).recommend(-18.0, -1.0);
+        // 15 dB SNR
+        assert_eq!(rec.noise_reduction_strength, Some(0.75));
+        assert_eq!(rec.vad_threshold, 0.6);
+        // 20 dB crest: compress, and peaks would pass the ceiling
+        assert_eq!(rec.compression_threshold_db, Some(-24.0));
+        assert!(rec.limiter);
+    }
+
+    #[test]
+    fn test_recommend_warnings() {
+        let rec = calibration(-55.0, -50.0, -0.1).recommend(-18.0, -1.0);
+        assert_eq!(rec.noise_reduction_strength, Some(1.0));
+        assert_eq!(rec.vad_threshold, 0.7);
+        assert_eq!(rec.warnings.len(), 3);
+    }
+
+    #[test]
+    fn test_apply_recommendation() {
+        let mut config = Config::default();
+        let rec = calibration(-40.0, -25.0, -5.0).recommend(-18.0, -1.0);
+        rec.apply(&mut config);
+
+        assert!(config.audio.preprocessing);
+        assert!(config.audio.normalization.enabled);
+        assert!(config.audio.compression.enabled);
+        assert_eq!(config.audio.compression.threshold_db, -24.0);
+        assert!(config.audio.limiter.enabled);
+        assert!(config.audio.noise_reduction.enabled);
+        assert_eq!(config.audio.noise_reduction.strength, 0.75);
+        assert_eq!(config.vad.threshold, 0.6);
+        assert!(config.validate().is_ok());
+    }
+}
@@ -0,0 +1,228 @@
+//! Audio input device monitoring.
+//!
+//! Polls the audio host for microphones being plugged in or removed and for
+//! changes of the system default input, so the daemon can follow the user's
+//! preferred device list (see `audio.preferred_devices`).
+
+use cpal::traits::{DeviceTrait, HostTrait};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+use super::audio::is_monitor_device;
+
+/// A microphone known to the audio host
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputDevice {
+    /// Backend device ID
+    pub id: String,
+    /// Human-readable device name
+    pub name: String,
+}
+
+/// Microphones available at one point in time
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceSnapshot {
+    /// Available microphones (monitor sources excluded)
+    pub devices: Vec<InputDevice>,
+    /// ID of the system default input, if it is a microphone
+    pub default_id: Option<String>,
+}
+
+impl DeviceSnapshot {
+    /// Enumerate the current input devices.
+    ///
+    /// This queries the audio backend and may block briefly.
+    pub fn capture() -> Self {
+        let host = cpal::default_host();
+
+        let describe = |device: &cpal::Device| -> Option<InputDevice> {
+            if is_monitor_device(device) {
+                return None;
+            }
+            let name = device.description().ok()?.name().to_string();
+            let id = device
+                .id()
+                .map(|id| id.to_string())
+                .unwrap_or_else(|_| name.clone());
+            Some(InputDevice { id, name })
+        };
+
+        let devices = host
+            .input_devices()
+            .map(|devices| devices.filter_map(|d| describe(&d)).collect())
+            .unwrap_or_default();
+        let default_id = host
+            .default_input_device()
+            .and_then(|d| describe(&d))
+            .map(|d| d.id);
+
+        Self {
+            devices,
+            default_id,
+        }
+    }
+
+    /// Pick the device to capture from.
+    ///
+    /// The first entry of `preferred` that matches an available device wins.
+    /// Otherwise `fallback` (the configured `input_device`) is used if
+    /// present, then the system default, then any microphone.
+    pub fn select(&self, preferred: &[String], fallback: Option<&str>) -> Option<&InputDevice> {
+        preferred
+            .iter()
+            .find_map(|pattern| self.devices.iter().find(|d| d.matches(pattern)))
+            .or_else(|| {
+                fallback.and_then(|id| self.devices.iter().find(|d| d.id == id || d.name == id))
+            })
+            .or_else(|| {
+                self.default_id
+                    .as_deref()
+                    .and_then(|id| self.devices.iter().find(|d| d.id == id))
+            })
+            .or_else(|| self.devices.first())
+    }
+}
+
+impl InputDevice {
+    /// Whether a `preferred_devices` entry refers to this device.
+    ///
+    /// Matches the exact ID or name, or part of the name ignoring case.
+    fn matches(&self, pattern: &str) -> bool {
+        let pattern = pattern.trim();
+        self.id == pattern
+            || self.name == pattern
+            || self.name.to_lowercase().contains(&pattern.to_lowercase())
+    }
+}
+
+/// Start polling for device changes.
+///
+/// The first snapshot is sent immediately, then one whenever the set of
+/// devices or the default input changes. Polling stops once the receiver
+/// is dropped.
+pub fn spawn_device_watcher(interval: Duration) -> mpsc::Receiver<DeviceSnapshot> {
+    let (tx, rx) = mpsc::channel(4);
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut last: Option<DeviceSnapshot> = None;
+
+        loop {
+            ticker.tick().await;
+            if tx.is_closed() {
+                break;
+            }
+            let Ok(snapshot) = tokio::task::spawn_blocking(DeviceSnapshot::capture).await else {
+                break;
+            };
+            if last.as_ref() == Some(&snapshot) {
+                continue;
+            }
+            debug!(
+                "Audio input devices changed: {} available, default {:?}",
+                snapshot.devices.len(),
+                snapshot.default_id
+            );
+            if tx.send(snapshot.clone()).await.is_err() {
+                break;
+            }
+            last = Some(snapshot);
+        }
+    });
+
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(id: &str, name: &str) -> InputDevice {
+        InputDevice {
+            id: id.into(),
+            name: name.into(),
+        }
+    }
+
+    fn snapshot() -> DeviceSnapshot {
+        DeviceSnapshot {
+            devices: vec![
+                device("alsa_input.pci", "Built-in Audio Analog Stereo"),
+                device("bluez_input.jabra", "Jabra Evolve2 Headset"),
+                device("alsa_input.usb", "USB Microphone"),
+            ],
+            default_id: Some("alsa_input.usb".into()),
+        }
+    }
+
+    // ===================
+    // Device Selection Tests
+    // ===================
+
+    #[test]
+    fn test_select_follows_priority_order() {
+        let snap = snapshot();
+        let preferred = vec!["headset".to_string(), "Built-in".to_string()];
+        assert_eq!(
+            snap.select(&preferred, None).unwrap().id,
+            "bluez_input.jabra"
+        );
+
+        // Headset unplugged: next preference wins over the default
+        let mut unplugged = snap.clone();
+        unplugged.devices.retain(|d| d.id != "bluez_input.jabra");
+        assert_eq!(
+            unplugged.select(&preferred, None).unwrap().id,
+            "alsa_input.pci"
+        );
+    }
+
+    #[test]
+    fn test_select_matches_id_and_name() {
+        let snap = snapshot();
+        assert_eq!(
+            snap.select(&["alsa_input.pci".to_string()], None)
+                .unwrap()
+                .name,
+            "Built-in Audio Analog Stereo"
+        );
+        assert_eq!(
+            snap.select(&["USB Microphone".to_string()], None)
+                .unwrap()
+                .id,
+            "alsa_input.usb"
+        );
+    }
+
+    #[test]
+    fn test_select_falls_back_to_input_device_then_default() {
+        let snap = snapshot();
+        let preferred = vec!["webcam".to_string()];
+
+        assert_eq!(
+            snap.select(&preferred, Some("Built-in Audio Analog Stereo"))
+                .unwrap()
+                .id,
+            "alsa_input.pci"
+        );
+        assert_eq!(
+            snap.select(&preferred, Some("missing")).unwrap().id,
+            "alsa_input.usb"
+        );
+
+        let no_default = DeviceSnapshot {
+            default_id: None,
+            ..snap
+        };
+        assert_eq!(no_default.select(&[], None).unwrap().id, "alsa_input.pci");
+    }
+
+    #[test]
+    fn test_select_without_devices() {
+        assert!(DeviceSnapshot::default()
+            .select(&["headset".to_string()], None)
+            .is_none());
+    }
+}
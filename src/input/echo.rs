@@ -0,0 +1,867 @@
+//! Acoustic echo cancellation.
+//!
+//! Removes audio played through the speakers (e.g. the far end of a call)
+//! from the microphone signal. System audio captured by `SystemAudioCapture`
+//! serves as the reference; an NLMS adaptive filter models the speaker-to-mic
+//! echo path and subtracts its estimate from the mic before VAD and Whisper.
+//!
+//! The reference reaches the mic callback in bursts and with an unknown
+//! latency, so it first goes through a jitter buffer read at the mic's pace.
+//! The bulk delay between the two streams is found by cross-correlation and
+//! removed before the filter, which then only has to model the room.
+//!
+//! The filter is too heavy for the realtime audio callback, so it runs on a
+//! worker thread ([`EchoThread`]) that the callback hands its audio to.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use super::audio::{AudioRecorderError, BlockResampler};
+use super::ring_buffer::AudioRingBuffer;
+use super::SystemAudioCapture;
+use crate::config::ResamplingQuality;
+
+/// Sample rate the canceller runs at (matches system audio capture)
+pub const ECHO_SAMPLE_RATE: u32 = 16000;
+
+/// NLMS adaptation step size (0 < mu < 2)
+const STEP_SIZE: f32 = 0.5;
+
+/// Mic louder than this share of the recent far-end peak counts as the
+/// user talking (Geigel double-talk detector); adaptation pauses then
+const DOUBLE_TALK_RATIO: f32 = 0.5;
+
+/// Adaptation stays paused this long after double talk (30ms)
+const DOUBLE_TALK_HOLD: usize = 480;
+
+/// How often the reference thread collects system audio
+const REFERENCE_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Mic blocks queued for the worker (about 2 seconds of 10ms callbacks)
+const MIC_QUEUE_BLOCKS: usize = 200;
+
+/// How often the worker collects mic audio from the queue
+const WORKER_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Reference audio kept while waiting for the mic (1 second)
+const MAX_REFERENCE_BACKLOG: usize = ECHO_SAMPLE_RATE as usize;
+
+/// Reference held back behind the newest sample, absorbing the bursts in
+/// which the capture thread hands it over (40ms)
+const JITTER_TARGET: usize = 640;
+
+/// Time constant of the smoothed jitter buffer fill (1 second)
+const FILL_TIME_CONSTANT: f32 = ECHO_SAMPLE_RATE as f32;
+
+/// Smoothed fill may stray this far from the target before the read
+/// position follows it (clock drift between the devices, 5ms)
+const DRIFT_TOLERANCE: f32 = 80.0;
+
+/// Fill this far off target means the streams lost each other (mic stalled
+/// or reference stopped); the buffer starts over (200ms)
+const RESYNC_THRESHOLD: i64 = 3200;
+
+/// Longest speaker-to-mic delay searched for (250ms)
+const MAX_DELAY: usize = 4000;
+
+/// Reference is read at most this far ahead of the mic, leaving part of
+/// the jitter buffer as margin for late bursts (30ms)
+const MAX_READ_AHEAD: usize = 480;
+
+/// Mic audio correlated with the reference per delay estimate (500ms)
+const ESTIMATE_WINDOW: usize = 8000;
+
+/// How often the delay is estimated again (1 second)
+const ESTIMATE_INTERVAL: usize = ECHO_SAMPLE_RATE as usize;
+
+/// Both streams are decimated by this factor for the correlation
+const DECIMATION: usize = 8;
+
+/// Normalized correlation needed to trust a delay estimate
+const MIN_CORRELATION: f32 = 0.3;
+
+/// The main echo is placed this far into the filter, leaving room for
+/// estimation error and drift (4ms)
+const ALIGN_MARGIN: i64 = 64;
+
+/// The filter is realigned once the echo moved this far from its place (2ms)
+const REALIGN_THRESHOLD: i64 = 32;
+
+/// NLMS echo canceller.
+pub struct EchoCanceller {
+    /// Estimated echo path (impulse response)
+    weights: Vec<f32>,
+    /// Reference history, stored twice so the last `taps` samples are
+    /// always a contiguous slice (newest first)
+    history: Vec<f32>,
+    /// Start of the current history window
+    pos: usize,
+    /// Energy of the reference window
+    power: f32,
+    /// Decaying peak of the reference, for double-talk detection
+    far_peak: f32,
+    /// Per-sample decay of `far_peak` (falls off over the tail length)
+    peak_decay: f32,
+    /// Samples left until adaptation resumes after double talk
+    hold: usize,
+}
+
+impl EchoCanceller {
+    /// Create a canceller covering echo paths up to `tail_ms` long.
+    pub fn new(tail_ms: u32) -> Self {
+        let taps = (ECHO_SAMPLE_RATE as usize * tail_ms as usize / 1000).max(1);
+        Self {
+            weights: vec![0.0; taps],
+            history: vec![0.0; taps * 2],
+            pos: 0,
+            power: 0.0,
+            far_peak: 0.0,
+            peak_decay: (-1.0 / taps as f32).exp(),
+            hold: 0,
+        }
+    }
+
+    /// Number of filter taps
+    pub fn taps(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Follow the reference being taken `delta` samples further back.
+    ///
+    /// The learned echo path moves with it and the reference history is
+    /// replaced by `recent` (oldest first, `taps` samples).
+    fn realign(&mut self, delta: i64, recent: &[f32]) {
+        let taps = self.weights.len();
+        let mut weights = vec![0.0; taps];
+        for (tap, weight) in self.weights.iter().enumerate() {
+            let moved = tap as i64 - delta;
+            if (0..taps as i64).contains(&moved) {
+                weights[moved as usize] = *weight;
+            }
+        }
+        self.weights = weights;
+
+        // Newest first and stored twice, as `process_sample` keeps it
+        self.history.fill(0.0);
+        self.pos = 0;
+        for (i, far) in recent.iter().rev().take(taps).enumerate() {
+            self.history[i] = *far;
+            self.history[i + taps] = *far;
+        }
+        self.power = self.history[..taps].iter().map(|x| x * x).sum();
+    }
+
+    /// Cancel echo in `mic` in place.
+    ///
+    /// `reference` holds the system audio played during the same period and
+    /// must be the same length; missing reference counts as silence.
+    pub fn process(&mut self, mic: &mut [f32], reference: &[f32]) {
+        for (i, sample) in mic.iter_mut().enumerate() {
+            let far = reference.get(i).copied().unwrap_or(0.0);
+            *sample = self.process_sample(*sample, far);
+        }
+    }
+
+    fn process_sample(&mut self, near: f32, far: f32) -> f32 {
+        let taps = self.weights.len();
+
+        // Slide the window: the slot being reused holds the sample leaving it
+        self.pos = if self.pos == 0 {
+            taps - 1
+        } else {
+            self.pos - 1
+        };
+        let leaving = self.history[self.pos];
+        self.history[self.pos] = far;
+        self.history[self.pos + taps] = far;
+
+        if self.pos == taps - 1 {
+            // Recompute once per window so rounding errors don't accumulate
+            self.power = self.history[..taps].iter().map(|x| x * x).sum();
+        } else {
+            self.power = (self.power + far * far - leaving * leaving).max(0.0);
+        }
+        self.far_peak = far.abs().max(self.far_peak * self.peak_decay);
+
+        let window = &self.history[self.pos..self.pos + taps];
+        let estimate: f32 = self.weights.iter().zip(window).map(|(w, x)| w * x).sum();
+        let error = near - estimate;
+
+        let regularization = taps as f32 * 1e-6;
+        if near.abs() > DOUBLE_TALK_RATIO * self.far_peak {
+            self.hold = DOUBLE_TALK_HOLD;
+        } else {
+            self.hold = self.hold.saturating_sub(1);
+        }
+        if self.hold == 0 && self.power > regularization {
+            let gain = STEP_SIZE * error / (self.power + regularization);
+            for (w, x) in self.weights.iter_mut().zip(window) {
+                *w += gain * x;
+            }
+        }
+
+        error
+    }
+}
+
+/// Echo canceller fed from a live system audio reference.
+///
+/// Runs on the [`EchoThread`] on 16kHz audio. Positions count
+/// samples of the reference stream since the stage was created.
+pub(crate) struct EchoStage {
+    canceller: EchoCanceller,
+    reference: Arc<Mutex<VecDeque<f32>>>,
+    /// Jitter buffer: reference received so far, oldest first
+    line: VecDeque<f32>,
+    /// Position of `line[0]`
+    line_start: i64,
+    /// Position of the reference played alongside the next mic sample;
+    /// None until reference arrives
+    cursor: Option<i64>,
+    /// Smoothed distance from the cursor to the newest reference
+    fill: f32,
+    /// Bulk delay from the reference to its echo in the mic
+    delay: i64,
+    /// Recent mic audio (before cancellation) for delay estimation
+    mic_history: VecDeque<f32>,
+    /// Mic samples since the last delay estimate
+    since_estimate: usize,
+}
+
+impl EchoStage {
+    fn new(canceller: EchoCanceller, reference: Arc<Mutex<VecDeque<f32>>>) -> Self {
+        Self {
+            canceller,
+            reference,
+            line: VecDeque::new(),
+            line_start: 0,
+            cursor: None,
+            fill: 0.0,
+            delay: 0,
+            mic_history: VecDeque::with_capacity(ESTIMATE_WINDOW),
+            since_estimate: 0,
+        }
+    }
+
+    /// Number of filter taps
+    pub(crate) fn taps(&self) -> usize {
+        self.canceller.taps()
+    }
+
+    /// Cancel echo in a block of mic audio using the matching reference.
+    pub(crate) fn process(&mut self, mic: &mut [f32]) {
+        self.receive();
+        if self.cursor.is_none() {
+            // Nothing played yet, so there is nothing to cancel
+            return;
+        }
+        let cursor = self.track_drift(mic.len());
+
+        self.mic_history.extend(mic.iter().copied());
+        let excess = self.mic_history.len().saturating_sub(ESTIMATE_WINDOW);
+        self.mic_history.drain(..excess);
+
+        let reference: Vec<f32> = (0..mic.len() as i64)
+            .map(|i| self.reference_at(cursor + i - self.delay))
+            .collect();
+        self.canceller.process(mic, &reference);
+        self.cursor = Some(cursor + mic.len() as i64);
+
+        self.since_estimate += mic.len();
+        if self.since_estimate >= ESTIMATE_INTERVAL && self.mic_history.len() == ESTIMATE_WINDOW {
+            self.since_estimate = 0;
+            self.estimate_delay();
+        }
+        self.trim();
+    }
+
+    /// Move newly captured reference into the jitter buffer.
+    fn receive(&mut self) {
+        {
+            let mut queue = self.reference.lock().unwrap_or_else(|e| e.into_inner());
+            self.line.extend(queue.drain(..));
+        }
+
+        let offset = match self.cursor {
+            Some(cursor) => self.line_end() - cursor - JITTER_TARGET as i64,
+            None if self.line.is_empty() => return,
+            None => {
+                self.resync();
+                return;
+            }
+        };
+        if offset > RESYNC_THRESHOLD {
+            debug!("Echo reference backlog of {} samples skipped", offset);
+            self.resync();
+        } else if offset < -RESYNC_THRESHOLD {
+            debug!("Echo reference stopped, waiting for it");
+            self.line_start = self.line_end();
+            self.line.clear();
+            self.cursor = None;
+            self.mic_history.clear();
+        }
+    }
+
+    /// Read the reference from the jitter target behind the newest sample.
+    fn resync(&mut self) {
+        self.cursor = Some(self.line_end() - JITTER_TARGET as i64);
+        self.fill = JITTER_TARGET as f32;
+        self.mic_history.clear();
+    }
+
+    /// Keep the smoothed buffer fill on target, following the clock drift
+    /// between the mic and the output device. Returns the read position.
+    fn track_drift(&mut self, block: usize) -> i64 {
+        let cursor = self.cursor.unwrap_or_else(|| self.line_end());
+        let fill = (self.line_end() - cursor) as f32;
+        let weight = (block as f32 / FILL_TIME_CONSTANT).min(1.0);
+        self.fill += weight * (fill - self.fill);
+
+        let error = self.fill - JITTER_TARGET as f32;
+        if error.abs() > DRIFT_TOLERANCE {
+            let step = error.round() as i64;
+            self.cursor = Some(cursor + step);
+            self.fill -= step as f32;
+            self.mic_history.clear();
+            self.realign(-step);
+            debug!("Echo reference drifted, moved by {} samples", step);
+        }
+        self.cursor.unwrap_or(cursor)
+    }
+
+    /// Find the delay between the reference and its echo in the recent mic
+    /// audio and realign the filter when it moved.
+    fn estimate_delay(&mut self) {
+        let Some(cursor) = self.cursor else {
+            return;
+        };
+        let mic_start = cursor - ESTIMATE_WINDOW as i64;
+        let reference_start = mic_start - MAX_DELAY as i64;
+        let reference_end = cursor + MAX_READ_AHEAD as i64;
+
+        let mic = decimate(self.mic_history.iter().copied());
+        let reference = decimate((reference_start..reference_end).map(|p| self.reference_at(p)));
+        let Some(lag) = best_lag(&mic, &reference) else {
+            return;
+        };
+
+        let found = MAX_DELAY as i64 - (lag * DECIMATION) as i64;
+        let delay = (found - ALIGN_MARGIN).max(-(MAX_READ_AHEAD as i64));
+        if (delay - self.delay).abs() > REALIGN_THRESHOLD {
+            debug!("Echo delay {} ms", found * 1000 / ECHO_SAMPLE_RATE as i64);
+            let delta = delay - self.delay;
+            self.delay = delay;
+            self.realign(delta);
+        }
+    }
+
+    /// Move the filter along with a change of the reference read position.
+    fn realign(&mut self, delta: i64) {
+        let Some(cursor) = self.cursor else {
+            return;
+        };
+        let start = cursor - self.delay;
+        let taps = self.canceller.taps() as i64;
+        let recent: Vec<f32> = (start - taps..start)
+            .map(|p| self.reference_at(p))
+            .collect();
+        self.canceller.realign(delta, &recent);
+    }
+
+    /// Drop reference no longer needed for cancelling or estimating.
+    fn trim(&mut self) {
+        let Some(cursor) = self.cursor else {
+            return;
+        };
+        let keep_from = cursor - (ESTIMATE_WINDOW + MAX_DELAY) as i64;
+        let stale = (keep_from - self.line_start).clamp(0, self.line.len() as i64);
+        self.line.drain(..stale as usize);
+        self.line_start += stale;
+    }
+
+    fn line_end(&self) -> i64 {
+        self.line_start + self.line.len() as i64
+    }
+
+    /// Reference at `position`; silence where none was received.
+    fn reference_at(&self, position: i64) -> f32 {
+        usize::try_from(position - self.line_start)
+            .ok()
+            .and_then(|i| self.line.get(i).copied())
+            .unwrap_or(0.0)
+    }
+}
+
+/// Sum blocks of `DECIMATION` samples (a crude low-pass plus downsampling).
+fn decimate(samples: impl Iterator<Item = f32>) -> Vec<f32> {
+    let samples: Vec<f32> = samples.collect();
+    samples
+        .chunks_exact(DECIMATION)
+        .map(|block| block.iter().sum())
+        .collect()
+}
+
+/// Offset into `reference` where `mic` matches best by normalized
+/// cross-correlation, or None without a clear match.
+fn best_lag(mic: &[f32], reference: &[f32]) -> Option<usize> {
+    let len = mic.len();
+    if len == 0 || reference.len() < len {
+        return None;
+    }
+    let mic_energy: f64 = mic.iter().map(|x| f64::from(x * x)).sum();
+    let mut window_energy: f64 = reference[..len].iter().map(|x| f64::from(x * x)).sum();
+    if mic_energy < 1e-9 {
+        return None;
+    }
+
+    let mut best = (0, 0.0);
+    for lag in 0..=reference.len() - len {
+        if lag > 0 {
+            let entering = reference[lag + len - 1];
+            let leaving = reference[lag - 1];
+            window_energy = (window_energy + f64::from(entering * entering)
+                - f64::from(leaving * leaving))
+            .max(0.0);
+        }
+        if window_energy < 1e-9 {
+            continue;
+        }
+        let dot: f32 = mic.iter().zip(&reference[lag..]).map(|(m, r)| m * r).sum();
+        let score = f64::from(dot).abs() / (mic_energy * window_energy).sqrt();
+        if score > best.1 {
+            best = (lag, score);
+        }
+    }
+    (best.1 >= f64::from(MIN_CORRELATION)).then_some(best.0)
+}
+
+/// Background capture of the system audio used as echo reference.
+pub struct EchoReference {
+    queue: Arc<Mutex<VecDeque<f32>>>,
+    running: Arc<AtomicBool>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl EchoReference {
+    /// Start capturing system audio from `source` (None = default output).
+    pub fn start(source: Option<&str>) -> Result<Self, AudioRecorderError> {
+        let queue = Arc::new(Mutex::new(VecDeque::new()));
+        let running = Arc::new(AtomicBool::new(true));
+        let (ready_tx, ready_rx) = mpsc::channel();
+
+        let thread_queue = Arc::clone(&queue);
+        let thread_running = Arc::clone(&running);
+        let source = source.map(str::to_string);
+
+        // The capture lives on its own thread (it is not `Send` on every platform)
+        let thread = thread::spawn(move || {
+            let capture = match SystemAudioCapture::new(source.as_deref()) {
+                Ok(capture) => {
+                    let _ = ready_tx.send(Ok(capture.source_name().to_string()));
+                    capture
+                }
+                Err(e) => {
+                    let _ = ready_tx.send(Err(e.to_string()));
+                    return;
+                }
+            };
+
+            while thread_running.load(Ordering::SeqCst) {
+                let samples = capture.extract_samples();
+                if !samples.is_empty() {
+                    let mut queue = thread_queue.lock().unwrap_or_else(|e| e.into_inner());
+                    queue.extend(samples);
+                    // Nobody is consuming (mic stalled); keep only recent audio
+                    if queue.len() > MAX_REFERENCE_BACKLOG {
+                        let excess = queue.len() - MAX_REFERENCE_BACKLOG;
+                        queue.drain(..excess);
+                        debug!("Echo reference backlog trimmed by {} samples", excess);
+                    }
+                }
+                thread::sleep(REFERENCE_POLL_INTERVAL);
+            }
+        });
+
+        match ready_rx.recv() {
+            Ok(Ok(name)) => {
+                info!("Echo cancellation reference: {}", name);
+                Ok(Self {
+                    queue,
+                    running,
+                    thread: Some(thread),
+                })
+            }
+            Ok(Err(e)) => {
+                let _ = thread.join();
+                Err(AudioRecorderError::EchoReference(e))
+            }
+            Err(_) => {
+                warn!("Echo reference thread exited during startup");
+                Err(AudioRecorderError::EchoReference(
+                    "capture thread exited".into(),
+                ))
+            }
+        }
+    }
+
+    /// Create a canceller stage consuming this reference.
+    pub(crate) fn stage(&self, tail_ms: u32) -> EchoStage {
+        EchoStage::new(EchoCanceller::new(tail_ms), Arc::clone(&self.queue))
+    }
+}
+
+impl Drop for EchoReference {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+        if let Some(handle) = self.thread.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Worker thread running an [`EchoStage`] on the mic audio.
+///
+/// The mic callback queues its mono blocks through an [`EchoInput`] and
+/// returns at once. The worker resamples them to 16kHz, cancels the echo
+/// and writes the result to the ring buffer, becoming its only producer.
+pub(crate) struct EchoThread {
+    input: EchoInput,
+    running: Arc<AtomicBool>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl EchoThread {
+    /// Start cancelling with `stage`, writing to `ring_buffer` (which must
+    /// run at [`ECHO_SAMPLE_RATE`]).
+    pub(crate) fn spawn(
+        mut stage: EchoStage,
+        ring_buffer: Arc<AudioRingBuffer>,
+        quality: ResamplingQuality,
+    ) -> Self {
+        // A bounded channel is a lock-free array queue: sending from the
+        // callback never blocks or allocates
+        let (tx, rx) = mpsc::sync_channel::<(u32, Vec<f32>)>(MIC_QUEUE_BLOCKS);
+        let dropped = Arc::new(AtomicU64::new(0));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let thread_dropped = Arc::clone(&dropped);
+        let thread_running = Arc::clone(&running);
+        let thread = thread::spawn(move || {
+            // Resampler for the rate of the current mic, rebuilt on a switch
+            let mut current: Option<(u32, BlockResampler)> = None;
+
+            while thread_running.load(Ordering::SeqCst) {
+                // Polled rather than blocking, so the callback never has to
+                // wake the worker
+                while let Ok((rate, block)) = rx.try_recv() {
+                    if current.as_ref().is_none_or(|(r, _)| *r != rate) {
+                        current =
+                            Some((rate, BlockResampler::new(rate, ECHO_SAMPLE_RATE, quality)));
+                    }
+                    let Some((_, resampler)) = current.as_mut() else {
+                        continue;
+                    };
+                    let mut samples = resampler.process(&block);
+                    stage.process(&mut samples);
+                    ring_buffer.push_samples(&samples);
+                }
+
+                let lost = thread_dropped.swap(0, Ordering::Relaxed);
+                if lost > 0 {
+                    warn!("Echo cancellation fell behind, {} mic blocks lost", lost);
+                }
+                thread::sleep(WORKER_POLL_INTERVAL);
+            }
+        });
+
+        Self {
+            input: EchoInput { tx, dropped },
+            running,
+            thread: Some(thread),
+        }
+    }
+
+    /// Handle for the mic callback to queue audio with.
+    pub(crate) fn input(&self) -> EchoInput {
+        self.input.clone()
+    }
+}
+
+impl Drop for EchoThread {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+        if let Some(handle) = self.thread.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Sending end of the [`EchoThread`] queue.
+#[derive(Clone)]
+pub(crate) struct EchoInput {
+    tx: mpsc::SyncSender<(u32, Vec<f32>)>,
+    /// Blocks dropped since the worker last reported
+    dropped: Arc<AtomicU64>,
+}
+
+impl EchoInput {
+    /// Queue a block of mono mic audio captured at `rate`.
+    ///
+    /// Never blocks: when the worker falls behind the block is dropped.
+    pub(crate) fn push(&self, rate: u32, block: Vec<f32>) {
+        if self.tx.try_send((rate, block)).is_err() {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic white noise in [-0.5, 0.5]
+    fn noise(len: usize, seed: u32) -> Vec<f32> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as f32 / u32::MAX as f32 - 0.5
+            })
+            .collect()
+    }
+
+    /// Speaker bleed: the reference delayed and attenuated (at least 6 dB,
+    /// as the double-talk detector assumes), plus a reflection
+    fn echo_of(reference: &[f32], delay: usize) -> Vec<f32> {
+        (0..reference.len())
+            .map(|i| {
+                let direct = i.checked_sub(delay).map_or(0.0, |j| 0.3 * reference[j]);
+                let reflection = i
+                    .checked_sub(delay + 30)
+                    .map_or(0.0, |j| -0.1 * reference[j]);
+                direct + reflection
+            })
+            .collect()
+    }
+
+    fn energy(samples: &[f32]) -> f32 {
+        samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32
+    }
+
+    // ===================
+    // Echo Canceller Tests
+    // ===================
+
+    #[test]
+    fn test_taps_from_tail() {
+        assert_eq!(EchoCanceller::new(200).taps(), 3200);
+        assert_eq!(EchoCanceller::new(10).taps(), 160);
+    }
+
+    #[test]
+    fn test_cancels_echo() {
+        let reference = noise(32000, 0x1234_5678);
+        let mut mic = echo_of(&reference, 40);
+        let original = mic.clone();
+
+        let mut canceller = EchoCanceller::new(16);
+        for (mic, reference) in mic.chunks_mut(160).zip(reference.chunks(160)) {
+            canceller.process(mic, reference);
+        }
+
+        // After two seconds the residual echo is at least 25 dB down
+        let tail = 24000..32000;
+        let reduction = energy(&original[tail.clone()]) / energy(&mic[tail]);
+        assert!(reduction > 10f32.powf(2.5), "only {:.1}x", reduction);
+    }
+
+    #[test]
+    fn test_keeps_near_end_speech() {
+        let reference = noise(48000, 0x8765_4321);
+        let echo = echo_of(&reference, 40);
+        // User starts talking after two seconds of far-end only audio
+        let speech: Vec<f32> = (0..48000)
+            .map(|i| {
+                if i < 32000 {
+                    0.0
+                } else {
+                    0.3 * (i as f32 * 0.07).sin()
+                }
+            })
+            .collect();
+        let mut mic: Vec<f32> = echo.iter().zip(&speech).map(|(e, s)| e + s).collect();
+
+        let mut canceller = EchoCanceller::new(16);
+        canceller.process(&mut mic, &reference);
+
+        let tail = 40000..48000;
+        let residual: Vec<f32> = mic[tail.clone()]
+            .iter()
+            .zip(&speech[tail.clone()])
+            .map(|(out, s)| out - s)
+            .collect();
+        assert!(energy(&residual) < energy(&speech[tail]) / 30.0);
+    }
+
+    #[test]
+    fn test_passes_mic_without_reference() {
+        let original = noise(1600, 42);
+        let mut mic = original.clone();
+
+        let mut canceller = EchoCanceller::new(16);
+        canceller.process(&mut mic, &[]);
+        assert_eq!(mic, original);
+    }
+
+    // ===================
+    // Echo Stage Tests
+    // ===================
+
+    fn stage(tail_ms: u32) -> (EchoStage, Arc<Mutex<VecDeque<f32>>>) {
+        let queue = Arc::new(Mutex::new(VecDeque::new()));
+        let stage = EchoStage::new(EchoCanceller::new(tail_ms), Arc::clone(&queue));
+        (stage, queue)
+    }
+
+    /// Feed `mic` through the stage in 10ms blocks. Before each block the
+    /// reference captured so far (`captured(mic position)` samples) is
+    /// handed over in `burst`-sample steps, like the capture thread does.
+    fn run_stage(
+        stage: &mut EchoStage,
+        queue: &Mutex<VecDeque<f32>>,
+        mic: &mut [f32],
+        reference: &[f32],
+        burst: usize,
+        captured: impl Fn(usize) -> usize,
+    ) {
+        let mut delivered = 0;
+        for (block, mic) in mic.chunks_mut(160).enumerate() {
+            let available = (captured(block * 160) / burst * burst).min(reference.len());
+            if available > delivered {
+                queue
+                    .lock()
+                    .unwrap()
+                    .extend(&reference[delivered..available]);
+                delivered = available;
+            }
+            stage.process(mic);
+        }
+    }
+
+    #[test]
+    fn test_best_lag_finds_shifted_copy() {
+        let reference = noise(1500, 7);
+        let mic = reference[300..1300].to_vec();
+        assert_eq!(best_lag(&mic, &reference), Some(300));
+
+        // Unrelated audio gives no estimate
+        assert_eq!(best_lag(&noise(1000, 99), &reference), None);
+        assert_eq!(best_lag(&[0.0; 1000], &reference), None);
+    }
+
+    #[test]
+    fn test_realign_moves_echo_path() {
+        let mut canceller = EchoCanceller::new(1);
+        canceller.weights[5] = 1.0;
+        canceller.realign(3, &[]);
+        assert_eq!(canceller.weights[2], 1.0);
+        canceller.realign(-10, &[]);
+        assert_eq!(canceller.weights[12], 1.0);
+        assert_eq!(canceller.weights.iter().sum::<f32>(), 1.0);
+    }
+
+    #[test]
+    fn test_stage_finds_echo_delay() {
+        // 100ms from speaker to mic, far beyond the 16ms filter
+        let reference = noise(80000, 0x2468_1357);
+        let mut mic = echo_of(&reference, 1600);
+        let original = mic.clone();
+
+        let (mut stage, queue) = stage(16);
+        run_stage(&mut stage, &queue, &mut mic, &reference, 160, |p| p + 160);
+
+        assert!(stage.delay > stage.taps() as i64);
+        let tail = 64000..80000;
+        let reduction = energy(&original[tail.clone()]) / energy(&mic[tail]);
+        assert!(reduction > 100.0, "only {:.1}x", reduction);
+    }
+
+    #[test]
+    fn test_stage_absorbs_bursty_reference() {
+        // Reference shows up 30ms at a time, sometimes after its echo
+        let reference = noise(80000, 0x1357_2468);
+        let mut mic = echo_of(&reference, 800);
+        let original = mic.clone();
+
+        let (mut stage, queue) = stage(16);
+        run_stage(&mut stage, &queue, &mut mic, &reference, 480, |p| p + 160);
+
+        let tail = 64000..80000;
+        let reduction = energy(&original[tail.clone()]) / energy(&mic[tail]);
+        assert!(reduction > 100.0, "only {:.1}x", reduction);
+    }
+
+    #[test]
+    fn test_stage_follows_clock_drift() {
+        // The output device runs 0.05% fast: 8 extra reference samples a
+        // second. Music rather than noise, so the echo path stays learnable
+        // while it creeps.
+        let reference: Vec<f32> = noise(480_300, 0x0f0f_f0f0)
+            .windows(8)
+            .map(|w| w.iter().sum::<f32>() / 2.0)
+            .collect();
+        let fast = |p: usize| p + p / 2000;
+        let played: Vec<f32> = (0..480_000)
+            .map(|p| {
+                let position = p as f64 * 1.0005;
+                let (i, frac) = (position as usize, position.fract() as f32);
+                reference[i] * (1.0 - frac) + reference[i + 1] * frac
+            })
+            .collect();
+        let mut mic = echo_of(&played, 400);
+        let original = mic.clone();
+
+        let (mut stage, queue) = stage(16);
+        run_stage(&mut stage, &queue, &mut mic, &reference, 160, |p| {
+            fast(p) + 160
+        });
+
+        // The read position kept up instead of letting the backlog grow
+        let fill = stage.line_end() - stage.cursor.unwrap();
+        assert!((fill - JITTER_TARGET as i64).abs() < 2 * DRIFT_TOLERANCE as i64);
+
+        let tail = 464_000..480_000;
+        let reduction = energy(&original[tail.clone()]) / energy(&mic[tail]);
+        assert!(reduction > 10.0, "only {:.1}x", reduction);
+    }
+
+    #[test]
+    fn test_stage_waits_for_reference() {
+        let (mut stage, queue) = stage(10);
+        let original = noise(160, 3);
+
+        // Mic passes untouched until reference arrives
+        let mut mic = original.clone();
+        stage.process(&mut mic);
+        assert_eq!(mic, original);
+        assert!(stage.cursor.is_none());
+
+        queue.lock().unwrap().extend(vec![0.0; 800]);
+        stage.process(&mut mic);
+        assert_eq!(stage.cursor, Some(800 - JITTER_TARGET as i64 + 160));
+
+        // Reference stopped: the stage waits for it instead of reading
+        // ever further past its end
+        for _ in 0..100 {
+            stage.process(&mut mic);
+        }
+        assert!(stage.cursor.is_none());
+        assert!(stage.line.is_empty());
+    }
+}
@@ -32,11 +32,17 @@ pub enum HotkeyEvent {
     Pressed,
     /// Hotkey was released (stop recording)
     Released,
+    /// Replay key was pressed (transcribe recent audio)
+    Replay,
+    /// Language key was pressed (switch to the next language)
+    CycleLanguage,
 }
 
 /// Global hotkey listener
 pub struct HotkeyListener {
     key: Key,
+    replay_key: Option<Key>,
+    language_key: Option<Key>,
     running: Arc<AtomicBool>,
     event_tx: mpsc::Sender<HotkeyEvent>,
 }
@@ -51,6 +57,8 @@ impl HotkeyListener {
         Ok((
             Self {
                 key,
+                replay_key: None,
+                language_key: None,
                 running,
                 event_tx,
             },
@@ -58,6 +66,18 @@ impl HotkeyListener {
         ))
     }
 
+    /// Also listen for a replay key, reported as [`HotkeyEvent::Replay`].
+    pub fn with_replay_key(mut self, key_str: &str) -> Result<Self, HotkeyListenerError> {
+        self.replay_key = Some(parse_key(key_str)?);
+        Ok(self)
+    }
+
+    /// Also listen for a language key, reported as [`HotkeyEvent::CycleLanguage`].
+    pub fn with_language_key(mut self, key_str: &str) -> Result<Self, HotkeyListenerError> {
+        self.language_key = Some(parse_key(key_str)?);
+        Ok(self)
+    }
+
     /// Start listening for hotkey events
     ///
     /// This spawns a background thread that listens for keyboard events.
@@ -70,12 +90,16 @@ impl HotkeyListener {
         self.running.store(true, Ordering::SeqCst);
 
         let key = self.key;
+        let replay_key = self.replay_key;
+        let language_key = self.language_key;
         let running = self.running.clone();
         let event_tx = self.event_tx.clone();
 
         // Track key state to avoid duplicate events
         let key_pressed = Arc::new(AtomicBool::new(false));
         let key_pressed_clone = key_pressed.clone();
+        let replay_pressed = AtomicBool::new(false);
+        let language_pressed = AtomicBool::new(false);
 
         thread::spawn(move || {
             info!("Hotkey listener started for {:?}", key);
@@ -102,6 +126,30 @@ impl HotkeyListener {
                             error!("Failed to send hotkey event: {}", e);
                         }
                     }
+                    EventType::KeyPress(pressed_key)
+                        if Some(pressed_key) == replay_key
+                            && !replay_pressed.swap(true, Ordering::SeqCst) =>
+                    {
+                        debug!("Replay key pressed: {:?}", pressed_key);
+                        if let Err(e) = event_tx.blocking_send(HotkeyEvent::Replay) {
+                            error!("Failed to send hotkey event: {}", e);
+                        }
+                    }
+                    EventType::KeyRelease(released_key) if Some(released_key) == replay_key => {
+                        replay_pressed.store(false, Ordering::SeqCst);
+                    }
+                    EventType::KeyPress(pressed_key)
+                        if Some(pressed_key) == language_key
+                            && !language_pressed.swap(true, Ordering::SeqCst) =>
+                    {
+                        debug!("Language key pressed: {:?}", pressed_key);
+                        if let Err(e) = event_tx.blocking_send(HotkeyEvent::CycleLanguage) {
+                            error!("Failed to send hotkey event: {}", e);
+                        }
+                    }
+                    EventType::KeyRelease(released_key) if Some(released_key) == language_key => {
+                        language_pressed.store(false, Ordering::SeqCst);
+                    }
                     _ => {}
                 }
             };
@@ -379,6 +427,7 @@ mod tests {
         assert_eq!(HotkeyEvent::Pressed, HotkeyEvent::Pressed);
         assert_eq!(HotkeyEvent::Released, HotkeyEvent::Released);
         assert_ne!(HotkeyEvent::Pressed, HotkeyEvent::Released);
+        assert_ne!(HotkeyEvent::Pressed, HotkeyEvent::Replay);
     }
 
     #[test]
@@ -426,4 +475,24 @@ mod tests {
         let result = HotkeyListener::new("invalid_key_xyz");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_hotkey_listener_replay_key() {
+        let (listener, _rx) = HotkeyListener::new("ControlRight").unwrap();
+        let listener = listener.with_replay_key("F9").unwrap();
+        assert_eq!(listener.replay_key, Some(Key::F9));
+
+        let (listener, _rx) = HotkeyListener::new("ControlRight").unwrap();
+        assert!(listener.with_replay_key("invalid_key_xyz").is_err());
+    }
+
+    #[test]
+    fn test_hotkey_listener_language_key() {
+        let (listener, _rx) = HotkeyListener::new("ControlRight").unwrap();
+        let listener = listener.with_language_key("F8").unwrap();
+        assert_eq!(listener.language_key, Some(Key::F8));
+
+        let (listener, _rx) = HotkeyListener::new("ControlRight").unwrap();
+        assert!(listener.with_language_key("invalid_key_xyz").is_err());
+    }
 }
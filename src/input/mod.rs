@@ -1,6 +1,9 @@
 //! Input handling: hotkey detection and audio capture.
 
 pub mod audio;
+pub mod calibration;
+pub mod device_watch;
+pub mod echo;
 pub mod hotkey;
 pub mod ring_buffer;
 #[cfg(target_os = "linux")]
@@ -12,14 +15,20 @@ pub mod system_audio_windows;
 pub mod wake_word;
 
 #[allow(unused_imports)]
-pub use audio::{load_wav_file, AudioBuffer, AudioRecorder, AudioRecorderError, ChannelMix};
+pub use audio::{
+    load_wav_bytes, load_wav_file, AudioBuffer, AudioRecorder, AudioRecorderError, ChannelMix,
+};
+#[allow(unused_imports)]
+pub use device_watch::{spawn_device_watcher, DeviceSnapshot, InputDevice};
 pub use hotkey::{HotkeyEvent, HotkeyListener, HotkeyListenerError};
 pub use ring_buffer::AudioMark;
 #[allow(unused_imports)]
 pub use ring_buffer::AudioRingBuffer;
 #[allow(unused_imports)]
 #[cfg(target_os = "linux")]
-pub use system_audio::{AudioSource, SourceInfo, SystemAudioCapture, SystemAudioError};
+pub use system_audio::{
+    AppStreamInfo, AudioSource, SourceInfo, SystemAudioCapture, SystemAudioError,
+};
 #[allow(unused_imports)]
 #[cfg(target_os = "macos")]
 pub use system_audio_macos::{AudioSource, SourceInfo, SystemAudioCapture, SystemAudioError};
@@ -36,6 +45,17 @@ pub enum AudioDeviceType {
     Microphone,
     /// System audio monitor (loopback)
     Monitor,
+    /// A single application's playback (Linux, PulseAudio/PipeWire)
+    Application,
+}
+
+/// Prefix of device IDs that refer to an application rather than a device
+pub const APP_DEVICE_PREFIX: &str = "app:";
+
+/// Application name from an `app:<name>` device ID
+pub fn app_from_device_id(id: &str) -> Option<&str> {
+    id.strip_prefix(APP_DEVICE_PREFIX)
+        .filter(|app| !app.is_empty())
 }
 
 /// Information about an audio input device and its channels
@@ -134,6 +154,32 @@ pub fn enumerate_audio_inputs() -> Vec<AudioDeviceInfo> {
         }
     }
 
+    // Applications playing audio, captured individually via their sink input
+    if let Ok(streams) = system_audio::list_app_streams() {
+        for stream in &streams {
+            // Tell apart several streams of one app (e.g. browser tabs) by title
+            let shared = streams
+                .iter()
+                .filter(|s| s.app_name == stream.app_name)
+                .count()
+                > 1;
+            let key = if shared && !stream.media_name.is_empty() {
+                &stream.media_name
+            } else {
+                &stream.app_name
+            };
+            devices.push(AudioDeviceInfo {
+                id: format!("{}{}", APP_DEVICE_PREFIX, key),
+                name: format!("{}: {}", stream.app_name, stream.media_name),
+                device_type: AudioDeviceType::Application,
+                channel_count: stream.channels,
+                channel_names: AudioDeviceInfo::default_channel_names(stream.channels),
+                sample_rate: stream.sample_rate,
+                is_default: false,
+            });
+        }
+    }
+
     devices
 }
 
@@ -186,6 +232,13 @@ mod tests {
     // AudioDeviceType Tests
     // ===================
 
+    #[test]
+    fn test_app_from_device_id() {
+        assert_eq!(app_from_device_id("app:Firefox"), Some("Firefox"));
+        assert_eq!(app_from_device_id("app:"), None);
+        assert_eq!(app_from_device_id("alsa_input.pci"), None);
+    }
+
     #[test]
     fn test_audio_device_type_debug() {
         let mic = AudioDeviceType::Microphone;
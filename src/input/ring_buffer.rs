@@ -80,6 +80,13 @@ pub struct AudioMark {
     pub sequence_id: u64,
 }
 
+impl AudioMark {
+    /// Position in the buffer when the mark was created.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
 impl PartialEq for AudioMark {
     fn eq(&self, other: &Self) -> bool {
         // Compare by sequence_id only (unique identifier)
@@ -279,6 +286,15 @@ impl AudioRingBuffer {
         result
     }
 
+    /// Start position of the most recent `samples` samples before `to_pos`.
+    ///
+    /// Used to transcribe audio retroactively. The range is clamped to what
+    /// the buffer still holds, so shortly after start or for requests longer
+    /// than the capacity the returned range is shorter than asked for.
+    pub fn position_before(&self, to_pos: usize, samples: usize) -> usize {
+        to_pos - samples.min(self.capacity).min(to_pos)
+    }
+
     /// Get the current write position (for debugging/metrics).
     #[allow(dead_code)]
     pub fn write_position(&self) -> usize {
@@ -453,6 +469,24 @@ mod tests {
         assert_eq!(extracted.len(), buffer.capacity());
     }
 
+    #[test]
+    fn test_position_before() {
+        let buffer = AudioRingBuffer::new(0.1, 16000); // 2048 capacity
+
+        // Less written than requested: start of the recording
+        buffer.push_samples(&[0.1; 1000]);
+        let pos = buffer.current_position();
+        assert_eq!(buffer.position_before(pos, 1600), 0);
+        assert_eq!(buffer.position_before(pos, 400), 600);
+
+        // More requested than the buffer holds: clamped to capacity
+        buffer.push_samples(&[0.2; 5000]);
+        let pos = buffer.current_position();
+        let start = buffer.position_before(pos, 10_000);
+        assert_eq!(pos - start, buffer.capacity());
+        assert_eq!(buffer.extract_range(start, pos), vec![0.2; 2048]);
+    }
+
     #[test]
     fn test_current_levels_empty_buffer() {
         let buffer = AudioRingBuffer::new(1.0, 16000);
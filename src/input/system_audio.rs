@@ -11,11 +11,13 @@ use pulse::mainloop::standard::Mainloop;
 use pulse::proplist::Proplist;
 use pulse::sample::{Format, Spec};
 use pulse::stream::{FlagSet, Stream};
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
 use std::rc::Rc;
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::Duration;
 use thiserror::Error;
 use tracing::{debug, error, info, warn};
 
@@ -64,6 +66,9 @@ pub enum SystemAudioError {
 
     #[error("Operation failed: {0}")]
     OperationFailed(String),
+
+    #[error("No application playing audio matches '{0}'")]
+    AppNotFound(String),
 }
 
 /// Information about a PulseAudio source (input device or monitor)
@@ -81,6 +86,25 @@ pub struct SourceInfo {
     pub channels: u8,
 }
 
+/// An application playing audio (a PulseAudio sink input)
+#[derive(Debug, Clone)]
+pub struct AppStreamInfo {
+    /// Sink input index (changes when the app reopens its stream)
+    pub index: u32,
+    /// Application name (e.g., "Firefox")
+    pub app_name: String,
+    /// Executable name (e.g., "firefox"), if reported
+    pub binary: Option<String>,
+    /// Stream description (e.g., the browser tab title)
+    pub media_name: String,
+    /// Monitor source of the sink the app plays on
+    pub monitor_source: String,
+    /// Sample rate
+    pub sample_rate: u32,
+    /// Number of channels
+    pub channels: u8,
+}
+
 /// System audio capture using PulseAudio
 pub struct SystemAudioCapture {
     /// Audio samples buffer
@@ -117,7 +141,8 @@ impl SystemAudioCapture {
         let (shutdown_tx, shutdown_rx) = mpsc::channel();
 
         let thread_handle = thread::spawn(move || {
-            if let Err(e) = run_capture_loop(&source_name_clone, samples_clone, shutdown_rx) {
+            if let Err(e) = run_capture_loop(&source_name_clone, None, samples_clone, &shutdown_rx)
+            {
                 error!("System audio capture error: {}", e);
             }
         });
@@ -132,6 +157,58 @@ impl SystemAudioCapture {
         })
     }
 
+    /// Capture a single application's audio instead of a whole sink.
+    ///
+    /// `app` is matched with [`find_app_stream`]. If the application closes
+    /// and reopens its stream (e.g. a new call), capture follows it.
+    pub fn new_for_app(app: &str) -> Result<Self, SystemAudioError> {
+        let streams = list_app_streams()?;
+        let target = find_app_stream(&streams, app)
+            .cloned()
+            .ok_or_else(|| SystemAudioError::AppNotFound(app.to_string()))?;
+
+        let samples = Arc::new(Mutex::new(Vec::new()));
+        let samples_clone = Arc::clone(&samples);
+        let (shutdown_tx, shutdown_rx) = mpsc::channel();
+        let source = format!("{} ({})", target.app_name, target.media_name);
+        let query = app.to_string();
+
+        let thread_handle = thread::spawn(move || {
+            let mut current = Some(target);
+            loop {
+                if let Some(ref stream) = current {
+                    match run_capture_loop(
+                        &stream.monitor_source,
+                        Some(stream.index),
+                        Arc::clone(&samples_clone),
+                        &shutdown_rx,
+                    ) {
+                        Ok(()) => break,
+                        Err(e) => warn!("Capture of {} ended: {}", stream.app_name, e),
+                    }
+                }
+
+                // Wait for the application to play audio again
+                match shutdown_rx.recv_timeout(Duration::from_secs(1)) {
+                    Err(mpsc::RecvTimeoutError::Timeout) => {}
+                    _ => break,
+                }
+                current = list_app_streams()
+                    .ok()
+                    .and_then(|streams| find_app_stream(&streams, &query).cloned());
+            }
+        });
+
+        info!("Application audio capture started from: {}", source);
+
+        Ok(Self {
+            samples,
+            shutdown_tx: Some(shutdown_tx),
+            thread_handle: Some(thread_handle),
+            source_name: source,
+        })
+    }
+
     /// Get the source name being captured.
     pub fn source_name(&self) -> &str {
         &self.source_name
@@ -333,11 +410,157 @@ pub fn list_all_sources() -> Result<Vec<SourceInfo>, SystemAudioError> {
     Ok(sources)
 }
 
+/// A connected PulseAudio mainloop and context.
+type PulseConnection = (Rc<RefCell<Mainloop>>, Rc<RefCell<Context>>);
+
+/// Connect to the PulseAudio server and wait until the context is ready.
+fn connect_context() -> Result<PulseConnection, SystemAudioError> {
+    let mainloop =
+        Rc::new(RefCell::new(Mainloop::new().ok_or_else(|| {
+            SystemAudioError::ConnectionFailed("Mainloop failed".into())
+        })?));
+
+    let mut proplist = Proplist::new().unwrap();
+    proplist
+        .set_str(pulse::proplist::properties::APPLICATION_NAME, "OpenHush")
+        .ok();
+
+    let context = Rc::new(RefCell::new(
+        Context::new_with_proplist(&*mainloop.borrow(), "OpenHush", &proplist)
+            .ok_or_else(|| SystemAudioError::ConnectionFailed("Context failed".into()))?,
+    ));
+
+    context
+        .borrow_mut()
+        .connect(None, pulse::context::FlagSet::NOFLAGS, None)
+        .map_err(|e| SystemAudioError::ConnectionFailed(format!("{:?}", e)))?;
+
+    loop {
+        mainloop.borrow_mut().iterate(true);
+        match context.borrow().get_state() {
+            pulse::context::State::Ready => break,
+            pulse::context::State::Failed | pulse::context::State::Terminated => {
+                return Err(SystemAudioError::ConnectionFailed(
+                    "Connection terminated".into(),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    Ok((mainloop, context))
+}
+
+/// List applications currently playing audio (PulseAudio sink inputs).
+pub fn list_app_streams() -> Result<Vec<AppStreamInfo>, SystemAudioError> {
+    use pulse::callbacks::ListResult;
+    use pulse::proplist::properties;
+
+    let (mainloop, context) = connect_context()?;
+    let introspect = context.borrow().introspect();
+
+    // Map sinks to their monitor sources
+    let monitors = Rc::new(RefCell::new(HashMap::new()));
+    let done = Rc::new(Cell::new(false));
+    {
+        let monitors = Rc::clone(&monitors);
+        let finished = Rc::clone(&done);
+        let _op = introspect.get_sink_info_list(move |result| match result {
+            ListResult::Item(info) => {
+                if let Some(ref monitor) = info.monitor_source_name {
+                    monitors
+                        .borrow_mut()
+                        .insert(info.index, monitor.to_string());
+                }
+            }
+            ListResult::End | ListResult::Error => finished.set(true),
+        });
+        while !done.get() {
+            mainloop.borrow_mut().iterate(true);
+        }
+    }
+
+    let streams = Rc::new(RefCell::new(Vec::new()));
+    done.set(false);
+    {
+        let streams = Rc::clone(&streams);
+        let monitors = Rc::clone(&monitors);
+        let finished = Rc::clone(&done);
+        let _op = introspect.get_sink_input_info_list(move |result| match result {
+            ListResult::Item(info) => {
+                let app_name = info
+                    .proplist
+                    .get_str(properties::APPLICATION_NAME)
+                    .unwrap_or_default();
+                // Skip our own feedback sounds
+                if app_name == "OpenHush" {
+                    return;
+                }
+                let Some(monitor_source) = monitors.borrow().get(&info.sink).cloned() else {
+                    return;
+                };
+                streams.borrow_mut().push(AppStreamInfo {
+                    index: info.index,
+                    app_name,
+                    binary: info
+                        .proplist
+                        .get_str(properties::APPLICATION_PROCESS_BINARY),
+                    media_name: info
+                        .proplist
+                        .get_str(properties::MEDIA_NAME)
+                        .or_else(|| info.name.as_ref().map(|n| n.to_string()))
+                        .unwrap_or_default(),
+                    monitor_source,
+                    sample_rate: info.sample_spec.rate,
+                    channels: info.sample_spec.channels,
+                });
+            }
+            ListResult::End | ListResult::Error => finished.set(true),
+        });
+        while !done.get() {
+            mainloop.borrow_mut().iterate(true);
+        }
+    }
+
+    let streams = streams.borrow().clone();
+    debug!("Found {} application streams", streams.len());
+    Ok(streams)
+}
+
+/// Find the stream of the application named `query`.
+///
+/// An exact application or executable name (ignoring case) wins; otherwise
+/// the first stream whose application name or description contains `query`,
+/// so a browser tab can be picked by its title.
+pub fn find_app_stream<'a>(streams: &'a [AppStreamInfo], query: &str) -> Option<&'a AppStreamInfo> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return None;
+    }
+
+    streams
+        .iter()
+        .find(|s| {
+            s.app_name.to_lowercase() == query
+                || s.binary.as_deref().map(str::to_lowercase).as_deref() == Some(query.as_str())
+        })
+        .or_else(|| {
+            streams.iter().find(|s| {
+                s.app_name.to_lowercase().contains(&query)
+                    || s.media_name.to_lowercase().contains(&query)
+            })
+        })
+}
+
 /// Run the audio capture loop (called in a separate thread).
+///
+/// With `monitor_stream` set, only that sink input is recorded from the
+/// monitor source. Returns `Ok` on shutdown and an error if the stream ends.
 fn run_capture_loop(
     source_name: &str,
+    monitor_stream: Option<u32>,
     samples: Arc<Mutex<Vec<f32>>>,
-    shutdown_rx: mpsc::Receiver<()>,
+    shutdown_rx: &mpsc::Receiver<()>,
 ) -> Result<(), SystemAudioError> {
     let mainloop =
         Rc::new(RefCell::new(Mainloop::new().ok_or_else(|| {
@@ -434,6 +657,14 @@ fn run_capture_loop(
             }
         })));
 
+    // Restrict the monitor to one application's stream
+    if let Some(index) = monitor_stream {
+        stream
+            .borrow_mut()
+            .set_monitor_stream(index)
+            .map_err(|e| SystemAudioError::StreamFailed(format!("Monitor stream: {:?}", e)))?;
+    }
+
     // Connect to the source
     stream
         .borrow_mut()
@@ -468,6 +699,15 @@ fn run_capture_loop(
 
         // Process PulseAudio events with timeout
         mainloop.borrow_mut().iterate(false);
+
+        // The monitored application may go away
+        if matches!(
+            stream.borrow().get_state(),
+            pulse::stream::State::Failed | pulse::stream::State::Terminated
+        ) {
+            return Err(SystemAudioError::StreamFailed("Stream terminated".into()));
+        }
+
         std::thread::sleep(std::time::Duration::from_millis(10));
     }
 
@@ -724,4 +964,60 @@ mod tests {
             assert!(duration >= 0.0);
         }
     }
+
+    // ===================
+    // Application Stream Tests
+    // ===================
+
+    fn app_stream(index: u32, app_name: &str, binary: &str, media_name: &str) -> AppStreamInfo {
+        AppStreamInfo {
+            index,
+            app_name: app_name.to_string(),
+            binary: Some(binary.to_string()),
+            media_name: media_name.to_string(),
+            monitor_source: "alsa_output.pci.analog-stereo.monitor".to_string(),
+            sample_rate: 48000,
+            channels: 2,
+        }
+    }
+
+    fn app_streams() -> Vec<AppStreamInfo> {
+        vec![
+            app_stream(12, "Spotify", "spotify", "Spotify"),
+            app_stream(15, "Firefox", "firefox", "Weekly sync - Google Meet"),
+            app_stream(17, "Firefox", "firefox", "Lo-fi beats - YouTube"),
+            app_stream(21, "ZOOM VoiceEngine", "zoom", "playStream"),
+        ]
+    }
+
+    #[test]
+    fn test_find_app_stream_by_name_and_binary() {
+        let streams = app_streams();
+        assert_eq!(find_app_stream(&streams, "spotify").unwrap().index, 12);
+        assert_eq!(find_app_stream(&streams, "Zoom").unwrap().index, 21);
+    }
+
+    #[test]
+    fn test_find_app_stream_by_tab_title() {
+        let streams = app_streams();
+        assert_eq!(find_app_stream(&streams, "google meet").unwrap().index, 15);
+        assert_eq!(find_app_stream(&streams, "YouTube").unwrap().index, 17);
+    }
+
+    #[test]
+    fn test_find_app_stream_no_match() {
+        let streams = app_streams();
+        assert!(find_app_stream(&streams, "teams").is_none());
+        assert!(find_app_stream(&streams, "  ").is_none());
+        assert!(find_app_stream(&[], "firefox").is_none());
+    }
+
+    #[test]
+    fn test_app_not_found_error_display() {
+        let err = SystemAudioError::AppNotFound("teams".to_string());
+        assert_eq!(
+            format!("{}", err),
+            "No application playing audio matches 'teams'"
+        );
+    }
 }
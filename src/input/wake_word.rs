@@ -3,11 +3,19 @@
 //! Uses a three-stage pipeline:
 //! 1. melspectrogram.onnx: Audio (16kHz) → Mel spectrogram (76x32)
 //! 2. embedding_model.onnx: Mel spectrogram → Speech embeddings (96-dim)
-//! 3. hey_jarvis.onnx: Accumulated embeddings (1536-dim) → Detection probability
+//! 3. Wake word classifiers (hey_jarvis.onnx by default): Accumulated
+//!    embeddings (1536-dim) → Detection probability
+//!
+//! The first two stages are shared, so each extra wake word only costs one
+//! small classifier. Every classifier is bound to a [`WakeWordAction`].
 //!
 //! Models from: <https://github.com/dscripka/openWakeWord>
 
-use crate::config::{Config, WakeWordConfig};
+use crate::config::{
+    Config, ModelFamily, ModelsConfig, WakeWordAction, WakeWordConfig, WakeWordModel,
+};
+use crate::engine::catalog::expand_home;
+use crate::manifest::{self, IntegrityError, ModelVerifier};
 use ort::session::{builder::GraphOptimizationLevel, Session};
 use ort::value::Tensor;
 use std::collections::VecDeque;
@@ -35,9 +43,9 @@ const EMBEDDING_DIM: usize = 96;
 const EMBEDDING_WINDOW: usize = 16;
 
 /// Model file names
-const MELSPEC_MODEL: &str = "melspectrogram.onnx";
-const EMBEDDING_MODEL: &str = "embedding_model.onnx";
-const WAKE_WORD_MODEL: &str = "hey_jarvis_v0.1.onnx";
+pub const MELSPEC_MODEL: &str = "melspectrogram.onnx";
+pub const EMBEDDING_MODEL: &str = "embedding_model.onnx";
+pub const WAKE_WORD_MODEL: &str = "hey_jarvis_v0.1.onnx";
 
 /// Model download URLs
 const MELSPEC_URL: &str =
@@ -63,6 +71,9 @@ pub enum WakeWordError {
 
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Integrity(#[from] IntegrityError),
 }
 
 /// Event emitted when wake word is detected.
@@ -72,19 +83,29 @@ pub struct WakeWordEvent {
     pub name: String,
     /// Detection score (0.0 - 1.0)
     pub score: f32,
+    /// Action bound to the wake word
+    pub action: WakeWordAction,
     /// Timestamp when detected
     #[allow(dead_code)] // Available for future timeout tracking
     pub timestamp: std::time::Instant,
 }
 
+/// A loaded wake word classifier.
+struct Classifier {
+    name: String,
+    action: WakeWordAction,
+    threshold: f32,
+    session: Session,
+}
+
 /// Wake word detector using openWakeWord ONNX models.
 pub struct WakeWordDetector {
     /// Mel spectrogram model
     melspec_model: Session,
     /// Speech embedding model
     embedding_model: Session,
-    /// Wake word classification model
-    wakeword_model: Session,
+    /// Wake word classification models
+    classifiers: Vec<Classifier>,
     /// Configuration
     config: WakeWordConfig,
     /// Audio sample buffer
@@ -103,7 +124,6 @@ impl WakeWordDetector {
         // Check if models exist
         let melspec_path = models_dir.join(MELSPEC_MODEL);
         let embedding_path = models_dir.join(EMBEDDING_MODEL);
-        let wakeword_path = models_dir.join(WAKE_WORD_MODEL);
 
         if !melspec_path.exists() {
             return Err(WakeWordError::ModelNotFound(MELSPEC_MODEL.to_string()));
@@ -111,49 +131,49 @@ impl WakeWordDetector {
         if !embedding_path.exists() {
             return Err(WakeWordError::ModelNotFound(EMBEDDING_MODEL.to_string()));
         }
-        if !wakeword_path.exists() {
-            return Err(WakeWordError::ModelNotFound(WAKE_WORD_MODEL.to_string()));
+
+        let specs = Self::model_specs(config);
+        let mut verifier = ModelVerifier::new()?;
+        for path in [&melspec_path, &embedding_path] {
+            verifier.verify_on_load(path)?;
+        }
+        for spec in &specs {
+            let path = resolve_model_path(&models_dir, &spec.path);
+            if !path.exists() {
+                return Err(WakeWordError::ModelNotFound(spec.path.clone()));
+            }
+            verifier.verify_on_load(&path)?;
         }
 
         info!("Loading wake word models from {:?}", models_dir);
 
-        // Load models with optimizations
-        let melspec_model = Session::builder()
-            .map_err(|e| WakeWordError::ModelError(e.to_string()))?
-            .with_optimization_level(GraphOptimizationLevel::Level3)
-            .map_err(|e| WakeWordError::ModelError(e.to_string()))?
-            .with_intra_threads(1)
-            .map_err(|e| WakeWordError::ModelError(e.to_string()))?
-            .commit_from_file(&melspec_path)
-            .map_err(|e: ort::Error| WakeWordError::ModelError(format!("melspec: {}", e)))?;
-
-        let embedding_model = Session::builder()
-            .map_err(|e| WakeWordError::ModelError(e.to_string()))?
-            .with_optimization_level(GraphOptimizationLevel::Level3)
-            .map_err(|e| WakeWordError::ModelError(e.to_string()))?
-            .with_intra_threads(1)
-            .map_err(|e| WakeWordError::ModelError(e.to_string()))?
-            .commit_from_file(&embedding_path)
-            .map_err(|e: ort::Error| WakeWordError::ModelError(format!("embedding: {}", e)))?;
-
-        let wakeword_model = Session::builder()
-            .map_err(|e| WakeWordError::ModelError(e.to_string()))?
-            .with_optimization_level(GraphOptimizationLevel::Level3)
-            .map_err(|e| WakeWordError::ModelError(e.to_string()))?
-            .with_intra_threads(1)
-            .map_err(|e| WakeWordError::ModelError(e.to_string()))?
-            .commit_from_file(&wakeword_path)
-            .map_err(|e: ort::Error| WakeWordError::ModelError(format!("wakeword: {}", e)))?;
+        let melspec_model = load_session(&melspec_path, "melspec")?;
+        let embedding_model = load_session(&embedding_path, "embedding")?;
+
+        let mut classifiers = Vec::with_capacity(specs.len());
+        for spec in specs {
+            let path = resolve_model_path(&models_dir, &spec.path);
+            classifiers.push(Classifier {
+                session: load_session(&path, &spec.name)?,
+                threshold: spec.threshold.unwrap_or(config.threshold),
+                name: spec.name,
+                action: spec.action,
+            });
+        }
 
         info!(
-            "Wake word detector initialized (threshold: {:.2})",
-            config.threshold
+            "Wake word detector initialized ({})",
+            classifiers
+                .iter()
+                .map(|c| format!("\"{}\" → {:?} at {:.2}", c.name, c.action, c.threshold))
+                .collect::<Vec<_>>()
+                .join(", ")
         );
 
         Ok(Self {
             melspec_model,
             embedding_model,
-            wakeword_model,
+            classifiers,
             config: config.clone(),
             sample_buffer: Vec::with_capacity(SAMPLES_PER_FRAME * 2),
             mel_buffer: VecDeque::with_capacity(MEL_FRAMES + 10),
@@ -161,6 +181,31 @@ impl WakeWordDetector {
         })
     }
 
+    /// Wake word models to load: `models`, or else the single default
+    /// model (`model_path` or the built-in "hey jarvis") starting dictation.
+    pub fn model_specs(config: &WakeWordConfig) -> Vec<WakeWordModel> {
+        if !config.models.is_empty() {
+            return config.models.clone();
+        }
+
+        let (name, path) = match &config.model_path {
+            Some(path) => {
+                let stem = Path::new(path)
+                    .file_stem()
+                    .map(|s| s.to_string_lossy().replace('_', " "))
+                    .unwrap_or_else(|| path.clone());
+                (stem, path.clone())
+            }
+            None => ("hey jarvis".to_string(), WAKE_WORD_MODEL.to_string()),
+        };
+        vec![WakeWordModel {
+            name,
+            path,
+            threshold: None,
+            action: WakeWordAction::Dictate,
+        }]
+    }
+
     /// Get the models directory path
     fn models_dir() -> Result<PathBuf, WakeWordError> {
         Config::data_dir()
@@ -180,10 +225,14 @@ impl WakeWordDetector {
     }
 
     /// Download wake word models
-    pub async fn download_models() -> Result<(), WakeWordError> {
+    pub async fn download_models(models: &ModelsConfig) -> Result<(), WakeWordError> {
         let models_dir = Self::models_dir()?;
         std::fs::create_dir_all(&models_dir)?;
 
+        if let Err(e) = manifest::refresh_pinned(models).await {
+            tracing::warn!("Using cached model manifest: {}", e);
+        }
+
         let downloads = [
             (MELSPEC_URL, MELSPEC_MODEL),
             (EMBEDDING_URL, EMBEDDING_MODEL),
@@ -194,7 +243,10 @@ impl WakeWordDetector {
             let path = models_dir.join(name);
             if !path.exists() {
                 info!("Downloading {}...", name);
-                Self::download_file(url, &path).await?;
+                let url = models
+                    .mirrors
+                    .url(ModelFamily::WakeWord, name, url.to_string());
+                Self::download_file(&url, &path).await?;
             }
         }
 
@@ -283,6 +335,9 @@ impl WakeWordDetector {
             let chunk = chunk.map_err(|e| WakeWordError::ModelError(e.to_string()))?;
             file.write_all(&chunk)?;
         }
+        drop(file);
+
+        manifest::verify_download(temp_path.clone(), path.to_path_buf()).await?;
 
         // Rename temp to final
         std::fs::rename(&temp_path, path)?;
@@ -298,50 +353,73 @@ impl WakeWordDetector {
         self.sample_buffer.extend_from_slice(samples);
 
         // Process complete frames
-        while self.sample_buffer.len() >= SAMPLES_PER_FRAME {
-            // Extract frame
-            let frame: Vec<f32> = self.sample_buffer.drain(..SAMPLES_PER_FRAME).collect();
-
-            // Compute mel spectrogram
-            if let Ok(mel_frames) = self.compute_melspec(&frame) {
-                for mel_frame in mel_frames {
-                    self.mel_buffer.push_back(mel_frame);
-
-                    // Keep only what we need for embedding
-                    while self.mel_buffer.len() > MEL_FRAMES {
-                        self.mel_buffer.pop_front();
-                    }
-                }
+        while let Some(scores) = self.next_frame() {
+            // Report the strongest detection above its own threshold
+            let best = scores
+                .iter()
+                .zip(&self.classifiers)
+                .filter(|(score, c)| **score >= c.threshold)
+                .max_by(|a, b| a.0.total_cmp(b.0));
+            if let Some((&score, classifier)) = best {
+                debug!(
+                    "Wake word \"{}\" detected with score: {:.3}",
+                    classifier.name, score
+                );
+                return Some(WakeWordEvent {
+                    name: classifier.name.clone(),
+                    score,
+                    action: classifier.action,
+                    timestamp: std::time::Instant::now(),
+                });
             }
+        }
+
+        None
+    }
+
+    /// Run one buffered frame through the pipeline.
+    ///
+    /// Returns None once the sample buffer holds no complete frame, and the
+    /// classifier scores (empty until enough embeddings accumulated) otherwise.
+    fn next_frame(&mut self) -> Option<Vec<f32>> {
+        if self.sample_buffer.len() < SAMPLES_PER_FRAME {
+            return None;
+        }
 
-            // Compute embedding when we have enough mel frames
-            if self.mel_buffer.len() >= MEL_FRAMES {
-                if let Ok(embedding) = self.compute_embedding() {
-                    self.embedding_buffer.push_back(embedding);
+        // Extract frame
+        let frame: Vec<f32> = self.sample_buffer.drain(..SAMPLES_PER_FRAME).collect();
 
-                    // Keep sliding window
-                    while self.embedding_buffer.len() > EMBEDDING_WINDOW {
-                        self.embedding_buffer.pop_front();
-                    }
+        // Compute mel spectrogram
+        if let Ok(mel_frames) = self.compute_melspec(&frame) {
+            for mel_frame in mel_frames {
+                self.mel_buffer.push_back(mel_frame);
+
+                // Keep only what we need for embedding
+                while self.mel_buffer.len() > MEL_FRAMES {
+                    self.mel_buffer.pop_front();
                 }
             }
+        }
 
-            // Check for wake word when we have enough embeddings
-            if self.embedding_buffer.len() >= EMBEDDING_WINDOW {
-                if let Ok(score) = self.detect_wakeword() {
-                    if score >= self.config.threshold {
-                        debug!("Wake word detected with score: {:.3}", score);
-                        return Some(WakeWordEvent {
-                            name: "hey jarvis".to_string(),
-                            score,
-                            timestamp: std::time::Instant::now(),
-                        });
-                    }
+        // Compute embedding when we have enough mel frames
+        if self.mel_buffer.len() >= MEL_FRAMES {
+            if let Ok(embedding) = self.compute_embedding() {
+                self.embedding_buffer.push_back(embedding);
+
+                // Keep sliding window
+                while self.embedding_buffer.len() > EMBEDDING_WINDOW {
+                    self.embedding_buffer.pop_front();
                 }
             }
         }
 
-        None
+        // Check for wake words when we have enough embeddings
+        if self.embedding_buffer.len() >= EMBEDDING_WINDOW {
+            if let Ok(scores) = self.detect_wakewords() {
+                return Some(scores);
+            }
+        }
+        Some(Vec::new())
     }
 
     /// Compute mel spectrogram from audio frame
@@ -428,8 +506,8 @@ impl WakeWordDetector {
         Ok(slice.to_vec())
     }
 
-    /// Detect wake word from accumulated embeddings
-    fn detect_wakeword(&mut self) -> Result<f32, WakeWordError> {
+    /// Score the accumulated embeddings with every classifier
+    fn detect_wakewords(&mut self) -> Result<Vec<f32>, WakeWordError> {
         // Flatten embeddings into input [1, 1536]
         let mut input_data = Vec::with_capacity(EMBEDDING_WINDOW * EMBEDDING_DIM);
 
@@ -442,23 +520,60 @@ impl WakeWordDetector {
             input_data.push(0.0);
         }
 
-        let input = Tensor::from_array(([1, EMBEDDING_WINDOW * EMBEDDING_DIM], input_data))
-            .map_err(|e| WakeWordError::ProcessError(format!("create wakeword input: {}", e)))?;
+        let mut scores = Vec::with_capacity(self.classifiers.len());
+        for classifier in &mut self.classifiers {
+            let input =
+                Tensor::from_array(([1, EMBEDDING_WINDOW * EMBEDDING_DIM], input_data.clone()))
+                    .map_err(|e| {
+                        WakeWordError::ProcessError(format!("create wakeword input: {}", e))
+                    })?;
+
+            // Run inference
+            let outputs = classifier
+                .session
+                .run(ort::inputs!["input" => input])
+                .map_err(|e: ort::Error| {
+                    WakeWordError::ProcessError(format!("wakeword inference: {}", e))
+                })?;
+
+            // Extract probability
+            let (_shape, slice): (&ort::tensor::Shape, &[f32]) = outputs[0]
+                .try_extract_tensor::<f32>()
+                .map_err(|e: ort::Error| WakeWordError::ProcessError(e.to_string()))?;
+
+            scores.push(slice.first().copied().unwrap_or(0.0));
+        }
 
-        // Run inference
-        let outputs = self
-            .wakeword_model
-            .run(ort::inputs!["input" => input])
-            .map_err(|e: ort::Error| {
-                WakeWordError::ProcessError(format!("wakeword inference: {}", e))
-            })?;
+        Ok(scores)
+    }
 
-        // Extract probability
-        let (_shape, slice): (&ort::tensor::Shape, &[f32]) = outputs[0]
-            .try_extract_tensor::<f32>()
-            .map_err(|e: ort::Error| WakeWordError::ProcessError(e.to_string()))?;
+    /// Highest score each classifier reaches over a clip.
+    ///
+    /// Resets the detector before and after. Used to evaluate models
+    /// against recorded clips.
+    pub fn peak_scores(&mut self, samples: &[f32]) -> Vec<f32> {
+        self.reset();
+        self.sample_buffer.extend_from_slice(samples);
+
+        let mut peaks = vec![0.0f32; self.classifiers.len()];
+        while let Some(scores) = self.next_frame() {
+            for (peak, score) in peaks.iter_mut().zip(scores) {
+                *peak = peak.max(score);
+            }
+        }
+
+        self.reset();
+        peaks
+    }
 
-        Ok(slice.first().copied().unwrap_or(0.0))
+    /// Names of the loaded wake words, in configuration order.
+    pub fn names(&self) -> Vec<&str> {
+        self.classifiers.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    /// Whether any loaded wake word triggers `action`.
+    pub fn has_action(&self, action: WakeWordAction) -> bool {
+        self.classifiers.iter().any(|c| c.action == action)
     }
 
     /// Reset the detector state (call after detection to avoid repeats).
@@ -485,6 +600,120 @@ impl WakeWordDetector {
     }
 }
 
+/// Build an ONNX session for one of the wake word models.
+fn load_session(path: &Path, label: &str) -> Result<Session, WakeWordError> {
+    Session::builder()
+        .map_err(|e| WakeWordError::ModelError(e.to_string()))?
+        .with_optimization_level(GraphOptimizationLevel::Level3)
+        .map_err(|e| WakeWordError::ModelError(e.to_string()))?
+        .with_intra_threads(1)
+        .map_err(|e| WakeWordError::ModelError(e.to_string()))?
+        .commit_from_file(path)
+        .map_err(|e: ort::Error| WakeWordError::ModelError(format!("{}: {}", label, e)))
+}
+
+/// Resolve a configured model path; relative paths live in `models_dir`.
+fn resolve_model_path(models_dir: &Path, path: &str) -> PathBuf {
+    let path = expand_home(path);
+    if path.is_absolute() {
+        path
+    } else {
+        models_dir.join(path)
+    }
+}
+
+/// Lowercase a word and drop its punctuation.
+fn normalize_word(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Remove `phrase` from the end of a transcription.
+///
+/// Matching ignores case and punctuation. Returns the remaining text if the
+/// transcription ended with the phrase, `None` otherwise.
+pub fn strip_stop_phrase(text: &str, phrase: &str) -> Option<String> {
+    let phrase: Vec<String> = phrase
+        .split_whitespace()
+        .map(normalize_word)
+        .filter(|w| !w.is_empty())
+        .collect();
+    if phrase.is_empty() {
+        return None;
+    }
+
+    // Words of the text with their byte offsets
+    let words: Vec<(usize, String)> = text
+        .split_whitespace()
+        .map(|raw| {
+            (
+                raw.as_ptr() as usize - text.as_ptr() as usize,
+                normalize_word(raw),
+            )
+        })
+        .filter(|(_, w)| !w.is_empty())
+        .collect();
+    if words.len() < phrase.len() {
+        return None;
+    }
+
+    let tail = &words[words.len() - phrase.len()..];
+    if !tail.iter().map(|(_, w)| w).eq(phrase.iter()) {
+        return None;
+    }
+
+    let rest = text[..tail[0].0].trim_end();
+    let rest = rest.trim_end_matches([',', ';', ':', '-']);
+    Some(rest.trim_end().to_string())
+}
+
+/// Detection rates of a wake word model at one threshold.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThresholdStats {
+    pub threshold: f32,
+    /// Share of positive clips detected
+    pub recall: f32,
+    /// Share of negative clips that falsely triggered
+    pub false_positive_rate: f32,
+}
+
+/// Evaluate thresholds 0.05 - 0.95 against peak scores of labelled clips.
+pub fn sweep_thresholds(positive: &[f32], negative: &[f32]) -> Vec<ThresholdStats> {
+    let rate = |scores: &[f32], threshold: f32| {
+        if scores.is_empty() {
+            0.0
+        } else {
+            scores.iter().filter(|&&s| s >= threshold).count() as f32 / scores.len() as f32
+        }
+    };
+
+    (1..20)
+        .map(|step| {
+            let threshold = step as f32 / 20.0;
+            ThresholdStats {
+                threshold,
+                recall: rate(positive, threshold),
+                false_positive_rate: rate(negative, threshold),
+            }
+        })
+        .collect()
+}
+
+/// Pick the threshold with the best recall minus false positive rate,
+/// preferring the stricter threshold on ties.
+pub fn recommend_threshold(stats: &[ThresholdStats]) -> Option<f32> {
+    stats
+        .iter()
+        .max_by(|a, b| {
+            (a.recall - a.false_positive_rate)
+                .total_cmp(&(b.recall - b.false_positive_rate))
+                .then(a.threshold.total_cmp(&b.threshold))
+        })
+        .map(|s| s.threshold)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -510,6 +739,8 @@ mod tests {
         let config = WakeWordConfig {
             enabled: true,
             model_path: Some("/custom/path".to_string()),
+            models: Vec::new(),
+            stop_phrase: Some("stop dictation".to_string()),
             sensitivity: 0.7,
             threshold: 0.8,
             timeout_secs: 15.0,
@@ -525,6 +756,132 @@ mod tests {
         assert!(!config.notify_on_detect);
     }
 
+    #[test]
+    fn test_model_specs_default() {
+        let specs = WakeWordDetector::model_specs(&WakeWordConfig::default());
+        assert_eq!(specs.len(), 1);
+        assert_eq!(specs[0].name, "hey jarvis");
+        assert_eq!(specs[0].path, WAKE_WORD_MODEL);
+        assert_eq!(specs[0].action, WakeWordAction::Dictate);
+        assert_eq!(specs[0].threshold, None);
+    }
+
+    #[test]
+    fn test_model_specs_model_path_override() {
+        let config = WakeWordConfig {
+            model_path: Some("/models/hey_computer.onnx".to_string()),
+            ..Default::default()
+        };
+        let specs = WakeWordDetector::model_specs(&config);
+        assert_eq!(specs.len(), 1);
+        assert_eq!(specs[0].name, "hey computer");
+        assert_eq!(specs[0].path, "/models/hey_computer.onnx");
+    }
+
+    #[test]
+    fn test_model_specs_models_list() {
+        let models = vec![
+            WakeWordModel {
+                name: "hey jarvis".to_string(),
+                path: WAKE_WORD_MODEL.to_string(),
+                threshold: Some(0.6),
+                action: WakeWordAction::Dictate,
+            },
+            WakeWordModel {
+                name: "alexa".to_string(),
+                path: "alexa_v0.1.onnx".to_string(),
+                threshold: None,
+                action: WakeWordAction::Cancel,
+            },
+        ];
+        let config = WakeWordConfig {
+            // The list wins over the single override
+            model_path: Some("/ignored.onnx".to_string()),
+            models: models.clone(),
+            ..Default::default()
+        };
+        assert_eq!(WakeWordDetector::model_specs(&config), models);
+    }
+
+    #[test]
+    fn test_resolve_model_path() {
+        let dir = Path::new("/data/models/wake_word");
+        assert_eq!(
+            resolve_model_path(dir, "alexa.onnx"),
+            dir.join("alexa.onnx")
+        );
+        assert_eq!(
+            resolve_model_path(dir, "/opt/alexa.onnx"),
+            PathBuf::from("/opt/alexa.onnx")
+        );
+    }
+
+    // ===================
+    // Stop Phrase Tests
+    // ===================
+
+    #[test]
+    fn test_strip_stop_phrase() {
+        assert_eq!(
+            strip_stop_phrase("Buy milk and eggs. Stop dictation.", "stop dictation"),
+            Some("Buy milk and eggs.".to_string())
+        );
+        assert_eq!(
+            strip_stop_phrase("Call Anna, stop dictation", "Stop Dictation"),
+            Some("Call Anna".to_string())
+        );
+        assert_eq!(
+            strip_stop_phrase(" Stop dictation!", "stop dictation"),
+            Some(String::new())
+        );
+    }
+
+    #[test]
+    fn test_strip_stop_phrase_no_match() {
+        // Only at the end of the utterance
+        assert_eq!(
+            strip_stop_phrase("Stop dictation is a phrase", "stop dictation"),
+            None
+        );
+        assert_eq!(strip_stop_phrase("dictation", "stop dictation"), None);
+        assert_eq!(strip_stop_phrase("anything", "  "), None);
+        // Whole words only
+        assert_eq!(
+            strip_stop_phrase("nonstop dictation", "stop dictation"),
+            None
+        );
+    }
+
+    // ===================
+    // Threshold Evaluation Tests
+    // ===================
+
+    #[test]
+    fn test_sweep_thresholds() {
+        let stats = sweep_thresholds(&[0.9, 0.8, 0.3], &[0.1, 0.6]);
+        assert_eq!(stats.len(), 19);
+
+        let at = |t: f32| {
+            *stats
+                .iter()
+                .find(|s| (s.threshold - t).abs() < 0.001)
+                .unwrap()
+        };
+        assert!((at(0.5).recall - 2.0 / 3.0).abs() < 0.001);
+        assert!((at(0.5).false_positive_rate - 0.5).abs() < 0.001);
+        assert!((at(0.7).false_positive_rate).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_recommend_threshold() {
+        // 0.65 - 0.8 all separate perfectly; prefer the strictest
+        let stats = sweep_thresholds(&[0.9, 0.8], &[0.1, 0.6]);
+        let threshold = recommend_threshold(&stats).unwrap();
+        assert!((threshold - 0.8).abs() < 0.001);
+
+        assert_eq!(recommend_threshold(&[]), None);
+    }
+
     // ===================
     // WakeWordDetector Tests
     // ===================
@@ -580,6 +937,7 @@ mod tests {
         let event = WakeWordEvent {
             name: "hey_jarvis".to_string(),
             score: 0.95,
+            action: WakeWordAction::Dictate,
             timestamp: std::time::Instant::now(),
         };
         assert_eq!(event.name, "hey_jarvis");
@@ -591,6 +949,7 @@ mod tests {
         let event = WakeWordEvent {
             name: "hey_jarvis".to_string(),
             score: 0.85,
+            action: WakeWordAction::Meeting,
             timestamp: std::time::Instant::now(),
         };
         let cloned = event.clone();
@@ -89,6 +89,13 @@ impl IpcClient {
         self.inner.send(cmd)
     }
 
+    /// Change how long `send` waits for a response (`None` = no limit).
+    ///
+    /// Commands such as `TranscribeFile` answer only once the work is done.
+    pub fn set_timeout(&mut self, timeout: Option<std::time::Duration>) {
+        self.inner.set_timeout(timeout)
+    }
+
     /// Subscribe to events. Returns a receiver for events.
     pub fn subscribe(&mut self) -> Result<std::sync::mpsc::Receiver<IpcEvent>, IpcError> {
         self.inner.subscribe()
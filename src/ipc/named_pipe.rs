@@ -165,6 +165,9 @@ impl IpcClientInner {
         serde_json::from_str(line).map_err(|e| IpcError::RecvFailed(e.to_string()))
     }
 
+    /// Reads block until the daemon answers, so there is nothing to change.
+    pub fn set_timeout(&mut self, _timeout: Option<std::time::Duration>) {}
+
     /// Subscribe to events (Windows: not yet implemented, returns empty receiver).
     pub fn subscribe(&mut self) -> Result<Receiver<IpcEvent>, IpcError> {
         // TODO: Implement persistent connection with event streaming for Windows
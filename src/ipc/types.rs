@@ -27,6 +27,19 @@ pub enum IpcCommand {
     /// Toggle recording state.
     ToggleRecording,
 
+    /// Transcribe audio already in the ring buffer.
+    TranscribeLast {
+        /// Seconds to transcribe (default: `replay.default_secs`).
+        #[serde(default)]
+        secs: Option<f32>,
+    },
+
+    /// Transcribe a WAV file as background work and return the text.
+    TranscribeFile {
+        /// Absolute path of the file, readable by the daemon.
+        path: String,
+    },
+
     /// Subscribe to events.
     Subscribe {
         /// Event types to subscribe to (empty = all).
@@ -53,6 +66,9 @@ pub enum IpcCommand {
 
     /// Ping (for connection health check).
     Ping,
+
+    /// Get transcription queue statistics per worker.
+    QueueStats,
 }
 
 fn default_limit() -> usize {
@@ -84,9 +100,24 @@ pub enum IpcResponseData {
         items: Vec<HistoryItem>,
         total: usize,
     },
+    Queue {
+        /// Jobs queued or being transcribed.
+        pending: usize,
+        /// Results waiting to be output in order.
+        waiting: usize,
+        workers: Vec<WorkerQueueStats>,
+    },
     Pong {
         timestamp: u64,
     },
+    Transcription {
+        text: String,
+        /// Duration of the audio in seconds.
+        duration_secs: f32,
+        /// Detected or configured language (ISO code).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
+    },
     Empty {},
 }
 
@@ -121,6 +152,21 @@ pub enum DaemonState {
     Processing,
 }
 
+/// Statistics of one transcription worker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkerQueueStats {
+    /// Worker index within the pool.
+    pub id: usize,
+    /// Jobs dispatched but not yet finished.
+    pub in_flight: usize,
+    /// Jobs finished successfully.
+    pub completed: u64,
+    /// Jobs that failed.
+    pub failed: u64,
+    /// Total time spent transcribing in milliseconds.
+    pub busy_ms: u64,
+}
+
 /// Transcription history item.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistoryItem {
@@ -129,6 +175,9 @@ pub struct HistoryItem {
     pub text: String,
     pub duration_secs: f64,
     pub llm_corrected: bool,
+    /// Detected or configured language of the utterance (ISO code).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -175,6 +224,38 @@ impl IpcResponse {
         }
     }
 
+    pub fn history(items: Vec<HistoryItem>, total: usize) -> Self {
+        Self {
+            ok: true,
+            data: Some(IpcResponseData::History { items, total }),
+            error: None,
+        }
+    }
+
+    pub fn queue_stats(pending: usize, waiting: usize, workers: Vec<WorkerQueueStats>) -> Self {
+        Self {
+            ok: true,
+            data: Some(IpcResponseData::Queue {
+                pending,
+                waiting,
+                workers,
+            }),
+            error: None,
+        }
+    }
+
+    pub fn transcription(text: String, duration_secs: f32, language: Option<String>) -> Self {
+        Self {
+            ok: true,
+            data: Some(IpcResponseData::Transcription {
+                text,
+                duration_secs,
+                language,
+            }),
+            error: None,
+        }
+    }
+
     pub fn pong() -> Self {
         use std::time::{SystemTime, UNIX_EPOCH};
         let timestamp = SystemTime::now()
@@ -233,6 +314,8 @@ pub enum IpcEvent {
         text: String,
         duration_secs: f64,
         llm_corrected: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
     },
 
     /// State changed.
@@ -241,13 +324,16 @@ pub enum IpcEvent {
     /// Error occurred.
     Error { code: String, message: String },
 
-    /// Model loading progress.
+    /// Model download or loading progress.
     ModelProgress {
         model: String,
         progress: f32,
         status: String,
     },
 
+    /// Active audio input device changed.
+    DeviceChanged { device_id: String, name: String },
+
     /// Daemon shutting down.
     Shutdown,
 }
@@ -370,6 +456,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_transcribe_last_command_serialization() {
+        let parsed: IpcCommand = serde_json::from_str(r#"{"cmd":"transcribe_last"}"#).unwrap();
+        assert!(matches!(parsed, IpcCommand::TranscribeLast { secs: None }));
+
+        let parsed: IpcCommand =
+            serde_json::from_str(r#"{"cmd":"transcribe_last","secs":20.0}"#).unwrap();
+        match parsed {
+            IpcCommand::TranscribeLast { secs } => assert_eq!(secs, Some(20.0)),
+            _ => panic!("Expected TranscribeLast command"),
+        }
+    }
+
     #[test]
     fn test_command_message_serialization() {
         let msg = IpcMessage::Command {
@@ -393,6 +492,67 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_transcribe_file_command_serialization() {
+        let parsed: IpcCommand =
+            serde_json::from_str(r#"{"cmd":"transcribe_file","path":"/tmp/talk.wav"}"#).unwrap();
+        match parsed {
+            IpcCommand::TranscribeFile { path } => assert_eq!(path, "/tmp/talk.wav"),
+            _ => panic!("Expected TranscribeFile command"),
+        }
+    }
+
+    #[test]
+    fn test_transcription_response_roundtrip() {
+        let response = IpcResponse::transcription("Hallo".into(), 12.5, Some("de".into()));
+
+        let json = serde_json::to_string(&response).unwrap();
+        let parsed: IpcResponse = serde_json::from_str(&json).unwrap();
+        match parsed.data {
+            Some(IpcResponseData::Transcription {
+                text,
+                duration_secs,
+                language,
+            }) => {
+                assert_eq!(text, "Hallo");
+                assert_eq!(duration_secs, 12.5);
+                assert_eq!(language.as_deref(), Some("de"));
+            }
+            _ => panic!("Expected Transcription data"),
+        }
+    }
+
+    #[test]
+    fn test_queue_stats_response_roundtrip() {
+        let response = IpcResponse::queue_stats(
+            3,
+            1,
+            vec![WorkerQueueStats {
+                id: 0,
+                in_flight: 2,
+                completed: 10,
+                failed: 1,
+                busy_ms: 4200,
+            }],
+        );
+
+        let json = serde_json::to_string(&response).unwrap();
+        let parsed: IpcResponse = serde_json::from_str(&json).unwrap();
+        match parsed.data {
+            Some(IpcResponseData::Queue {
+                pending,
+                waiting,
+                workers,
+            }) => {
+                assert_eq!(pending, 3);
+                assert_eq!(waiting, 1);
+                assert_eq!(workers.len(), 1);
+                assert_eq!(workers[0].completed, 10);
+            }
+            _ => panic!("Expected Queue data"),
+        }
+    }
+
     #[test]
     fn test_pong_response() {
         let response = IpcResponse::pong();
@@ -424,12 +584,14 @@ mod tests {
             text: "Hello world".to_string(),
             duration_secs: 2.5,
             llm_corrected: true,
+            language: Some("de".to_string()),
         };
 
         let json = serde_json::to_string(&event).unwrap();
         assert!(json.contains("\"event\":\"transcription_complete\""));
         assert!(json.contains("\"text\":\"Hello world\""));
         assert!(json.contains("\"llm_corrected\":true"));
+        assert!(json.contains("\"language\":\"de\""));
 
         // Roundtrip
         let parsed: IpcEvent = serde_json::from_str(&json).unwrap();
@@ -440,12 +602,14 @@ mod tests {
                 text,
                 duration_secs,
                 llm_corrected,
+                language,
             } => {
                 assert_eq!(id, 123);
                 assert_eq!(recording_id, 456);
                 assert_eq!(text, "Hello world");
                 assert!((duration_secs - 2.5).abs() < 0.001);
                 assert!(llm_corrected);
+                assert_eq!(language.as_deref(), Some("de"));
             }
             _ => panic!("Expected TranscriptionComplete event"),
         }
@@ -466,4 +630,19 @@ mod tests {
             "\"processing\""
         );
     }
+
+    #[test]
+    fn test_device_changed_event() {
+        let event = IpcEvent::DeviceChanged {
+            device_id: "bluez_input.headset".to_string(),
+            name: "Headset".to_string(),
+        };
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"event\":\"device_changed\""));
+        assert!(json.contains("\"name\":\"Headset\""));
+
+        let parsed: IpcEvent = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, IpcEvent::DeviceChanged { name, .. } if name == "Headset"));
+    }
 }
@@ -110,6 +110,11 @@ impl IpcClientInner {
         }
     }
 
+    /// Set the read timeout used while waiting for a response.
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.stream.set_read_timeout(timeout).ok();
+    }
+
     /// Subscribe to events. Starts a reader thread.
     pub fn subscribe(&mut self) -> Result<Receiver<IpcEvent>, IpcError> {
         if self.event_rx.is_some() {
@@ -5,6 +5,7 @@
 
 pub mod api;
 pub mod config;
+pub mod context;
 pub mod correction;
 pub mod daemon;
 #[cfg(target_os = "linux")]
@@ -13,8 +14,10 @@ pub mod download_queue;
 pub mod engine;
 #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
 pub mod gui;
+pub mod history;
 pub mod input;
 pub mod ipc;
+pub mod manifest;
 pub mod output;
 pub mod panic_handler;
 pub mod platform;
@@ -1,5 +1,5 @@
 use clap::{Parser, Subcommand};
-use tracing::info;
+use tracing::{debug, info};
 use tracing_appender::rolling::{RollingFileAppender, Rotation};
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
@@ -16,8 +16,11 @@ mod download_queue;
 mod engine;
 #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
 mod gui;
+mod history;
 mod input;
 mod ipc;
+mod manifest;
+mod model_store;
 mod output;
 mod panic_handler;
 mod platform;
@@ -126,6 +129,12 @@ enum Commands {
         action: DeviceAction,
     },
 
+    /// Tune wake word models
+    WakeWord {
+        #[command(subcommand)]
+        action: WakeWordAction,
+    },
+
     /// One-shot transcription from file
     Transcribe {
         /// Audio file to transcribe
@@ -143,8 +152,9 @@ enum Commands {
     /// Record and transcribe audio (system audio or microphone)
     Record {
         /// Audio source: mic, monitor (system audio), or both
-        #[arg(short, long, default_value = "mic")]
-        source: String,
+        /// [default: mic, or monitor with --app or audio.capture_app]
+        #[arg(short, long)]
+        source: Option<String>,
 
         /// Output file (e.g., meeting.txt, call.srt)
         #[arg(short, long)]
@@ -161,6 +171,21 @@ enum Commands {
         /// Output format: text, timestamped, srt, vtt
         #[arg(short = 'F', long, default_value = "text")]
         format: String,
+
+        /// Capture only this application's audio, e.g. "zoom" or a browser
+        /// tab title (Linux; with --source monitor or both)
+        #[arg(long)]
+        app: Option<String>,
+
+        /// Translate complete sentences into this language (e.g. "en") and
+        /// output them together with the original
+        #[arg(long, value_name = "LANG")]
+        translate_to: Option<String>,
+
+        /// Placement of translations: stacked (two lines per entry),
+        /// side-by-side (one line), tracks (separate file, e.g. meeting.en.srt)
+        #[arg(long, default_value = "stacked")]
+        bilingual: String,
     },
 
     /// Control recording on a running daemon
@@ -189,6 +214,12 @@ enum Commands {
         action: ApiKeyAction,
     },
 
+    /// Manage TLS certificates for the REST API
+    ApiCert {
+        #[command(subcommand)]
+        action: ApiCertAction,
+    },
+
     /// Summarize a transcription or audio file using LLM
     Summarize {
         /// Input file (transcription text or audio file)
@@ -233,6 +264,13 @@ enum RecordingAction {
     /// Toggle recording state
     Toggle,
 
+    /// Transcribe the last seconds of audio already captured
+    Replay {
+        /// Seconds to transcribe (default: replay.default_secs from config)
+        #[arg(short, long)]
+        secs: Option<f32>,
+    },
+
     /// Show current recording status
     Status,
 }
@@ -299,6 +337,21 @@ enum ApiKeyAction {
     Status,
 }
 
+/// REST API TLS certificate actions
+#[derive(Subcommand)]
+enum ApiCertAction {
+    /// Generate a self-signed certificate for local use
+    Generate {
+        /// Host name or IP to include (repeatable, default: localhost)
+        #[arg(long = "host")]
+        hosts: Vec<String>,
+
+        /// Overwrite an existing certificate
+        #[arg(short, long)]
+        force: bool,
+    },
+}
+
 #[derive(Subcommand)]
 enum ModelAction {
     /// Download a model
@@ -316,6 +369,41 @@ enum ModelAction {
         name: String,
     },
 
+    /// Verify downloaded models against their SHA-256 hashes
+    Verify,
+
+    /// Benchmark downloaded models and recommend one for this machine
+    Recommend {
+        /// Maximum time to transcribe one utterance, in seconds
+        #[arg(short, long, default_value_t = engine::recommend::DEFAULT_LATENCY_BUDGET_SECS)]
+        latency: f32,
+
+        /// Maximum memory for the model, in MB
+        #[arg(long)]
+        max_memory: Option<u64>,
+
+        /// WAV file to benchmark with instead of the built-in test clip
+        #[arg(long)]
+        clip: Option<String>,
+
+        /// Save the recommended model/preset to the config
+        #[arg(long)]
+        apply: bool,
+    },
+
+    /// Install models from a local file, directory or bundle (offline)
+    Import {
+        /// Model file, M2M-100 model directory, or bundle from `model export`
+        path: String,
+    },
+
+    /// Pack all installed models into a bundle for another machine
+    Export {
+        /// Output file (gzip-compressed unless it ends in .tar)
+        #[arg(default_value = "openhush-models.tar.gz")]
+        output: String,
+    },
+
     /// Load model into GPU memory (requires running daemon)
     Load,
 
@@ -343,6 +431,39 @@ enum DeviceAction {
         /// Channel selection: "all" or comma-separated indices (e.g., "0,1")
         selection: String,
     },
+
+    /// Measure room noise and speech level, then tune preprocessing and VAD
+    Calibrate {
+        /// Seconds of silence to record for the noise floor
+        #[arg(long, default_value = "3")]
+        noise_secs: f32,
+
+        /// Seconds of speech to record
+        #[arg(long, default_value = "6")]
+        speech_secs: f32,
+
+        /// Show the recommendations without changing the config
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum WakeWordAction {
+    /// Score wake word models against recorded clips and suggest thresholds
+    Eval {
+        /// Folder of WAV clips that contain the wake word
+        #[arg(long)]
+        positive: String,
+
+        /// Folder of WAV clips that don't (speech, TV, room noise)
+        #[arg(long)]
+        negative: String,
+
+        /// Model to evaluate (.onnx); default: the configured wake words
+        #[arg(short, long)]
+        model: Option<String>,
+    },
 }
 
 /// Guard that must be kept alive for file logging to work
@@ -361,6 +482,46 @@ enum LogMode {
     Tui,
 }
 
+/// Transcribe a file on the running daemon's worker pool.
+///
+/// Returns `Ok(None)` when no daemon is reachable, so the caller can load a
+/// model itself. Returns the text, audio duration and language otherwise.
+fn transcribe_with_daemon(
+    path: &std::path::Path,
+) -> anyhow::Result<Option<(String, f32, Option<String>)>> {
+    use crate::ipc::{IpcClient, IpcCommand, IpcResponseData};
+
+    let mut client = match IpcClient::connect() {
+        Ok(client) => client,
+        Err(e) => {
+            debug!("No daemon to transcribe with: {}", e);
+            return Ok(None);
+        }
+    };
+    // The daemon answers once the text is ready
+    client.set_timeout(None);
+
+    let path = path.canonicalize()?;
+    info!("Transcribing {} on the running daemon", path.display());
+    let response = client.send(IpcCommand::TranscribeFile {
+        path: path.to_string_lossy().into_owned(),
+    })?;
+    match response.data {
+        Some(IpcResponseData::Transcription {
+            text,
+            duration_secs,
+            language,
+        }) if response.ok => Ok(Some((text, duration_secs, language))),
+        _ => anyhow::bail!(
+            "Daemon failed to transcribe {}: {}",
+            path.display(),
+            response
+                .error
+                .unwrap_or_else(|| "unexpected response".into())
+        ),
+    }
+}
+
 fn init_logging(verbose: bool, mode: LogMode, config_level: Option<&str>) -> LogGuard {
     // Priority: RUST_LOG env > --verbose flag > config file > default
     let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| {
@@ -541,11 +702,12 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
 
         Commands::Model { action } => match action {
             ModelAction::Download { name } => {
-                use engine::whisper::{
-                    download_model, format_size, model_size_bytes, WhisperModel,
-                };
+                use engine::catalog::ModelSpec;
+                use engine::whisper::{download_model, format_size, model_size_bytes};
                 use std::io::Write;
 
+                let models = config::Config::load().map(|c| c.models).unwrap_or_default();
+
                 // Handle wake-word model download separately
                 if name == "wake-word" {
                     use input::wake_word::WakeWordDetector;
@@ -556,9 +718,11 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                     }
 
                     println!("Downloading wake word models (~3.7 MB)...");
-                    WakeWordDetector::download_models().await.map_err(|e| {
-                        anyhow::anyhow!("Failed to download wake word models: {}", e)
-                    })?;
+                    WakeWordDetector::download_models(&models)
+                        .await
+                        .map_err(|e| {
+                            anyhow::anyhow!("Failed to download wake word models: {}", e)
+                        })?;
                     println!("Wake word models downloaded successfully.");
                     return Ok(());
                 }
@@ -589,28 +753,29 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                     let mut current_file = String::new();
                     let mut last_percent = 0u32;
 
-                    let path = download_m2m100_model(model, |filename, downloaded, total| {
-                        if current_file != filename {
-                            if !current_file.is_empty() {
-                                println!();
+                    let path =
+                        download_m2m100_model(model, &models, |filename, downloaded, total| {
+                            if current_file != filename {
+                                if !current_file.is_empty() {
+                                    println!();
+                                }
+                                current_file = filename.to_string();
+                                last_percent = 0;
+                                print!("  {}: ", filename);
+                                let _ = std::io::stdout().flush();
                             }
-                            current_file = filename.to_string();
-                            last_percent = 0;
-                            print!("  {}: ", filename);
-                            let _ = std::io::stdout().flush();
-                        }
 
-                        if total > 0 {
-                            let percent = ((downloaded as f64 / total as f64) * 100.0) as u32;
-                            if percent > last_percent {
-                                last_percent = percent;
-                                print!("\r  {}: {}%", filename, percent);
-                                let _ = std::io::stdout().flush();
+                            if total > 0 {
+                                let percent = ((downloaded as f64 / total as f64) * 100.0) as u32;
+                                if percent > last_percent {
+                                    last_percent = percent;
+                                    print!("\r  {}: {}%", filename, percent);
+                                    let _ = std::io::stdout().flush();
+                                }
                             }
-                        }
-                    })
-                    .await
-                    .map_err(|e| anyhow::anyhow!("{}", e))?;
+                        })
+                        .await
+                        .map_err(|e| anyhow::anyhow!("{}", e))?;
 
                     println!(
                         "\n\nM2M-100 {} downloaded to: {}",
@@ -622,9 +787,19 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                     return Ok(());
                 }
 
-                let model: WhisperModel = name.parse().map_err(|()| {
+                if config::Config::load()
+                    .ok()
+                    .is_some_and(|c| c.transcription.custom_model(&name).is_some())
+                {
+                    anyhow::bail!(
+                        "'{}' is a custom model from your config and cannot be downloaded",
+                        name
+                    );
+                }
+
+                let model: ModelSpec = name.parse().map_err(|()| {
                     anyhow::anyhow!(
-                        "Unknown model '{}'. Available: tiny, base, small, medium, large-v3, wake-word, m2m100-418m, m2m100-1.2b",
+                        "Unknown model '{}'. Run 'openhush model list' to see available models",
                         name
                     )
                 })?;
@@ -636,7 +811,7 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                 );
 
                 let mut last_percent = 0;
-                let path = download_model(model, |downloaded, total| {
+                let path = download_model(model, &models, |downloaded, total| {
                     let percent = ((downloaded as f64 / total as f64) * 100.0) as u32;
                     if percent > last_percent {
                         last_percent = percent;
@@ -654,34 +829,46 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                 println!("\nDownloaded to: {}", path.display());
             }
             ModelAction::List => {
-                use engine::whisper::{
-                    all_models, format_size, is_model_downloaded, model_size_bytes,
-                };
+                use engine::catalog::list_models;
+                use engine::whisper::format_size;
 
-                println!("Available Whisper models:\n");
+                let config = config::Config::load().unwrap_or_default();
+
+                println!("Whisper models:\n");
                 println!(
-                    "  {:<12} {:<10} {:<10} Description",
-                    "Model", "Size", "Status"
+                    "  {:<20} {:<10} {:<6} {:<14} {:<10} Description",
+                    "Model", "Size", "Quant", "Origin", "Status"
                 );
-                println!("  {}", "-".repeat(60));
-
-                for model in all_models() {
-                    let name = format!("{:?}", model).to_lowercase();
-                    let size = format_size(model_size_bytes(model));
-                    let status = if is_model_downloaded(model) {
+                println!("  {}", "-".repeat(90));
+
+                for listing in list_models(&config.transcription) {
+                    let size = listing
+                        .size_bytes
+                        .map(format_size)
+                        .unwrap_or_else(|| "-".to_string());
+                    let status = if listing.installed {
                         "✓ local"
+                    } else if listing.path.is_some() {
+                        "missing"
                     } else {
                         "remote"
                     };
-                    let desc = match model {
-                        engine::whisper::WhisperModel::Tiny => "Fastest, lowest accuracy",
-                        engine::whisper::WhisperModel::Base => "Fast, good for simple audio",
-                        engine::whisper::WhisperModel::Small => "Balanced speed/accuracy",
-                        engine::whisper::WhisperModel::Medium => "Good accuracy, slower",
-                        engine::whisper::WhisperModel::LargeV3 => "Best accuracy, slowest",
+                    // Custom models show their file in the description column
+                    let desc = match listing.path {
+                        Some(ref path) if listing.description.is_empty() => {
+                            path.display().to_string()
+                        }
+                        Some(ref path) => format!("{} ({})", listing.description, path.display()),
+                        None => listing.description.clone(),
                     };
-                    println!("  {:<12} {:<10} {:<10} {}", name, size, status, desc);
+                    println!(
+                        "  {:<20} {:<10} {:<6} {:<14} {:<10} {}",
+                        listing.name, size, listing.quantization, listing.origin, status, desc
+                    );
                 }
+                println!(
+                    "\n  Quantized variants: append -q5 or -q8 (e.g. medium-q5, large-v3-turbo-q8)."
+                );
 
                 // Show wake-word model status
                 println!("\nWake word models:\n");
@@ -737,7 +924,8 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                 println!("\nUse 'openhush model download <name>' to download a model.");
             }
             ModelAction::Remove { name } => {
-                use engine::whisper::{remove_model, WhisperModel};
+                use engine::catalog::ModelSpec;
+                use engine::whisper::remove_model;
 
                 // Handle wake-word model removal separately
                 if name == "wake-word" {
@@ -773,9 +961,9 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                     return Ok(());
                 }
 
-                let model: WhisperModel = name.parse().map_err(|()| {
+                let model: ModelSpec = name.parse().map_err(|()| {
                     anyhow::anyhow!(
-                        "Unknown model '{}'. Available: tiny, base, small, medium, large-v3, wake-word, m2m100-418m, m2m100-1.2b",
+                        "Unknown model '{}'. Run 'openhush model list' to see available models",
                         name
                     )
                 })?;
@@ -783,6 +971,194 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                 remove_model(model)?;
                 println!("Removed model: {}", model.filename());
             }
+            ModelAction::Verify => {
+                use engine::whisper::format_size;
+                use manifest::{ModelVerifier, VerifyStatus};
+
+                let models = config::Config::load().map(|c| c.models).unwrap_or_default();
+                match manifest::refresh_pinned(&models).await {
+                    Ok(true) => println!("Fetched model manifest."),
+                    Ok(false) => {}
+                    Err(e) => eprintln!("Using cached model manifest: {}", e),
+                }
+
+                let mut verifier = ModelVerifier::new()?;
+                let reports = verifier.verify_all()?;
+                if reports.is_empty() {
+                    println!("No models downloaded.");
+                    return Ok(());
+                }
+
+                let mut failed = 0;
+                println!("{:<48} {:>10}  Status", "File", "Size");
+                println!("{}", "-".repeat(76));
+                for report in &reports {
+                    let status = match &report.status {
+                        VerifyStatus::Pinned => "ok (manifest)".to_string(),
+                        VerifyStatus::Recorded => "ok (recorded)".to_string(),
+                        VerifyStatus::New => "recorded now".to_string(),
+                        VerifyStatus::Quarantined(path) => {
+                            failed += 1;
+                            format!("CORRUPT, moved to {}", path.display())
+                        }
+                    };
+                    println!(
+                        "{:<48} {:>10}  {}",
+                        report.path,
+                        format_size(report.size),
+                        status
+                    );
+                }
+
+                if failed > 0 {
+                    anyhow::bail!(
+                        "{} file(s) failed verification. Download them again with 'openhush model download'",
+                        failed
+                    );
+                }
+                println!("\nAll {} file(s) verified.", reports.len());
+            }
+            ModelAction::Recommend {
+                latency,
+                max_memory,
+                clip,
+                apply,
+            } => {
+                use engine::recommend::{
+                    benchmark_model, preset_for, recommend, test_clip, Budget,
+                };
+                use engine::whisper::{format_size, list_downloaded_models};
+
+                let mut config = config::Config::load().unwrap_or_default();
+                let models = list_downloaded_models();
+                if models.is_empty() {
+                    anyhow::bail!("No models downloaded. Run: openhush model download small");
+                }
+
+                let audio = match clip {
+                    Some(path) => input::load_wav_file(
+                        std::path::Path::new(&path),
+                        config.audio.resampling_quality,
+                    )?,
+                    None => test_clip(),
+                };
+                let language = config.transcription.language.clone();
+                let use_gpu = config.transcription.device.to_lowercase() != "cpu";
+
+                println!(
+                    "Benchmarking {} model(s) on {:.1}s of audio (GPU: {})...\n",
+                    models.len(),
+                    audio.duration_secs(),
+                    use_gpu
+                );
+
+                let mut results = Vec::new();
+                for spec in models {
+                    match benchmark_model(spec, &audio, &language, use_gpu) {
+                        Ok(result) => results.push(result),
+                        Err(e) => eprintln!("Skipping {}: {}", spec, e),
+                    }
+                }
+
+                let budget = Budget {
+                    latency_secs: latency,
+                    max_memory_bytes: max_memory.map(|mb| mb * 1024 * 1024),
+                };
+
+                println!(
+                    "{:<20} {:>8} {:>9} {:>6} {:>10}  Budget",
+                    "Model", "Load", "Latency", "RTF", "Memory"
+                );
+                println!("{}", "-".repeat(66));
+                for result in &results {
+                    println!(
+                        "{:<20} {:>7.1}s {:>8.2}s {:>6.2} {:>9}{}  {}",
+                        result.spec.name(),
+                        result.load_secs,
+                        result.latency_secs,
+                        result.rtf,
+                        format_size(result.memory_bytes),
+                        if result.memory_estimated { "*" } else { " " },
+                        if budget.fits(result) {
+                            "ok"
+                        } else {
+                            "too slow/large"
+                        }
+                    );
+                }
+                if results.iter().any(|r| r.memory_estimated) {
+                    println!("* memory not measurable, model file size shown");
+                }
+                if use_gpu {
+                    println!("Memory is RAM only; GPU memory is not included.");
+                }
+
+                let Some(rec) = recommend(&results, &budget, &language) else {
+                    anyhow::bail!("No suitable model for language '{}'", language);
+                };
+                let best = &results[rec.index];
+                let preset = preset_for(&best.spec);
+
+                println!();
+                if rec.within_budget {
+                    println!("Recommended: {} (preset: {:?})", best.spec.name(), preset);
+                } else {
+                    println!(
+                        "No model fits a {:.1}s budget; fastest is {} ({:.2}s)",
+                        latency,
+                        best.spec.name(),
+                        best.latency_secs
+                    );
+                }
+
+                if apply {
+                    config.transcription.preset = preset;
+                    config.transcription.model = best.spec.name();
+                    config.save()?;
+                    println!("Saved to config. Restart the daemon to use it.");
+                } else {
+                    println!("Run with --apply to use it.");
+                }
+            }
+            ModelAction::Import { path } => {
+                use manifest::VerifyStatus;
+                use model_store::ImportStatus;
+
+                let mut verifier = manifest::ModelVerifier::new()?;
+                let imported = model_store::import(&mut verifier, std::path::Path::new(&path))?;
+                if imported.is_empty() {
+                    println!("No model files found in {}", path);
+                    return Ok(());
+                }
+                for file in &imported {
+                    match &file.status {
+                        ImportStatus::Installed(VerifyStatus::Pinned) => {
+                            println!("Installed {} (matches manifest)", file.path)
+                        }
+                        ImportStatus::Installed(VerifyStatus::Recorded) => {
+                            println!("Installed {} (matches bundle)", file.path)
+                        }
+                        ImportStatus::Installed(_) => println!("Installed {}", file.path),
+                        ImportStatus::AlreadyInstalled => {
+                            println!("Skipped {} (already installed)", file.path)
+                        }
+                    }
+                }
+            }
+            ModelAction::Export { output } => {
+                use engine::whisper::format_size;
+
+                println!("Verifying installed models...");
+                let mut verifier = manifest::ModelVerifier::new()?;
+                let summary = model_store::export(&mut verifier, std::path::Path::new(&output))?;
+                println!(
+                    "Exported {} file(s) ({}) to {}",
+                    summary.files,
+                    format_size(summary.bytes),
+                    output
+                );
+                println!("Install on another machine with: openhush model import <bundle>");
+            }
             ModelAction::Load => {
                 #[cfg(target_os = "linux")]
                 {
@@ -912,6 +1288,7 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                         let type_str = match device.device_type {
                             input::AudioDeviceType::Microphone => "mic",
                             input::AudioDeviceType::Monitor => "monitor",
+                            input::AudioDeviceType::Application => "app",
                         };
                         let default_str = if device.is_default { "✓" } else { "" };
                         let channels = device.channel_names.join(", ");
@@ -948,9 +1325,15 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                     std::process::exit(1);
                 }
 
-                config.audio.input_device = Some(id.clone());
-                config.save()?;
-                println!("Input device set to: {}", id);
+                if let Some(app) = input::app_from_device_id(&id) {
+                    config.audio.capture_app = Some(app.to_string());
+                    config.save()?;
+                    println!("Recording application set to: {}", app);
+                } else {
+                    config.audio.input_device = Some(id.clone());
+                    config.save()?;
+                    println!("Input device set to: {}", id);
+                }
             }
             DeviceAction::Channels { selection } => {
                 let channels = config::ChannelSelection::from_cli_arg(&selection)
@@ -969,6 +1352,198 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                     }
                 }
             }
+            DeviceAction::Calibrate {
+                noise_secs,
+                speech_secs,
+                dry_run,
+            } => {
+                use input::calibration::Calibration;
+                use std::io::BufRead;
+                use std::time::Duration;
+
+                if noise_secs < 1.0 || speech_secs < 2.0 {
+                    eprintln!("Record at least 1s of silence and 2s of speech.");
+                    std::process::exit(1);
+                }
+
+                let mut config = config::Config::load().unwrap_or_default();
+                let channel_mix = match &config.audio.channels {
+                    config::ChannelSelection::All => input::ChannelMix::All,
+                    config::ChannelSelection::Select(channels) => {
+                        input::ChannelMix::Select(channels.clone())
+                    }
+                };
+                let recorder = input::AudioRecorder::new_always_on_with_device(
+                    noise_secs + speech_secs + 5.0,
+                    config.audio.resampling_quality,
+                    channel_mix,
+                    config.audio.input_device.as_deref(),
+                )?;
+                println!("Calibrating {}\n", recorder.device_name());
+
+                println!(
+                    "Step 1/2: stay quiet for {:.0} seconds while the room noise is measured...",
+                    noise_secs
+                );
+                // Skip the first moments while the stream settles
+                std::thread::sleep(Duration::from_secs_f32(noise_secs + 0.5));
+                let noise = recorder
+                    .extract_last(noise_secs)
+                    .ok_or_else(|| anyhow::anyhow!("No audio captured"))?;
+
+                println!(
+                    "Step 2/2: press Enter, then read aloud at your normal volume for {:.0} seconds.",
+                    speech_secs
+                );
+                std::io::stdin().lock().read_line(&mut String::new())?;
+                println!("Recording...");
+                std::thread::sleep(Duration::from_secs_f32(speech_secs));
+                let speech = recorder
+                    .extract_last(speech_secs)
+                    .ok_or_else(|| anyhow::anyhow!("No audio captured"))?;
+
+                let calibration = Calibration::measure(&noise, &speech)?;
+                println!("\nMeasurements:");
+                println!("  Noise floor:  {:>6.1} dBFS", calibration.noise_floor_db);
+                println!("  Speech level: {:>6.1} dBFS", calibration.speech_rms_db);
+                println!("  Peak:         {:>6.1} dBFS", calibration.peak_db);
+                println!("  SNR:          {:>6.1} dB", calibration.snr_db);
+
+                let rec = calibration.recommend(
+                    config.audio.normalization.target_db,
+                    config.audio.limiter.ceiling_db,
+                );
+                println!("\nRecommended settings:");
+                println!(
+                    "  audio.normalization.target_db = {:.1}",
+                    rec.normalization_target_db
+                );
+                match rec.compression_threshold_db {
+                    Some(threshold) => {
+                        println!("  audio.compression.threshold_db = {:.1}", threshold)
+                    }
+                    None => println!("  audio.compression = off"),
+                }
+                println!(
+                    "  audio.limiter = {}",
+                    if rec.limiter { "on" } else { "off" }
+                );
+                match rec.noise_reduction_strength {
+                    Some(strength) => {
+                        println!("  audio.noise_reduction.strength = {:.2}", strength)
+                    }
+                    None => println!("  audio.noise_reduction = off"),
+                }
+                println!("  vad.threshold = {:.2}", rec.vad_threshold);
+
+                for warning in &rec.warnings {
+                    println!("\n⚠ {}", warning);
+                }
+
+                if dry_run {
+                    println!("\nDry run: config not changed.");
+                } else {
+                    rec.apply(&mut config);
+                    config.validate()?;
+                    config.save()?;
+                    println!("\nSaved to config. Restart the daemon to apply.");
+                }
+            }
+        },
+
+        Commands::WakeWord { action } => match action {
+            WakeWordAction::Eval {
+                positive,
+                negative,
+                model,
+            } => {
+                use input::wake_word::{
+                    recommend_threshold, sweep_thresholds, WakeWordDetector, WAKE_WORD_SAMPLE_RATE,
+                };
+
+                let app_config = config::Config::load().unwrap_or_default();
+                let mut wake_config = app_config.wake_word.clone();
+                if let Some(path) = model {
+                    wake_config.model_path = Some(path);
+                    wake_config.models.clear();
+                }
+                let mut detector = WakeWordDetector::new(&wake_config)?;
+                let names: Vec<String> = detector.names().iter().map(|n| n.to_string()).collect();
+
+                // Peak score of every model on each clip in a folder
+                let mut score_dir = |dir: &str| -> anyhow::Result<Vec<(String, Vec<f32>)>> {
+                    let mut files: Vec<_> = std::fs::read_dir(dir)?
+                        .filter_map(|entry| entry.ok().map(|e| e.path()))
+                        .filter(|p| {
+                            p.extension()
+                                .is_some_and(|ext| ext.eq_ignore_ascii_case("wav"))
+                        })
+                        .collect();
+                    files.sort();
+                    if files.is_empty() {
+                        anyhow::bail!("No WAV clips found in {}", dir);
+                    }
+
+                    let mut scores = Vec::with_capacity(files.len());
+                    for file in files {
+                        let audio =
+                            input::load_wav_file(&file, app_config.audio.resampling_quality)?;
+                        // Trailing silence lets a wake word at the very end register
+                        let mut samples = audio.samples;
+                        samples.resize(samples.len() + WAKE_WORD_SAMPLE_RATE as usize, 0.0);
+                        let name = file
+                            .file_name()
+                            .map(|n| n.to_string_lossy().into_owned())
+                            .unwrap_or_default();
+                        scores.push((name, detector.peak_scores(&samples)));
+                    }
+                    Ok(scores)
+                };
+
+                let positives = score_dir(&positive)?;
+                let negatives = score_dir(&negative)?;
+                println!(
+                    "Evaluated {} positive and {} negative clip(s) from {} and {}",
+                    positives.len(),
+                    negatives.len(),
+                    positive,
+                    negative
+                );
+
+                for (i, name) in names.iter().enumerate() {
+                    let pos: Vec<f32> = positives.iter().map(|(_, s)| s[i]).collect();
+                    let neg: Vec<f32> = negatives.iter().map(|(_, s)| s[i]).collect();
+                    let stats = sweep_thresholds(&pos, &neg);
+
+                    println!("\nWake word \"{}\"\n", name);
+                    println!("  {:<10} {:<8} False positives", "Threshold", "Recall");
+                    println!("  {}", "-".repeat(36));
+                    for s in &stats {
+                        println!(
+                            "  {:<10.2} {:<8} {:.0}%",
+                            s.threshold,
+                            format!("{:.0}%", s.recall * 100.0),
+                            s.false_positive_rate * 100.0
+                        );
+                    }
+
+                    if let Some(threshold) = recommend_threshold(&stats) {
+                        println!("\n  Recommended threshold: {:.2}", threshold);
+                        for (clip, scores) in &positives {
+                            if scores[i] < threshold {
+                                println!("    missed      {:.2}  {}", scores[i], clip);
+                            }
+                        }
+                        for (clip, scores) in &negatives {
+                            if scores[i] >= threshold {
+                                println!("    false alarm {:.2}  {}", scores[i], clip);
+                            }
+                        }
+                    }
+                }
+
+                println!("\nSet it with `threshold` in [wake_word] or per model in [[wake_word.models]].");
+            }
         },
 
         Commands::Transcribe {
@@ -989,6 +1564,37 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
             // Load config for model and language settings
             let config = config::Config::load().unwrap_or_default();
 
+            // A running daemon transcribes the file on its worker pool as
+            // batch work, so it never holds up push-to-talk
+            if model_override.is_none() {
+                let start = Instant::now();
+                if let Some((text, duration_secs, language)) = transcribe_with_daemon(file_path)? {
+                    let elapsed = start.elapsed();
+                    let rtf = elapsed.as_secs_f32() / duration_secs.max(f32::EPSILON);
+                    if format == "json" {
+                        let json = serde_json::json!({
+                            "text": text,
+                            "language": language,
+                            "audio_duration_secs": duration_secs,
+                            "transcription_time_ms": elapsed.as_millis() as u64,
+                            "real_time_factor": rtf,
+                            "daemon": true,
+                        });
+                        println!("{}", serde_json::to_string_pretty(&json)?);
+                    } else {
+                        println!("\n--- Transcription ---");
+                        println!("{}", text);
+                        println!("---");
+                        println!(
+                            "\nTime: {:.0}ms (RTF: {:.3}x, by the running daemon)",
+                            elapsed.as_millis(),
+                            rtf
+                        );
+                    }
+                    return Ok(());
+                }
+            }
+
             // Load audio file
             info!("Loading audio file: {}", file);
             let start_load = Instant::now();
@@ -1003,17 +1609,11 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
             );
 
             // Initialize Whisper engine
-            let data_dir = config::Config::data_dir()?;
             let model_name = model_override
                 .as_deref()
-                .unwrap_or_else(|| config.transcription.effective_model());
-            let model: engine::whisper::WhisperModel = model_name.parse().map_err(|()| {
-                anyhow::anyhow!(
-                    "Unknown model '{}'. Available: tiny, base, small, medium, large-v3",
-                    model_name
-                )
-            })?;
-            let model_path = data_dir.join("models").join(model.filename());
+                .unwrap_or_else(|| config.transcription.effective_accurate_model());
+            let model_path =
+                engine::catalog::resolve_model(model_name, &config.transcription)?.path()?;
 
             if !model_path.exists() {
                 anyhow::bail!(
@@ -1025,17 +1625,18 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
 
             println!(
                 "Loading model: {} (GPU: {})",
-                model.filename(),
+                model_name,
                 config.transcription.device.to_lowercase() != "cpu"
             );
 
             let start_model = Instant::now();
-            let engine = engine::whisper::WhisperEngine::new(
+            let mut engine = engine::whisper::WhisperEngine::new(
                 &model_path,
                 &config.transcription.language,
                 config.transcription.translate,
                 config.transcription.device.to_lowercase() != "cpu",
             )?;
+            engine.set_allowed_languages(&config.transcription.allowed_languages);
             let model_time = start_model.elapsed();
             println!("Model loaded in {:.0}ms", model_time.as_millis());
 
@@ -1058,7 +1659,7 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                         "audio_duration_secs": audio.duration_secs(),
                         "transcription_time_ms": transcribe_time.as_millis() as u64,
                         "real_time_factor": rtf,
-                        "model": format!("{:?}", model).to_lowercase(),
+                        "model": model_name,
                     });
                     println!("{}", serde_json::to_string_pretty(&json)?);
                 }
@@ -1082,17 +1683,24 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
             diarize,
             live,
             format,
+            app,
+            translate_to,
+            bilingual,
         } => {
             use crate::recording::{RecordingConfig, RecordingSession};
 
-            let audio_source = source.parse().map_err(|e: String| anyhow::anyhow!(e))?;
-
             let config = RecordingConfig {
-                source: audio_source,
+                source: source
+                    .map(|s| s.parse())
+                    .transpose()
+                    .map_err(|e: String| anyhow::anyhow!(e))?,
                 output_file: output,
                 enable_diarization: diarize,
                 live_mode: live,
                 output_format: format.parse().unwrap_or_default(),
+                app,
+                translate_to,
+                bilingual_layout: bilingual.parse().map_err(|e: String| anyhow::anyhow!(e))?,
             };
 
             info!("Starting recording session...");
@@ -1134,6 +1742,10 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                         let status = client.get_status().await?;
                         println!("Recording toggled: {}", status);
                     }
+                    RecordingAction::Replay { secs } => {
+                        client.transcribe_last(secs.map_or(0.0, f64::from)).await?;
+                        println!("Transcribing recent audio");
+                    }
                     RecordingAction::Status => {
                         let status = client.get_status().await?;
                         let queue = client.queue_depth().await?;
@@ -1201,6 +1813,22 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                             std::process::exit(1);
                         }
                     },
+                    RecordingAction::Replay { secs } => {
+                        match client.send(IpcCommand::TranscribeLast { secs }) {
+                            Ok(response) => {
+                                if response.ok {
+                                    println!("Transcribing recent audio");
+                                } else if let Some(err) = response.error {
+                                    eprintln!("Failed to replay: {}", err);
+                                    std::process::exit(1);
+                                }
+                            }
+                            Err(e) => {
+                                eprintln!("Failed to replay: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
                     RecordingAction::Status => match client.send(IpcCommand::Status) {
                         Ok(response) => {
                             if response.ok {
@@ -1295,6 +1923,20 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                     } else {
                         println!("API key: NOT configured (API is open!)");
                     }
+                    println!(
+                        "TLS: {}",
+                        if config.api.tls.enabled {
+                            "enabled"
+                        } else {
+                            "disabled"
+                        }
+                    );
+                    if let Some(ref socket) = config.api.unix_socket {
+                        println!(
+                            "Unix socket: {} (mode {:o})",
+                            socket, config.api.unix_socket_mode
+                        );
+                    }
                     println!(
                         "Swagger UI: {}",
                         if config.api.swagger_ui {
@@ -1312,6 +1954,36 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
             }
         },
 
+        Commands::ApiCert { action } => match action {
+            ApiCertAction::Generate { hosts, force } => {
+                let dir = api::tls::tls_dir()?;
+                if dir.join("cert.pem").exists() && !force {
+                    anyhow::bail!(
+                        "Certificate already exists in {}. Use --force to overwrite.",
+                        dir.display()
+                    );
+                }
+
+                let hosts = if hosts.is_empty() {
+                    api::tls::default_hostnames()
+                } else {
+                    hosts
+                };
+                let (cert, key) = api::tls::generate_self_signed(&dir, &hosts)?;
+
+                println!(
+                    "Generated self-signed certificate for: {}\n",
+                    hosts.join(", ")
+                );
+                println!("  Certificate: {}", cert.display());
+                println!("  Private key: {}", key.display());
+                println!("\nTo enable HTTPS, add to ~/.config/openhush/config.toml:\n");
+                println!("[api.tls]");
+                println!("enabled = true");
+                println!("\nClients must trust this certificate (e.g. curl --cacert).");
+            }
+        },
+
         Commands::Summarize {
             input,
             template,
@@ -1345,15 +2017,9 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                 let audio = input::load_wav_file(file_path, config.audio.resampling_quality)?;
 
                 // Initialize Whisper engine
-                let data_dir = config::Config::data_dir()?;
-                let model_name = config.transcription.effective_model();
-                let model: engine::whisper::WhisperModel = model_name.parse().map_err(|()| {
-                    anyhow::anyhow!(
-                        "Unknown model '{}'. Available: tiny, base, small, medium, large-v3",
-                        model_name
-                    )
-                })?;
-                let model_path = data_dir.join("models").join(model.filename());
+                let model_name = config.transcription.effective_accurate_model();
+                let model_path =
+                    engine::catalog::resolve_model(model_name, &config.transcription)?.path()?;
 
                 if !model_path.exists() {
                     anyhow::bail!(
@@ -1363,12 +2029,13 @@ async fn async_main(cli: Cli) -> anyhow::Result<()> {
                     );
                 }
 
-                let engine = engine::whisper::WhisperEngine::new(
+                let mut engine = engine::whisper::WhisperEngine::new(
                     &model_path,
                     &config.transcription.language,
                     config.transcription.translate,
                     config.transcription.device.to_lowercase() != "cpu",
                 )?;
+                engine.set_allowed_languages(&config.transcription.allowed_languages);
                 let result = engine.transcribe(&audio)?;
                 result.text
             } else {
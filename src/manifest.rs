@@ -0,0 +1,778 @@
+//! Model manifest, download mirrors and integrity verification.
+//!
+//! Every file below the models directory (Whisper, M2M-100 and wake word
+//! models) is checked against a SHA-256 hash and size:
+//!
+//! - The built-in manifest (`src/model_hashes.toml`, generated by
+//!   `contrib/update-model-manifest.sh`) pins every model downloaded from
+//!   its upstream URL.
+//! - `models/manifest.toml` adds or replaces pinned hashes, e.g. published
+//!   alongside a model mirror and fetched from `models.manifest_url`.
+//! - `models/verified.toml` records the hash of every file that passed
+//!   verification. Files without a pinned hash (custom mirrors without a
+//!   manifest) are recorded when they are downloaded, so later corruption
+//!   or tampering is still detected.
+//!
+//! Files that fail verification are moved to `models/quarantine/` so they are
+//! neither loaded nor resumed from.
+//!
+//! Downloads go to the mirror configured for the model family in
+//! `[models.mirrors]`, falling back to the upstream URL.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use thiserror::Error;
+use tracing::{debug, info, warn};
+
+use crate::config::{Config, ModelsConfig};
+
+/// Hashes of the upstream model files, built into the binary
+const BUILTIN_MANIFEST: &str = include_str!("model_hashes.toml");
+
+/// Pinned manifest file name (in the models directory)
+pub const PINNED_MANIFEST: &str = "manifest.toml";
+
+/// Record of verified files (in the models directory)
+const VERIFIED_RECORD: &str = "verified.toml";
+
+/// Directory for files that failed verification
+const QUARANTINE_DIR: &str = "quarantine";
+
+/// Hash list at the root of an export bundle
+pub const BUNDLE_MANIFEST: &str = "bundle.toml";
+
+#[derive(Error, Debug)]
+pub enum IntegrityError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid manifest {0}: {1}")]
+    InvalidManifest(PathBuf, String),
+
+    #[error("Failed to fetch manifest: {0}")]
+    Fetch(String),
+
+    #[error("Cannot determine models directory: {0}")]
+    NoModelsDir(String),
+
+    #[error(
+        "{name} failed integrity check ({reason}). The file was moved to {} - \
+         download it again with 'openhush model download'",
+        quarantined.display()
+    )]
+    Mismatch {
+        name: String,
+        reason: String,
+        quarantined: PathBuf,
+    },
+}
+
+/// Expected hash and size of one model file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArtifactEntry {
+    /// Path relative to the models directory, `/`-separated
+    /// (e.g. `ggml-small.bin`, `m2m100/m2m100-418m/tokenizer.json`)
+    pub path: String,
+
+    /// Lowercase hex SHA-256
+    pub sha256: String,
+
+    /// Size in bytes
+    pub size: u64,
+
+    /// Modification time (Unix seconds) when the file was last hashed.
+    /// Only used in the local record to skip re-hashing unchanged files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified: Option<u64>,
+}
+
+/// A list of model files with their expected hashes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModelManifest {
+    #[serde(default, rename = "artifact")]
+    pub artifacts: Vec<ArtifactEntry>,
+}
+
+impl ModelManifest {
+    /// Parse a manifest from TOML.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let manifest: Self = toml::from_str(content).map_err(|e| e.to_string())?;
+        for entry in &manifest.artifacts {
+            if entry.sha256.len() != 64 || hex::decode(&entry.sha256).is_err() {
+                return Err(format!("invalid sha256 for {}", entry.path));
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// Load a manifest file. A missing file is an empty manifest.
+    pub fn load(path: &Path) -> Result<Self, IntegrityError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)?;
+        Self::parse(&content).map_err(|e| IntegrityError::InvalidManifest(path.to_path_buf(), e))
+    }
+
+    /// Write the manifest atomically.
+    pub fn save(&self, path: &Path) -> Result<(), IntegrityError> {
+        let content = toml::to_string_pretty(self)
+            .map_err(|e| IntegrityError::InvalidManifest(path.to_path_buf(), e.to_string()))?;
+        let temp_path = path.with_extension("toml.tmp");
+        fs::write(&temp_path, content)?;
+        fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+
+    /// Find the entry for a relative path.
+    pub fn get(&self, path: &str) -> Option<&ArtifactEntry> {
+        self.artifacts.iter().find(|a| a.path == path)
+    }
+
+    /// Insert or replace the entry for `entry.path`.
+    pub fn upsert(&mut self, entry: ArtifactEntry) {
+        match self.artifacts.iter_mut().find(|a| a.path == entry.path) {
+            Some(existing) => *existing = entry,
+            None => self.artifacts.push(entry),
+        }
+    }
+}
+
+/// Outcome of verifying one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifyStatus {
+    /// Matches the pinned manifest
+    Pinned,
+    /// Matches the hash recorded when it was downloaded (or listed in an
+    /// import bundle)
+    Recorded,
+    /// No reference hash yet; the current hash was recorded
+    New,
+    /// Failed verification and was moved to the given path
+    Quarantined(PathBuf),
+}
+
+/// Verification result for `openhush model verify`.
+#[derive(Debug, Clone)]
+pub struct VerifyReport {
+    /// Path relative to the models directory
+    pub path: String,
+    pub size: u64,
+    pub status: VerifyStatus,
+}
+
+/// How strictly a file is checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckMode {
+    /// Fresh download: compare against the pinned manifest only
+    Download,
+    /// Before loading: skip hashing if unchanged since the last check
+    Load,
+    /// Always hash
+    Full,
+}
+
+/// Verifies model files below a models directory.
+pub struct ModelVerifier {
+    models_dir: PathBuf,
+    pinned: ModelManifest,
+    record: ModelManifest,
+}
+
+impl ModelVerifier {
+    /// Open the verifier for the default models directory.
+    pub fn new() -> Result<Self, IntegrityError> {
+        Self::open(models_dir()?)
+    }
+
+    /// Open the verifier for a models directory.
+    pub fn open(models_dir: impl Into<PathBuf>) -> Result<Self, IntegrityError> {
+        Self::with_builtin(models_dir, builtin_manifest())
+    }
+
+    /// Open the verifier with `builtin` as the base of the pinned hashes.
+    /// Entries in the models directory's `manifest.toml` take precedence.
+    pub fn with_builtin(
+        models_dir: impl Into<PathBuf>,
+        builtin: ModelManifest,
+    ) -> Result<Self, IntegrityError> {
+        let models_dir = models_dir.into();
+        let mut pinned = builtin;
+        for entry in ModelManifest::load(&models_dir.join(PINNED_MANIFEST))?.artifacts {
+            pinned.upsert(entry);
+        }
+        let record = ModelManifest::load(&models_dir.join(VERIFIED_RECORD))?;
+        Ok(Self {
+            models_dir,
+            pinned,
+            record,
+        })
+    }
+
+    /// Verify a completed download in `temp` before it is renamed to `dest`.
+    ///
+    /// A corrupt download is quarantined, so it is not resumed from.
+    pub fn verify_download(
+        &mut self,
+        temp: &Path,
+        dest: &Path,
+    ) -> Result<VerifyStatus, IntegrityError> {
+        let Some(rel) = self.relative(dest) else {
+            return Ok(VerifyStatus::New);
+        };
+        self.check(&rel, temp, CheckMode::Download, None)
+    }
+
+    /// Verify an imported file in `temp` before it is renamed to `dest`.
+    ///
+    /// `listed` is the file's entry in the import bundle, if any; the pinned
+    /// manifest still takes precedence.
+    pub fn verify_import(
+        &mut self,
+        temp: &Path,
+        dest: &Path,
+        listed: Option<&ArtifactEntry>,
+    ) -> Result<VerifyStatus, IntegrityError> {
+        let Some(rel) = self.relative(dest) else {
+            return Ok(VerifyStatus::New);
+        };
+        self.check(&rel, temp, CheckMode::Download, listed.cloned())
+    }
+
+    /// Verify a model file before loading it.
+    ///
+    /// Files outside the models directory (custom models) are not checked.
+    pub fn verify_on_load(&mut self, path: &Path) -> Result<VerifyStatus, IntegrityError> {
+        let Some(rel) = self.relative(path) else {
+            debug!("Not verifying {} (outside models dir)", path.display());
+            return Ok(VerifyStatus::New);
+        };
+        self.check(&rel, path, CheckMode::Load, None)
+    }
+
+    /// Hash every file in the models directory.
+    ///
+    /// Mismatching files are quarantined and reported rather than failing the
+    /// whole run. Record entries for deleted files are dropped.
+    pub fn verify_all(&mut self) -> Result<Vec<VerifyReport>, IntegrityError> {
+        let files = if self.models_dir.exists() {
+            model_files(&self.models_dir)?
+        } else {
+            Vec::new()
+        };
+
+        let mut reports = Vec::new();
+        for file in files {
+            let Some(rel) = self.relative(&file) else {
+                continue;
+            };
+            let size = fs::metadata(&file)?.len();
+            let status = match self.check(&rel, &file, CheckMode::Full, None) {
+                Ok(status) => status,
+                Err(IntegrityError::Mismatch { quarantined, .. }) => {
+                    VerifyStatus::Quarantined(quarantined)
+                }
+                Err(e) => return Err(e),
+            };
+            reports.push(VerifyReport {
+                path: rel,
+                size,
+                status,
+            });
+        }
+
+        let before = self.record.artifacts.len();
+        let models_dir = self.models_dir.clone();
+        self.record
+            .artifacts
+            .retain(|a| models_dir.join(&a.path).exists());
+        if self.record.artifacts.len() != before {
+            self.save_record()?;
+        }
+
+        Ok(reports)
+    }
+
+    /// Models directory this verifier checks.
+    pub fn models_dir(&self) -> &Path {
+        &self.models_dir
+    }
+
+    /// Hash recorded for a path relative to the models directory.
+    pub fn recorded(&self, rel: &str) -> Option<&ArtifactEntry> {
+        self.record.get(rel)
+    }
+
+    /// Check `file` as the model at `rel`. Downloads are compared against
+    /// `listed` instead of the local record.
+    fn check(
+        &mut self,
+        rel: &str,
+        file: &Path,
+        mode: CheckMode,
+        listed: Option<ArtifactEntry>,
+    ) -> Result<VerifyStatus, IntegrityError> {
+        let meta = fs::metadata(file)?;
+        let size = meta.len();
+        let modified = modified_secs(&meta);
+
+        let pinned = self.pinned.get(rel).cloned();
+        let recorded = match mode {
+            CheckMode::Download => listed,
+            _ => self.record.get(rel).cloned(),
+        };
+
+        if mode == CheckMode::Load {
+            if let Some(ref r) = recorded {
+                let pin_agrees = pinned
+                    .as_ref()
+                    .is_none_or(|p| p.sha256.eq_ignore_ascii_case(&r.sha256));
+                if r.size == size && modified.is_some() && r.modified == modified && pin_agrees {
+                    return Ok(if pinned.is_some() {
+                        VerifyStatus::Pinned
+                    } else {
+                        VerifyStatus::Recorded
+                    });
+                }
+            }
+        }
+
+        // The pinned manifest wins over the local record
+        let expected = pinned.as_ref().or(recorded.as_ref());
+        if expected.is_none() && mode == CheckMode::Download {
+            warn!(
+                "No pinned hash for {}; recording it on first use. Set models.manifest_url \
+                 to verify files from a mirror",
+                rel
+            );
+        }
+
+        if let Some(e) = expected {
+            if e.size != size {
+                let reason = format!("expected {} bytes, got {}", e.size, size);
+                return Err(self.quarantine(rel, file, reason)?);
+            }
+        }
+
+        info!("Verifying {}...", rel);
+        let sha256 = sha256_file(file)?;
+
+        if let Some(e) = expected {
+            if !e.sha256.eq_ignore_ascii_case(&sha256) {
+                let reason = format!("expected sha256 {}, got {}", e.sha256, sha256);
+                return Err(self.quarantine(rel, file, reason)?);
+            }
+        }
+
+        let status = if pinned.is_some() {
+            VerifyStatus::Pinned
+        } else if expected.is_some() {
+            VerifyStatus::Recorded
+        } else {
+            VerifyStatus::New
+        };
+
+        self.record.upsert(ArtifactEntry {
+            path: rel.to_string(),
+            sha256,
+            size,
+            modified,
+        });
+        self.save_record()?;
+
+        Ok(status)
+    }
+
+    /// Move a bad file into quarantine and build the error describing it.
+    fn quarantine(
+        &self,
+        rel: &str,
+        file: &Path,
+        reason: String,
+    ) -> Result<IntegrityError, IntegrityError> {
+        let dir = self.models_dir.join(QUARANTINE_DIR);
+        fs::create_dir_all(&dir)?;
+        let quarantined = dir.join(rel.replace('/', "__"));
+        if quarantined.exists() {
+            fs::remove_file(&quarantined)?;
+        }
+        fs::rename(file, &quarantined)?;
+        warn!(
+            "{} failed integrity check ({}), quarantined at {}",
+            rel,
+            reason,
+            quarantined.display()
+        );
+
+        Ok(IntegrityError::Mismatch {
+            name: rel.to_string(),
+            reason,
+            quarantined,
+        })
+    }
+
+    fn save_record(&self) -> Result<(), IntegrityError> {
+        fs::create_dir_all(&self.models_dir)?;
+        self.record.save(&self.models_dir.join(VERIFIED_RECORD))
+    }
+
+    /// Path relative to the models directory, or None if outside it.
+    fn relative(&self, path: &Path) -> Option<String> {
+        let rel = path.strip_prefix(&self.models_dir).ok()?;
+        let parts: Vec<_> = rel
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join("/"))
+        }
+    }
+}
+
+/// Default models directory (`<data dir>/models`).
+fn models_dir() -> Result<PathBuf, IntegrityError> {
+    Config::data_dir()
+        .map(|d| d.join("models"))
+        .map_err(|e| IntegrityError::NoModelsDir(e.to_string()))
+}
+
+/// The manifest built into the binary.
+pub fn builtin_manifest() -> ModelManifest {
+    ModelManifest::parse(BUILTIN_MANIFEST).unwrap_or_else(|e| {
+        warn!("Ignoring invalid built-in model manifest: {}", e);
+        ModelManifest::default()
+    })
+}
+
+/// Verify a completed download off the async runtime; hashing a large
+/// model takes seconds. See [`ModelVerifier::verify_download`].
+pub async fn verify_download(temp: PathBuf, dest: PathBuf) -> Result<VerifyStatus, IntegrityError> {
+    tokio::task::spawn_blocking(move || ModelVerifier::new()?.verify_download(&temp, &dest))
+        .await
+        .map_err(|e| IntegrityError::Io(std::io::Error::other(e)))?
+}
+
+/// Fetch the pinned manifest from `models.manifest_url`, if configured.
+///
+/// Returns false if no URL is configured. On failure the previously fetched
+/// manifest stays in place.
+pub async fn refresh_pinned(models: &ModelsConfig) -> Result<bool, IntegrityError> {
+    let Some(ref url) = models.manifest_url else {
+        return Ok(false);
+    };
+
+    let response = reqwest::get(url)
+        .await
+        .map_err(|e| IntegrityError::Fetch(e.to_string()))?;
+    if !response.status().is_success() {
+        return Err(IntegrityError::Fetch(format!(
+            "HTTP {}: {}",
+            response.status(),
+            url
+        )));
+    }
+    let content = response
+        .text()
+        .await
+        .map_err(|e| IntegrityError::Fetch(e.to_string()))?;
+
+    let manifest = ModelManifest::parse(&content).map_err(IntegrityError::Fetch)?;
+    let dir = models_dir()?;
+    fs::create_dir_all(&dir)?;
+    manifest.save(&dir.join(PINNED_MANIFEST))?;
+
+    info!(
+        "Fetched model manifest with {} entries from {}",
+        manifest.artifacts.len(),
+        url
+    );
+    Ok(true)
+}
+
+/// SHA-256 of a file as lowercase hex.
+pub fn sha256_file(path: &Path) -> Result<String, IntegrityError> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 1024 * 1024];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+fn modified_secs(meta: &fs::Metadata) -> Option<u64> {
+    meta.modified()
+        .ok()?
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_secs())
+}
+
+/// Model files below a models directory (or an extracted bundle), sorted.
+///
+/// Bookkeeping files, partial downloads and quarantined files are skipped.
+pub fn model_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    collect_files(dir, dir, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn collect_files(root: &Path, dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            if dir == root && path.file_name().is_some_and(|n| n == QUARANTINE_DIR) {
+                continue;
+            }
+            collect_files(root, &path, files)?;
+            continue;
+        }
+
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        let bookkeeping = dir == root
+            && (name == PINNED_MANIFEST || name == VERIFIED_RECORD || name == BUNDLE_MANIFEST);
+        if bookkeeping || name.ends_with(".tmp") {
+            continue;
+        }
+        files.push(path);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const ABC_SHA256: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+    /// Verifier without the built-in manifest, so tests can use any content
+    /// for the well-known model names.
+    fn open_verifier(dir: &Path) -> ModelVerifier {
+        ModelVerifier::with_builtin(dir, ModelManifest::default()).unwrap()
+    }
+
+    fn pin(dir: &Path, path: &str, sha256: &str, size: u64) {
+        let manifest = ModelManifest {
+            artifacts: vec![ArtifactEntry {
+                path: path.into(),
+                sha256: sha256.into(),
+                size,
+                modified: None,
+            }],
+        };
+        manifest.save(&dir.join(PINNED_MANIFEST)).unwrap();
+    }
+
+    #[test]
+    fn test_sha256_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("abc");
+        fs::write(&path, b"abc").unwrap();
+        assert_eq!(sha256_file(&path).unwrap(), ABC_SHA256);
+    }
+
+    #[test]
+    fn test_parse_manifest() {
+        let toml = format!(
+            r#"
+            [[artifact]]
+            path = "ggml-small.bin"
+            sha256 = "{}"
+            size = 3
+        "#,
+            ABC_SHA256
+        );
+        let manifest = ModelManifest::parse(&toml).unwrap();
+        assert_eq!(manifest.get("ggml-small.bin").unwrap().size, 3);
+        assert!(manifest.get("ggml-base.bin").is_none());
+
+        let bad = r#"
+            [[artifact]]
+            path = "ggml-small.bin"
+            sha256 = "not-a-hash"
+            size = 3
+        "#;
+        assert!(ModelManifest::parse(bad).is_err());
+    }
+
+    #[test]
+    fn test_download_records_unpinned_file() {
+        let dir = tempdir().unwrap();
+        let temp = dir.path().join("ggml-small.bin.tmp");
+        let dest = dir.path().join("ggml-small.bin");
+        fs::write(&temp, b"abc").unwrap();
+
+        let mut verifier = open_verifier(dir.path());
+        assert_eq!(
+            verifier.verify_download(&temp, &dest).unwrap(),
+            VerifyStatus::New
+        );
+        fs::rename(&temp, &dest).unwrap();
+
+        // Reopened verifier checks against the record
+        let mut verifier = open_verifier(dir.path());
+        assert_eq!(
+            verifier.verify_on_load(&dest).unwrap(),
+            VerifyStatus::Recorded
+        );
+    }
+
+    #[test]
+    fn test_download_mismatch_is_quarantined() {
+        let dir = tempdir().unwrap();
+        pin(dir.path(), "ggml-small.bin", ABC_SHA256, 3);
+
+        let temp = dir.path().join("ggml-small.bin.tmp");
+        let dest = dir.path().join("ggml-small.bin");
+        fs::write(&temp, b"abd").unwrap();
+
+        let mut verifier = open_verifier(dir.path());
+        let err = verifier.verify_download(&temp, &dest).unwrap_err();
+        assert!(matches!(err, IntegrityError::Mismatch { .. }));
+        assert!(!temp.exists());
+        assert!(dir.path().join("quarantine/ggml-small.bin").exists());
+
+        // A matching download passes
+        fs::write(&temp, b"abc").unwrap();
+        assert_eq!(
+            verifier.verify_download(&temp, &dest).unwrap(),
+            VerifyStatus::Pinned
+        );
+    }
+
+    #[test]
+    fn test_builtin_manifest_pins_downloads() {
+        assert_eq!(
+            ModelManifest::parse(BUILTIN_MANIFEST).unwrap(),
+            builtin_manifest()
+        );
+
+        let dir = tempdir().unwrap();
+        let builtin = ModelManifest {
+            artifacts: vec![ArtifactEntry {
+                path: "ggml-small.bin".into(),
+                sha256: ABC_SHA256.into(),
+                size: 3,
+                modified: None,
+            }],
+        };
+        let temp = dir.path().join("ggml-small.bin.tmp");
+        let dest = dir.path().join("ggml-small.bin");
+
+        // Without any local manifest, the first download is already checked
+        fs::write(&temp, b"abd").unwrap();
+        let mut verifier = ModelVerifier::with_builtin(dir.path(), builtin.clone()).unwrap();
+        assert!(verifier.verify_download(&temp, &dest).is_err());
+
+        fs::write(&temp, b"abc").unwrap();
+        assert_eq!(
+            verifier.verify_download(&temp, &dest).unwrap(),
+            VerifyStatus::Pinned
+        );
+
+        // A fetched manifest replaces the built-in entry (e.g. for a mirror)
+        let sha = hex::encode(Sha256::digest(b"abd"));
+        pin(dir.path(), "ggml-small.bin", &sha, 3);
+        let mut verifier = ModelVerifier::with_builtin(dir.path(), builtin).unwrap();
+        fs::write(&temp, b"abd").unwrap();
+        assert_eq!(
+            verifier.verify_download(&temp, &dest).unwrap(),
+            VerifyStatus::Pinned
+        );
+    }
+
+    #[test]
+    fn test_builtin_manifest_pins_default_models() {
+        use crate::config::TranscriptionPreset;
+        use crate::engine::whisper::WhisperModel;
+
+        let manifest = builtin_manifest();
+        for preset in [
+            TranscriptionPreset::Instant,
+            TranscriptionPreset::Balanced,
+            TranscriptionPreset::Quality,
+            TranscriptionPreset::Custom,
+        ] {
+            let model: WhisperModel = preset.model().parse().unwrap();
+            let entry = manifest.get(model.filename());
+            assert!(
+                entry.is_some_and(|e| e.sha256.len() == 64 && e.size > 0),
+                "{} is not pinned; run contrib/update-model-manifest.sh",
+                model.filename()
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_detects_size_change() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("m2m100/m2m100-418m/tokenizer.json");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, b"abc").unwrap();
+
+        let mut verifier = open_verifier(dir.path());
+        assert_eq!(verifier.verify_on_load(&path).unwrap(), VerifyStatus::New);
+
+        fs::write(&path, b"abcd").unwrap();
+        assert!(verifier.verify_on_load(&path).is_err());
+        assert!(!path.exists());
+        assert!(dir
+            .path()
+            .join("quarantine/m2m100__m2m100-418m__tokenizer.json")
+            .exists());
+    }
+
+    #[test]
+    fn test_verify_all_detects_tampering() {
+        let dir = tempdir().unwrap();
+        let good = dir.path().join("ggml-base.bin");
+        let bad = dir.path().join("ggml-small.bin");
+        fs::write(&good, b"abc").unwrap();
+        fs::write(&bad, b"abc").unwrap();
+
+        let mut verifier = open_verifier(dir.path());
+        let reports = verifier.verify_all().unwrap();
+        assert_eq!(reports.len(), 2);
+        assert!(reports.iter().all(|r| r.status == VerifyStatus::New));
+
+        // Same size, different content
+        fs::write(&bad, b"xyz").unwrap();
+        fs::write(dir.path().join("ggml-tiny.bin.tmp"), b"partial").unwrap();
+
+        let reports = verifier.verify_all().unwrap();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].path, "ggml-base.bin");
+        assert_eq!(reports[0].status, VerifyStatus::Recorded);
+        assert_eq!(reports[1].path, "ggml-small.bin");
+        assert!(matches!(reports[1].status, VerifyStatus::Quarantined(_)));
+
+        // The quarantined file's record is dropped
+        let reports = verifier.verify_all().unwrap();
+        assert_eq!(reports.len(), 1);
+        assert!(verifier.record.get("ggml-small.bin").is_none());
+    }
+
+    #[test]
+    fn test_files_outside_models_dir_are_skipped() {
+        let models = tempdir().unwrap();
+        let other = tempdir().unwrap();
+        let path = other.path().join("custom.bin");
+        fs::write(&path, b"abc").unwrap();
+
+        let mut verifier = open_verifier(models.path());
+        assert_eq!(verifier.verify_on_load(&path).unwrap(), VerifyStatus::New);
+        assert!(!models.path().join(VERIFIED_RECORD).exists());
+    }
+}
@@ -0,0 +1,636 @@
+//! Offline model import and export.
+//!
+//! Bundles are tar archives (optionally gzip-compressed) laid out like the
+//! models directory:
+//!
+//! ```text
+//! bundle.toml                        SHA-256 and size of every file
+//! ggml-small.bin                     Whisper models
+//! m2m100/m2m100-418m/tokenizer.json  M2M-100 models
+//! wake_word/melspectrogram.onnx      openWakeWord models
+//! diarization/segmentation-3.0.onnx  Diarization models
+//! ```
+//!
+//! Imported files are verified against `bundle.toml` and the pinned
+//! manifest before they are moved into place. Archives must carry a
+//! `bundle.toml` listing every model file in them; bundles with unlisted
+//! files are rejected before anything is installed.
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Component, Path, PathBuf};
+use thiserror::Error;
+use tracing::{info, warn};
+
+use crate::input::wake_word;
+use crate::manifest::{
+    model_files, IntegrityError, ModelManifest, ModelVerifier, VerifyStatus, BUNDLE_MANIFEST,
+};
+use crate::translation::{M2M100Model, M2M100_MODEL_FILES};
+
+/// Diarization model file names. Defined here rather than in `diarization`
+/// so imports recognise them without the `diarization` feature.
+pub const DIARIZATION_SEGMENTATION_MODEL: &str = "segmentation-3.0.onnx";
+pub const DIARIZATION_EMBEDDING_MODEL: &str = "wespeaker_en_voxceleb_CAM++.onnx";
+
+#[derive(Error, Debug)]
+pub enum ModelStoreError {
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error(transparent)]
+    Integrity(#[from] IntegrityError),
+
+    #[error(
+        "Cannot tell which model '{0}' is. Import the models directory layout \
+         (e.g. m2m100/m2m100-418m/), or register custom Whisper models in \
+         [[transcription.custom_models]]"
+    )]
+    UnknownFile(String),
+
+    #[error("Invalid bundle: {0}")]
+    InvalidBundle(String),
+
+    #[error("{0} model file(s) failed verification; fix them before exporting")]
+    Corrupt(usize),
+
+    #[error("No models installed")]
+    NothingToExport,
+}
+
+/// Outcome of importing one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportStatus {
+    /// Verified and installed
+    Installed(VerifyStatus),
+    /// A file with this name is already installed
+    AlreadyInstalled,
+}
+
+/// Import result for one file.
+#[derive(Debug, Clone)]
+pub struct ImportedFile {
+    /// Path relative to the models directory
+    pub path: String,
+    pub status: ImportStatus,
+}
+
+/// Export result.
+#[derive(Debug, Clone)]
+pub struct ExportSummary {
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// Whether `rel` (relative to the models directory) is a known model file.
+pub fn is_model_path(rel: &str) -> bool {
+    let parts: Vec<&str> = rel.split('/').collect();
+    match parts.as_slice() {
+        [file] => file.starts_with("ggml-") && file.ends_with(".bin"),
+        ["wake_word", file] | ["diarization", file] => file.ends_with(".onnx"),
+        ["m2m100", model, file] => {
+            model.parse::<M2M100Model>().is_ok() && M2M100_MODEL_FILES.contains(file)
+        }
+        _ => false,
+    }
+}
+
+/// Destination of a single model file, recognised by its name.
+fn single_file_path(name: &str) -> Option<String> {
+    if name.starts_with("ggml-") && name.ends_with(".bin") {
+        return Some(name.to_string());
+    }
+    if [
+        wake_word::MELSPEC_MODEL,
+        wake_word::EMBEDDING_MODEL,
+        wake_word::WAKE_WORD_MODEL,
+    ]
+    .contains(&name)
+    {
+        return Some(format!("wake_word/{}", name));
+    }
+    if [DIARIZATION_SEGMENTATION_MODEL, DIARIZATION_EMBEDDING_MODEL].contains(&name) {
+        return Some(format!("diarization/{}", name));
+    }
+    None
+}
+
+/// Convert a relative path to the `/`-separated form, rejecting absolute
+/// paths and `..`.
+fn normalize(path: &Path) -> Option<String> {
+    let mut parts = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_str()?.to_string()),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join("/"))
+    }
+}
+
+/// Import models from a single file, a directory or a bundle.
+///
+/// Directories are either in the models directory layout (e.g. an extracted
+/// bundle) or a single M2M-100 model directory such as `m2m100-418m/`.
+pub fn import(
+    verifier: &mut ModelVerifier,
+    source: &Path,
+) -> Result<Vec<ImportedFile>, ModelStoreError> {
+    if source.is_dir() {
+        return import_dir(verifier, source);
+    }
+
+    let name = source
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default()
+        .to_string();
+    if let Some(rel) = single_file_path(&name) {
+        let mut file = fs::File::open(source)?;
+        let status = install(verifier, &ModelManifest::default(), &rel, &mut file)?;
+        return Ok(vec![ImportedFile { path: rel, status }]);
+    }
+
+    if is_archive(source)? {
+        return import_archive(verifier, source);
+    }
+
+    Err(ModelStoreError::UnknownFile(name))
+}
+
+fn import_dir(
+    verifier: &mut ModelVerifier,
+    dir: &Path,
+) -> Result<Vec<ImportedFile>, ModelStoreError> {
+    // A bare M2M-100 model directory
+    let m2m100_model = dir
+        .file_name()
+        .and_then(|n| n.to_str())
+        .and_then(|n| n.parse::<M2M100Model>().ok())
+        .filter(|_| M2M100_MODEL_FILES.iter().all(|f| dir.join(f).exists()));
+    if let Some(model) = m2m100_model {
+        let mut imported = Vec::new();
+        for file in M2M100_MODEL_FILES {
+            let rel = format!("m2m100/{}/{}", model.name(), file);
+            let mut source = fs::File::open(dir.join(file))?;
+            let status = install(verifier, &ModelManifest::default(), &rel, &mut source)?;
+            imported.push(ImportedFile { path: rel, status });
+        }
+        return Ok(imported);
+    }
+
+    // An extracted bundle is held to its bundle.toml; a plain copy of a
+    // models directory is only checked against the pinned manifest
+    let bundle_path = dir.join(BUNDLE_MANIFEST);
+    let listed_only = bundle_path.exists();
+    let bundle = ModelManifest::load(&bundle_path)?;
+
+    let mut files = Vec::new();
+    for path in model_files(dir)? {
+        let Some(rel) = path.strip_prefix(dir).ok().and_then(normalize) else {
+            continue;
+        };
+        if !is_model_path(&rel) {
+            warn!("Skipping {} (not a model file)", rel);
+            continue;
+        }
+        if listed_only {
+            check_listed(&bundle, &rel)?;
+        }
+        files.push((rel, path));
+    }
+
+    let mut imported = Vec::new();
+    for (rel, path) in files {
+        let mut source = fs::File::open(&path)?;
+        let status = install(verifier, &bundle, &rel, &mut source)?;
+        imported.push(ImportedFile { path: rel, status });
+    }
+    Ok(imported)
+}
+
+fn import_archive(
+    verifier: &mut ModelVerifier,
+    path: &Path,
+) -> Result<Vec<ImportedFile>, ModelStoreError> {
+    // First pass: read bundle.toml wherever it is and check that it lists
+    // every model file, so nothing is installed from a tampered bundle
+    let mut bundle = None;
+    let mut models = Vec::new();
+    let mut archive = tar::Archive::new(open_archive(path)?);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let Some(rel) = archive_path(&entry)? else {
+            continue;
+        };
+        if rel == BUNDLE_MANIFEST {
+            if bundle.is_some() {
+                return Err(ModelStoreError::InvalidBundle(format!(
+                    "more than one {}",
+                    BUNDLE_MANIFEST
+                )));
+            }
+            let mut content = String::new();
+            entry.read_to_string(&mut content)?;
+            bundle = Some(
+                ModelManifest::parse(&content)
+                    .map_err(|e| ModelStoreError::InvalidBundle(format!("{}: {}", rel, e)))?,
+            );
+        } else if is_model_path(&rel) {
+            models.push(rel);
+        } else {
+            warn!("Skipping {} (not a model file)", rel);
+        }
+    }
+    let bundle =
+        bundle.ok_or_else(|| ModelStoreError::InvalidBundle(format!("no {}", BUNDLE_MANIFEST)))?;
+    for rel in &models {
+        check_listed(&bundle, rel)?;
+    }
+
+    // Second pass: install
+    let mut imported = Vec::new();
+    let mut archive = tar::Archive::new(open_archive(path)?);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let Some(rel) = archive_path(&entry)? else {
+            continue;
+        };
+        if !models.contains(&rel) {
+            continue;
+        }
+        let status = install(verifier, &bundle, &rel, &mut entry)?;
+        imported.push(ImportedFile { path: rel, status });
+    }
+
+    Ok(imported)
+}
+
+/// Normalized path of a regular file in an archive, None for other entries.
+fn archive_path<R: Read>(entry: &tar::Entry<R>) -> Result<Option<String>, ModelStoreError> {
+    if !entry.header().entry_type().is_file() {
+        return Ok(None);
+    }
+    normalize(&entry.path()?)
+        .map(Some)
+        .ok_or_else(|| ModelStoreError::InvalidBundle("entry path escapes the bundle".into()))
+}
+
+/// Reject a bundled model file that bundle.toml does not list.
+fn check_listed(bundle: &ModelManifest, rel: &str) -> Result<(), ModelStoreError> {
+    if bundle.get(rel).is_none() {
+        return Err(ModelStoreError::InvalidBundle(format!(
+            "{} is not listed in {}",
+            rel, BUNDLE_MANIFEST
+        )));
+    }
+    Ok(())
+}
+
+/// Copy one file into the models directory, verifying it first.
+fn install(
+    verifier: &mut ModelVerifier,
+    bundle: &ModelManifest,
+    rel: &str,
+    source: &mut dyn Read,
+) -> Result<ImportStatus, ModelStoreError> {
+    let dest = verifier.models_dir().join(rel);
+    if dest.exists() {
+        info!("{} already installed, skipping", rel);
+        return Ok(ImportStatus::AlreadyInstalled);
+    }
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let temp = PathBuf::from(format!("{}.tmp", dest.display()));
+    {
+        let mut file = fs::File::create(&temp)?;
+        io::copy(source, &mut file)?;
+    }
+
+    let status = verifier.verify_import(&temp, &dest, bundle.get(rel))?;
+    fs::rename(&temp, &dest)?;
+
+    info!("Imported {}", rel);
+    Ok(ImportStatus::Installed(status))
+}
+
+/// Whether `path` starts like a tar or gzip file.
+fn is_archive(path: &Path) -> Result<bool, ModelStoreError> {
+    let mut header = [0u8; 262];
+    let mut file = fs::File::open(path)?;
+    let n = file.read(&mut header)?;
+    let gzip = n >= 2 && header[..2] == [0x1f, 0x8b];
+    let tar = n >= 262 && &header[257..262] == b"ustar";
+    Ok(gzip || tar)
+}
+
+fn open_archive(path: &Path) -> Result<Box<dyn Read>, ModelStoreError> {
+    let mut magic = [0u8; 2];
+    let n = fs::File::open(path)?.read(&mut magic)?;
+    let file = fs::File::open(path)?;
+    if n == 2 && magic == [0x1f, 0x8b] {
+        Ok(Box::new(GzDecoder::new(file)))
+    } else {
+        Ok(Box::new(file))
+    }
+}
+
+/// Pack all installed models into a bundle at `output`.
+///
+/// Every file is re-hashed first; the hashes are stored in `bundle.toml`.
+/// The bundle is gzip-compressed unless `output` ends in `.tar`.
+pub fn export(
+    verifier: &mut ModelVerifier,
+    output: &Path,
+) -> Result<ExportSummary, ModelStoreError> {
+    let reports = verifier.verify_all()?;
+    let corrupt = reports
+        .iter()
+        .filter(|r| matches!(r.status, VerifyStatus::Quarantined(_)))
+        .count();
+    if corrupt > 0 {
+        return Err(ModelStoreError::Corrupt(corrupt));
+    }
+
+    let mut bundle = ModelManifest::default();
+    for report in &reports {
+        if !is_model_path(&report.path) {
+            continue;
+        }
+        if let Some(entry) = verifier.recorded(&report.path) {
+            let mut entry = entry.clone();
+            entry.modified = None;
+            bundle.artifacts.push(entry);
+        }
+    }
+    if bundle.artifacts.is_empty() {
+        return Err(ModelStoreError::NothingToExport);
+    }
+
+    let file = fs::File::create(output)?;
+    let gzip = output.extension().is_none_or(|ext| ext != "tar");
+    if gzip {
+        let encoder = GzEncoder::new(file, Compression::default());
+        write_bundle(encoder, verifier.models_dir(), &bundle)?.finish()?;
+    } else {
+        write_bundle(file, verifier.models_dir(), &bundle)?;
+    }
+
+    Ok(ExportSummary {
+        files: bundle.artifacts.len(),
+        bytes: bundle.artifacts.iter().map(|a| a.size).sum(),
+    })
+}
+
+fn write_bundle<W: Write>(
+    writer: W,
+    models_dir: &Path,
+    bundle: &ModelManifest,
+) -> Result<W, ModelStoreError> {
+    let mut builder = tar::Builder::new(writer);
+
+    let content = toml::to_string_pretty(bundle)
+        .map_err(|e| ModelStoreError::InvalidBundle(e.to_string()))?;
+    let mut header = tar::Header::new_gnu();
+    header.set_size(content.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    builder.append_data(&mut header, BUNDLE_MANIFEST, content.as_bytes())?;
+
+    for entry in &bundle.artifacts {
+        builder.append_path_with_name(models_dir.join(&entry.path), &entry.path)?;
+    }
+
+    Ok(builder.into_inner()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    /// Verifier without the built-in manifest, so tests can use any content
+    /// for the well-known model names.
+    fn open_verifier(dir: &Path) -> ModelVerifier {
+        ModelVerifier::with_builtin(dir, ModelManifest::default()).unwrap()
+    }
+
+    fn write(path: &Path, content: &[u8]) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_is_model_path() {
+        assert!(is_model_path("ggml-small.bin"));
+        assert!(is_model_path("ggml-medium-q5_0.bin"));
+        assert!(is_model_path("wake_word/melspectrogram.onnx"));
+        assert!(is_model_path("diarization/segmentation-3.0.onnx"));
+        assert!(is_model_path("m2m100/m2m100-418m/tokenizer.json"));
+
+        assert!(!is_model_path("verified.toml"));
+        assert!(!is_model_path("m2m100/unknown/tokenizer.json"));
+        assert!(!is_model_path("m2m100/m2m100-418m/notes.txt"));
+        assert!(!is_model_path("other/ggml-small.bin"));
+    }
+
+    #[test]
+    fn test_single_file_path() {
+        assert_eq!(
+            single_file_path("ggml-small.bin").as_deref(),
+            Some("ggml-small.bin")
+        );
+        assert_eq!(
+            single_file_path(DIARIZATION_SEGMENTATION_MODEL).as_deref(),
+            Some("diarization/segmentation-3.0.onnx")
+        );
+        assert_eq!(single_file_path("model.onnx"), None);
+    }
+
+    #[test]
+    fn test_normalize_rejects_traversal() {
+        assert_eq!(
+            normalize(Path::new("./wake_word/x.onnx")).as_deref(),
+            Some("wake_word/x.onnx")
+        );
+        assert!(normalize(Path::new("../ggml-small.bin")).is_none());
+        assert!(normalize(Path::new("/etc/passwd")).is_none());
+    }
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let source = tempdir().unwrap();
+        write(&source.path().join("ggml-tiny.bin"), b"tiny model");
+        write(
+            &source.path().join("wake_word/melspectrogram.onnx"),
+            b"melspec",
+        );
+        write(&source.path().join("ggml-base.bin.tmp"), b"partial");
+
+        let bundle_dir = tempdir().unwrap();
+        let bundle_path = bundle_dir.path().join("models.tar.gz");
+        let mut verifier = open_verifier(source.path());
+        let summary = export(&mut verifier, &bundle_path).unwrap();
+        assert_eq!(summary.files, 2);
+
+        let target = tempdir().unwrap();
+        let mut verifier = open_verifier(target.path());
+        let imported = import(&mut verifier, &bundle_path).unwrap();
+        assert_eq!(imported.len(), 2);
+        assert!(imported
+            .iter()
+            .all(|f| f.status == ImportStatus::Installed(VerifyStatus::Recorded)));
+        assert_eq!(
+            fs::read(target.path().join("ggml-tiny.bin")).unwrap(),
+            b"tiny model"
+        );
+
+        // Importing again leaves installed files alone
+        let imported = import(&mut verifier, &bundle_path).unwrap();
+        assert!(imported
+            .iter()
+            .all(|f| f.status == ImportStatus::AlreadyInstalled));
+    }
+
+    #[test]
+    fn test_import_rejects_bundle_mismatch() {
+        let source = tempdir().unwrap();
+        write(&source.path().join("ggml-tiny.bin"), b"tiny model");
+        let bundle = ModelManifest {
+            artifacts: vec![crate::manifest::ArtifactEntry {
+                path: "ggml-tiny.bin".into(),
+                sha256: "0".repeat(64),
+                size: 10,
+                modified: None,
+            }],
+        };
+        bundle.save(&source.path().join(BUNDLE_MANIFEST)).unwrap();
+
+        let target = tempdir().unwrap();
+        let mut verifier = open_verifier(target.path());
+        let err = import(&mut verifier, source.path()).unwrap_err();
+        assert!(matches!(
+            err,
+            ModelStoreError::Integrity(IntegrityError::Mismatch { .. })
+        ));
+        assert!(!target.path().join("ggml-tiny.bin").exists());
+    }
+
+    /// Write a tar archive with the given entries, in order.
+    fn write_tar(path: &Path, entries: &[(&str, &[u8])]) {
+        let mut builder = tar::Builder::new(fs::File::create(path).unwrap());
+        for (name, content) in entries {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, name, *content).unwrap();
+        }
+        builder.finish().unwrap();
+    }
+
+    fn bundle_toml(path: &str, content: &[u8]) -> String {
+        use sha2::Digest;
+        let bundle = ModelManifest {
+            artifacts: vec![crate::manifest::ArtifactEntry {
+                path: path.into(),
+                sha256: hex::encode(sha2::Sha256::digest(content)),
+                size: content.len() as u64,
+                modified: None,
+            }],
+        };
+        toml::to_string_pretty(&bundle).unwrap()
+    }
+
+    #[test]
+    fn test_import_archive_manifest_last() {
+        let dir = tempdir().unwrap();
+        let archive = dir.path().join("models.tar");
+        let manifest = bundle_toml("ggml-tiny.bin", b"tiny model");
+        write_tar(
+            &archive,
+            &[
+                ("ggml-tiny.bin", b"tiny model"),
+                (BUNDLE_MANIFEST, manifest.as_bytes()),
+            ],
+        );
+
+        let target = tempdir().unwrap();
+        let mut verifier = open_verifier(target.path());
+        let imported = import(&mut verifier, &archive).unwrap();
+        assert_eq!(
+            imported[0].status,
+            ImportStatus::Installed(VerifyStatus::Recorded)
+        );
+    }
+
+    #[test]
+    fn test_import_archive_rejects_unlisted_files() {
+        let dir = tempdir().unwrap();
+        let manifest = bundle_toml("ggml-tiny.bin", b"tiny model");
+
+        let unlisted = dir.path().join("unlisted.tar");
+        write_tar(
+            &unlisted,
+            &[
+                (BUNDLE_MANIFEST, manifest.as_bytes()),
+                ("ggml-tiny.bin", b"tiny model"),
+                ("ggml-base.bin", b"base model"),
+            ],
+        );
+        let unverified = dir.path().join("unverified.tar");
+        write_tar(&unverified, &[("ggml-tiny.bin", b"tiny model")]);
+
+        let target = tempdir().unwrap();
+        let mut verifier = open_verifier(target.path());
+        for archive in [&unlisted, &unverified] {
+            assert!(matches!(
+                import(&mut verifier, archive),
+                Err(ModelStoreError::InvalidBundle(_))
+            ));
+        }
+        // Nothing is installed from a rejected bundle
+        assert!(!target.path().join("ggml-tiny.bin").exists());
+    }
+
+    #[test]
+    fn test_import_single_files() {
+        let source = tempdir().unwrap();
+        let model = source.path().join("ggml-small.bin");
+        write(&model, b"small");
+        let m2m100 = source.path().join("m2m100-418m");
+        for file in M2M100_MODEL_FILES {
+            write(&m2m100.join(file), file.as_bytes());
+        }
+        let unknown = source.path().join("model.bin");
+        write(&unknown, b"?");
+
+        let target = tempdir().unwrap();
+        let mut verifier = open_verifier(target.path());
+
+        import(&mut verifier, &model).unwrap();
+        assert!(target.path().join("ggml-small.bin").exists());
+
+        let imported = import(&mut verifier, &m2m100).unwrap();
+        assert_eq!(imported.len(), 3);
+        assert!(target
+            .path()
+            .join("m2m100/m2m100-418m/tokenizer.json")
+            .exists());
+
+        assert!(matches!(
+            import(&mut verifier, &unknown),
+            Err(ModelStoreError::UnknownFile(_))
+        ));
+    }
+}
@@ -45,6 +45,15 @@ pub struct ActionContext {
     pub model: String,
     /// Transcription sequence ID
     pub seq_id: u64,
+    /// Language of `text` when it is a translation
+    pub language: Option<String>,
+    /// Whether `text` is a translation into one of `extra_targets`
+    /// rather than the typed output
+    pub extra_target: bool,
+    /// Round-trip similarity of the translation (0.0 to 1.0), if checked
+    pub similarity: Option<f32>,
+    /// The round trip diverged from the source beyond the threshold
+    pub flagged: bool,
 }
 
 impl ActionContext {
@@ -56,9 +65,32 @@ impl ActionContext {
             timestamp: Utc::now(),
             model,
             seq_id,
+            language: None,
+            extra_target: false,
+            similarity: None,
+            flagged: false,
         }
     }
 
+    /// Mark the text as a translation into `language`.
+    pub fn with_language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    /// Mark the text as a translation into an extra target language.
+    pub fn for_extra_target(mut self) -> Self {
+        self.extra_target = true;
+        self
+    }
+
+    /// Attach the result of a back-translation check.
+    pub fn with_round_trip(mut self, similarity: f32, flagged: bool) -> Self {
+        self.similarity = Some(similarity);
+        self.flagged = flagged;
+        self
+    }
+
     /// Substitute variables in a template string.
     ///
     /// Supported variables:
@@ -70,6 +102,9 @@ impl ActionContext {
     /// - `{duration}` - Recording duration in seconds
     /// - `{model}` - Whisper model used
     /// - `{seq_id}` - Transcription sequence ID
+    /// - `{language}` - Language of a translation (empty otherwise)
+    /// - `{similarity}` - Back-translation similarity, 0.00 to 1.00 (empty if unchecked)
+    /// - `{flagged}` - "true" if the back-translation check failed
     pub fn substitute(&self, template: &str) -> String {
         let text_escaped = serde_json::to_string(&self.text)
             .unwrap_or_else(|_| self.text.clone())
@@ -87,6 +122,15 @@ impl ActionContext {
             .replace("{duration}", &format!("{:.1}", self.duration_secs))
             .replace("{model}", &self.model)
             .replace("{seq_id}", &self.seq_id.to_string())
+            .replace("{language}", self.language.as_deref().unwrap_or_default())
+            .replace(
+                "{similarity}",
+                &self
+                    .similarity
+                    .map(|s| format!("{:.2}", s))
+                    .unwrap_or_default(),
+            )
+            .replace("{flagged}", &self.flagged.to_string())
     }
 
     /// Sanitize text for safe shell execution.
@@ -116,6 +160,10 @@ pub enum ActionConfig {
         /// Whether this action is enabled
         #[serde(default = "default_true")]
         enabled: bool,
+        /// Only run for the translation into this language ("*" = every
+        /// output); unset runs for the typed output
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
     },
 
     /// Make an HTTP request.
@@ -137,6 +185,10 @@ pub enum ActionConfig {
         /// Whether this action is enabled
         #[serde(default = "default_true")]
         enabled: bool,
+        /// Only run for the translation into this language ("*" = every
+        /// output); unset runs for the typed output
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
     },
 
     /// Append or write to a file.
@@ -152,6 +204,10 @@ pub enum ActionConfig {
         /// Whether this action is enabled
         #[serde(default = "default_true")]
         enabled: bool,
+        /// Only run for the translation into this language ("*" = every
+        /// output); unset runs for the typed output
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
     },
 }
 
@@ -181,6 +237,24 @@ impl ActionConfig {
         }
     }
 
+    /// Language filter of this action.
+    pub fn language(&self) -> Option<&str> {
+        match self {
+            ActionConfig::Shell { language, .. }
+            | ActionConfig::Http { language, .. }
+            | ActionConfig::File { language, .. } => language.as_deref(),
+        }
+    }
+
+    /// Whether this action runs for the output described by `ctx`.
+    pub fn runs_for(&self, ctx: &ActionContext) -> bool {
+        match self.language() {
+            None => !ctx.extra_target,
+            Some("*") => true,
+            Some(language) => ctx.language.as_deref() == Some(language),
+        }
+    }
+
     /// Get a display name for this action type.
     pub fn name(&self) -> &'static str {
         match self {
@@ -395,21 +469,31 @@ impl ActionRunner {
         !self.actions.is_empty()
     }
 
-    /// Execute all actions with the given context.
+    /// Check if any action is bound to a translation language.
+    pub fn has_language_actions(&self) -> bool {
+        self.actions.iter().any(|a| a.language().is_some())
+    }
+
+    /// Execute all actions for the output described by the context.
     ///
     /// Errors in individual actions are logged but don't stop other actions.
     pub async fn run_all(&self, ctx: &ActionContext) {
-        if self.actions.is_empty() {
+        let actions: Vec<_> = self.actions.iter().filter(|a| a.runs_for(ctx)).collect();
+        if actions.is_empty() {
             return;
         }
 
         info!(
-            "Running {} post-transcription action(s) for seq_id={}",
-            self.actions.len(),
-            ctx.seq_id
+            "Running {} post-transcription action(s) for seq_id={}{}",
+            actions.len(),
+            ctx.seq_id,
+            ctx.language
+                .as_deref()
+                .map(|l| format!(" ({})", l))
+                .unwrap_or_default()
         );
 
-        for action in &self.actions {
+        for action in actions {
             if let Err(e) = action.execute(ctx).await {
                 error!("Action '{}' failed: {}", action.name(), e);
                 // Continue with other actions
@@ -500,6 +584,21 @@ mod tests {
         assert_eq!(result, "Text: Hello, Model: base, ID: 1");
     }
 
+    #[test]
+    fn test_substitute_translation_variables() {
+        let ctx = ActionContext::new("Hallo".to_string(), 1.0, "base".to_string(), 1);
+        assert_eq!(
+            ctx.substitute("[{language}|{similarity}|{flagged}]"),
+            "[||false]"
+        );
+
+        let ctx = ctx.with_language("de").with_round_trip(0.425, true);
+        assert_eq!(
+            ctx.substitute("[{language}|{similarity}|{flagged}]"),
+            "[de|0.42|true]"
+        );
+    }
+
     // ===================
     // Sanitization Tests
     // ===================
@@ -544,6 +643,7 @@ mod tests {
             command: "echo test".to_string(),
             timeout_secs: 30,
             enabled: true,
+            language: None,
         };
         assert!(action.is_enabled());
         assert_eq!(action.name(), "shell");
@@ -555,6 +655,7 @@ mod tests {
             command: "echo test".to_string(),
             timeout_secs: 30,
             enabled: false,
+            language: None,
         };
         assert!(!action.is_enabled());
     }
@@ -568,6 +669,7 @@ mod tests {
             headers: HashMap::new(),
             timeout_secs: 30,
             enabled: true,
+            language: None,
         };
         assert!(action.is_enabled());
         assert_eq!(action.name(), "http");
@@ -580,6 +682,7 @@ mod tests {
             format: "{text}\n".to_string(),
             append: true,
             enabled: true,
+            language: None,
         };
         assert!(action.is_enabled());
         assert_eq!(action.name(), "file");
@@ -603,10 +706,12 @@ enabled = true
                 command,
                 timeout_secs,
                 enabled,
+                language,
             } => {
                 assert_eq!(command, "echo '{text}'");
                 assert_eq!(timeout_secs, 10);
                 assert!(enabled);
+                assert_eq!(language, None);
             }
             _ => panic!("Expected Shell action"),
         }
@@ -663,11 +768,13 @@ enabled = true
                 format,
                 append,
                 enabled,
+                language,
             } => {
                 assert_eq!(path, "~/notes/{date}.md");
                 assert_eq!(format, "{text}\n");
                 assert!(append);
                 assert!(enabled);
+                assert_eq!(language, None);
             }
             _ => panic!("Expected File action"),
         }
@@ -693,6 +800,43 @@ command = "echo test"
         }
     }
 
+    #[test]
+    fn test_parse_action_language() {
+        let toml_str = r#"
+type = "file"
+path = "~/notes/fr.md"
+language = "fr"
+"#;
+        let action: ActionConfig = toml::from_str(toml_str).unwrap();
+        assert_eq!(action.language(), Some("fr"));
+    }
+
+    #[test]
+    fn test_action_runs_for_language() {
+        let action = |language: Option<&str>| ActionConfig::Shell {
+            command: "echo".to_string(),
+            timeout_secs: 30,
+            enabled: true,
+            language: language.map(String::from),
+        };
+        let ctx = || ActionContext::new("x".to_string(), 1.0, "base".to_string(), 1);
+        let primary = ctx().with_language("de");
+        let extra = ctx().with_language("fr").for_extra_target();
+
+        // Unfiltered actions only see the typed output
+        assert!(action(None).runs_for(&ctx()));
+        assert!(action(None).runs_for(&primary));
+        assert!(!action(None).runs_for(&extra));
+
+        assert!(action(Some("*")).runs_for(&primary));
+        assert!(action(Some("*")).runs_for(&extra));
+
+        assert!(action(Some("fr")).runs_for(&extra));
+        assert!(!action(Some("fr")).runs_for(&primary));
+        assert!(!action(Some("fr")).runs_for(&ctx()));
+        assert!(action(Some("de")).runs_for(&primary));
+    }
+
     // ===================
     // ActionRunner Tests
     // ===================
@@ -710,11 +854,13 @@ command = "echo test"
                 command: "echo 1".to_string(),
                 timeout_secs: 30,
                 enabled: true,
+                language: None,
             },
             ActionConfig::Shell {
                 command: "echo 2".to_string(),
                 timeout_secs: 30,
                 enabled: false, // disabled
+                language: None,
             },
         ];
         let runner = ActionRunner::new(actions);
@@ -728,6 +874,7 @@ command = "echo test"
             command: "echo 1".to_string(),
             timeout_secs: 30,
             enabled: false,
+            language: None,
         }];
         let runner = ActionRunner::new(actions);
         assert!(!runner.has_actions());
@@ -743,6 +890,7 @@ command = "echo test"
             command: "exit 1".to_string(), // Would fail if executed
             timeout_secs: 1,
             enabled: false,
+            language: None,
         };
         let ctx = ActionContext::new("test".to_string(), 1.0, "base".to_string(), 1);
 
@@ -757,6 +905,7 @@ command = "echo test"
             command: "echo '{text}'".to_string(),
             timeout_secs: 5,
             enabled: true,
+            language: None,
         };
         let ctx = ActionContext::new("hello".to_string(), 1.0, "base".to_string(), 1);
 
@@ -770,6 +919,7 @@ command = "echo test"
             command: "echo '{text}'".to_string(),
             timeout_secs: 5,
             enabled: true,
+            language: None,
         };
         // Malicious input
         let ctx = ActionContext::new("test $(whoami)".to_string(), 1.0, "base".to_string(), 1);
@@ -792,6 +942,7 @@ command = "echo test"
             format: "{text}\n".to_string(),
             append: false,
             enabled: true,
+            language: None,
         };
         let ctx = ActionContext::new("Hello from test".to_string(), 1.0, "base".to_string(), 1);
 
@@ -820,6 +971,7 @@ command = "echo test"
             format: "{text}\n".to_string(),
             append: true,
             enabled: true,
+            language: None,
         };
         let ctx = ActionContext::new("Line 2".to_string(), 1.0, "base".to_string(), 1);
 
@@ -845,12 +997,14 @@ command = "echo test"
                 command: "echo 'shell ran'".to_string(),
                 timeout_secs: 5,
                 enabled: true,
+                language: None,
             },
             ActionConfig::File {
                 path: temp_file.to_string_lossy().to_string(),
                 format: "{text}".to_string(),
                 append: false,
                 enabled: true,
+                language: None,
             },
         ];
 
@@ -6,7 +6,7 @@ pub mod paste;
 
 pub use actions::{ActionContext, ActionRunner};
 pub use clipboard::{copy_to_clipboard, ClipboardError};
-pub use paste::{paste_text, PasteError};
+pub use paste::{paste_text, replace_typed, PasteError};
 
 use crate::config::OutputConfig;
 use thiserror::Error;
@@ -60,8 +60,23 @@ impl OutputHandler {
         Ok(())
     }
 
+    /// Output a refined version of text that was already output.
+    ///
+    /// The refined text goes to the clipboard (if enabled). With `in_place`,
+    /// the typed draft is corrected at the cursor (if paste is enabled).
+    pub fn refine(&self, draft: &str, refined: &str, in_place: bool) -> Result<(), OutputError> {
+        if self.clipboard_enabled {
+            copy_to_clipboard(refined)?;
+        }
+
+        if in_place && self.paste_enabled {
+            replace_typed(draft, refined)?;
+        }
+
+        Ok(())
+    }
+
     /// Copy text to clipboard only
-    #[allow(dead_code)]
     pub fn copy_only(&self, text: &str) -> Result<(), OutputError> {
         copy_to_clipboard(text)?;
         Ok(())
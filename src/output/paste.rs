@@ -68,6 +68,57 @@ fn paste_by_typing(text: &str) -> Result<(), PasteError> {
     Ok(())
 }
 
+/// Replace text that was just typed at the cursor.
+///
+/// Only the part after the common prefix is erased (with Backspace) and
+/// retyped, so the cursor must still be at the end of `old`.
+pub fn replace_typed(old: &str, new: &str) -> Result<(), PasteError> {
+    use enigo::{Direction, Key};
+
+    let (erase, suffix) = retype_plan(old, new);
+    if erase == 0 && suffix.is_empty() {
+        return Ok(());
+    }
+
+    let mut enigo =
+        Enigo::new(&Settings::default()).map_err(|e| PasteError::InitFailed(format!("{:?}", e)))?;
+
+    thread::sleep(Duration::from_millis(50));
+
+    for _ in 0..erase {
+        enigo
+            .key(Key::Backspace, Direction::Click)
+            .map_err(|e| PasteError::TypeFailed(format!("{:?}", e)))?;
+    }
+    if !suffix.is_empty() {
+        enigo
+            .text(suffix)
+            .map_err(|e| PasteError::TypeFailed(format!("{:?}", e)))?;
+    }
+
+    info!(
+        "Replaced typed text ({} chars erased, {} chars typed)",
+        erase,
+        suffix.chars().count()
+    );
+
+    Ok(())
+}
+
+/// Work out how to turn `old` into `new` at the cursor.
+///
+/// Returns the number of characters to erase from the end of `old` and
+/// the text to type afterwards.
+fn retype_plan<'a>(old: &str, new: &'a str) -> (usize, &'a str) {
+    let common: usize = old
+        .chars()
+        .zip(new.chars())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum();
+    (old[common..].chars().count(), &new[common..])
+}
+
 /// Paste using Ctrl+V (requires text to be in clipboard)
 fn paste_by_ctrl_v() -> Result<(), PasteError> {
     use enigo::Key;
@@ -153,6 +204,27 @@ pub fn detect_paste_method() -> PasteMethod {
 mod tests {
     use super::*;
 
+    // ===================
+    // Retype Plan Tests
+    // ===================
+
+    #[test]
+    fn test_retype_plan_changed_suffix() {
+        assert_eq!(retype_plan("hello wrld", "hello world"), (3, "orld"));
+    }
+
+    #[test]
+    fn test_retype_plan_identical() {
+        assert_eq!(retype_plan("same text", "same text"), (0, ""));
+    }
+
+    #[test]
+    fn test_retype_plan_multibyte() {
+        assert_eq!(retype_plan("Grüße dich", "Grüße euch"), (4, "euch"));
+        assert_eq!(retype_plan("", "neu"), (0, "neu"));
+        assert_eq!(retype_plan("alt", ""), (3, ""));
+    }
+
     // ===================
     // PasteMethod Tests
     // ===================
@@ -0,0 +1,225 @@
+//! Whole-file transcriptions submitted to the running daemon.
+//!
+//! Uploads and `openhush transcribe` requests are queued on the worker pool
+//! as `Batch` jobs, so they only use workers left idle by live dictation.
+//! Their results bypass the tracker and go back to whoever submitted the
+//! file instead of being typed.
+
+use std::collections::HashMap;
+
+use super::{EngineRole, JobPriority, TranscriptionJob, TranscriptionResult};
+use crate::config::TranscriptionBackendKind;
+use crate::input::AudioBuffer;
+
+/// First sequence ID given to file jobs, above live and recovered ones.
+pub const FILE_SEQUENCE_BASE: u64 = 1 << 49;
+
+/// Called with the result of a file job.
+pub type FileReply = Box<dyn FnOnce(TranscriptionResult) + Send>;
+
+/// File jobs waiting for their result.
+#[derive(Default)]
+pub struct FileJobs {
+    next_sequence: u64,
+    replies: HashMap<u64, FileReply>,
+}
+
+impl FileJobs {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the job for a file and remember where its result goes.
+    pub fn submit(
+        &mut self,
+        buffer: AudioBuffer,
+        language: Option<String>,
+        backend: TranscriptionBackendKind,
+        reply: FileReply,
+    ) -> TranscriptionJob {
+        let sequence_id = FILE_SEQUENCE_BASE + self.next_sequence;
+        self.next_sequence += 1;
+        self.replies.insert(sequence_id, reply);
+
+        TranscriptionJob {
+            buffer,
+            sequence_id,
+            chunk_id: 0,
+            is_final: true,
+            role: EngineRole::Accurate,
+            priority: JobPriority::Batch,
+            language,
+            backend,
+        }
+    }
+
+    /// Hand a result back to its submitter.
+    ///
+    /// Returns the result unchanged if it does not belong to a file job.
+    pub fn complete(&mut self, result: TranscriptionResult) -> Option<TranscriptionResult> {
+        match self.replies.remove(&result.sequence_id) {
+            Some(reply) => {
+                reply(result);
+                None
+            }
+            None => Some(result),
+        }
+    }
+
+    /// Fail a job that never reached the pool.
+    pub fn fail(&mut self, sequence_id: u64, error: &str) {
+        if let Some(reply) = self.replies.remove(&sequence_id) {
+            reply(TranscriptionResult {
+                text: String::new(),
+                sequence_id,
+                chunk_id: 0,
+                is_final: true,
+                duration_secs: 0.0,
+                error: Some(error.to_string()),
+                language: None,
+                role: EngineRole::Accurate,
+            });
+        }
+    }
+
+    /// Number of files still being transcribed.
+    pub fn len(&self) -> usize {
+        self.replies.len()
+    }
+
+    /// Whether no file is being transcribed.
+    pub fn is_empty(&self) -> bool {
+        self.replies.is_empty()
+    }
+}
+
+impl std::fmt::Debug for FileJobs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileJobs")
+            .field("next_sequence", &self.next_sequence)
+            .field("pending", &self.replies.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    fn buffer() -> AudioBuffer {
+        AudioBuffer {
+            samples: vec![0.0; 16000],
+            sample_rate: 16000,
+        }
+    }
+
+    fn result_for(job: &TranscriptionJob, text: &str) -> TranscriptionResult {
+        TranscriptionResult {
+            text: text.to_string(),
+            sequence_id: job.sequence_id,
+            chunk_id: job.chunk_id,
+            is_final: true,
+            duration_secs: 1.0,
+            error: None,
+            language: None,
+            role: job.role,
+        }
+    }
+
+    // ===================
+    // FileJobs Tests
+    // ===================
+
+    #[test]
+    fn test_file_job_is_batch_on_accurate_engine() {
+        let mut files = FileJobs::new();
+        let job = files.submit(
+            buffer(),
+            Some("de".to_string()),
+            TranscriptionBackendKind::Remote,
+            Box::new(|_| {}),
+        );
+
+        assert!(job.sequence_id >= FILE_SEQUENCE_BASE);
+        assert_eq!(job.priority, JobPriority::Batch);
+        assert_eq!(job.role, EngineRole::Accurate);
+        assert_eq!(job.language.as_deref(), Some("de"));
+        assert_eq!(job.backend, TranscriptionBackendKind::Remote);
+        assert!(job.is_final);
+        assert_eq!(files.len(), 1);
+    }
+
+    #[test]
+    fn test_file_job_result_goes_to_its_submitter() {
+        let mut files = FileJobs::new();
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        let first = {
+            let received = Arc::clone(&received);
+            files.submit(
+                buffer(),
+                None,
+                TranscriptionBackendKind::Local,
+                Box::new(move |r| received.lock().unwrap().push((1, r.text))),
+            )
+        };
+        let second = {
+            let received = Arc::clone(&received);
+            files.submit(
+                buffer(),
+                None,
+                TranscriptionBackendKind::Local,
+                Box::new(move |r| received.lock().unwrap().push((2, r.text))),
+            )
+        };
+        assert_ne!(first.sequence_id, second.sequence_id);
+        assert_eq!(files.len(), 2);
+
+        assert!(files.complete(result_for(&second, "two")).is_none());
+        assert!(files.complete(result_for(&first, "one")).is_none());
+        assert!(files.is_empty());
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![(2, "two".to_string()), (1, "one".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_file_jobs_pass_through_other_results() {
+        let mut files = FileJobs::new();
+        let live = TranscriptionJob {
+            buffer: buffer(),
+            sequence_id: 3,
+            chunk_id: 0,
+            is_final: true,
+            role: EngineRole::Fast,
+            priority: JobPriority::Interactive,
+            language: None,
+            backend: TranscriptionBackendKind::Local,
+        };
+
+        let result = files.complete(result_for(&live, "typed")).unwrap();
+        assert_eq!(result.text, "typed");
+    }
+
+    #[test]
+    fn test_file_job_fail_reports_error() {
+        let mut files = FileJobs::new();
+        let error = Arc::new(Mutex::new(None));
+        let job = {
+            let error = Arc::clone(&error);
+            files.submit(
+                buffer(),
+                None,
+                TranscriptionBackendKind::Local,
+                Box::new(move |r| *error.lock().unwrap() = r.error),
+            )
+        };
+
+        files.fail(job.sequence_id, "worker gone");
+        assert!(files.is_empty());
+        assert_eq!(error.lock().unwrap().as_deref(), Some("worker gone"));
+    }
+}
@@ -0,0 +1,388 @@
+//! Crash-safe journal of submitted dictation audio.
+//!
+//! Every chunk sent for transcription is written to the journal directory
+//! and removed once its text has been output. Files still present at the
+//! next start belong to dictations the previous run never finished (crash,
+//! kill or shutdown with jobs in flight); they are replayed so the audio is
+//! never silently lost.
+//!
+//! Files are named `{session}-{sequence}-{chunk}.wav`, where the session is
+//! the daemon start time, because sequence IDs restart with every run.
+//!
+//! Files are written and removed in order by a writer thread, so the
+//! daemon's event loop never waits on the disk.
+
+use crate::config::{Config, TranscriptionBackendKind};
+use crate::input::AudioBuffer;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+use std::time::{SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+use tracing::{debug, info, warn};
+
+use super::spill::{read_wav, write_wav};
+use super::{EngineRole, JobPriority, TranscriptionJob};
+
+/// First sequence ID given to recovered dictations, far above live recordings.
+pub const RECOVERED_SEQUENCE_BASE: u64 = 1 << 48;
+
+#[derive(Error, Debug)]
+pub enum JournalError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Could not determine data directory")]
+    NoDataDir,
+}
+
+/// File operation handled by the writer thread.
+enum JournalOp {
+    Write(PathBuf, AudioBuffer),
+    Remove(Vec<PathBuf>),
+    /// Reply once every earlier operation is done
+    #[cfg(test)]
+    Sync(mpsc::Sender<()>),
+}
+
+/// Journal of audio that has not been output yet.
+#[derive(Debug)]
+pub struct Journal {
+    dir: PathBuf,
+    session: u64,
+    /// Journal files per (sequence, chunk)
+    entries: HashMap<(u64, u32), Vec<PathBuf>>,
+    /// Sequence IDs assigned to recovered dictations
+    recovered: HashSet<u64>,
+    /// Writer thread and its queue (taken on drop)
+    writer: Option<(mpsc::Sender<JournalOp>, JoinHandle<()>)>,
+}
+
+impl Journal {
+    /// Open (creating if needed) a journal directory.
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self, JournalError> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        let session = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        let (op_tx, op_rx) = mpsc::channel();
+        let handle = std::thread::Builder::new()
+            .name("audio-journal".to_string())
+            .spawn(move || run_writer(op_rx))?;
+        Ok(Self {
+            dir,
+            session,
+            entries: HashMap::new(),
+            recovered: HashSet::new(),
+            writer: Some((op_tx, handle)),
+        })
+    }
+
+    /// Default journal directory (`<data dir>/queue/journal`).
+    pub fn default_dir() -> Result<PathBuf, JournalError> {
+        Config::data_dir()
+            .map(|dir| dir.join("queue").join("journal"))
+            .map_err(|_| JournalError::NoDataDir)
+    }
+
+    /// Load dictations left behind by previous runs.
+    ///
+    /// Chunks of the same dictation are joined into one batch job. Their
+    /// files stay on disk until [`complete`](Self::complete) is called with
+    /// the returned job's sequence ID and chunk 0.
+    pub fn recover(&mut self) -> Vec<TranscriptionJob> {
+        let Ok(dir) = std::fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+
+        // (session, sequence) -> chunk -> path
+        let mut dictations: BTreeMap<(u64, u64), BTreeMap<u32, PathBuf>> = BTreeMap::new();
+        for path in dir.flatten().map(|entry| entry.path()) {
+            match parse_file_name(&path) {
+                Some((session, sequence_id, chunk_id)) if session != self.session => {
+                    dictations
+                        .entry((session, sequence_id))
+                        .or_default()
+                        .insert(chunk_id, path);
+                }
+                _ => {}
+            }
+        }
+
+        let mut jobs = Vec::new();
+        for chunks in dictations.into_values() {
+            let mut buffer: Option<AudioBuffer> = None;
+            let mut paths = Vec::new();
+            for path in chunks.into_values() {
+                match read_wav(&path) {
+                    Ok(chunk) => match buffer.as_mut() {
+                        Some(buffer) if buffer.sample_rate != chunk.sample_rate => {
+                            warn!(
+                                "Skipping journal file with mismatched sample rate: {}",
+                                path.display()
+                            );
+                        }
+                        Some(buffer) => buffer.samples.extend(chunk.samples),
+                        None => buffer = Some(chunk),
+                    },
+                    Err(e) => {
+                        // Most likely cut off mid-write by the crash
+                        warn!(
+                            "Discarding unreadable journal file {}: {}",
+                            path.display(),
+                            e
+                        );
+                        let _ = std::fs::remove_file(&path);
+                        continue;
+                    }
+                }
+                paths.push(path);
+            }
+            let Some(buffer) = buffer else {
+                continue;
+            };
+
+            let sequence_id = RECOVERED_SEQUENCE_BASE + jobs.len() as u64;
+            self.entries.insert((sequence_id, 0), paths);
+            self.recovered.insert(sequence_id);
+            jobs.push(TranscriptionJob {
+                buffer,
+                sequence_id,
+                chunk_id: 0,
+                is_final: true,
+                role: EngineRole::Accurate,
+                priority: JobPriority::Batch,
+                language: None,
+                backend: TranscriptionBackendKind::Local,
+            });
+        }
+
+        if !jobs.is_empty() {
+            info!(
+                "Recovered {} unfinished dictation(s) from the journal",
+                jobs.len()
+            );
+        }
+        jobs
+    }
+
+    /// Whether a sequence ID belongs to a recovered dictation.
+    pub fn is_recovered(&self, sequence_id: u64) -> bool {
+        self.recovered.contains(&sequence_id)
+    }
+
+    /// Journal a job's audio before it is submitted.
+    ///
+    /// The file is written in the background; failures are logged.
+    pub fn record(&mut self, job: &TranscriptionJob) {
+        let path = self.dir.join(format!(
+            "{}-{}-{}.wav",
+            self.session, job.sequence_id, job.chunk_id
+        ));
+        self.send(JournalOp::Write(path.clone(), job.buffer.clone()));
+        self.entries
+            .insert((job.sequence_id, job.chunk_id), vec![path]);
+    }
+
+    /// Forget a chunk whose text has been output (or that was dropped).
+    pub fn complete(&mut self, sequence_id: u64, chunk_id: u32) {
+        if let Some(paths) = self.entries.remove(&(sequence_id, chunk_id)) {
+            self.send(JournalOp::Remove(paths));
+        }
+        self.recovered.remove(&sequence_id);
+    }
+
+    /// Forget all live chunks (after a cancel). Recovered dictations are kept.
+    pub fn discard_live(&mut self) {
+        let recovered = &self.recovered;
+        let mut discarded = Vec::new();
+        self.entries.retain(|(sequence_id, _), paths| {
+            let keep = recovered.contains(sequence_id);
+            if !keep {
+                discarded.append(paths);
+            }
+            keep
+        });
+        if !discarded.is_empty() {
+            self.send(JournalOp::Remove(discarded));
+        }
+    }
+
+    fn send(&self, op: JournalOp) {
+        let sent = self
+            .writer
+            .as_ref()
+            .is_some_and(|(op_tx, _)| op_tx.send(op).is_ok());
+        if !sent {
+            warn!("Audio journal writer stopped, dictations are not crash-safe");
+        }
+    }
+
+    /// Wait until the writer has caught up.
+    #[cfg(test)]
+    fn sync(&self) {
+        let (done_tx, done_rx) = mpsc::channel();
+        self.send(JournalOp::Sync(done_tx));
+        let _ = done_rx.recv();
+    }
+
+    /// Number of chunks currently journaled.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether nothing is journaled.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Drop for Journal {
+    /// Let the writer finish so no queued audio is lost on shutdown.
+    fn drop(&mut self) {
+        if let Some((op_tx, handle)) = self.writer.take() {
+            drop(op_tx);
+            if handle.join().is_err() {
+                warn!("Audio journal writer panicked");
+            }
+        }
+    }
+}
+
+/// Apply journal file operations in order until the journal is dropped.
+fn run_writer(ops: mpsc::Receiver<JournalOp>) {
+    for op in ops {
+        match op {
+            JournalOp::Write(path, buffer) => {
+                if let Err(e) = write_wav(&path, &buffer) {
+                    warn!("Failed to journal audio to {}: {}", path.display(), e);
+                    let _ = std::fs::remove_file(&path);
+                }
+            }
+            JournalOp::Remove(paths) => remove_files(&paths),
+            #[cfg(test)]
+            JournalOp::Sync(done) => {
+                let _ = done.send(());
+            }
+        }
+    }
+}
+
+fn remove_files(paths: &[PathBuf]) {
+    for path in paths {
+        if let Err(e) = std::fs::remove_file(path) {
+            debug!("Failed to remove journal file {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// Parse `{session}-{sequence}-{chunk}.wav`.
+fn parse_file_name(path: &Path) -> Option<(u64, u64, u32)> {
+    if path.extension()? != "wav" {
+        return None;
+    }
+    let stem = path.file_stem()?.to_str()?;
+    let mut parts = stem.split('-');
+    let session = parts.next()?.parse().ok()?;
+    let sequence_id = parts.next()?.parse().ok()?;
+    let chunk_id = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((session, sequence_id, chunk_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn job(sequence_id: u64, chunk_id: u32, samples: Vec<f32>) -> TranscriptionJob {
+        TranscriptionJob {
+            buffer: AudioBuffer {
+                samples,
+                sample_rate: 16000,
+            },
+            sequence_id,
+            chunk_id,
+            is_final: false,
+            role: EngineRole::Fast,
+            priority: JobPriority::Interactive,
+            language: None,
+            backend: TranscriptionBackendKind::Local,
+        }
+    }
+
+    #[test]
+    fn test_parse_file_name() {
+        assert_eq!(
+            parse_file_name(Path::new("/tmp/1700000000000-4-2.wav")),
+            Some((1_700_000_000_000, 4, 2))
+        );
+        assert_eq!(parse_file_name(Path::new("/tmp/1-4-2.txt")), None);
+        assert_eq!(parse_file_name(Path::new("/tmp/1-4.wav")), None);
+        assert_eq!(parse_file_name(Path::new("/tmp/1-4-2-9.wav")), None);
+    }
+
+    #[test]
+    fn test_completed_chunks_are_removed() {
+        let dir = tempdir().unwrap();
+        let mut journal = Journal::open(dir.path()).unwrap();
+
+        journal.record(&job(0, 0, vec![0.1; 4]));
+        journal.record(&job(0, 1, vec![0.2; 4]));
+        assert_eq!(journal.len(), 2);
+
+        // Removal is queued behind the write of the same file
+        journal.complete(0, 0);
+        assert_eq!(journal.len(), 1);
+        journal.sync();
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        journal.discard_live();
+        assert!(journal.is_empty());
+        journal.sync();
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_recover_previous_session() {
+        let dir = tempdir().unwrap();
+
+        // A run that crashed with two dictations in flight
+        let mut crashed = Journal::open(dir.path()).unwrap();
+        crashed.session = 1;
+        crashed.record(&job(3, 1, vec![0.2, 0.2]));
+        crashed.record(&job(3, 0, vec![0.1, 0.1]));
+        crashed.record(&job(5, 0, vec![0.5]));
+        std::fs::write(dir.path().join("1-6-0.wav"), b"truncated").unwrap();
+        drop(crashed);
+
+        let mut journal = Journal::open(dir.path()).unwrap();
+        let jobs = journal.recover();
+        assert_eq!(jobs.len(), 2);
+
+        // Chunks are joined in order into one batch job per dictation
+        assert_eq!(jobs[0].buffer.samples, vec![0.1, 0.1, 0.2, 0.2]);
+        assert_eq!(jobs[0].priority, JobPriority::Batch);
+        assert_eq!(jobs[1].buffer.samples, vec![0.5]);
+        assert!(journal.is_recovered(jobs[0].sequence_id));
+        assert!(jobs[0].sequence_id >= RECOVERED_SEQUENCE_BASE);
+
+        // The unreadable file is discarded
+        assert!(!dir.path().join("1-6-0.wav").exists());
+
+        // Recovered audio survives a cancel and is removed once output
+        journal.discard_live();
+        journal.sync();
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 3);
+        journal.complete(jobs[0].sequence_id, 0);
+        journal.complete(jobs[1].sequence_id, 0);
+        assert!(!journal.is_recovered(jobs[0].sequence_id));
+        journal.sync();
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+}
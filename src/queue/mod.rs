@@ -5,13 +5,58 @@
 //! - Async transcription without blocking the main loop
 //! - Ordered output regardless of completion order
 
+pub mod files;
+pub mod journal;
+pub mod pool;
+pub mod spill;
 pub mod worker;
 
 pub use worker::WorkerCommand;
 
-use crate::config::BackpressureStrategy;
+use crate::config::{BackpressureStrategy, TranscriptionBackendKind};
 use crate::input::AudioBuffer;
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Which warm engine a job is routed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EngineRole {
+    /// Small, fast model for live push-to-talk and dictation chunks
+    #[default]
+    Fast,
+    /// Large, accurate model for recordings and refinement passes
+    Accurate,
+}
+
+impl std::fmt::Display for EngineRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fast => write!(f, "fast"),
+            Self::Accurate => write!(f, "accurate"),
+        }
+    }
+}
+
+/// Scheduling class of a job. Higher classes are always dispatched first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum JobPriority {
+    /// Background work such as file transcription and refinement passes
+    Batch,
+    /// Intermediate chunks of a recording that is still in progress
+    Recording,
+    /// Final push-to-talk chunks and dictation, where a user is waiting for the text
+    #[default]
+    Interactive,
+}
+
+impl std::fmt::Display for JobPriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Batch => write!(f, "batch"),
+            Self::Recording => write!(f, "recording"),
+            Self::Interactive => write!(f, "interactive"),
+        }
+    }
+}
 
 /// A job to be processed by the transcription worker.
 #[derive(Debug)]
@@ -24,6 +69,16 @@ pub struct TranscriptionJob {
     pub chunk_id: u32,
     /// True if this is the final chunk of the recording
     pub is_final: bool,
+    /// Engine the job should run on (falls back to any loaded engine)
+    pub role: EngineRole,
+    /// Scheduling class used by the worker pool
+    pub priority: JobPriority,
+    /// Language to transcribe in ("auto" or an ISO code), fixed when the
+    /// recording started; `None` uses the engine's configured language
+    pub language: Option<String>,
+    /// Backend the recording was started with; `Remote` only takes effect
+    /// when a remote server is configured
+    pub backend: TranscriptionBackendKind,
 }
 
 /// Result from a completed transcription.
@@ -40,6 +95,32 @@ pub struct TranscriptionResult {
     pub is_final: bool,
     /// Duration of the audio in seconds
     pub duration_secs: f32,
+    /// Error message if transcription failed (text is empty)
+    pub error: Option<String>,
+    /// Language detected or used for this chunk, if known
+    pub language: Option<String>,
+    /// Engine role the job was routed to (`Accurate` results are refinements)
+    pub role: EngineRole,
+}
+
+/// An accurate transcription of a recording whose draft has been output.
+#[derive(Debug, Clone)]
+pub struct Refinement {
+    /// Text that was output for the recording by the fast engine
+    pub draft: String,
+    /// Result from the accurate engine for the whole recording
+    pub result: TranscriptionResult,
+}
+
+impl Refinement {
+    /// Text to type over the draft for a refined transcription.
+    ///
+    /// The refinement has no chunk separator, so whitespace the draft was
+    /// typed with before its first word is kept and only the words change.
+    pub fn retyped(&self, refined: &str) -> String {
+        let lead = self.draft.len() - self.draft.trim_start().len();
+        format!("{}{}", &self.draft[..lead], refined.trim_start())
+    }
 }
 
 /// Composite key for tracking chunks: (sequence_id, chunk_id)
@@ -54,20 +135,34 @@ type ChunkKey = (u64, u32);
 /// Each chunk is output immediately as it completes. This gives fastest
 /// feedback for live dictation.
 ///
-/// # Ordered Mode (legacy)
-/// Results are buffered and output in sequence order.
+/// # Ordered Mode
+/// Results are buffered until every earlier chunk has completed, then output
+/// in (sequence, chunk) order. Used when a worker pool may finish chunks
+/// out of order.
 #[derive(Debug, Default)]
 pub struct TranscriptionTracker {
     /// Chunk keys that are currently being processed
     pending: HashSet<ChunkKey>,
     /// Results waiting to be output (keyed by (sequence_id, chunk_id))
     completed: BTreeMap<ChunkKey, TranscriptionResult>,
-    /// Next sequence ID to output (for ordered mode)
-    next_output_id: u64,
     /// Streaming mode: output chunks immediately
     streaming: bool,
     /// Last output text ending (for deduplication)
     last_text_suffix: String,
+    /// Results of this sequence and older ones are discarded (cancelled by
+    /// the user). Sequence IDs only grow and a cancel clears everything
+    /// queued, so one bound covers every cancelled recording.
+    cancelled_through: Option<u64>,
+    /// Chunks dropped by backpressure whose results are discarded
+    dropped: HashSet<ChunkKey>,
+    /// Dropped chunks not yet reported by [`take_dropped`](Self::take_dropped)
+    newly_dropped: Vec<ChunkKey>,
+    /// Draft text output so far, for sequences awaiting a refinement pass
+    drafts: HashMap<u64, String>,
+    /// Refinement results waiting for their draft to finish output
+    refinements: BTreeMap<u64, TranscriptionResult>,
+    /// Last sequence that produced output
+    last_output_id: Option<u64>,
 }
 
 impl TranscriptionTracker {
@@ -80,7 +175,6 @@ impl TranscriptionTracker {
     }
 
     /// Create a tracker in ordered (non-streaming) mode.
-    #[allow(dead_code)]
     pub fn new_ordered() -> Self {
         Self {
             streaming: false,
@@ -127,6 +221,8 @@ impl TranscriptionTracker {
                     // Drop the oldest pending job to make room
                     if let Some(&oldest_key) = self.pending.iter().min() {
                         self.pending.remove(&oldest_key);
+                        self.dropped.insert(oldest_key);
+                        self.newly_dropped.push(oldest_key);
                         tracing::warn!(
                             "Backpressure: dropped oldest job (seq {}.{}) to accept (seq {}.{})",
                             oldest_key.0,
@@ -153,6 +249,16 @@ impl TranscriptionTracker {
                         max_pending
                     );
                 }
+                BackpressureStrategy::SpillToDisk => {
+                    // The worker pool persists the overflow audio to disk
+                    tracing::info!(
+                        "Queue at capacity ({}/{}), spilling (seq {}.{}) to disk",
+                        pending_count,
+                        max_pending,
+                        sequence_id,
+                        chunk_id
+                    );
+                }
             }
         } else if high_water_mark > 0 && pending_count >= high_water_mark {
             // Approaching capacity - log warning
@@ -183,10 +289,28 @@ impl TranscriptionTracker {
         }
     }
 
+    /// Chunks dropped by backpressure since the last call, as
+    /// (sequence_id, chunk_id). Their results will never be output.
+    pub fn take_dropped(&mut self) -> Vec<(u64, u32)> {
+        std::mem::take(&mut self.newly_dropped)
+    }
+
     /// Add a completed transcription result.
     pub fn add_result(&mut self, result: TranscriptionResult) {
         let key = (result.sequence_id, result.chunk_id);
         self.pending.remove(&key);
+        if self.dropped.remove(&key) {
+            tracing::debug!("Discarding result of dropped job (seq {}.{})", key.0, key.1);
+            return;
+        }
+        if self.is_cancelled(key.0) {
+            tracing::debug!(
+                "Discarding result of cancelled recording (seq {}.{})",
+                key.0,
+                key.1
+            );
+            return;
+        }
         self.completed.insert(key, result);
         tracing::debug!(
             "Added result (seq {}.{}), {} pending, {} waiting",
@@ -197,6 +321,72 @@ impl TranscriptionTracker {
         );
     }
 
+    /// Expect a refinement pass for a recording.
+    ///
+    /// Text later recorded with [`record_draft`](Self::record_draft) is kept
+    /// until the refinement arrives.
+    pub fn expect_refinement(&mut self, sequence_id: u64) {
+        self.drafts.entry(sequence_id).or_default();
+    }
+
+    /// Whether a refinement pass of a recording is still outstanding.
+    pub fn expects_refinement(&self, sequence_id: u64) -> bool {
+        self.drafts.contains_key(&sequence_id)
+    }
+
+    /// Record text that was output for a recording.
+    ///
+    /// Record everything that was typed, separators included, so the draft
+    /// can be erased exactly.
+    pub fn record_draft(&mut self, sequence_id: u64, text: &str) {
+        if let Some(draft) = self.drafts.get_mut(&sequence_id) {
+            draft.push_str(text);
+        }
+        self.last_output_id = Some(sequence_id);
+    }
+
+    /// Add a refinement result from the accurate engine.
+    ///
+    /// Discarded if the recording was cancelled or no refinement was expected.
+    pub fn add_refinement(&mut self, result: TranscriptionResult) {
+        let sequence_id = result.sequence_id;
+        if self.is_cancelled(sequence_id) || !self.drafts.contains_key(&sequence_id) {
+            tracing::debug!("Discarding unexpected refinement (seq {})", sequence_id);
+            return;
+        }
+        self.refinements.insert(sequence_id, result);
+    }
+
+    /// Take refinements whose drafts have been fully output.
+    ///
+    /// A refinement waits while any chunk of its recording is still pending
+    /// or buffered, so the draft it is compared against is complete.
+    pub fn take_refinements(&mut self) -> Vec<Refinement> {
+        let ready: Vec<u64> = self
+            .refinements
+            .keys()
+            .copied()
+            .filter(|&seq| {
+                !self.pending.iter().any(|&(s, _)| s == seq)
+                    && !self.completed.keys().any(|&(s, _)| s == seq)
+            })
+            .collect();
+
+        ready
+            .into_iter()
+            .filter_map(|seq| {
+                let result = self.refinements.remove(&seq)?;
+                let draft = self.drafts.remove(&seq).unwrap_or_default();
+                Some(Refinement { draft, result })
+            })
+            .collect()
+    }
+
+    /// Last recording that produced output, if any.
+    pub fn last_output_id(&self) -> Option<u64> {
+        self.last_output_id
+    }
+
     /// Take results that are ready for output.
     ///
     /// In streaming mode: returns all completed results immediately.
@@ -211,12 +401,32 @@ impl TranscriptionTracker {
 
     /// Streaming mode: take all completed results, apply deduplication.
     fn take_ready_streaming(&mut self) -> Vec<TranscriptionResult> {
-        let mut ready: Vec<_> = std::mem::take(&mut self.completed).into_values().collect();
+        // BTreeMap yields results sorted by (sequence_id, chunk_id)
+        let ready: Vec<_> = std::mem::take(&mut self.completed).into_values().collect();
+        self.deduplicate_all(ready)
+    }
+
+    /// Ordered mode: take results in (sequence, chunk) order only.
+    ///
+    /// A result is held back while any earlier chunk is still pending, so
+    /// output order is preserved when several workers finish out of order.
+    fn take_ready_ordered(&mut self) -> Vec<TranscriptionResult> {
+        let mut ready = Vec::new();
+
+        while let Some((&key, _)) = self.completed.first_key_value() {
+            if self.pending.iter().any(|pending| *pending < key) {
+                break;
+            }
+            if let Some(result) = self.completed.remove(&key) {
+                ready.push(result);
+            }
+        }
 
-        // Sort by (sequence_id, chunk_id) for consistent ordering
-        ready.sort_by_key(|r| (r.sequence_id, r.chunk_id));
+        self.deduplicate_all(ready)
+    }
 
-        // Apply deduplication to each result
+    /// Apply deduplication to consecutive results.
+    fn deduplicate_all(&mut self, mut ready: Vec<TranscriptionResult>) -> Vec<TranscriptionResult> {
         for result in &mut ready {
             if !self.last_text_suffix.is_empty() && !result.text.is_empty() {
                 result.text = self.deduplicate_text(&result.text);
@@ -231,20 +441,6 @@ impl TranscriptionTracker {
         ready
     }
 
-    /// Ordered mode: take results in sequence order only.
-    fn take_ready_ordered(&mut self) -> Vec<TranscriptionResult> {
-        let mut ready = Vec::new();
-
-        // For ordered mode, we need to wait for complete recordings
-        // This is simplified - just output by sequence_id order
-        while let Some(result) = self.completed.remove(&(self.next_output_id, 0)) {
-            ready.push(result);
-            self.next_output_id += 1;
-        }
-
-        ready
-    }
-
     /// Remove duplicate words at the beginning of text that match the end of previous output.
     fn deduplicate_text(&self, text: &str) -> String {
         // Find overlap between last_text_suffix and start of new text
@@ -278,6 +474,33 @@ impl TranscriptionTracker {
         self.last_text_suffix.clear();
     }
 
+    /// Cancel all outstanding transcriptions.
+    ///
+    /// Buffered results are dropped and results still in flight for the
+    /// given or pending sequences are discarded when they arrive.
+    /// Returns the number of chunks that were discarded.
+    pub fn cancel_all(&mut self, active_sequence: Option<u64>) -> usize {
+        let discarded = self.pending.len() + self.completed.len();
+        let newest = self
+            .pending
+            .iter()
+            .map(|&(sequence_id, _)| sequence_id)
+            .chain(active_sequence)
+            .max();
+        self.cancelled_through = self.cancelled_through.max(newest);
+        self.pending.clear();
+        self.completed.clear();
+        self.last_text_suffix.clear();
+        self.drafts.clear();
+        self.refinements.clear();
+        discarded
+    }
+
+    fn is_cancelled(&self, sequence_id: u64) -> bool {
+        self.cancelled_through
+            .is_some_and(|newest| sequence_id <= newest)
+    }
+
     /// Check if there are any pending or buffered transcriptions.
     #[allow(dead_code)]
     pub fn is_empty(&self) -> bool {
@@ -318,6 +541,9 @@ mod tests {
             chunk_id: chunk,
             is_final,
             duration_secs: 1.0, // Test default
+            error: None,
+            language: None,
+            role: EngineRole::Fast,
         }
     }
 
@@ -435,6 +661,15 @@ mod tests {
         // Oldest (0,0) should have been dropped
         assert!(!tracker.pending.contains(&(0, 0)));
         assert!(tracker.pending.contains(&(0, 3)));
+        assert_eq!(tracker.take_dropped(), vec![(0, 0)]);
+        assert!(tracker.take_dropped().is_empty());
+
+        // Its result is never output
+        tracker.add_result(result(0, 0, "dropped", false));
+        tracker.add_result(result(0, 1, "kept", false));
+        let ready = tracker.take_ready();
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].text, "kept");
     }
 
     #[test]
@@ -451,6 +686,28 @@ mod tests {
         assert_eq!(tracker.pending_count(), 4);
     }
 
+    #[test]
+    fn test_backpressure_spill_accepts() {
+        let mut tracker = TranscriptionTracker::new();
+        let strategy = BackpressureStrategy::SpillToDisk;
+
+        for chunk in 0..3 {
+            assert!(tracker.add_pending_with_config(0, chunk, 3, 2, strategy));
+        }
+
+        // Overflow is parked on disk by the pool, so nothing is dropped
+        assert!(tracker.add_pending_with_config(0, 3, 3, 2, strategy));
+        assert_eq!(tracker.pending_count(), 4);
+    }
+
+    #[test]
+    fn test_job_priority_order() {
+        assert!(JobPriority::Interactive > JobPriority::Recording);
+        assert!(JobPriority::Recording > JobPriority::Batch);
+        assert_eq!(JobPriority::default(), JobPriority::Interactive);
+        assert_eq!(JobPriority::Batch.to_string(), "batch");
+    }
+
     #[test]
     fn test_queue_stats() {
         let mut tracker = TranscriptionTracker::new();
@@ -463,4 +720,133 @@ mod tests {
         assert_eq!(stats.pending_count, 1);
         assert_eq!(stats.waiting_count, 1);
     }
+
+    #[test]
+    fn test_cancel_all_discards_in_flight_results() {
+        let mut tracker = TranscriptionTracker::new();
+
+        tracker.add_pending(0, 0);
+        tracker.add_pending(0, 1);
+        tracker.add_result(result(0, 0, "buffered", false));
+
+        assert_eq!(tracker.cancel_all(Some(1)), 2);
+        assert!(tracker.is_empty());
+        assert_eq!(tracker.cancelled_through, Some(1));
+
+        // Late results of cancelled sequences are dropped
+        tracker.add_result(result(0, 1, "late", true));
+        tracker.add_result(result(1, 0, "active", true));
+        assert!(tracker.take_ready().is_empty());
+
+        // New recordings are unaffected
+        tracker.add_pending(2, 0);
+        tracker.add_result(result(2, 0, "next", true));
+        assert_eq!(tracker.take_ready().len(), 1);
+    }
+
+    fn refinement(seq: u64, text: &str) -> TranscriptionResult {
+        TranscriptionResult {
+            role: EngineRole::Accurate,
+            ..result(seq, 0, text, true)
+        }
+    }
+
+    #[test]
+    fn test_refinement_waits_for_draft() {
+        let mut tracker = TranscriptionTracker::new();
+        tracker.expect_refinement(0);
+        tracker.add_pending(0, 0);
+        tracker.add_pending(0, 1);
+
+        tracker.add_result(result(0, 0, "helo", false));
+        for ready in tracker.take_ready() {
+            tracker.record_draft(ready.sequence_id, &ready.text);
+        }
+
+        // Chunk 1 is still in flight, so the refinement is held back
+        tracker.add_refinement(refinement(0, "hello world"));
+        assert!(tracker.take_refinements().is_empty());
+
+        tracker.add_result(result(0, 1, " wrld", true));
+        for ready in tracker.take_ready() {
+            tracker.record_draft(ready.sequence_id, &ready.text);
+        }
+
+        let refinements = tracker.take_refinements();
+        assert_eq!(refinements.len(), 1);
+        assert_eq!(refinements[0].draft, "helo wrld");
+        assert_eq!(refinements[0].result.text, "hello world");
+        assert_eq!(tracker.last_output_id(), Some(0));
+        assert!(tracker.take_refinements().is_empty());
+        assert!(!tracker.expects_refinement(0));
+    }
+
+    #[test]
+    fn test_refinement_retyped_keeps_separator() {
+        let mut tracker = TranscriptionTracker::new();
+        tracker.expect_refinement(0);
+        assert!(tracker.expects_refinement(0));
+        tracker.add_pending(0, 0);
+        tracker.add_pending(0, 1);
+
+        // The first chunk was silent, so the draft starts with a separator
+        tracker.add_result(result(0, 0, "", false));
+        tracker.add_result(result(0, 1, " helo", true));
+        for ready in tracker.take_ready() {
+            tracker.record_draft(ready.sequence_id, &ready.text);
+        }
+        tracker.add_refinement(refinement(0, "Hello."));
+
+        let refinements = tracker.take_refinements();
+        assert_eq!(refinements[0].draft, " helo");
+        assert_eq!(refinements[0].retyped("Hello."), " Hello.");
+        assert_eq!(refinements[0].retyped(" Hello."), " Hello.");
+    }
+
+    #[test]
+    fn test_unexpected_refinement_discarded() {
+        let mut tracker = TranscriptionTracker::new();
+        tracker.add_refinement(refinement(3, "text"));
+        assert!(tracker.take_refinements().is_empty());
+    }
+
+    #[test]
+    fn test_cancel_all_discards_refinements() {
+        let mut tracker = TranscriptionTracker::new();
+        tracker.expect_refinement(0);
+        tracker.add_pending(0, 0);
+
+        tracker.cancel_all(Some(0));
+        tracker.add_refinement(refinement(0, "late"));
+        assert!(tracker.take_refinements().is_empty());
+    }
+
+    #[test]
+    fn test_ordered_mode_holds_later_chunks() {
+        let mut tracker = TranscriptionTracker::new_ordered();
+
+        tracker.add_pending(5, 0);
+        tracker.add_pending(5, 1);
+        tracker.add_pending(5, 2);
+
+        // Chunks 1 and 2 finish first on other workers
+        tracker.add_result(result(5, 2, "three", true));
+        tracker.add_result(result(5, 1, "two", false));
+        assert!(tracker.take_ready().is_empty());
+
+        tracker.add_result(result(5, 0, "one", false));
+        let texts: Vec<_> = tracker.take_ready().into_iter().map(|r| r.text).collect();
+        assert_eq!(texts, vec!["one", "two", "three"]);
+    }
+
+    #[test]
+    fn test_ordered_mode_skips_dropped_chunks() {
+        let mut tracker = TranscriptionTracker::new_ordered();
+
+        // Chunk 0 was rejected by backpressure and never became pending
+        tracker.add_pending(1, 1);
+        tracker.add_result(result(1, 1, "after drop", true));
+
+        assert_eq!(tracker.take_ready().len(), 1);
+    }
 }
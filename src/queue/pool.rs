@@ -0,0 +1,949 @@
+//! Pool of parallel transcription workers.
+//!
+//! A dispatcher thread receives [`WorkerCommand`]s from the daemon and fans
+//! them out: jobs wait in a priority queue and go to the next idle worker,
+//! while engine loads, unloads and backend changes reach every worker.
+//! Loaded engines are forked so all workers share the model weights and
+//! keep their own Whisper state. The memory budget is therefore kept here,
+//! counting each model once: when a load exceeds it, the least recently
+//! used role is evicted from every worker.
+//!
+//! Jobs are dispatched by [`JobPriority`] (interactive, then recording, then
+//! batch) and in arrival order within a class. With more than one worker a
+//! batch job is only started while another worker stays idle, so push-to-talk
+//! never waits behind background work. When a spill directory is configured,
+//! jobs beyond the in-memory limit are parked on disk until a worker is free.
+
+use crate::config::AudioConfig;
+use crate::engine::WhisperEngine;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashSet};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tracing::{debug, error, info, warn};
+
+use super::spill::{SpillStore, SpilledJob};
+use super::worker::{TranscriptionWorker, WarmEngines, WorkerCommand};
+use super::{EngineRole, JobPriority, TranscriptionJob, TranscriptionResult};
+
+/// Per-worker channel capacity (jobs are queued at the dispatcher)
+const WORKER_CHANNEL_SIZE: usize = 8;
+
+/// Live counters shared between a worker and the pool.
+#[derive(Debug, Default)]
+pub struct WorkerCounters {
+    /// Jobs dispatched to the worker but not yet finished
+    in_flight: AtomicUsize,
+    /// Jobs finished successfully
+    completed: AtomicU64,
+    /// Jobs that failed or ran without a loaded model
+    failed: AtomicU64,
+    /// Total time spent transcribing, in milliseconds
+    busy_ms: AtomicU64,
+    /// Tells the dispatcher when this worker becomes idle
+    notify: Option<(usize, std::sync::mpsc::Sender<PoolEvent>)>,
+}
+
+impl WorkerCounters {
+    fn notifying(id: usize, event_tx: std::sync::mpsc::Sender<PoolEvent>) -> Self {
+        Self {
+            notify: Some((id, event_tx)),
+            ..Self::default()
+        }
+    }
+
+    /// Record a job handed to the worker.
+    fn dispatched(&self) {
+        self.in_flight.fetch_add(1, Ordering::AcqRel);
+    }
+
+    /// Record a finished job.
+    pub fn finished(&self, elapsed_ms: u64, failed: bool) {
+        // Workers spawned on their own never see `dispatched`
+        let _ = self
+            .in_flight
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| n.checked_sub(1));
+        if failed {
+            self.failed.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.completed.fetch_add(1, Ordering::Relaxed);
+        }
+        self.busy_ms.fetch_add(elapsed_ms, Ordering::Relaxed);
+        if let Some((id, event_tx)) = &self.notify {
+            let _ = event_tx.send(PoolEvent::Finished(*id));
+        }
+    }
+
+    fn snapshot(&self, id: usize) -> WorkerStats {
+        WorkerStats {
+            id,
+            in_flight: self.in_flight.load(Ordering::Acquire),
+            completed: self.completed.load(Ordering::Relaxed),
+            failed: self.failed.load(Ordering::Relaxed),
+            busy_ms: self.busy_ms.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Snapshot of one worker's queue statistics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WorkerStats {
+    /// Worker index within the pool
+    pub id: usize,
+    /// Jobs dispatched but not yet finished
+    pub in_flight: usize,
+    /// Jobs finished successfully
+    pub completed: u64,
+    /// Jobs that failed
+    pub failed: u64,
+    /// Total time spent transcribing, in milliseconds
+    pub busy_ms: u64,
+}
+
+impl WorkerStats {
+    /// Total jobs this worker has finished.
+    pub fn finished(&self) -> u64 {
+        self.completed + self.failed
+    }
+}
+
+/// Cloneable handle for reading per-worker statistics.
+#[derive(Debug, Clone)]
+pub struct PoolStats {
+    counters: Vec<Arc<WorkerCounters>>,
+    /// Times the accurate engine was evicted to make room for another
+    accurate_evictions: Arc<AtomicU64>,
+}
+
+impl PoolStats {
+    /// Current statistics of every worker.
+    pub fn snapshot(&self) -> Vec<WorkerStats> {
+        self.counters
+            .iter()
+            .enumerate()
+            .map(|(id, counters)| counters.snapshot(id))
+            .collect()
+    }
+
+    /// Times the pool evicted the accurate engine.
+    ///
+    /// Jobs for that role fall back to another engine until it is loaded
+    /// again, so a change tells the caller its load has been undone.
+    pub fn accurate_evictions(&self) -> u64 {
+        self.accurate_evictions.load(Ordering::Acquire)
+    }
+}
+
+/// A running worker pool.
+pub struct WorkerPool {
+    dispatcher: std::thread::JoinHandle<()>,
+    stats: PoolStats,
+}
+
+impl WorkerPool {
+    /// Number of workers in the pool.
+    pub fn size(&self) -> usize {
+        self.stats.counters.len()
+    }
+
+    /// Handle for reading per-worker statistics.
+    pub fn stats(&self) -> PoolStats {
+        self.stats.clone()
+    }
+
+    /// Wait for the dispatcher and all workers to stop.
+    ///
+    /// They stop once the command channel is closed and queued jobs are done.
+    pub fn join(self) -> std::thread::Result<()> {
+        self.dispatcher.join()
+    }
+}
+
+/// Pool settings.
+#[derive(Debug, Clone, Default)]
+pub struct PoolConfig {
+    /// Number of workers (at least one)
+    pub workers: usize,
+    /// Memory allowed for warm engines (0 = unlimited). Workers share the
+    /// weights of forked engines, so each model counts once.
+    pub memory_budget_bytes: u64,
+    /// Jobs kept in memory while waiting for a worker (0 = unlimited)
+    pub max_queued: usize,
+    /// Directory for jobs beyond `max_queued` (None = keep them in memory)
+    pub spill_dir: Option<PathBuf>,
+}
+
+/// Events handled by the dispatcher thread.
+#[derive(Debug)]
+enum PoolEvent {
+    /// Command from the daemon
+    Command(WorkerCommand),
+    /// A worker finished a job and is idle
+    Finished(usize),
+    /// The daemon closed the command channel
+    Closed,
+}
+
+/// A job waiting for a worker.
+enum Queued {
+    Memory(TranscriptionJob),
+    Spilled(SpilledJob),
+}
+
+impl Queued {
+    fn role(&self) -> EngineRole {
+        match self {
+            Self::Memory(job) => job.role,
+            Self::Spilled(spilled) => spilled.role(),
+        }
+    }
+}
+
+/// Queue order: highest priority first, then arrival order.
+type QueueKey = (Reverse<JobPriority>, u64);
+
+/// Pick an idle worker for the next job.
+///
+/// Only idle workers are considered. Workers that can run the job (see
+/// [`capable`]) are preferred, waiting for one to become idle
+/// if needed, then the fewest jobs finished so work spreads evenly.
+fn pick_worker(stats: &[WorkerStats], capable: &[bool]) -> Option<usize> {
+    let any_capable = capable.iter().any(|&c| c);
+    stats
+        .iter()
+        .filter(|s| s.in_flight == 0 && (capable[s.id] || !any_capable))
+        .min_by_key(|s| s.finished())
+        .map(|s| s.id)
+}
+
+/// Which workers can run a job for `role`.
+///
+/// Workers with that engine if any has it, else workers with any engine
+/// (they fall back to it), else all of them.
+fn capable(roles: &[HashSet<EngineRole>], role: EngineRole) -> Vec<bool> {
+    let with_role: Vec<bool> = roles.iter().map(|r| r.contains(&role)).collect();
+    if with_role.contains(&true) {
+        return with_role;
+    }
+    roles.iter().map(|r| !r.is_empty()).collect()
+}
+
+/// Routes daemon commands to the pool's workers.
+struct Dispatcher {
+    workers: Vec<mpsc::Sender<WorkerCommand>>,
+    counters: Vec<Arc<WorkerCounters>>,
+    /// Engine roles resident in each worker
+    roles: Vec<HashSet<EngineRole>>,
+    /// Models resident in the pool, for the memory budget and LRU order
+    resident: WarmEngines<()>,
+    accurate_evictions: Arc<AtomicU64>,
+    /// Jobs waiting for an idle worker
+    queue: BTreeMap<QueueKey, Queued>,
+    next_order: u64,
+    /// Jobs kept in memory before spilling (0 = unlimited)
+    max_queued: usize,
+    spill: Option<SpillStore>,
+    /// Used to report jobs whose spilled audio could not be read back
+    result_tx: mpsc::Sender<TranscriptionResult>,
+    /// Unload requested; applied once queued jobs have been dispatched
+    unload_pending: bool,
+}
+
+impl Dispatcher {
+    fn run(mut self, events: std::sync::mpsc::Receiver<PoolEvent>) {
+        let mut closing = false;
+        while let Ok(event) = events.recv() {
+            match event {
+                PoolEvent::Command(WorkerCommand::Job(job)) => self.enqueue(job),
+                PoolEvent::Command(WorkerCommand::LoadEngine(engine)) => {
+                    self.flush_unload();
+                    self.load(EngineRole::Fast, engine);
+                }
+                PoolEvent::Command(WorkerCommand::LoadEngineAs(role, engine)) => {
+                    self.flush_unload();
+                    self.load(role, engine);
+                }
+                PoolEvent::Command(WorkerCommand::EvictEngine(role)) => self.evict(role),
+                PoolEvent::Command(WorkerCommand::UnloadEngine) => self.unload_pending = true,
+                PoolEvent::Command(WorkerCommand::SetRemote(backend, fallback)) => {
+                    self.broadcast(|| WorkerCommand::SetRemote(backend.clone(), fallback));
+                }
+                PoolEvent::Finished(index) => debug!("Transcription worker {} is idle", index),
+                PoolEvent::Closed => closing = true,
+            }
+
+            if !self.dispatch() {
+                break;
+            }
+            self.spill_overflow();
+            if self.queue.is_empty() {
+                self.flush_unload();
+                if closing {
+                    break;
+                }
+            }
+        }
+        debug!("Worker pool dispatcher stopped");
+    }
+
+    fn enqueue(&mut self, job: TranscriptionJob) {
+        debug!(
+            "Queued {} job (seq {}.{}), {} waiting",
+            job.priority,
+            job.sequence_id,
+            job.chunk_id,
+            self.queue.len() + 1
+        );
+        self.queue.insert(
+            (Reverse(job.priority), self.next_order),
+            Queued::Memory(job),
+        );
+        self.next_order += 1;
+    }
+
+    /// Hand queued jobs to idle workers, highest priority first.
+    ///
+    /// Returns false if a worker stopped unexpectedly.
+    fn dispatch(&mut self) -> bool {
+        while let Some(entry) = self.queue.first_entry() {
+            let stats: Vec<_> = self
+                .counters
+                .iter()
+                .enumerate()
+                .map(|(id, c)| c.snapshot(id))
+                .collect();
+
+            // Keep a worker free for push-to-talk while batch work runs
+            let (Reverse(priority), _) = *entry.key();
+            let idle = stats.iter().filter(|s| s.in_flight == 0).count();
+            if priority == JobPriority::Batch && self.workers.len() > 1 && idle < 2 {
+                break;
+            }
+            let role = entry.get().role();
+            let Some(index) = pick_worker(&stats, &capable(&self.roles, role)) else {
+                break;
+            };
+            self.resident.get(role);
+
+            let job = match entry.remove() {
+                Queued::Memory(job) => job,
+                Queued::Spilled(spilled) => match self.restore(spilled) {
+                    Some(job) => job,
+                    None => continue,
+                },
+            };
+            debug!(
+                "Dispatching {} job (seq {}.{}) to worker {}",
+                job.priority, job.sequence_id, job.chunk_id, index
+            );
+            self.counters[index].dispatched();
+            if self.workers[index]
+                .blocking_send(WorkerCommand::Job(job))
+                .is_err()
+            {
+                warn!("Transcription worker {} stopped unexpectedly", index);
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Move the lowest priority, newest in-memory jobs to disk while over the limit.
+    fn spill_overflow(&mut self) {
+        let Some(store) = self.spill.as_mut() else {
+            return;
+        };
+        if self.max_queued == 0 {
+            return;
+        }
+        let mut in_memory = self
+            .queue
+            .values()
+            .filter(|q| matches!(q, Queued::Memory(_)))
+            .count();
+        while in_memory > self.max_queued {
+            let Some(key) = self
+                .queue
+                .iter()
+                .rev()
+                .find(|(_, q)| matches!(q, Queued::Memory(_)))
+                .map(|(key, _)| *key)
+            else {
+                break;
+            };
+            let Some(Queued::Memory(job)) = self.queue.remove(&key) else {
+                break;
+            };
+            match store.spill(job) {
+                Ok(spilled) => {
+                    self.queue.insert(key, Queued::Spilled(spilled));
+                    in_memory -= 1;
+                }
+                Err(job) => {
+                    // Keep it in memory rather than losing it
+                    self.queue.insert(key, Queued::Memory(job));
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Read a spilled job back, reporting an error result if that fails.
+    fn restore(&self, spilled: SpilledJob) -> Option<TranscriptionJob> {
+        let store = self.spill.as_ref()?;
+        let (sequence_id, chunk_id) = (spilled.sequence_id(), spilled.chunk_id());
+        let (is_final, role) = (spilled.is_final(), spilled.role());
+        match store.restore(spilled) {
+            Ok(job) => Some(job),
+            Err(e) => {
+                error!(
+                    "Failed to restore spilled job (seq {}.{}): {}",
+                    sequence_id, chunk_id, e
+                );
+                let result = TranscriptionResult {
+                    text: String::new(),
+                    sequence_id,
+                    chunk_id,
+                    is_final,
+                    duration_secs: 0.0,
+                    error: Some(format!("Spilled audio lost: {}", e)),
+                    language: None,
+                    role,
+                };
+                let _ = self.result_tx.blocking_send(result);
+                None
+            }
+        }
+    }
+
+    /// Give every worker an engine for `role`, forking the original.
+    fn load(&mut self, role: EngineRole, engine: WhisperEngine) {
+        let memory_bytes = engine.model_bytes();
+        for index in 1..self.workers.len() {
+            match engine.fork() {
+                Ok(forked) => {
+                    if self.workers[index]
+                        .blocking_send(WorkerCommand::LoadEngineAs(role, forked))
+                        .is_ok()
+                    {
+                        self.roles[index].insert(role);
+                    }
+                }
+                Err(e) => warn!(
+                    "Failed to create {} engine state for worker {}: {}",
+                    role, index, e
+                ),
+            }
+        }
+        if self.workers[0]
+            .blocking_send(WorkerCommand::LoadEngineAs(role, engine))
+            .is_ok()
+        {
+            self.roles[0].insert(role);
+        }
+        self.make_resident(role, memory_bytes);
+    }
+
+    /// Charge a loaded model against the budget, evicting least recently
+    /// used roles from every worker while over it.
+    fn make_resident(&mut self, role: EngineRole, memory_bytes: u64) {
+        for evicted in self.resident.insert(role, (), memory_bytes) {
+            self.evict(evicted);
+        }
+    }
+
+    fn evict(&mut self, role: EngineRole) {
+        debug!("Evicting {} engine from all workers", role);
+        self.resident.remove(role);
+        self.broadcast(|| WorkerCommand::EvictEngine(role));
+        for roles in &mut self.roles {
+            roles.remove(&role);
+        }
+        if role == EngineRole::Accurate {
+            self.accurate_evictions.fetch_add(1, Ordering::AcqRel);
+        }
+    }
+
+    fn flush_unload(&mut self) {
+        if self.unload_pending {
+            self.unload_pending = false;
+            self.broadcast(|| WorkerCommand::UnloadEngine);
+            self.roles.iter_mut().for_each(HashSet::clear);
+            self.resident.clear();
+        }
+    }
+
+    fn broadcast(&self, command: impl Fn() -> WorkerCommand) {
+        for worker in &self.workers {
+            let _ = worker.blocking_send(command());
+        }
+    }
+}
+
+/// Spawn a pool of transcription workers behind a dispatcher thread.
+///
+/// # Arguments
+/// * `config` - Worker count, memory budget and queue limits
+/// * `engine` - Optional pre-loaded fast Whisper engine, forked for each worker
+/// * `command_rx` - Channel to receive worker commands (jobs, load, unload)
+/// * `result_tx` - Channel to send completed results (shared by all workers)
+/// * `audio_config` - Audio preprocessing configuration
+///
+/// # Errors
+/// Returns an error if a thread cannot be spawned or the spill directory
+/// cannot be created.
+pub fn spawn_pool(
+    config: PoolConfig,
+    engine: Option<WhisperEngine>,
+    mut command_rx: mpsc::Receiver<WorkerCommand>,
+    result_tx: mpsc::Sender<TranscriptionResult>,
+    audio_config: AudioConfig,
+) -> std::io::Result<WorkerPool> {
+    let size = config.workers.max(1);
+    let spill = match config.spill_dir {
+        Some(dir) => {
+            let store = SpillStore::open(dir).map_err(std::io::Error::other)?;
+            info!(
+                "Jobs beyond {} queued will spill to {}",
+                config.max_queued,
+                store.dir().display()
+            );
+            Some(store)
+        }
+        None => None,
+    };
+
+    let (event_tx, event_rx) = std::sync::mpsc::channel();
+    let counters: Vec<Arc<WorkerCounters>> = (0..size)
+        .map(|index| Arc::new(WorkerCounters::notifying(index, event_tx.clone())))
+        .collect();
+
+    // Fork the pre-loaded engine so every worker starts warm
+    let mut engines: Vec<Option<WhisperEngine>> = (1..size)
+        .map(|index| {
+            let forked = engine.as_ref()?.fork();
+            forked
+                .map_err(|e| warn!("Failed to create engine state for worker {}: {}", index, e))
+                .ok()
+        })
+        .collect();
+    let mut resident = WarmEngines::new(config.memory_budget_bytes);
+    if let Some(engine) = &engine {
+        resident.insert(EngineRole::Fast, (), engine.model_bytes());
+    }
+    engines.insert(0, engine);
+    let roles = engines
+        .iter()
+        .map(|engine| engine.iter().map(|_| EngineRole::Fast).collect())
+        .collect();
+    let accurate_evictions = Arc::new(AtomicU64::new(0));
+
+    let mut workers = Vec::with_capacity(size);
+    let mut handles = Vec::with_capacity(size);
+    for (index, engine) in engines.into_iter().enumerate() {
+        let (tx, rx) = mpsc::channel(WORKER_CHANNEL_SIZE);
+        let worker = TranscriptionWorker::new(engine, rx, result_tx.clone(), audio_config.clone())
+            .with_counters(Arc::clone(&counters[index]));
+        let handle = std::thread::Builder::new()
+            .name(format!("transcription-worker-{}", index))
+            .spawn(move || worker.run())?;
+        workers.push(tx);
+        handles.push(handle);
+    }
+
+    let dispatcher = Dispatcher {
+        workers,
+        counters: counters.clone(),
+        roles,
+        resident,
+        accurate_evictions: Arc::clone(&accurate_evictions),
+        queue: BTreeMap::new(),
+        next_order: 0,
+        max_queued: config.max_queued,
+        spill,
+        result_tx,
+        unload_pending: false,
+    };
+    let dispatcher = std::thread::Builder::new()
+        .name("transcription-dispatcher".to_string())
+        .spawn(move || {
+            dispatcher.run(event_rx);
+            // Dropping the dispatcher closed the worker channels; wait for them
+            for (index, handle) in handles.into_iter().enumerate() {
+                if handle.join().is_err() {
+                    warn!("Transcription worker {} panicked", index);
+                }
+            }
+        })?;
+
+    // Feed daemon commands into the dispatcher's event stream
+    std::thread::Builder::new()
+        .name("transcription-queue".to_string())
+        .spawn(move || {
+            while let Some(command) = command_rx.blocking_recv() {
+                if event_tx.send(PoolEvent::Command(command)).is_err() {
+                    return;
+                }
+            }
+            let _ = event_tx.send(PoolEvent::Closed);
+        })?;
+
+    info!("Transcription worker pool started ({} workers)", size);
+
+    Ok(WorkerPool {
+        dispatcher,
+        stats: PoolStats {
+            counters,
+            accurate_evictions,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TranscriptionBackendKind;
+    use crate::input::AudioBuffer;
+    use crate::queue::files::FileJobs;
+
+    fn stats(id: usize, in_flight: usize, completed: u64) -> WorkerStats {
+        WorkerStats {
+            id,
+            in_flight,
+            completed,
+            ..Default::default()
+        }
+    }
+
+    fn job(sequence_id: u64, chunk_id: u32) -> TranscriptionJob {
+        prioritized(sequence_id, chunk_id, JobPriority::Interactive)
+    }
+
+    fn prioritized(sequence_id: u64, chunk_id: u32, priority: JobPriority) -> TranscriptionJob {
+        TranscriptionJob {
+            buffer: AudioBuffer {
+                samples: vec![0.0; 1600],
+                sample_rate: 16000,
+            },
+            sequence_id,
+            chunk_id,
+            is_final: false,
+            role: EngineRole::Fast,
+            priority,
+            language: None,
+            backend: TranscriptionBackendKind::Local,
+        }
+    }
+
+    /// Dispatcher with loaded workers whose channels the test reads directly.
+    fn dispatcher(
+        size: usize,
+        spill: Option<SpillStore>,
+    ) -> (Dispatcher, Vec<mpsc::Receiver<WorkerCommand>>) {
+        let (workers, receivers) = (0..size)
+            .map(|_| mpsc::channel(WORKER_CHANNEL_SIZE))
+            .unzip();
+        let (result_tx, _) = mpsc::channel(1);
+        let dispatcher = Dispatcher {
+            workers,
+            counters: (0..size).map(|_| Arc::default()).collect(),
+            roles: vec![HashSet::from([EngineRole::Fast]); size],
+            resident: WarmEngines::new(0),
+            accurate_evictions: Arc::default(),
+            queue: BTreeMap::new(),
+            next_order: 0,
+            max_queued: 1,
+            spill,
+            result_tx,
+            unload_pending: false,
+        };
+        (dispatcher, receivers)
+    }
+
+    fn received(rx: &mut mpsc::Receiver<WorkerCommand>) -> Option<(u64, u32)> {
+        match rx.try_recv().ok()? {
+            WorkerCommand::Job(job) => Some((job.sequence_id, job.chunk_id)),
+            _ => None,
+        }
+    }
+
+    // ===================
+    // Worker Selection Tests
+    // ===================
+
+    #[test]
+    fn test_pick_worker_only_idle() {
+        let workers = [stats(0, 1, 0), stats(1, 0, 5), stats(2, 1, 0)];
+        assert_eq!(pick_worker(&workers, &[true, true, true]), Some(1));
+
+        let busy = [stats(0, 1, 0), stats(1, 1, 0)];
+        assert_eq!(pick_worker(&busy, &[true, true]), None);
+    }
+
+    #[test]
+    fn test_pick_worker_spreads_ties() {
+        let workers = [stats(0, 0, 3), stats(1, 0, 1), stats(2, 0, 2)];
+        assert_eq!(pick_worker(&workers, &[true, true, true]), Some(1));
+    }
+
+    #[test]
+    fn test_pick_worker_prefers_loaded() {
+        let workers = [stats(0, 1, 0), stats(1, 0, 0)];
+        // Waits for the loaded worker rather than using one without a model
+        assert_eq!(pick_worker(&workers, &[true, false]), None);
+        assert_eq!(pick_worker(&workers, &[false, false]), Some(1));
+    }
+
+    #[test]
+    fn test_counters_track_jobs() {
+        let counters = WorkerCounters::default();
+        counters.dispatched();
+        counters.dispatched();
+        counters.finished(120, false);
+        counters.finished(30, true);
+        // Extra finishes never underflow
+        counters.finished(0, false);
+
+        let snapshot = counters.snapshot(3);
+        assert_eq!(snapshot.id, 3);
+        assert_eq!(snapshot.in_flight, 0);
+        assert_eq!(snapshot.completed, 2);
+        assert_eq!(snapshot.failed, 1);
+        assert_eq!(snapshot.busy_ms, 150);
+    }
+
+    #[test]
+    fn test_dispatch_routes_by_role() {
+        let (mut dispatcher, mut receivers) = dispatcher(2, None);
+        dispatcher.roles[1].insert(EngineRole::Accurate);
+
+        // Worker 0 is idle and has finished fewer jobs, but lacks the engine
+        dispatcher.counters[1].finished(0, false);
+        let mut accurate = job(1, 0);
+        accurate.role = EngineRole::Accurate;
+        dispatcher.enqueue(accurate);
+        assert!(dispatcher.dispatch());
+        assert_eq!(received(&mut receivers[1]), Some((1, 0)));
+        assert_eq!(received(&mut receivers[0]), None);
+
+        // Without the engine anywhere, any loaded worker falls back
+        dispatcher.roles[1].remove(&EngineRole::Accurate);
+        assert_eq!(
+            capable(&dispatcher.roles, EngineRole::Accurate),
+            vec![true, true]
+        );
+    }
+
+    #[test]
+    fn test_budget_counts_shared_models_once() {
+        let (mut dispatcher, mut receivers) = dispatcher(3, None);
+        dispatcher.resident = WarmEngines::new(1000);
+        for roles in &mut dispatcher.roles {
+            roles.insert(EngineRole::Accurate);
+        }
+        let evictions = PoolStats {
+            counters: Vec::new(),
+            accurate_evictions: Arc::clone(&dispatcher.accurate_evictions),
+        };
+
+        // Three workers share both models, which fit the budget together
+        dispatcher.make_resident(EngineRole::Fast, 500);
+        dispatcher.make_resident(EngineRole::Accurate, 400);
+        assert!(receivers.iter_mut().all(|rx| rx.try_recv().is_err()));
+        assert_eq!(evictions.accurate_evictions(), 0);
+
+        // A bigger fast model pushes out the accurate one on every worker
+        dispatcher.make_resident(EngineRole::Fast, 700);
+        for rx in &mut receivers {
+            assert!(matches!(
+                rx.try_recv(),
+                Ok(WorkerCommand::EvictEngine(EngineRole::Accurate))
+            ));
+        }
+        assert!(dispatcher
+            .roles
+            .iter()
+            .all(|r| !r.contains(&EngineRole::Accurate)));
+        assert_eq!(evictions.accurate_evictions(), 1);
+    }
+
+    // ===================
+    // Priority Tests
+    // ===================
+
+    #[test]
+    fn test_dispatch_by_priority() {
+        let (mut dispatcher, mut rx) = dispatcher(1, None);
+        dispatcher.counters[0].dispatched(); // Worker busy
+
+        dispatcher.enqueue(prioritized(1, 0, JobPriority::Batch));
+        dispatcher.enqueue(prioritized(2, 0, JobPriority::Recording));
+        dispatcher.enqueue(prioritized(3, 0, JobPriority::Interactive));
+        dispatcher.enqueue(prioritized(3, 1, JobPriority::Interactive));
+        assert!(dispatcher.dispatch());
+        assert_eq!(received(&mut rx[0]), None);
+
+        let mut order = Vec::new();
+        for _ in 0..4 {
+            dispatcher.counters[0].finished(0, false);
+            assert!(dispatcher.dispatch());
+            order.extend(received(&mut rx[0]));
+        }
+        assert_eq!(order, vec![(3, 0), (3, 1), (2, 0), (1, 0)]);
+        assert!(dispatcher.queue.is_empty());
+    }
+
+    #[test]
+    fn test_batch_keeps_worker_free() {
+        let (mut dispatcher, mut rx) = dispatcher(2, None);
+        dispatcher.counters[0].dispatched(); // Worker 0 busy
+
+        // Batch work waits while only one worker is idle...
+        dispatcher.enqueue(prioritized(1, 0, JobPriority::Batch));
+        assert!(dispatcher.dispatch());
+        assert_eq!(received(&mut rx[1]), None);
+
+        // ...so push-to-talk starts immediately
+        dispatcher.enqueue(job(2, 0));
+        assert!(dispatcher.dispatch());
+        assert_eq!(received(&mut rx[1]), Some((2, 0)));
+
+        // Once both are idle the batch job runs
+        dispatcher.counters[0].finished(0, false);
+        dispatcher.counters[1].finished(0, false);
+        assert!(dispatcher.dispatch());
+        assert_eq!(received(&mut rx[0]), Some((1, 0)));
+    }
+
+    #[test]
+    fn test_file_job_never_delays_push_to_talk() {
+        let mut files = FileJobs::new();
+        let file_job = |files: &mut FileJobs| {
+            let buffer = AudioBuffer {
+                samples: vec![0.0; 16000],
+                sample_rate: 16000,
+            };
+            files.submit(
+                buffer,
+                None,
+                TranscriptionBackendKind::Local,
+                Box::new(|_| {}),
+            )
+        };
+
+        // One worker: a file queued first still runs after push-to-talk
+        let (mut single, mut rx) = dispatcher(1, None);
+        single.counters[0].dispatched(); // Worker busy
+        let first = file_job(&mut files);
+        let file_seq = first.sequence_id;
+        single.enqueue(first);
+        single.enqueue(job(1, 0));
+        single.counters[0].finished(0, false);
+        assert!(single.dispatch());
+        assert_eq!(received(&mut rx[0]), Some((1, 0)));
+        single.counters[0].finished(0, false);
+        assert!(single.dispatch());
+        assert_eq!(received(&mut rx[0]), Some((file_seq, 0)));
+
+        // Two workers: the file leaves one worker free for push-to-talk
+        let (mut pair, mut rx) = dispatcher(2, None);
+        let second = file_job(&mut files);
+        let file_seq = second.sequence_id;
+        pair.enqueue(second);
+        assert!(pair.dispatch());
+        assert_eq!(received(&mut rx[0]), Some((file_seq, 0)));
+        pair.enqueue(job(2, 0));
+        assert!(pair.dispatch());
+        assert_eq!(received(&mut rx[1]), Some((2, 0)));
+    }
+
+    #[test]
+    fn test_spill_overflow_and_restore() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SpillStore::open(dir.path()).unwrap();
+        let (mut dispatcher, mut rx) = dispatcher(1, Some(store));
+        dispatcher.counters[0].dispatched(); // Worker busy
+
+        dispatcher.enqueue(job(1, 0));
+        dispatcher.enqueue(job(1, 1));
+        dispatcher.enqueue(prioritized(2, 0, JobPriority::Batch));
+        assert!(dispatcher.dispatch());
+        dispatcher.spill_overflow();
+
+        // Only the highest priority, oldest job stays in memory
+        let spilled = dispatcher
+            .queue
+            .values()
+            .filter(|q| matches!(q, Queued::Spilled(_)))
+            .count();
+        assert_eq!(spilled, 2);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 2);
+
+        let mut order = Vec::new();
+        for _ in 0..3 {
+            dispatcher.counters[0].finished(0, false);
+            assert!(dispatcher.dispatch());
+            order.extend(received(&mut rx[0]));
+        }
+        assert_eq!(order, vec![(1, 0), (1, 1), (2, 0)]);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_unload_waits_for_queue() {
+        let (mut dispatcher, mut rx) = dispatcher(1, None);
+        dispatcher.counters[0].dispatched(); // Worker busy
+        dispatcher.enqueue(job(1, 0));
+        dispatcher.unload_pending = true;
+
+        dispatcher.counters[0].finished(0, false);
+        assert!(dispatcher.dispatch());
+        dispatcher.flush_unload();
+        assert_eq!(received(&mut rx[0]), Some((1, 0)));
+        assert!(matches!(rx[0].try_recv(), Ok(WorkerCommand::UnloadEngine)));
+        assert!(dispatcher.roles[0].is_empty());
+    }
+
+    // ===================
+    // Pool Tests
+    // ===================
+
+    #[tokio::test]
+    async fn test_pool_distributes_jobs() {
+        let (cmd_tx, cmd_rx) = mpsc::channel::<WorkerCommand>(16);
+        let (result_tx, mut result_rx) = mpsc::channel(16);
+        let config = PoolConfig {
+            workers: 3,
+            ..Default::default()
+        };
+
+        let pool = spawn_pool(config, None, cmd_rx, result_tx, AudioConfig::default()).unwrap();
+        assert_eq!(pool.size(), 3);
+        let stats = pool.stats();
+
+        for chunk in 0..6 {
+            cmd_tx
+                .send(WorkerCommand::Job(job(1, chunk)))
+                .await
+                .unwrap();
+        }
+        drop(cmd_tx);
+
+        // Without a model every job returns an error result
+        let mut chunks = Vec::new();
+        while let Some(result) = result_rx.recv().await {
+            assert!(result.error.is_some());
+            chunks.push(result.chunk_id);
+        }
+        chunks.sort_unstable();
+        assert_eq!(chunks, vec![0, 1, 2, 3, 4, 5]);
+
+        pool.join().expect("Worker pool panicked");
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.len(), 3);
+        assert_eq!(snapshot.iter().map(WorkerStats::finished).sum::<u64>(), 6);
+        assert!(snapshot.iter().all(|s| s.in_flight == 0));
+    }
+}
@@ -0,0 +1,221 @@
+//! Overflow storage for queued transcription jobs.
+//!
+//! With the `spill_to_disk` backpressure strategy, jobs beyond the queue
+//! limit are written to WAV files instead of being dropped, and read back
+//! when a worker becomes free.
+
+use crate::config::{Config, TranscriptionBackendKind};
+use crate::input::AudioBuffer;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use tracing::{debug, warn};
+
+use super::{EngineRole, JobPriority, TranscriptionJob};
+
+#[derive(Error, Debug)]
+pub enum SpillError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("WAV error: {0}")]
+    Wav(#[from] hound::Error),
+
+    #[error("Could not determine data directory")]
+    NoDataDir,
+}
+
+/// A job whose audio lives on disk.
+#[derive(Debug)]
+pub struct SpilledJob {
+    path: PathBuf,
+    sequence_id: u64,
+    chunk_id: u32,
+    is_final: bool,
+    role: EngineRole,
+    priority: JobPriority,
+    language: Option<String>,
+    backend: TranscriptionBackendKind,
+}
+
+impl SpilledJob {
+    pub fn sequence_id(&self) -> u64 {
+        self.sequence_id
+    }
+
+    pub fn chunk_id(&self) -> u32 {
+        self.chunk_id
+    }
+
+    pub fn is_final(&self) -> bool {
+        self.is_final
+    }
+
+    pub fn role(&self) -> EngineRole {
+        self.role
+    }
+}
+
+/// Directory of spilled job audio.
+#[derive(Debug)]
+pub struct SpillStore {
+    dir: PathBuf,
+    counter: u64,
+}
+
+impl SpillStore {
+    /// Open a spill directory, removing files left over from a previous run.
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self, SpillError> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        for entry in std::fs::read_dir(&dir)?.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "wav") {
+                debug!("Removing stale spill file {}", path.display());
+                let _ = std::fs::remove_file(path);
+            }
+        }
+        Ok(Self { dir, counter: 0 })
+    }
+
+    /// Default spill directory (`<data dir>/queue/spill`).
+    pub fn default_dir() -> Result<PathBuf, SpillError> {
+        Config::data_dir()
+            .map(|dir| dir.join("queue").join("spill"))
+            .map_err(|_| SpillError::NoDataDir)
+    }
+
+    /// Directory holding spill files.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Write a job's audio to disk.
+    ///
+    /// On failure the job is handed back so it can stay in memory.
+    pub fn spill(&mut self, job: TranscriptionJob) -> Result<SpilledJob, TranscriptionJob> {
+        self.counter += 1;
+        let path = self.dir.join(format!(
+            "{:06}-{}-{}.wav",
+            self.counter, job.sequence_id, job.chunk_id
+        ));
+        if let Err(e) = write_wav(&path, &job.buffer) {
+            warn!("Failed to spill job to {}: {}", path.display(), e);
+            let _ = std::fs::remove_file(&path);
+            return Err(job);
+        }
+        debug!(
+            "Spilled job (seq {}.{}) to {}",
+            job.sequence_id,
+            job.chunk_id,
+            path.display()
+        );
+        Ok(SpilledJob {
+            path,
+            sequence_id: job.sequence_id,
+            chunk_id: job.chunk_id,
+            is_final: job.is_final,
+            role: job.role,
+            priority: job.priority,
+            language: job.language,
+            backend: job.backend,
+        })
+    }
+
+    /// Read a spilled job back into memory and delete its file.
+    pub fn restore(&self, spilled: SpilledJob) -> Result<TranscriptionJob, SpillError> {
+        let buffer = read_wav(&spilled.path);
+        let _ = std::fs::remove_file(&spilled.path);
+        Ok(TranscriptionJob {
+            buffer: buffer?,
+            sequence_id: spilled.sequence_id,
+            chunk_id: spilled.chunk_id,
+            is_final: spilled.is_final,
+            role: spilled.role,
+            priority: spilled.priority,
+            language: spilled.language,
+            backend: spilled.backend,
+        })
+    }
+}
+
+/// Write mono audio as a 32-bit float WAV file.
+pub(super) fn write_wav(path: &Path, buffer: &AudioBuffer) -> Result<(), hound::Error> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: buffer.sample_rate,
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+    let mut writer = hound::WavWriter::create(path, spec)?;
+    for &sample in &buffer.samples {
+        writer.write_sample(sample)?;
+    }
+    writer.finalize()?;
+    Ok(())
+}
+
+/// Read audio written by [`write_wav`].
+pub(super) fn read_wav(path: &Path) -> Result<AudioBuffer, hound::Error> {
+    let mut reader = hound::WavReader::open(path)?;
+    let sample_rate = reader.spec().sample_rate;
+    let samples = reader.samples::<f32>().collect::<Result<Vec<_>, _>>()?;
+    Ok(AudioBuffer {
+        samples,
+        sample_rate,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn job(sequence_id: u64, chunk_id: u32) -> TranscriptionJob {
+        TranscriptionJob {
+            buffer: AudioBuffer {
+                samples: vec![0.25, -0.5, 0.75],
+                sample_rate: 16000,
+            },
+            sequence_id,
+            chunk_id,
+            is_final: true,
+            role: EngineRole::Accurate,
+            priority: JobPriority::Batch,
+            language: Some("fr".to_string()),
+            backend: TranscriptionBackendKind::Local,
+        }
+    }
+
+    #[test]
+    fn test_spill_roundtrip() {
+        let dir = tempdir().unwrap();
+        let mut store = SpillStore::open(dir.path()).unwrap();
+
+        let spilled = store.spill(job(7, 2)).unwrap();
+        assert_eq!(spilled.sequence_id(), 7);
+        assert_eq!(spilled.chunk_id(), 2);
+        assert_eq!(std::fs::read_dir(store.dir()).unwrap().count(), 1);
+
+        let restored = store.restore(spilled).unwrap();
+        assert_eq!(restored.buffer.samples, vec![0.25, -0.5, 0.75]);
+        assert_eq!(restored.buffer.sample_rate, 16000);
+        assert_eq!(restored.role, EngineRole::Accurate);
+        assert_eq!(restored.priority, JobPriority::Batch);
+        assert_eq!(restored.language.as_deref(), Some("fr"));
+        assert!(restored.is_final);
+
+        // The file is removed once restored
+        assert_eq!(std::fs::read_dir(store.dir()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_open_removes_stale_files() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("000001-1-0.wav"), b"stale").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), b"keep").unwrap();
+
+        SpillStore::open(dir.path()).unwrap();
+        assert!(!dir.path().join("000001-1-0.wav").exists());
+        assert!(dir.path().join("notes.txt").exists());
+    }
+}
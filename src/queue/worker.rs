@@ -5,23 +5,39 @@
 //! results back for ordered output.
 //!
 //! Supports dynamic model loading/unloading for GPU memory management.
+//! Several engines can be kept warm at once (a fast model for live
+//! dictation and an accurate one for recordings and refinement); jobs
+//! are routed by [`EngineRole`]. The pool keeps the memory budget and
+//! tells every worker which engine to evict.
+//!
+//! A remote [`TranscriptionBackend`] can take over transcription; the local
+//! engines then serve as fallback while the server is unavailable.
 
-use crate::config::AudioConfig;
-use crate::engine::WhisperEngine;
+use crate::config::{AudioConfig, TranscriptionBackendKind};
+use crate::engine::{SharedBackend, TranscriptionBackend, WhisperEngine};
 use crate::input::AudioBuffer;
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
-use super::{TranscriptionJob, TranscriptionResult};
+use super::pool::WorkerCounters;
+use super::{EngineRole, TranscriptionJob, TranscriptionResult};
+use std::sync::Arc;
 
 /// Commands that can be sent to the transcription worker.
 pub enum WorkerCommand {
     /// Process a transcription job
     Job(TranscriptionJob),
-    /// Load a new Whisper engine (replaces existing if any)
+    /// Load the fast Whisper engine (replaces existing fast engine if any)
     LoadEngine(WhisperEngine),
-    /// Unload the current engine to free GPU memory
+    /// Load a Whisper engine for a specific role (replaces existing if any)
+    LoadEngineAs(EngineRole, WhisperEngine),
+    /// Drop the engine for a role to stay within the memory budget
+    EvictEngine(EngineRole),
+    /// Unload all engines to free GPU memory
     UnloadEngine,
+    /// Route jobs to a remote backend (None = local only); the flag
+    /// allows falling back to the local engines when it is unavailable
+    SetRemote(Option<SharedBackend>, bool),
 }
 
 impl std::fmt::Debug for WorkerCommand {
@@ -29,8 +45,127 @@ impl std::fmt::Debug for WorkerCommand {
         match self {
             Self::Job(job) => f.debug_tuple("Job").field(job).finish(),
             Self::LoadEngine(_) => f.debug_tuple("LoadEngine").field(&"<engine>").finish(),
+            Self::LoadEngineAs(role, _) => f
+                .debug_tuple("LoadEngineAs")
+                .field(role)
+                .field(&"<engine>")
+                .finish(),
+            Self::EvictEngine(role) => f.debug_tuple("EvictEngine").field(role).finish(),
             Self::UnloadEngine => write!(f, "UnloadEngine"),
+            Self::SetRemote(backend, fallback) => f
+                .debug_tuple("SetRemote")
+                .field(&backend.as_ref().map(|b| b.name()))
+                .field(fallback)
+                .finish(),
+        }
+    }
+}
+
+/// A resident engine and its bookkeeping.
+struct WarmSlot<E> {
+    role: EngineRole,
+    engine: E,
+    memory_bytes: u64,
+    last_used: u64,
+}
+
+/// Engines kept resident, keyed by role.
+///
+/// Generic over the engine type so the routing and eviction logic can be
+/// tested without loading a model. The pool tracks its shared models with
+/// `WarmEngines<()>` to apply the memory budget.
+pub(super) struct WarmEngines<E> {
+    slots: Vec<WarmSlot<E>>,
+    /// Total memory allowed for resident engines (0 = unlimited)
+    budget_bytes: u64,
+    /// Monotonic use counter for LRU ordering
+    clock: u64,
+}
+
+impl<E> WarmEngines<E> {
+    pub(super) fn new(budget_bytes: u64) -> Self {
+        Self {
+            slots: Vec::new(),
+            budget_bytes,
+            clock: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    fn memory_bytes(&self) -> u64 {
+        self.slots.iter().map(|s| s.memory_bytes).sum()
+    }
+
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    /// Insert an engine, replacing any engine with the same role.
+    ///
+    /// Returns the roles evicted to stay within the memory budget. The
+    /// engine just inserted is never evicted, even if it alone exceeds
+    /// the budget.
+    pub(super) fn insert(
+        &mut self,
+        role: EngineRole,
+        engine: E,
+        memory_bytes: u64,
+    ) -> Vec<EngineRole> {
+        self.slots.retain(|s| s.role != role);
+        let last_used = self.tick();
+        self.slots.push(WarmSlot {
+            role,
+            engine,
+            memory_bytes,
+            last_used,
+        });
+
+        let mut evicted = Vec::new();
+        while self.budget_bytes > 0 && self.memory_bytes() > self.budget_bytes {
+            let Some(lru) = self
+                .slots
+                .iter()
+                .enumerate()
+                .filter(|(_, s)| s.role != role)
+                .min_by_key(|(_, s)| s.last_used)
+                .map(|(i, _)| i)
+            else {
+                break;
+            };
+            evicted.push(self.slots.remove(lru).role);
         }
+        evicted
+    }
+
+    /// Get the engine for a role, falling back to the most recently used
+    /// engine of another role. Marks the returned engine as used.
+    pub(super) fn get(&mut self, role: EngineRole) -> Option<(EngineRole, &E)> {
+        let index = self.slots.iter().position(|s| s.role == role).or_else(|| {
+            self.slots
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, s)| s.last_used)
+                .map(|(i, _)| i)
+        })?;
+        let now = self.tick();
+        let slot = &mut self.slots[index];
+        slot.last_used = now;
+        Some((slot.role, &slot.engine))
+    }
+
+    /// Drop the engine for a role. Returns whether one was resident.
+    pub(super) fn remove(&mut self, role: EngineRole) -> bool {
+        let before = self.slots.len();
+        self.slots.retain(|s| s.role != role);
+        self.slots.len() != before
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.slots.clear();
     }
 }
 
@@ -39,24 +174,30 @@ impl std::fmt::Debug for WorkerCommand {
 /// Runs in a dedicated thread with blocking receives to avoid async
 /// overhead in the GPU transcription path.
 ///
-/// The engine is held as `Option<WhisperEngine>` to support dynamic
-/// loading and unloading for GPU memory management.
+/// Engines are held in a role-keyed set to support dynamic loading and
+/// unloading for GPU memory management.
 pub struct TranscriptionWorker {
-    /// Whisper engine for transcription (None if unloaded)
-    engine: Option<WhisperEngine>,
+    /// Resident Whisper engines (empty if unloaded)
+    engines: WarmEngines<WhisperEngine>,
     /// Channel to receive commands (jobs, load, unload)
     command_rx: mpsc::Receiver<WorkerCommand>,
     /// Channel to send results
     result_tx: mpsc::Sender<TranscriptionResult>,
     /// Audio preprocessing config
     audio_config: AudioConfig,
+    /// Job statistics (shared with the pool when pooled)
+    counters: Arc<WorkerCounters>,
+    /// Remote backend that takes jobs before the local engines
+    remote: Option<SharedBackend>,
+    /// Use local engines when the remote backend is unavailable or fails
+    remote_fallback: bool,
 }
 
 impl TranscriptionWorker {
     /// Create a new transcription worker.
     ///
     /// # Arguments
-    /// * `engine` - Optional pre-loaded Whisper engine (None for lazy loading)
+    /// * `engine` - Optional pre-loaded fast Whisper engine (None for lazy loading)
     /// * `command_rx` - Channel to receive worker commands
     /// * `result_tx` - Channel to send completed results
     /// * `audio_config` - Audio preprocessing configuration
@@ -66,14 +207,29 @@ impl TranscriptionWorker {
         result_tx: mpsc::Sender<TranscriptionResult>,
         audio_config: AudioConfig,
     ) -> Self {
+        // The pool applies the memory budget across workers
+        let mut engines = WarmEngines::new(0);
+        if let Some(engine) = engine {
+            let memory_bytes = engine.model_bytes();
+            engines.insert(EngineRole::Fast, engine, memory_bytes);
+        }
         Self {
-            engine,
+            engines,
             command_rx,
             result_tx,
             audio_config,
+            counters: Arc::default(),
+            remote: None,
+            remote_fallback: true,
         }
     }
 
+    /// Report job statistics to shared counters.
+    pub fn with_counters(mut self, counters: Arc<WorkerCounters>) -> Self {
+        self.counters = counters;
+        self
+    }
+
     /// Run the worker loop (blocking, runs in dedicated thread).
     ///
     /// This method blocks on receiving commands and runs until the channel is closed.
@@ -85,10 +241,10 @@ impl TranscriptionWorker {
     pub fn run(mut self) {
         info!(
             "Transcription worker started (engine: {})",
-            if self.engine.is_some() {
-                "loaded"
-            } else {
+            if self.engines.is_empty() {
                 "not loaded"
+            } else {
+                "loaded"
             }
         );
 
@@ -98,31 +254,70 @@ impl TranscriptionWorker {
                     self.process_job(job);
                 }
                 WorkerCommand::LoadEngine(engine) => {
-                    info!("Loading Whisper engine in worker thread");
-                    self.engine = Some(engine);
+                    self.load_engine(EngineRole::Fast, engine);
+                }
+                WorkerCommand::LoadEngineAs(role, engine) => {
+                    self.load_engine(role, engine);
+                }
+                WorkerCommand::EvictEngine(role) => {
+                    if self.engines.remove(role) {
+                        info!("Evicted {} engine to stay within the memory budget", role);
+                    }
                 }
                 WorkerCommand::UnloadEngine => {
-                    if self.engine.is_some() {
-                        info!("Unloading Whisper engine to free GPU memory");
-                        self.engine = None;
-                    } else {
+                    if self.engines.is_empty() {
                         debug!("UnloadEngine received but engine already unloaded");
+                    } else {
+                        info!("Unloading Whisper engines to free GPU memory");
+                        self.engines.clear();
                     }
                 }
+                WorkerCommand::SetRemote(backend, fallback) => {
+                    match &backend {
+                        Some(backend) => info!(
+                            "Using {} transcription backend (model: {}, fallback to local: {})",
+                            backend.name(),
+                            backend.model(),
+                            fallback
+                        ),
+                        None => info!("Using local transcription backend"),
+                    }
+                    self.remote = backend;
+                    self.remote_fallback = fallback;
+                }
             }
         }
 
         info!("Transcription worker stopped");
     }
 
+    /// Make an engine resident.
+    fn load_engine(&mut self, role: EngineRole, engine: WhisperEngine) {
+        let memory_bytes = engine.model_bytes();
+        info!(
+            "Loading {} Whisper engine in worker thread ({} MB)",
+            role,
+            memory_bytes / 1_000_000
+        );
+        self.engines.insert(role, engine, memory_bytes);
+    }
+
     /// Process a single transcription job.
     fn process_job(&mut self, job: TranscriptionJob) {
         let sequence_id = job.sequence_id;
         let chunk_id = job.chunk_id;
         let is_final = job.is_final;
 
+        // Only recordings started with the remote backend use it; skip an
+        // unreachable server when the local engines can step in
+        let remote = self
+            .remote
+            .clone()
+            .filter(|_| job.backend == TranscriptionBackendKind::Remote)
+            .filter(|remote| !self.remote_fallback || remote.is_available());
+
         // Check if engine is loaded
-        let Some(engine) = &self.engine else {
+        if remote.is_none() && self.engines.is_empty() {
             warn!(
                 "Transcription job (seq {}.{}) received but model not loaded",
                 sequence_id, chunk_id
@@ -134,12 +329,16 @@ impl TranscriptionWorker {
                 chunk_id,
                 is_final,
                 duration_secs: 0.0,
+                error: Some("Model not loaded".to_string()),
+                language: None,
+                role: job.role,
             };
+            self.counters.finished(0, true);
             if self.result_tx.blocking_send(result).is_err() {
                 debug!("Result channel closed, worker shutting down");
             }
             return;
-        };
+        }
 
         let total_start = std::time::Instant::now();
         debug!(
@@ -156,11 +355,39 @@ impl TranscriptionWorker {
 
         // Transcribe
         let transcribe_start = std::time::Instant::now();
-        let text = match engine.transcribe(&buffer) {
-            Ok(result) => result.text,
-            Err(e) => {
-                error!("Transcription failed (sequence_id: {}): {}", sequence_id, e);
-                String::new()
+        let (text, language, error, backend) = match remote {
+            Some(remote) => match remote.transcribe(&buffer, job.language.as_deref()) {
+                Ok(result) => (
+                    result.text,
+                    known_language(result.language),
+                    None,
+                    remote.name(),
+                ),
+                Err(e) if self.remote_fallback && !self.engines.is_empty() => {
+                    warn!(
+                        "{} transcription failed, falling back to local Whisper (seq {}.{}): {}",
+                        remote.name(),
+                        sequence_id,
+                        chunk_id,
+                        e
+                    );
+                    let (text, language, error) = self.transcribe_local(
+                        job.role,
+                        job.language.as_deref(),
+                        &buffer,
+                        sequence_id,
+                    );
+                    (text, language, error, "whisper")
+                }
+                Err(e) => {
+                    error!("Transcription failed (sequence_id: {}): {}", sequence_id, e);
+                    (String::new(), None, Some(e.to_string()), remote.name())
+                }
+            },
+            None => {
+                let (text, language, error) =
+                    self.transcribe_local(job.role, job.language.as_deref(), &buffer, sequence_id);
+                (text, language, error, "whisper")
             }
         };
         let transcribe_ms = transcribe_start.elapsed().as_millis();
@@ -168,10 +395,11 @@ impl TranscriptionWorker {
 
         // Log timing breakdown
         info!(
-            "⏱️  Timing (seq {}.{}{}): audio={:.1}s | preprocess={}ms | transcribe={}ms | total={}ms | ratio={:.2}x",
+            "⏱️  Timing (seq {}.{}{}, {}): audio={:.1}s | preprocess={}ms | transcribe={}ms | total={}ms | ratio={:.2}x",
             sequence_id,
             chunk_id,
             if is_final { " FINAL" } else { "" },
+            backend,
             audio_duration_secs,
             preprocess_ms,
             transcribe_ms,
@@ -179,6 +407,8 @@ impl TranscriptionWorker {
             total_ms as f32 / (audio_duration_secs * 1000.0)
         );
 
+        self.counters.finished(total_ms as u64, error.is_some());
+
         // Send result
         let result = TranscriptionResult {
             text,
@@ -186,12 +416,41 @@ impl TranscriptionWorker {
             chunk_id,
             is_final,
             duration_secs: audio_duration_secs,
+            error,
+            language,
+            role: job.role,
         };
         if self.result_tx.blocking_send(result).is_err() {
             debug!("Result channel closed, worker shutting down");
         }
     }
 
+    /// Transcribe on the local engine for `role` (or any loaded engine).
+    fn transcribe_local(
+        &mut self,
+        role: EngineRole,
+        language: Option<&str>,
+        buffer: &AudioBuffer,
+        sequence_id: u64,
+    ) -> (String, Option<String>, Option<String>) {
+        let Some((loaded, engine)) = self.engines.get(role) else {
+            return (String::new(), None, Some("Model not loaded".to_string()));
+        };
+        if loaded != role {
+            debug!(
+                "No {} engine loaded, using {} engine (seq {})",
+                role, loaded, sequence_id
+            );
+        }
+        match TranscriptionBackend::transcribe(engine, buffer, language) {
+            Ok(result) => (result.text, known_language(result.language), None),
+            Err(e) => {
+                error!("Transcription failed (sequence_id: {}): {}", sequence_id, e);
+                (String::new(), None, Some(e.to_string()))
+            }
+        }
+    }
+
     /// Apply audio preprocessing (noise reduction, normalization, compression, limiter).
     fn preprocess_audio(buffer: &mut AudioBuffer, config: &AudioConfig) {
         // Noise reduction is independent of the preprocessing flag
@@ -240,30 +499,9 @@ impl TranscriptionWorker {
     }
 }
 
-/// Spawn a transcription worker in a dedicated thread.
-///
-/// Returns a handle to the thread for optional join on shutdown.
-///
-/// # Arguments
-/// * `engine` - Optional pre-loaded Whisper engine (None for lazy loading)
-/// * `command_rx` - Channel to receive worker commands (jobs, load, unload)
-/// * `result_tx` - Channel to send completed results
-/// * `audio_config` - Audio preprocessing configuration
-///
-/// # Errors
-/// Returns an error if the thread cannot be spawned (rare, usually resource exhaustion).
-pub fn spawn_worker(
-    engine: Option<WhisperEngine>,
-    command_rx: mpsc::Receiver<WorkerCommand>,
-    result_tx: mpsc::Sender<TranscriptionResult>,
-    audio_config: AudioConfig,
-) -> std::io::Result<std::thread::JoinHandle<()>> {
-    std::thread::Builder::new()
-        .name("transcription-worker".to_string())
-        .spawn(move || {
-            let worker = TranscriptionWorker::new(engine, command_rx, result_tx, audio_config);
-            worker.run();
-        })
+/// Keep a reported language unless it only says detection failed.
+fn known_language(language: String) -> Option<String> {
+    (!language.is_empty() && language != "auto" && language != "unknown").then_some(language)
 }
 
 #[cfg(test)]
@@ -285,6 +523,9 @@ mod tests {
             noise_reduction: crate::config::NoiseReductionConfig::default(),
             channels: crate::config::ChannelSelection::default(),
             input_device: None,
+            preferred_devices: Vec::new(),
+            capture_app: None,
+            echo_cancellation: crate::config::EchoCancellationConfig::default(),
         }
     }
 
@@ -313,6 +554,9 @@ mod tests {
             noise_reduction: crate::config::NoiseReductionConfig::default(),
             channels: crate::config::ChannelSelection::default(),
             input_device: None,
+            preferred_devices: Vec::new(),
+            capture_app: None,
+            echo_cancellation: crate::config::EchoCancellationConfig::default(),
         }
     }
 
@@ -415,6 +659,10 @@ mod tests {
             sequence_id: 42,
             chunk_id: 1,
             is_final: true,
+            role: EngineRole::Fast,
+            priority: crate::queue::JobPriority::default(),
+            language: None,
+            backend: TranscriptionBackendKind::Local,
         };
         let cmd = WorkerCommand::Job(job);
         let debug_str = format!("{:?}", cmd);
@@ -437,6 +685,14 @@ mod tests {
         assert_eq!(debug_str, "UnloadEngine");
     }
 
+    #[test]
+    fn test_known_language() {
+        assert_eq!(known_language("de".to_string()).as_deref(), Some("de"));
+        assert_eq!(known_language("auto".to_string()), None);
+        assert_eq!(known_language("unknown".to_string()), None);
+        assert_eq!(known_language(String::new()), None);
+    }
+
     // ===================
     // TranscriptionResult Tests
     // ===================
@@ -449,6 +705,9 @@ mod tests {
             chunk_id: 0,
             is_final: true,
             duration_secs: 5.5,
+            error: None,
+            language: None,
+            role: EngineRole::Fast,
         };
         let cloned = result.clone();
         assert_eq!(result.text, cloned.text);
@@ -466,6 +725,9 @@ mod tests {
             chunk_id: 2,
             is_final: false,
             duration_secs: 3.5,
+            error: None,
+            language: None,
+            role: EngineRole::Fast,
         };
         let debug_str = format!("{:?}", result);
         assert!(debug_str.contains("Test"));
@@ -487,53 +749,181 @@ mod tests {
             sequence_id: 10,
             chunk_id: 3,
             is_final: true,
+            role: EngineRole::Accurate,
+            priority: crate::queue::JobPriority::default(),
+            language: Some("de".to_string()),
+            backend: TranscriptionBackendKind::Local,
         };
         let debug_str = format!("{:?}", job);
         assert!(debug_str.contains("sequence_id: 10"));
         assert!(debug_str.contains("chunk_id: 3"));
         assert!(debug_str.contains("is_final: true"));
+        assert!(debug_str.contains("role: Accurate"));
+        assert!(debug_str.contains("language: Some(\"de\")"));
     }
 
     // ===================
-    // Spawn Worker Tests
+    // Warm Engine Tests
     // ===================
 
     #[test]
-    fn test_spawn_worker_starts_thread() {
-        use tokio::sync::mpsc;
+    fn test_warm_engines_routes_by_role() {
+        let mut engines = WarmEngines::new(0);
+        engines.insert(EngineRole::Fast, "small", 500);
+        engines.insert(EngineRole::Accurate, "large-v3", 3000);
+
+        assert_eq!(
+            engines.get(EngineRole::Fast),
+            Some((EngineRole::Fast, &"small"))
+        );
+        assert_eq!(
+            engines.get(EngineRole::Accurate),
+            Some((EngineRole::Accurate, &"large-v3"))
+        );
+    }
 
-        let (cmd_tx, cmd_rx) = mpsc::channel::<WorkerCommand>(10);
-        let (result_tx, _result_rx) = mpsc::channel(10);
-        let config = test_audio_config_disabled();
+    #[test]
+    fn test_warm_engines_falls_back_to_loaded_engine() {
+        let mut engines = WarmEngines::new(0);
+        assert!(engines.get(EngineRole::Fast).is_none());
+
+        engines.insert(EngineRole::Fast, "small", 500);
+        assert_eq!(
+            engines.get(EngineRole::Accurate),
+            Some((EngineRole::Fast, &"small"))
+        );
+    }
 
-        // Spawn worker without engine (lazy loading mode)
-        let handle = spawn_worker(None, cmd_rx, result_tx, config);
-        assert!(handle.is_ok());
+    #[test]
+    fn test_warm_engines_replaces_same_role() {
+        let mut engines = WarmEngines::new(0);
+        engines.insert(EngineRole::Fast, "tiny", 100);
+        engines.insert(EngineRole::Fast, "small", 500);
+
+        assert_eq!(engines.memory_bytes(), 500);
+        assert_eq!(
+            engines.get(EngineRole::Fast),
+            Some((EngineRole::Fast, &"small"))
+        );
+    }
 
-        // Drop the command channel to signal shutdown
-        drop(cmd_tx);
+    #[test]
+    fn test_warm_engines_evicts_least_recently_used() {
+        let mut engines = WarmEngines::new(1000);
+        engines.insert(EngineRole::Fast, "small", 500);
+        engines.insert(EngineRole::Accurate, "medium", 400);
 
-        // Worker should exit gracefully
-        let join_handle = handle.unwrap();
-        let join_result = join_handle.join();
-        assert!(join_result.is_ok());
+        // Fast engine is used more recently than the accurate one
+        engines.get(EngineRole::Fast);
+
+        let evicted = engines.insert(EngineRole::Fast, "base", 700);
+        assert_eq!(evicted, vec![EngineRole::Accurate]);
+        assert_eq!(engines.memory_bytes(), 700);
     }
 
-    #[tokio::test]
-    async fn test_worker_receives_unload_command() {
-        let (cmd_tx, cmd_rx) = tokio::sync::mpsc::channel::<WorkerCommand>(10);
-        let (result_tx, _result_rx) = tokio::sync::mpsc::channel(10);
-        let config = test_audio_config_disabled();
+    #[test]
+    fn test_warm_engines_keeps_new_engine_over_budget() {
+        let mut engines = WarmEngines::new(1000);
+        engines.insert(EngineRole::Fast, "small", 500);
+
+        let evicted = engines.insert(EngineRole::Accurate, "large-v3", 3000);
+        assert_eq!(evicted, vec![EngineRole::Fast]);
+        assert_eq!(
+            engines.get(EngineRole::Fast),
+            Some((EngineRole::Accurate, &"large-v3"))
+        );
+    }
 
-        let handle = spawn_worker(None, cmd_rx, result_tx, config).unwrap();
+    #[test]
+    fn test_warm_engines_unlimited_budget() {
+        let mut engines = WarmEngines::new(0);
+        engines.insert(EngineRole::Fast, "small", 500);
+        let evicted = engines.insert(EngineRole::Accurate, "large-v3", 3000);
+        assert!(evicted.is_empty());
+
+        assert!(engines.remove(EngineRole::Accurate));
+        assert!(!engines.remove(EngineRole::Accurate));
+        assert_eq!(engines.memory_bytes(), 500);
+
+        engines.clear();
+        assert!(engines.is_empty());
+    }
 
-        // Send unload command (should be a no-op when no engine loaded)
-        cmd_tx.send(WorkerCommand::UnloadEngine).await.unwrap();
+    /// Remote backend that counts its calls.
+    #[derive(Default)]
+    struct CountingBackend {
+        calls: std::sync::atomic::AtomicUsize,
+    }
 
-        // Close channel to trigger shutdown
+    impl TranscriptionBackend for CountingBackend {
+        fn name(&self) -> &'static str {
+            "counting"
+        }
+
+        fn model(&self) -> &str {
+            "counting"
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+
+        fn transcribe(
+            &self,
+            _audio: &AudioBuffer,
+            language: Option<&str>,
+        ) -> Result<crate::engine::whisper::TranscriptionResult, crate::engine::BackendError>
+        {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(crate::engine::whisper::TranscriptionResult {
+                text: "remote".to_string(),
+                language: language.unwrap_or("en").to_string(),
+                duration_ms: 0,
+            })
+        }
+    }
+
+    #[test]
+    fn test_only_remote_jobs_use_remote_backend() {
+        let (cmd_tx, cmd_rx) = mpsc::channel::<WorkerCommand>(10);
+        let (result_tx, mut result_rx) = mpsc::channel(10);
+        let backend = Arc::new(CountingBackend::default());
+
+        let job = |sequence_id, backend| TranscriptionJob {
+            buffer: AudioBuffer {
+                samples: vec![0.0; 16000],
+                sample_rate: 16000,
+            },
+            sequence_id,
+            chunk_id: 0,
+            is_final: true,
+            role: EngineRole::Fast,
+            priority: crate::queue::JobPriority::default(),
+            language: None,
+            backend,
+        };
+        cmd_tx
+            .blocking_send(WorkerCommand::SetRemote(
+                Some(backend.clone() as SharedBackend),
+                false,
+            ))
+            .unwrap();
+        cmd_tx
+            .blocking_send(WorkerCommand::Job(job(1, TranscriptionBackendKind::Remote)))
+            .unwrap();
+        cmd_tx
+            .blocking_send(WorkerCommand::Job(job(2, TranscriptionBackendKind::Local)))
+            .unwrap();
         drop(cmd_tx);
 
-        // Worker should exit gracefully
-        handle.join().expect("Worker thread panicked");
+        TranscriptionWorker::new(None, cmd_rx, result_tx, test_audio_config_disabled()).run();
+
+        let remote = result_rx.blocking_recv().unwrap();
+        assert_eq!((remote.sequence_id, remote.text.as_str()), (1, "remote"));
+        // A local job stays local even with a server configured
+        let local = result_rx.blocking_recv().unwrap();
+        assert_eq!(local.sequence_id, 2);
+        assert_eq!(local.error.as_deref(), Some("Model not loaded"));
+        assert_eq!(backend.calls.load(std::sync::atomic::Ordering::SeqCst), 1);
     }
 }
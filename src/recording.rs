@@ -5,24 +5,30 @@
 //! - VAD-based natural break detection
 //! - Live output mode
 //! - File output with multiple formats
+//! - Live translated subtitles, one sentence at a time
 
 #![allow(dead_code)] // Diarization and mixed recording features used in Phase 3
 
-use crate::config::Config;
+use crate::config::{ChannelSelection, Config, TranslationConfig};
 #[cfg(feature = "diarization")]
 use crate::diarization::{DiarizationConfig, DiarizationEngine, DiarizationError};
-use crate::engine::whisper::{WhisperEngine, WhisperError, WhisperModel};
-use crate::input::{AudioBuffer, AudioRecorder, AudioRecorderError};
+use crate::engine::catalog::resolve_model;
+use crate::engine::whisper::{WhisperEngine, WhisperError};
+use crate::input::{AudioBuffer, AudioRecorder, AudioRecorderError, ChannelMix};
 use crate::input::{AudioSource, SystemAudioCapture, SystemAudioError};
+use crate::translation::{SentenceBuffer, Translator};
 use crate::vad::silero::SileroVad;
 use crate::vad::VadConfig;
+use std::collections::VecDeque;
 use std::fs::File;
 use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use thiserror::Error;
 use tokio::signal;
+use tokio::task::JoinHandle;
 use tracing::{info, warn};
 
 /// Chunk duration for streaming transcription (seconds)
@@ -34,6 +40,17 @@ const CHUNK_OVERLAP_SECS: f32 = 0.5;
 /// Minimum silence duration to consider end of speech (ms)
 const MIN_SILENCE_MS: u32 = 500;
 
+/// One stream of a mixed recording may run this far ahead of the other
+/// before it is mixed with silence (1 second at 16kHz)
+const MAX_MIX_SKEW: usize = 16000;
+
+/// An unfinished sentence is translated anyway once it spans this long
+/// (speech without punctuation, e.g. a missed sentence end)
+const MAX_SENTENCE_SECS: f32 = 20.0;
+
+/// ...or once this much text is waiting
+const MAX_SENTENCE_CHARS: usize = 300;
+
 /// Recording session errors
 #[derive(Error, Debug)]
 pub enum RecordingError {
@@ -61,6 +78,12 @@ pub enum RecordingError {
 
     #[error("Model not found: {0}")]
     ModelNotFound(String),
+
+    #[error("Invalid audio source: {0}")]
+    InvalidSource(String),
+
+    #[error("Translation unavailable: {0}")]
+    Translation(String),
 }
 
 impl From<AudioRecorderError> for RecordingError {
@@ -100,11 +123,40 @@ impl std::str::FromStr for OutputFormat {
     }
 }
 
+/// Placement of translations relative to the original text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BilingualLayout {
+    /// Original and translation as two lines of the same entry
+    #[default]
+    Stacked,
+    /// Original and translation on one line: "Hallo. | Hello."
+    SideBySide,
+    /// Translation in its own file next to the output (meeting.en.srt)
+    Tracks,
+}
+
+impl std::str::FromStr for BilingualLayout {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "stacked" => Ok(Self::Stacked),
+            "side-by-side" | "side_by_side" | "side" => Ok(Self::SideBySide),
+            "tracks" | "dual-track" | "dual" => Ok(Self::Tracks),
+            _ => Err(format!(
+                "Unknown layout '{}'. Use: stacked, side-by-side, tracks",
+                s
+            )),
+        }
+    }
+}
+
 /// Recording session configuration
 #[derive(Debug, Clone)]
 pub struct RecordingConfig {
-    /// Audio source
-    pub source: AudioSource,
+    /// Audio source (None = the monitor of `app` or `audio.capture_app`
+    /// when one is set, otherwise the microphone)
+    pub source: Option<AudioSource>,
     /// Output file path (None for stdout only)
     pub output_file: Option<String>,
     /// Enable speaker diarization
@@ -113,6 +165,13 @@ pub struct RecordingConfig {
     pub live_mode: bool,
     /// Output format
     pub output_format: OutputFormat,
+    /// Capture only this application's audio instead of the whole system
+    /// output (monitor and both sources, Linux)
+    pub app: Option<String>,
+    /// Translate complete sentences into this language
+    pub translate_to: Option<String>,
+    /// How translations are shown next to the original
+    pub bilingual_layout: BilingualLayout,
 }
 
 /// A transcribed segment with timing
@@ -126,9 +185,51 @@ pub struct TranscribedSegment {
     pub text: String,
     /// Speaker ID (if diarization enabled)
     pub speaker_id: Option<u32>,
+    /// Translation of `text` (live translation only)
+    pub translation: Option<String>,
 }
 
 impl TranscribedSegment {
+    /// The segment as shown in the main output, with its translation
+    /// placed according to `layout`.
+    pub fn bilingual(&self, layout: BilingualLayout) -> TranscribedSegment {
+        let text = match (&self.translation, layout) {
+            (Some(translation), BilingualLayout::Stacked) => {
+                format!("{}\n{}", self.text, translation)
+            }
+            (Some(translation), BilingualLayout::SideBySide) => {
+                format!("{} | {}", self.text, translation)
+            }
+            _ => self.text.clone(),
+        };
+        TranscribedSegment {
+            text,
+            translation: None,
+            ..self.clone()
+        }
+    }
+
+    /// The translated segment, for the separate translation track.
+    pub fn translated(&self) -> Option<TranscribedSegment> {
+        self.translation
+            .as_ref()
+            .map(|translation| TranscribedSegment {
+                text: translation.clone(),
+                translation: None,
+                ..self.clone()
+            })
+    }
+
+    /// Format in `format`; `index` numbers SRT entries
+    pub fn format(&self, format: OutputFormat, index: usize) -> String {
+        match format {
+            OutputFormat::Text => format!("{}\n", self.text),
+            OutputFormat::Timestamped => format!("{}\n", self.format_timestamped()),
+            OutputFormat::Srt => self.format_srt(index),
+            OutputFormat::Vtt => self.format_vtt(),
+        }
+    }
+
     /// Format as timestamped text
     pub fn format_timestamped(&self) -> String {
         let start = format_timestamp(self.start_secs);
@@ -193,14 +294,236 @@ fn format_vtt_timestamp(secs: f32) -> String {
     format!("{:02}:{:02}:{:02}.{:03}", hours, mins, secs, ms)
 }
 
+/// Capture a single application's audio (PulseAudio/PipeWire only).
+#[cfg(target_os = "linux")]
+fn app_capture(app: &str) -> Result<SystemAudioCapture, RecordingError> {
+    use crate::input::system_audio::list_app_streams;
+
+    SystemAudioCapture::new_for_app(app).map_err(|e| match e {
+        SystemAudioError::AppNotFound(_) => {
+            let playing: Vec<String> = list_app_streams()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|s| format!("{} ({})", s.app_name, s.media_name))
+                .collect();
+            RecordingError::AudioCapture(if playing.is_empty() {
+                format!("{}. No application is playing audio right now", e)
+            } else {
+                format!("{}. Playing now: {}", e, playing.join(", "))
+            })
+        }
+        e => e.into(),
+    })
+}
+
+/// Capture a single application's audio (PulseAudio/PipeWire only).
+#[cfg(not(target_os = "linux"))]
+fn app_capture(app: &str) -> Result<SystemAudioCapture, RecordingError> {
+    Err(RecordingError::AudioCapture(format!(
+        "Capturing a single application ('{}') is only supported on Linux",
+        app
+    )))
+}
+
+/// Pick the audio source and the application to capture.
+///
+/// An explicit `--source` is taken as given: `audio.capture_app` only
+/// applies when no source was chosen, so `--source monitor` always records
+/// the whole system output. `--app` needs the system audio, so it can't be
+/// combined with the microphone alone.
+fn resolve_source(
+    source: Option<AudioSource>,
+    app: Option<String>,
+    capture_app: Option<&str>,
+) -> Result<(AudioSource, Option<String>), RecordingError> {
+    match (source, app) {
+        (Some(AudioSource::Microphone), Some(app)) => Err(RecordingError::InvalidSource(format!(
+            "--app {} captures application audio, use --source monitor or --source both",
+            app
+        ))),
+        (Some(source), app) => Ok((source, app)),
+        (None, Some(app)) => Ok((AudioSource::Monitor, Some(app))),
+        (None, None) => Ok(match capture_app {
+            Some(app) => (AudioSource::Monitor, Some(app.to_string())),
+            None => (AudioSource::Microphone, None),
+        }),
+    }
+}
+
+/// Path of the translation track for an output file (meeting.srt -> meeting.de.srt)
+fn track_path(path: &str, language: &str) -> PathBuf {
+    let path = Path::new(path);
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let name = match path.extension() {
+        Some(ext) => format!("{}.{}.{}", stem, language, ext.to_string_lossy()),
+        None => format!("{}.{}", stem, language),
+    };
+    path.with_file_name(name)
+}
+
+/// Regroups transcribed chunks into whole sentences.
+///
+/// Chunks are cut every few seconds regardless of speech, so translating
+/// them directly would feed the translator sentence fragments. Complete
+/// sentences are released with the time span of the chunks they came from.
+/// Text that runs past [`MAX_SENTENCE_SECS`] or [`MAX_SENTENCE_CHARS`]
+/// without a sentence end is released as it is.
+#[derive(Debug, Default)]
+struct SentenceSegmenter {
+    sentences: SentenceBuffer,
+    /// Start of the text waiting in the buffer
+    pending_start: Option<f32>,
+    /// Speaker of the text waiting in the buffer
+    pending_speaker: Option<u32>,
+}
+
+impl SentenceSegmenter {
+    /// Add a chunk and return its complete sentences as one segment.
+    fn push(&mut self, chunk: &TranscribedSegment) -> Option<TranscribedSegment> {
+        if self.pending_start.is_none() {
+            self.pending_start = Some(chunk.start_secs);
+            self.pending_speaker = chunk.speaker_id;
+        }
+        let sentences = self.sentences.add(&format!("{} ", chunk.text));
+        if sentences.is_empty() {
+            let span = chunk.end_secs - self.pending_start.unwrap_or(chunk.start_secs);
+            if span >= MAX_SENTENCE_SECS || self.sentences.len() >= MAX_SENTENCE_CHARS {
+                return self.flush(chunk.end_secs);
+            }
+            return None;
+        }
+
+        let segment = TranscribedSegment {
+            start_secs: self.pending_start.unwrap_or(chunk.start_secs),
+            end_secs: chunk.end_secs,
+            text: sentences.join(" "),
+            speaker_id: self.pending_speaker,
+            translation: None,
+        };
+        // The unfinished sentence that follows began within this chunk
+        if self.sentences.is_empty() {
+            self.pending_start = None;
+        } else {
+            self.pending_start = Some(chunk.start_secs);
+            self.pending_speaker = chunk.speaker_id;
+        }
+        Some(segment)
+    }
+
+    /// Release the unfinished sentence at the end of the recording.
+    fn flush(&mut self, end_secs: f32) -> Option<TranscribedSegment> {
+        let text = self.sentences.flush()?;
+        Some(TranscribedSegment {
+            start_secs: self.pending_start.take().unwrap_or(end_secs),
+            end_secs,
+            text,
+            speaker_id: self.pending_speaker.take(),
+            translation: None,
+        })
+    }
+}
+
+/// Translates a recording sentence by sentence.
+///
+/// Translations run on their own tasks so a slow translator doesn't hold
+/// up the capture loop; finished ones are released in recording order.
+struct LiveTranslator {
+    translator: Arc<Translator>,
+    target_language: String,
+    segmenter: SentenceSegmenter,
+    /// Translations in flight, oldest first
+    in_flight: VecDeque<JoinHandle<TranscribedSegment>>,
+}
+
+impl LiveTranslator {
+    fn new(translator: Arc<Translator>, target_language: String) -> Self {
+        Self {
+            translator,
+            target_language,
+            segmenter: SentenceSegmenter::default(),
+            in_flight: VecDeque::new(),
+        }
+    }
+
+    /// Add a transcribed chunk, translating the sentences it completes.
+    fn push(&mut self, chunk: &TranscribedSegment, language: &str) {
+        if let Some(segment) = self.segmenter.push(chunk) {
+            self.translate(segment, language);
+        }
+    }
+
+    /// Translated sentences that are ready, in recording order.
+    async fn ready(&mut self) -> Vec<TranscribedSegment> {
+        let mut ready = Vec::new();
+        while self.in_flight.front().is_some_and(|t| t.is_finished()) {
+            if let Some(segment) = self.next().await {
+                ready.push(segment);
+            }
+        }
+        ready
+    }
+
+    /// Translate what is left at the end of the recording and wait for
+    /// all translations.
+    async fn finish(&mut self, end_secs: f32, language: &str) -> Vec<TranscribedSegment> {
+        if let Some(segment) = self.segmenter.flush(end_secs) {
+            self.translate(segment, language);
+        }
+        let mut rest = Vec::new();
+        while !self.in_flight.is_empty() {
+            if let Some(segment) = self.next().await {
+                rest.push(segment);
+            }
+        }
+        rest
+    }
+
+    /// Wait for the oldest translation.
+    async fn next(&mut self) -> Option<TranscribedSegment> {
+        match self.in_flight.pop_front()?.await {
+            Ok(segment) => Some(segment),
+            Err(e) => {
+                warn!("Translation task failed: {}", e);
+                None
+            }
+        }
+    }
+
+    fn translate(&mut self, segment: TranscribedSegment, language: &str) {
+        let translator = Arc::clone(&self.translator);
+        let source_language = language.to_string();
+        let target_language = self.target_language.clone();
+        self.in_flight.push_back(tokio::spawn(async move {
+            let translation = match translator
+                .translate(&segment.text, &source_language, &target_language)
+                .await
+            {
+                Ok(translated) => Some(translated.trim().to_string()),
+                Err(e) => {
+                    warn!("Translation failed: {}", e);
+                    None
+                }
+            };
+            TranscribedSegment {
+                translation,
+                ..segment
+            }
+        }));
+    }
+}
+
 /// Audio source wrapper that handles both mic and system audio
 enum AudioSourceCapture {
     Microphone(AudioRecorder),
     Monitor(SystemAudioCapture),
+    Both(MixedCapture),
 }
 
 impl AudioSourceCapture {
-    fn extract_samples(&self) -> Vec<f32> {
+    fn extract_samples(&mut self) -> Vec<f32> {
         match self {
             AudioSourceCapture::Microphone(_recorder) => {
                 // For microphone, we need to use the ring buffer
@@ -208,10 +531,85 @@ impl AudioSourceCapture {
                 vec![]
             }
             AudioSourceCapture::Monitor(capture) => capture.extract_samples(),
+            AudioSourceCapture::Both(capture) => capture.extract_samples(),
         }
     }
 }
 
+/// Microphone and system audio mixed into one stream.
+///
+/// With `audio.echo_cancellation` enabled, system audio bleeding into the
+/// mic through the speakers is cancelled first, so the other side of a call
+/// is not heard twice.
+struct MixedCapture {
+    mic: AudioRecorder,
+    /// Ring buffer position of the next mic sample to read
+    mic_pos: usize,
+    system: SystemAudioCapture,
+    mic_pending: Vec<f32>,
+    system_pending: Vec<f32>,
+}
+
+impl MixedCapture {
+    fn new(config: &Config, system: SystemAudioCapture) -> Result<Self, RecordingError> {
+        let channel_mix = match &config.audio.channels {
+            ChannelSelection::All => ChannelMix::All,
+            ChannelSelection::Select(channels) => ChannelMix::Select(channels.clone()),
+        };
+        let mut mic = AudioRecorder::new_always_on_with_device(
+            config.audio.prebuffer_duration_secs,
+            config.audio.resampling_quality,
+            channel_mix,
+            config.audio.input_device.as_deref(),
+        )?;
+        if config.audio.echo_cancellation.enabled {
+            if let Err(e) = mic.enable_echo_cancellation(&config.audio.echo_cancellation) {
+                warn!("{}. The speakers will be heard twice in the mix.", e);
+            }
+        }
+
+        Ok(Self {
+            mic_pos: mic.current_position(),
+            mic,
+            system,
+            mic_pending: Vec::new(),
+            system_pending: Vec::new(),
+        })
+    }
+
+    fn extract_samples(&mut self) -> Vec<f32> {
+        let to_pos = self.mic.current_position();
+        let mic = self.mic.extract_samples(self.mic_pos, to_pos);
+        self.mic_pos = to_pos;
+
+        self.mic_pending.extend(mic);
+        self.system_pending.extend(self.system.extract_samples());
+        mix_streams(&mut self.mic_pending, &mut self.system_pending)
+    }
+}
+
+/// Mix the audio both streams have delivered so far.
+///
+/// Samples one stream is still missing stay pending, unless it fell more
+/// than [`MAX_MIX_SKEW`] behind; then it counts as silent.
+fn mix_streams(a: &mut Vec<f32>, b: &mut Vec<f32>) -> Vec<f32> {
+    let len = if a.len().abs_diff(b.len()) > MAX_MIX_SKEW {
+        a.len().max(b.len())
+    } else {
+        a.len().min(b.len())
+    };
+
+    let mixed = (0..len)
+        .map(|i| {
+            let sum = a.get(i).copied().unwrap_or(0.0) + b.get(i).copied().unwrap_or(0.0);
+            sum.clamp(-1.0, 1.0)
+        })
+        .collect();
+    a.drain(..len.min(a.len()));
+    b.drain(..len.min(b.len()));
+    mixed
+}
+
 /// Long-running recording session
 pub struct RecordingSession {
     config: RecordingConfig,
@@ -222,9 +620,17 @@ pub struct RecordingSession {
 
 impl RecordingSession {
     /// Create a new recording session
-    pub fn new(config: RecordingConfig) -> Result<Self, RecordingError> {
+    pub fn new(mut config: RecordingConfig) -> Result<Self, RecordingError> {
         let app_config = Config::load().map_err(|e| RecordingError::Config(e.into()))?;
 
+        let (source, app) = resolve_source(
+            config.source,
+            config.app.take(),
+            app_config.audio.capture_app.as_deref(),
+        )?;
+        config.source = Some(source);
+        config.app = app;
+
         Ok(Self {
             config,
             app_config,
@@ -244,15 +650,10 @@ impl RecordingSession {
         });
 
         // Initialize Whisper engine
-        let data_dir = Config::data_dir().map_err(|e| RecordingError::Config(e.into()))?;
-        let model_name = self.app_config.transcription.effective_model();
-        let model: WhisperModel = model_name.parse().map_err(|()| {
-            RecordingError::ModelNotFound(format!(
-                "Unknown model '{}'. Use: tiny, base, small, medium, large-v3",
-                model_name
-            ))
-        })?;
-        let model_path = data_dir.join("models").join(model.filename());
+        let model_name = self.app_config.transcription.effective_accurate_model();
+        let model_path = resolve_model(model_name, &self.app_config.transcription)
+            .and_then(|resolved| resolved.path())
+            .map_err(|e| RecordingError::ModelNotFound(e.to_string()))?;
 
         if !model_path.exists() {
             return Err(RecordingError::ModelNotFound(format!(
@@ -262,16 +663,24 @@ impl RecordingSession {
             )));
         }
 
-        info!("Loading Whisper model: {}", model.filename());
-        let engine = WhisperEngine::new(
+        info!("Loading Whisper model: {}", model_path.display());
+        let mut engine = WhisperEngine::new(
             &model_path,
             &self.app_config.transcription.language,
             self.app_config.transcription.translate,
             self.app_config.transcription.device.to_lowercase() != "cpu",
         )?;
+        engine.set_allowed_languages(&self.app_config.transcription.allowed_languages);
 
         // Initialize audio capture based on source
-        let capture = match self.config.source {
+        let system = || match self.config.app.as_deref() {
+            Some(app) => {
+                info!("Capturing the audio of {}...", app);
+                app_capture(app)
+            }
+            None => Ok(SystemAudioCapture::new(None)?),
+        };
+        let mut capture = match self.config.source.unwrap_or_default() {
             AudioSource::Microphone => {
                 info!("Recording from microphone...");
                 // For now, use system audio with default source
@@ -283,13 +692,11 @@ impl RecordingSession {
             }
             AudioSource::Monitor => {
                 info!("Recording system audio...");
-                SystemAudioCapture::new(None)?
+                AudioSourceCapture::Monitor(system()?)
             }
             AudioSource::Both => {
                 info!("Recording both microphone and system audio...");
-                return Err(RecordingError::AudioCapture(
-                    "Mixed recording not yet implemented. Use --source monitor".into(),
-                ));
+                AudioSourceCapture::Both(MixedCapture::new(&self.app_config, system()?)?)
             }
         };
 
@@ -305,7 +712,7 @@ impl RecordingSession {
                 similarity_threshold: self.app_config.diarization.similarity_threshold,
             };
             info!("Initializing speaker diarization...");
-            Some(DiarizationEngine::new(diar_config).await?)
+            Some(DiarizationEngine::new(diar_config, &self.app_config.models).await?)
         } else {
             None
         };
@@ -313,6 +720,31 @@ impl RecordingSession {
         #[cfg(not(feature = "diarization"))]
         let _diarization_engine: Option<()> = None;
 
+        // Translate sentence by sentence if requested
+        let mut live_translator = match self.config.translate_to {
+            Some(ref target) => {
+                let translation_config = TranslationConfig {
+                    enabled: true,
+                    target_language: target.clone(),
+                    ..self.app_config.translation.clone()
+                };
+                let translator =
+                    crate::daemon::init_translator(&translation_config, &self.app_config.models)
+                        .await
+                        .ok_or_else(|| {
+                            RecordingError::Translation(format!(
+                                "no translation engine is ready (is Ollama running at {}?)",
+                                translation_config.ollama_url
+                            ))
+                        })?;
+                info!("Translating subtitles into '{}'", target);
+                Some(LiveTranslator::new(translator, target.clone()))
+            }
+            None => None,
+        };
+        // Language of the latest chunk, used as the translation source
+        let mut language = self.app_config.transcription.language.clone();
+
         // Print header for VTT format
         if self.config.output_format == OutputFormat::Vtt && self.config.live_mode {
             println!("WEBVTT\n");
@@ -321,7 +753,6 @@ impl RecordingSession {
         let start_time = Instant::now();
         let mut last_transcribe_time = Instant::now();
         let mut accumulated_samples: Vec<f32> = Vec::new();
-        let mut segment_index = 1;
 
         let mode_str = if self.config.enable_diarization {
             "with diarization"
@@ -384,31 +815,16 @@ impl RecordingSession {
                 // Transcribe
                 match engine.transcribe(&audio) {
                     Ok(result) => {
+                        language = result.language;
                         if !result.text.trim().is_empty() {
                             let segment = TranscribedSegment {
                                 start_secs: chunk_start_secs,
                                 end_secs: start_time.elapsed().as_secs_f32(),
                                 text: result.text.trim().to_string(),
                                 speaker_id,
+                                translation: None,
                             };
-
-                            // Output based on format
-                            let output = match self.config.output_format {
-                                OutputFormat::Text => format!("{}\n", segment.text),
-                                OutputFormat::Timestamped => {
-                                    format!("{}\n", segment.format_timestamped())
-                                }
-                                OutputFormat::Srt => segment.format_srt(segment_index),
-                                OutputFormat::Vtt => segment.format_vtt(),
-                            };
-
-                            if self.config.live_mode {
-                                print!("{}", output);
-                                std::io::stdout().flush().ok();
-                            }
-
-                            self.segments.push(segment);
-                            segment_index += 1;
+                            self.output(segment, &mut live_translator, &language);
                         }
                     }
                     Err(e) => {
@@ -428,6 +844,12 @@ impl RecordingSession {
                 last_transcribe_time = Instant::now();
             }
 
+            if let Some(ref mut translator) = live_translator {
+                for segment in translator.ready().await {
+                    self.emit(segment);
+                }
+            }
+
             // Small sleep to prevent busy-waiting
             tokio::time::sleep(Duration::from_millis(100)).await;
         }
@@ -457,30 +879,28 @@ impl RecordingSession {
             };
 
             if let Ok(result) = engine.transcribe(&audio) {
+                language = result.language;
                 if !result.text.trim().is_empty() {
                     let segment = TranscribedSegment {
                         start_secs: chunk_start_secs,
                         end_secs: start_time.elapsed().as_secs_f32(),
                         text: result.text.trim().to_string(),
                         speaker_id: final_speaker_id,
+                        translation: None,
                     };
-
-                    let output = match self.config.output_format {
-                        OutputFormat::Text => format!("{}\n", segment.text),
-                        OutputFormat::Timestamped => format!("{}\n", segment.format_timestamped()),
-                        OutputFormat::Srt => segment.format_srt(segment_index),
-                        OutputFormat::Vtt => segment.format_vtt(),
-                    };
-
-                    if self.config.live_mode {
-                        print!("{}", output);
-                    }
-
-                    self.segments.push(segment);
+                    self.output(segment, &mut live_translator, &language);
                 }
             }
         }
 
+        // Translate the last, unfinished sentence and wait for the rest
+        if let Some(ref mut translator) = live_translator {
+            let end_secs = start_time.elapsed().as_secs_f32();
+            for segment in translator.finish(end_secs, &language).await {
+                self.emit(segment);
+            }
+        }
+
         let total_duration = start_time.elapsed();
         println!("\n--- Recording stopped ---");
         println!(
@@ -495,15 +915,68 @@ impl RecordingSession {
 
         // Save to file if output path specified
         if let Some(ref output_path) = self.config.output_file {
-            self.save_to_file(output_path)?;
+            let layout = self.config.bilingual_layout;
+            let segments: Vec<_> = self.segments.iter().map(|s| s.bilingual(layout)).collect();
+            self.save_to_file(output_path, &segments)?;
             println!("Saved to: {}", output_path);
+
+            if let (BilingualLayout::Tracks, Some(ref language)) =
+                (layout, &self.config.translate_to)
+            {
+                let track = track_path(output_path, language);
+                let translated: Vec<_> = self
+                    .segments
+                    .iter()
+                    .filter_map(|s| s.translated())
+                    .collect();
+                self.save_to_file(&track.to_string_lossy(), &translated)?;
+                println!("Translation saved to: {}", track.display());
+            }
         }
 
         Ok(())
     }
 
-    /// Save transcription to file
-    fn save_to_file(&self, path: &str) -> Result<(), RecordingError> {
+    /// Output a transcribed chunk, or hand it to the translator, which
+    /// releases whole sentences once they are translated.
+    fn output(
+        &mut self,
+        chunk: TranscribedSegment,
+        translator: &mut Option<LiveTranslator>,
+        language: &str,
+    ) {
+        match translator {
+            Some(translator) => translator.push(&chunk, language),
+            None => self.emit(chunk),
+        }
+    }
+
+    /// Print a finished segment in live mode and keep it for the file.
+    fn emit(&mut self, segment: TranscribedSegment) {
+        if self.config.live_mode {
+            // The terminal has a single track, so tracks show stacked
+            let layout = match self.config.bilingual_layout {
+                BilingualLayout::Tracks => BilingualLayout::Stacked,
+                layout => layout,
+            };
+            let index = self.segments.len() + 1;
+            print!(
+                "{}",
+                segment
+                    .bilingual(layout)
+                    .format(self.config.output_format, index)
+            );
+            std::io::stdout().flush().ok();
+        }
+        self.segments.push(segment);
+    }
+
+    /// Save segments to file
+    fn save_to_file(
+        &self,
+        path: &str,
+        segments: &[TranscribedSegment],
+    ) -> Result<(), RecordingError> {
         let mut file = File::create(path)?;
 
         // Write header for VTT
@@ -511,14 +984,8 @@ impl RecordingSession {
             writeln!(file, "WEBVTT\n")?;
         }
 
-        for (i, segment) in self.segments.iter().enumerate() {
-            let output = match self.config.output_format {
-                OutputFormat::Text => format!("{}\n", segment.text),
-                OutputFormat::Timestamped => format!("{}\n", segment.format_timestamped()),
-                OutputFormat::Srt => segment.format_srt(i + 1),
-                OutputFormat::Vtt => segment.format_vtt(),
-            };
-            write!(file, "{}", output)?;
+        for (i, segment) in segments.iter().enumerate() {
+            write!(file, "{}", segment.format(self.config.output_format, i + 1))?;
         }
 
         Ok(())
@@ -564,6 +1031,7 @@ mod tests {
             end_secs: 5.0,
             text: "Hello world".to_string(),
             speaker_id: None,
+            translation: None,
         };
 
         assert!(segment.format_timestamped().contains("[00:00:00]"));
@@ -574,4 +1042,167 @@ mod tests {
             .format_vtt()
             .contains("00:00:00.000 --> 00:00:05.000"));
     }
+
+    fn chunk(start_secs: f32, end_secs: f32, text: &str) -> TranscribedSegment {
+        TranscribedSegment {
+            start_secs,
+            end_secs,
+            text: text.to_string(),
+            speaker_id: None,
+            translation: None,
+        }
+    }
+
+    #[test]
+    fn test_bilingual_layout_from_str() {
+        assert_eq!(
+            "stacked".parse::<BilingualLayout>().unwrap(),
+            BilingualLayout::Stacked
+        );
+        assert_eq!(
+            "side-by-side".parse::<BilingualLayout>().unwrap(),
+            BilingualLayout::SideBySide
+        );
+        assert_eq!(
+            "tracks".parse::<BilingualLayout>().unwrap(),
+            BilingualLayout::Tracks
+        );
+        assert!("columns".parse::<BilingualLayout>().is_err());
+    }
+
+    #[test]
+    fn test_bilingual_segment_formatting() {
+        let segment = TranscribedSegment {
+            translation: Some("Hello world.".to_string()),
+            ..chunk(0.0, 5.0, "Hallo Welt.")
+        };
+
+        assert_eq!(
+            segment.bilingual(BilingualLayout::Stacked).format_srt(1),
+            "1\n00:00:00,000 --> 00:00:05,000\nHallo Welt.\nHello world.\n"
+        );
+        assert_eq!(
+            segment
+                .bilingual(BilingualLayout::SideBySide)
+                .format(OutputFormat::Text, 1),
+            "Hallo Welt. | Hello world.\n"
+        );
+        assert_eq!(
+            segment.bilingual(BilingualLayout::Tracks).text,
+            "Hallo Welt."
+        );
+        assert_eq!(segment.translated().unwrap().text, "Hello world.");
+        assert!(chunk(0.0, 1.0, "Hallo").translated().is_none());
+    }
+
+    #[test]
+    fn test_track_path() {
+        assert_eq!(
+            track_path("/tmp/meeting.srt", "en"),
+            PathBuf::from("/tmp/meeting.en.srt")
+        );
+        assert_eq!(track_path("notes", "de"), PathBuf::from("notes.de"));
+    }
+
+    #[test]
+    fn test_sentence_segmenter_regroups_chunks() {
+        let mut segmenter = SentenceSegmenter::default();
+
+        assert!(segmenter
+            .push(&chunk(0.0, 5.0, "Guten Morgen, wir"))
+            .is_none());
+
+        let first = segmenter
+            .push(&chunk(4.5, 10.0, "fangen an. Heute geht es um"))
+            .unwrap();
+        assert_eq!(first.text, "Guten Morgen, wir fangen an.");
+        assert_eq!(first.start_secs, 0.0);
+        assert_eq!(first.end_secs, 10.0);
+
+        let second = segmenter
+            .push(&chunk(9.5, 15.0, "das Budget. Fragen? Gerne."))
+            .unwrap();
+        assert_eq!(second.text, "Heute geht es um das Budget. Fragen? Gerne.");
+        assert_eq!(second.start_secs, 4.5);
+
+        assert!(segmenter.flush(20.0).is_none());
+        assert!(segmenter.push(&chunk(15.0, 20.0, "Noch etwas")).is_none());
+        let rest = segmenter.flush(21.0).unwrap();
+        assert_eq!(rest.text, "Noch etwas");
+        assert_eq!((rest.start_secs, rest.end_secs), (15.0, 21.0));
+    }
+
+    #[test]
+    fn test_sentence_segmenter_releases_long_runs() {
+        let mut segmenter = SentenceSegmenter::default();
+
+        // Speech without sentence ends is released after MAX_SENTENCE_SECS
+        assert!(segmenter.push(&chunk(0.0, 5.0, "und dann")).is_none());
+        assert!(segmenter.push(&chunk(5.0, 10.0, "haben wir")).is_none());
+        assert!(segmenter.push(&chunk(10.0, 15.0, "noch")).is_none());
+        let run = segmenter.push(&chunk(15.0, 20.0, "geredet")).unwrap();
+        assert_eq!(run.text, "und dann haben wir noch geredet");
+        assert_eq!((run.start_secs, run.end_secs), (0.0, 20.0));
+        assert!(segmenter.flush(21.0).is_none());
+
+        // ...or once the text gets too long
+        let long = "wort ".repeat(MAX_SENTENCE_CHARS / 5);
+        assert!(segmenter.push(&chunk(20.0, 25.0, long.trim())).is_some());
+    }
+
+    #[test]
+    fn test_resolve_source() {
+        let app = || Some("zoom".to_string());
+
+        // An explicit source wins over the configured application
+        let (source, app_name) =
+            resolve_source(Some(AudioSource::Monitor), None, Some("zoom")).unwrap();
+        assert_eq!((source, app_name), (AudioSource::Monitor, None));
+
+        // --app alone captures that application's playback
+        let (source, app_name) = resolve_source(None, app(), None).unwrap();
+        assert_eq!((source, app_name), (AudioSource::Monitor, app()));
+
+        // ...and can be mixed with the microphone
+        let (source, app_name) = resolve_source(Some(AudioSource::Both), app(), None).unwrap();
+        assert_eq!((source, app_name), (AudioSource::Both, app()));
+
+        // The configured application applies when nothing was chosen
+        let (source, app_name) = resolve_source(None, None, Some("zoom")).unwrap();
+        assert_eq!((source, app_name), (AudioSource::Monitor, app()));
+        let (source, app_name) = resolve_source(None, None, None).unwrap();
+        assert_eq!((source, app_name), (AudioSource::Microphone, None));
+
+        assert!(matches!(
+            resolve_source(Some(AudioSource::Microphone), app(), None),
+            Err(RecordingError::InvalidSource(_))
+        ));
+    }
+
+    #[test]
+    fn test_mix_streams_waits_for_slower_stream() {
+        let mut mic = vec![0.25; 300];
+        let mut system = vec![0.5; 200];
+
+        let mixed = mix_streams(&mut mic, &mut system);
+        assert_eq!(mixed, vec![0.75; 200]);
+        assert_eq!(mic.len(), 100);
+        assert!(system.is_empty());
+
+        // Loud peaks are clipped rather than wrapping around
+        system.extend([1.0; 100]);
+        assert_eq!(mix_streams(&mut mic, &mut system), vec![1.0; 100]);
+    }
+
+    #[test]
+    fn test_mix_streams_gives_up_on_stalled_stream() {
+        let mut mic = vec![0.25; MAX_MIX_SKEW + 10];
+        let mut system = vec![0.5; 5];
+
+        let mixed = mix_streams(&mut mic, &mut system);
+        assert_eq!(mixed.len(), MAX_MIX_SKEW + 10);
+        assert_eq!(mixed[0], 0.75);
+        assert_eq!(mixed[5], 0.25);
+        assert!(mic.is_empty() && system.is_empty());
+    }
 }
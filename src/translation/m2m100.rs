@@ -14,7 +14,8 @@
 #![allow(dead_code)]
 
 use super::{is_m2m100_language, TranslationEngine, TranslationError};
-use crate::config::Config;
+use crate::config::{Config, ModelFamily, ModelsConfig};
+use crate::manifest::{self, IntegrityError, ModelVerifier};
 use ort::session::{builder::GraphOptimizationLevel, Session};
 use ort::value::Tensor;
 use std::path::{Path, PathBuf};
@@ -46,6 +47,9 @@ pub enum M2M100Error {
 
     #[error("ONNX error: {0}")]
     Onnx(String),
+
+    #[error(transparent)]
+    Integrity(#[from] IntegrityError),
 }
 
 impl From<ort::Error> for M2M100Error {
@@ -114,7 +118,7 @@ impl std::str::FromStr for M2M100Model {
 }
 
 /// Files required for M2M-100 model.
-const MODEL_FILES: &[&str] = &["encoder_model.onnx", "decoder_model.onnx", "tokenizer.json"];
+pub const MODEL_FILES: &[&str] = &["encoder_model.onnx", "decoder_model.onnx", "tokenizer.json"];
 
 /// Get the models directory for M2M-100.
 pub fn models_dir() -> Result<PathBuf, M2M100Error> {
@@ -142,6 +146,7 @@ pub fn is_model_downloaded(model: M2M100Model) -> bool {
 /// Downloads encoder, decoder, and tokenizer files with progress callback.
 pub async fn download_model<F>(
     model: M2M100Model,
+    models: &ModelsConfig,
     mut progress_callback: F,
 ) -> Result<PathBuf, M2M100Error>
 where
@@ -168,13 +173,17 @@ where
         model.onnx_repo()
     );
 
+    if let Err(e) = manifest::refresh_pinned(models).await {
+        warn!("Using cached model manifest: {}", e);
+    }
+
     let client = reqwest::Client::builder()
         .user_agent("openhush/0.6.0")
         .build()
         .map_err(|e| M2M100Error::Inference(format!("HTTP client error: {}", e)))?;
 
     for filename in MODEL_FILES {
-        let url = if *filename == "tokenizer.json" {
+        let upstream = if *filename == "tokenizer.json" {
             // Tokenizer is in the root, not onnx subdirectory
             format!(
                 "https://huggingface.co/{}/resolve/main/tokenizer.json",
@@ -183,6 +192,11 @@ where
         } else {
             format!("{}/{}", base_url, filename)
         };
+        let url = models.mirrors.url(
+            ModelFamily::M2M100,
+            &format!("{}/{}", model.name(), filename),
+            upstream,
+        );
 
         let dest_path = dir.join(filename);
         let temp_path = dir.join(format!("{}.tmp", filename));
@@ -289,6 +303,9 @@ where
             downloaded += chunk.len() as u64;
             progress_callback(filename, downloaded, total_size);
         }
+        drop(file);
+
+        manifest::verify_download(temp_path.clone(), dest_path.clone()).await?;
 
         // Rename temp to final
         std::fs::rename(&temp_path, &dest_path)
@@ -508,6 +525,11 @@ impl M2M100Engine {
             return Err(M2M100Error::ModelNotDownloaded);
         }
 
+        let mut verifier = ModelVerifier::new()?;
+        for filename in MODEL_FILES {
+            verifier.verify_on_load(&self.model_dir.join(filename))?;
+        }
+
         // Load tokenizer
         let tokenizer_path = self.model_dir.join("tokenizer.json");
         let tokenizer = Tokenizer::from_file(&tokenizer_path)
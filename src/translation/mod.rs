@@ -15,13 +15,14 @@ mod sentence_buffer;
 pub use m2m100::{
     download_model as download_m2m100_model, is_model_downloaded as is_m2m100_downloaded,
     model_dir as m2m100_model_dir, remove_model as remove_m2m100_model, M2M100Engine, M2M100Error,
-    M2M100Model,
+    M2M100Model, MODEL_FILES as M2M100_MODEL_FILES,
 };
 pub use ollama::OllamaTranslator;
 pub use sentence_buffer::SentenceBuffer;
 
+use std::collections::HashMap;
 use std::sync::Arc;
-use tracing::warn;
+use tracing::{debug, warn};
 
 use std::fmt;
 use thiserror::Error;
@@ -173,6 +174,61 @@ impl Translator {
         }
     }
 
+    /// Translate text and, if requested, translate the result back into
+    /// the source language to measure how much meaning survived.
+    ///
+    /// Returns the translation and the round-trip similarity (see
+    /// [`Translator::round_trip`]).
+    pub async fn translate_checked(
+        &self,
+        text: &str,
+        from: &str,
+        to: &str,
+        check_round_trip: bool,
+    ) -> Result<(String, Option<f32>), TranslationError> {
+        let translated = self.translate(text, from, to).await?;
+        if !check_round_trip {
+            return Ok((translated, None));
+        }
+        let similarity = self.round_trip(text, &translated, from, to).await;
+        Ok((translated, similarity))
+    }
+
+    /// Translate `translated` back from `to` into `from` and measure how
+    /// much of `text` survived (see [`round_trip_similarity`]).
+    ///
+    /// Returns `None` when the source language is unknown ("auto"), equals
+    /// the target, or the back-translation failed. A failure is logged; it
+    /// says nothing about the forward translation.
+    pub async fn round_trip(
+        &self,
+        text: &str,
+        translated: &str,
+        from: &str,
+        to: &str,
+    ) -> Option<f32> {
+        if from == "auto" || from == to {
+            return None;
+        }
+
+        let back = match self.translate(translated, to, from).await {
+            Ok(back) => back,
+            Err(e) => {
+                warn!(
+                    "Back-translation {} -> {} failed, skipping the check: {}",
+                    to, from, e
+                );
+                return None;
+            }
+        };
+        let similarity = round_trip_similarity(text, &back);
+        debug!(
+            "Back-translation {} -> {} -> {}: {:.2} ({:?})",
+            from, to, from, similarity, back
+        );
+        Some(similarity)
+    }
+
     /// Get the name of the translation engine.
     #[allow(dead_code)]
     pub fn name(&self) -> &str {
@@ -192,6 +248,41 @@ impl Translator {
     }
 }
 
+/// Word overlap between a source text and its back-translation.
+///
+/// F1 score over lowercase alphanumeric words, counted as multisets:
+/// 1.0 means the round trip reproduced every word, 0.0 means no word
+/// survived. Crude, but cheap and independent of the engine.
+pub fn round_trip_similarity(source: &str, back: &str) -> f32 {
+    fn words(text: &str) -> HashMap<String, usize> {
+        let mut counts = HashMap::new();
+        for word in text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+        {
+            *counts.entry(word.to_lowercase()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    let source = words(source);
+    let back = words(back);
+    let source_len: usize = source.values().sum();
+    let back_len: usize = back.values().sum();
+    if source_len == 0 && back_len == 0 {
+        return 1.0;
+    }
+    if source_len == 0 || back_len == 0 {
+        return 0.0;
+    }
+
+    let common: usize = source
+        .iter()
+        .map(|(word, n)| (*n).min(back.get(word).copied().unwrap_or(0)))
+        .sum();
+    2.0 * common as f32 / (source_len + back_len) as f32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -233,4 +324,20 @@ mod tests {
         assert!(err.to_string().contains("xx"));
         assert!(err.to_string().contains("yy"));
     }
+
+    #[test]
+    fn test_round_trip_similarity() {
+        assert_eq!(round_trip_similarity("Hello, world!", "hello world"), 1.0);
+        assert_eq!(round_trip_similarity("", ""), 1.0);
+        assert_eq!(round_trip_similarity("hello", ""), 0.0);
+        assert_eq!(round_trip_similarity("the cat sat", "a dog ran"), 0.0);
+
+        // 2 common words out of 3 + 3
+        let sim = round_trip_similarity("the cat sat", "the cat slept");
+        assert!((sim - 2.0 / 3.0).abs() < 1e-6);
+
+        // Repeated words only count as often as they appear on both sides
+        let sim = round_trip_similarity("very very good", "very good");
+        assert!((sim - 0.8).abs() < 1e-6);
+    }
 }
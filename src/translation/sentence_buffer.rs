@@ -79,13 +79,11 @@ impl SentenceBuffer {
     }
 
     /// Check if the buffer is empty.
-    #[allow(dead_code)] // Used in tests
     pub fn is_empty(&self) -> bool {
         self.pending.trim().is_empty()
     }
 
     /// Get the current buffer length.
-    #[allow(dead_code)] // Used in tests
     pub fn len(&self) -> usize {
         self.pending.len()
     }
@@ -1,6 +1,6 @@
 //! Linux system tray implementation using ksni (D-Bus StatusNotifierItem).
 
-use super::{TrayError, TrayEvent, TrayStatus};
+use super::{menu_label, TrayError, TrayEvent, TrayState, TrayStatus, QUICK_PRESETS};
 use ksni::menu::*;
 use ksni::{Handle, ToolTip, Tray, TrayMethods};
 use std::sync::mpsc::{self, Receiver, Sender};
@@ -8,17 +8,131 @@ use tracing::{debug, info};
 
 /// OpenHush tray implementation
 struct OpenHushTray {
-    status: TrayStatus,
+    state: TrayState,
     event_tx: Sender<TrayEvent>,
 }
 
+impl OpenHushTray {
+    /// Menu item that sends `event` when clicked.
+    fn action_item(label: &str, event: TrayEvent, enabled: bool) -> MenuItem<Self> {
+        StandardItem {
+            label: label.into(),
+            enabled,
+            activate: Box::new(move |tray: &mut Self| {
+                debug!("Tray: {:?}", event);
+                let _ = tray.event_tx.send(event.clone());
+            }),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    /// Checkbox item that sends `event` when toggled.
+    fn toggle_item(label: &str, checked: bool, event: TrayEvent) -> MenuItem<Self> {
+        CheckmarkItem {
+            label: label.into(),
+            checked,
+            activate: Box::new(move |tray: &mut Self| {
+                debug!("Tray: {:?}", event);
+                let _ = tray.event_tx.send(event.clone());
+            }),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    fn recent_menu(&self) -> MenuItem<Self> {
+        let submenu = if self.state.recent.is_empty() {
+            vec![StandardItem {
+                label: "No transcriptions yet".into(),
+                enabled: false,
+                ..Default::default()
+            }
+            .into()]
+        } else {
+            self.state
+                .recent
+                .iter()
+                .map(|(id, text)| {
+                    Self::action_item(&menu_label(text), TrayEvent::CopyTranscription(*id), true)
+                })
+                .collect()
+        };
+
+        SubMenu {
+            label: "Recent Transcriptions".into(),
+            submenu,
+            ..Default::default()
+        }
+        .into()
+    }
+
+    fn language_menu(&self) -> MenuItem<Self> {
+        let (options, selected) = self.state.language_options();
+        let codes: Vec<String> = options.iter().map(|(code, _)| code.clone()).collect();
+
+        SubMenu {
+            label: "Language".into(),
+            submenu: vec![RadioGroup {
+                selected,
+                select: Box::new(move |tray: &mut Self, index: usize| {
+                    if let Some(code) = codes.get(index) {
+                        debug!("Tray: language {}", code);
+                        let _ = tray.event_tx.send(TrayEvent::SetLanguage(code.clone()));
+                    }
+                }),
+                options: options
+                    .into_iter()
+                    .map(|(_, label)| RadioItem {
+                        label,
+                        ..Default::default()
+                    })
+                    .collect(),
+            }
+            .into()],
+            ..Default::default()
+        }
+        .into()
+    }
+
+    fn preset_menu(&self) -> MenuItem<Self> {
+        let selected = QUICK_PRESETS
+            .iter()
+            .position(|(preset, _)| *preset == self.state.preset)
+            .unwrap_or_default();
+
+        SubMenu {
+            label: "Preset".into(),
+            submenu: vec![RadioGroup {
+                selected,
+                select: Box::new(|tray: &mut Self, index: usize| {
+                    if let Some((preset, _)) = QUICK_PRESETS.get(index) {
+                        debug!("Tray: preset {:?}", preset);
+                        let _ = tray.event_tx.send(TrayEvent::SetPreset(*preset));
+                    }
+                }),
+                options: QUICK_PRESETS
+                    .iter()
+                    .map(|(_, label)| RadioItem {
+                        label: (*label).into(),
+                        ..Default::default()
+                    })
+                    .collect(),
+            }
+            .into()],
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
 impl Tray for OpenHushTray {
     fn id(&self) -> String {
         "openhush".into()
     }
 
     fn icon_name(&self) -> String {
-        self.status.icon_name().into()
+        self.state.status.icon_name().into()
     }
 
     fn title(&self) -> String {
@@ -28,23 +142,61 @@ impl Tray for OpenHushTray {
     fn tool_tip(&self) -> ToolTip {
         ToolTip {
             title: "OpenHush - Voice to Text".into(),
-            description: self.status.as_str().into(),
-            icon_name: self.status.icon_name().into(),
+            description: self.state.status_line(),
+            icon_name: self.state.status.icon_name().into(),
             icon_pixmap: Vec::new(),
         }
     }
 
     fn menu(&self) -> Vec<MenuItem<Self>> {
-        let status_text = self.status.as_str();
+        let state = &self.state;
+        let recording = state.status.is_recording();
 
         vec![
-            // Status (disabled, just for display)
+            // Status lines (disabled, just for display)
             StandardItem {
-                label: status_text.into(),
+                label: state.status_line(),
                 enabled: false,
                 ..Default::default()
             }
             .into(),
+            StandardItem {
+                label: state.model_line(),
+                enabled: false,
+                ..Default::default()
+            }
+            .into(),
+            MenuItem::Separator,
+            Self::action_item(
+                if recording {
+                    "Stop Recording"
+                } else {
+                    "Start Recording"
+                },
+                TrayEvent::ToggleRecording,
+                true,
+            ),
+            self.recent_menu(),
+            MenuItem::Separator,
+            // Quick settings
+            self.language_menu(),
+            self.preset_menu(),
+            Self::toggle_item(
+                "LLM Correction",
+                state.correction_enabled,
+                TrayEvent::ToggleCorrection,
+            ),
+            Self::toggle_item(
+                "Translation",
+                state.translation_enabled,
+                TrayEvent::ToggleTranslation,
+            ),
+            MenuItem::Separator,
+            if state.model_loaded {
+                Self::action_item("Unload Model", TrayEvent::UnloadModel, !recording)
+            } else {
+                Self::action_item("Load Model", TrayEvent::LoadModel, true)
+            },
             MenuItem::Separator,
             // Preferences
             StandardItem {
@@ -97,7 +249,7 @@ impl TrayManager {
         let (event_tx, event_rx) = mpsc::channel();
 
         let tray = OpenHushTray {
-            status: TrayStatus::Idle,
+            state: TrayState::default(),
             event_tx,
         };
 
@@ -128,17 +280,12 @@ impl TrayManager {
     pub fn update_status(&self, status: &str) {
         debug!("Updating tray status: {}", status);
         // Map string to TrayStatus
-        let new_status = match status {
-            s if s.contains("Recording") => TrayStatus::Recording,
-            s if s.contains("Processing") => TrayStatus::Processing,
-            s if s.contains("Error") => TrayStatus::Error,
-            _ => TrayStatus::Idle,
-        };
+        let new_status = TrayStatus::from_status_str(status);
 
         // ksni handle.update() returns a Future, but dropping it is fine
         // since the update is queued internally by ksni.
-        let _ = self.handle.update(|tray| {
-            tray.status = new_status;
+        let _ = self.handle.update(move |tray| {
+            tray.state.status = new_status;
         });
     }
 
@@ -149,8 +296,18 @@ impl TrayManager {
         debug!("Setting tray status: {:?}", status);
         // ksni handle.update() returns a Future, but dropping it is fine
         // since the update is queued internally by ksni.
-        let _ = self.handle.update(|tray| {
-            tray.status = status;
+        let _ = self.handle.update(move |tray| {
+            tray.state.status = status;
         });
     }
+
+    /// Replace the state shown in the tray menu
+    pub async fn update_state(&self, state: TrayState) {
+        debug!("Updating tray state: {}", state.status_line());
+        self.handle
+            .update(move |tray| {
+                tray.state = state;
+            })
+            .await;
+    }
 }
@@ -0,0 +1,148 @@
+//! Submenus shared by the tray-icon based trays (macOS and Windows).
+//!
+//! Recent transcriptions, language and preset. Items carry their payload in
+//! the menu ID, which [`super::submenu_event`] turns back into a
+//! [`super::TrayEvent`].
+
+use super::{
+    menu_label, TrayError, TrayState, LANGUAGE_ID_PREFIX, PRESET_ID_PREFIX, QUICK_PRESETS,
+    RECENT_ID_PREFIX,
+};
+use std::cell::RefCell;
+use tracing::warn;
+use tray_icon::menu::{CheckMenuItem, Menu, MenuItem, Submenu};
+
+/// The quick-settings submenus and the items currently in them.
+pub(super) struct QuickMenus {
+    recent: Submenu,
+    language: Submenu,
+    preset: Submenu,
+    /// Recent transcriptions shown, as (history ID, text)
+    recent_shown: RefCell<Option<Vec<(i64, String)>>>,
+    recent_items: RefCell<Vec<MenuItem>>,
+    /// Language items by code
+    language_items: RefCell<Vec<(String, CheckMenuItem)>>,
+    preset_items: Vec<CheckMenuItem>,
+}
+
+impl QuickMenus {
+    pub(super) fn new() -> Result<Self, TrayError> {
+        let preset = Submenu::new("Preset", true);
+        let preset_items: Vec<CheckMenuItem> = QUICK_PRESETS
+            .iter()
+            .enumerate()
+            .map(|(index, (_, label))| {
+                CheckMenuItem::with_id(
+                    format!("{}{}", PRESET_ID_PREFIX, index),
+                    *label,
+                    true,
+                    false,
+                    None,
+                )
+            })
+            .collect();
+        for item in &preset_items {
+            preset
+                .append(item)
+                .map_err(|e| TrayError::MenuCreation(e.to_string()))?;
+        }
+
+        let menus = Self {
+            recent: Submenu::new("Recent Transcriptions", true),
+            language: Submenu::new("Language", true),
+            preset,
+            recent_shown: RefCell::new(None),
+            recent_items: RefCell::new(Vec::new()),
+            language_items: RefCell::new(Vec::new()),
+            preset_items,
+        };
+        menus.update(&TrayState::default());
+        Ok(menus)
+    }
+
+    /// Append the recent transcriptions submenu.
+    pub(super) fn append_recent(&self, menu: &Menu) -> Result<(), TrayError> {
+        menu.append(&self.recent)
+            .map_err(|e| TrayError::MenuCreation(e.to_string()))
+    }
+
+    /// Append the language and preset submenus.
+    pub(super) fn append_settings(&self, menu: &Menu) -> Result<(), TrayError> {
+        menu.append(&self.language)
+            .map_err(|e| TrayError::MenuCreation(e.to_string()))?;
+        menu.append(&self.preset)
+            .map_err(|e| TrayError::MenuCreation(e.to_string()))
+    }
+
+    /// Show the daemon state in the submenus.
+    pub(super) fn update(&self, state: &TrayState) {
+        self.update_recent(&state.recent);
+        self.update_language(state);
+        for ((preset, _), item) in QUICK_PRESETS.iter().zip(&self.preset_items) {
+            item.set_checked(*preset == state.preset);
+        }
+    }
+
+    fn update_recent(&self, recent: &[(i64, String)]) {
+        if self.recent_shown.borrow().as_deref() == Some(recent) {
+            return;
+        }
+
+        let mut items = self.recent_items.borrow_mut();
+        for item in items.drain(..) {
+            let _ = self.recent.remove(&item);
+        }
+        if recent.is_empty() {
+            items.push(MenuItem::new("No transcriptions yet", false, None));
+        } else {
+            items.extend(recent.iter().map(|(id, text)| {
+                MenuItem::with_id(
+                    format!("{}{}", RECENT_ID_PREFIX, id),
+                    menu_label(text),
+                    true,
+                    None,
+                )
+            }));
+        }
+        for item in items.iter() {
+            if let Err(e) = self.recent.append(item) {
+                warn!("Failed to update recent transcriptions menu: {}", e);
+            }
+        }
+        *self.recent_shown.borrow_mut() = Some(recent.to_vec());
+    }
+
+    fn update_language(&self, state: &TrayState) {
+        let (options, selected) = state.language_options();
+        let mut items = self.language_items.borrow_mut();
+
+        // The list only changes when a language outside the quick list is set
+        let unchanged = items.len() == options.len()
+            && items
+                .iter()
+                .zip(&options)
+                .all(|((shown, _), (code, _))| shown == code);
+        if !unchanged {
+            for (_, item) in items.drain(..) {
+                let _ = self.language.remove(&item);
+            }
+            for (code, label) in options {
+                let item = CheckMenuItem::with_id(
+                    format!("{}{}", LANGUAGE_ID_PREFIX, code),
+                    label,
+                    true,
+                    false,
+                    None,
+                );
+                if let Err(e) = self.language.append(&item) {
+                    warn!("Failed to update language menu: {}", e);
+                }
+                items.push((code, item));
+            }
+        }
+
+        for (index, (_, item)) in items.iter().enumerate() {
+            item.set_checked(index == selected);
+        }
+    }
+}
@@ -5,10 +5,14 @@
 
 use thiserror::Error;
 
+use crate::config::TranscriptionPreset;
+
 #[cfg(target_os = "linux")]
 mod linux;
 #[cfg(target_os = "macos")]
 mod macos;
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+mod menu;
 #[cfg(target_os = "windows")]
 mod windows;
 
@@ -42,6 +46,7 @@ pub enum TrayError {
 
 /// Events from the system tray menu
 #[derive(Debug, Clone)]
+#[allow(dead_code)] // Not every platform menu offers every item
 pub enum TrayEvent {
     /// User clicked "Preferences..."
     ShowPreferences,
@@ -49,14 +54,67 @@ pub enum TrayEvent {
     Quit,
     /// Status item was clicked (informational)
     StatusClicked,
+    /// User clicked "Start Recording" / "Stop Recording"
+    ToggleRecording,
+    /// User picked an entry from "Recent Transcriptions" (history ID)
+    CopyTranscription(i64),
+    /// User picked a transcription language
+    SetLanguage(String),
+    /// User picked a transcription preset
+    SetPreset(TranscriptionPreset),
+    /// User toggled LLM correction
+    ToggleCorrection,
+    /// User toggled translation
+    ToggleTranslation,
+    /// User clicked "Load Model"
+    LoadModel,
+    /// User clicked "Unload Model"
+    UnloadModel,
 }
 
+/// Languages offered in the tray's language menu (code, label).
+pub const QUICK_LANGUAGES: &[(&str, &str)] = &[
+    ("auto", "Auto-detect"),
+    ("en", "English"),
+    ("de", "German"),
+    ("fr", "French"),
+    ("es", "Spanish"),
+    ("it", "Italian"),
+    ("pt", "Portuguese"),
+    ("nl", "Dutch"),
+    ("pl", "Polish"),
+    ("ru", "Russian"),
+    ("ja", "Japanese"),
+    ("zh", "Chinese"),
+];
+
+/// Presets offered in the tray's preset menu.
+pub const QUICK_PRESETS: &[(TranscriptionPreset, &str)] = &[
+    (TranscriptionPreset::Instant, "Instant (small)"),
+    (TranscriptionPreset::Balanced, "Balanced (medium)"),
+    (TranscriptionPreset::Quality, "Quality (large-v3)"),
+    (TranscriptionPreset::Custom, "Custom"),
+];
+
+/// Number of recent transcriptions listed in the tray menu.
+pub const TRAY_RECENT_LIMIT: usize = 5;
+
+/// Maximum label length of a recent transcription in the menu.
+const RECENT_LABEL_CHARS: usize = 40;
+
+/// Menu item ID prefixes of the tray-icon submenus (macOS, Windows).
+const RECENT_ID_PREFIX: &str = "recent:";
+const LANGUAGE_ID_PREFIX: &str = "language:";
+const PRESET_ID_PREFIX: &str = "preset:";
+
 /// Status for tray icon display
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(dead_code)]
 pub enum TrayStatus {
     Idle,
     Recording,
+    /// Hands-free dictation waiting for speech
+    Listening,
     Processing,
     Error,
 }
@@ -66,21 +124,147 @@ impl TrayStatus {
         match self {
             TrayStatus::Idle => "Status: Idle",
             TrayStatus::Recording => "Status: Recording...",
+            TrayStatus::Listening => "Status: Listening (hands-free)...",
             TrayStatus::Processing => "Status: Processing...",
             TrayStatus::Error => "Status: Error",
         }
     }
 
+    /// Whether the microphone is open (push-to-talk or hands-free)
+    pub fn is_recording(&self) -> bool {
+        matches!(self, TrayStatus::Recording | TrayStatus::Listening)
+    }
+
+    /// Map a free-form status string onto a status
+    pub fn from_status_str(status: &str) -> Self {
+        match status {
+            s if s.contains("Recording") => TrayStatus::Recording,
+            s if s.contains("Listening") => TrayStatus::Listening,
+            s if s.contains("Processing") => TrayStatus::Processing,
+            s if s.contains("Error") => TrayStatus::Error,
+            _ => TrayStatus::Idle,
+        }
+    }
+
     pub fn icon_name(&self) -> &'static str {
         match self {
             TrayStatus::Idle => "audio-input-microphone",
             TrayStatus::Recording => "media-record",
+            TrayStatus::Listening => "audio-input-microphone-high",
             TrayStatus::Processing => "view-refresh",
             TrayStatus::Error => "dialog-error",
         }
     }
 }
 
+/// Daemon state shown in the tray menu.
+///
+/// The daemon pushes a fresh snapshot whenever it changes; the menu is
+/// rebuilt from it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrayState {
+    pub status: TrayStatus,
+    /// Chunks waiting for transcription
+    pub queue_depth: usize,
+    pub model: String,
+    pub model_loaded: bool,
+    pub language: String,
+    pub preset: TranscriptionPreset,
+    pub correction_enabled: bool,
+    pub translation_enabled: bool,
+    /// Recent transcriptions as (history ID, text), newest first
+    pub recent: Vec<(i64, String)>,
+}
+
+impl Default for TrayState {
+    fn default() -> Self {
+        Self {
+            status: TrayStatus::Idle,
+            queue_depth: 0,
+            model: String::new(),
+            model_loaded: false,
+            language: "auto".to_string(),
+            preset: TranscriptionPreset::default(),
+            correction_enabled: false,
+            translation_enabled: false,
+            recent: Vec::new(),
+        }
+    }
+}
+
+impl TrayState {
+    /// Status line including the queue depth, e.g. "Status: Processing... (3 queued)".
+    pub fn status_line(&self) -> String {
+        if self.queue_depth > 0 {
+            format!("{} ({} queued)", self.status.as_str(), self.queue_depth)
+        } else {
+            self.status.as_str().to_string()
+        }
+    }
+
+    /// Model line, e.g. "Model: medium (loaded)".
+    pub fn model_line(&self) -> String {
+        format!(
+            "Model: {} ({})",
+            self.model,
+            if self.model_loaded {
+                "loaded"
+            } else {
+                "not loaded"
+            }
+        )
+    }
+
+    /// Language choices (code, label) and the index of the current one.
+    ///
+    /// A configured language missing from [`QUICK_LANGUAGES`] is appended
+    /// so that it can still be shown as selected.
+    pub fn language_options(&self) -> (Vec<(String, String)>, usize) {
+        let mut options: Vec<(String, String)> = QUICK_LANGUAGES
+            .iter()
+            .map(|(code, label)| (code.to_string(), label.to_string()))
+            .collect();
+
+        let selected = match options.iter().position(|(code, _)| *code == self.language) {
+            Some(index) => index,
+            None => {
+                options.push((self.language.clone(), self.language.clone()));
+                options.len() - 1
+            }
+        };
+        (options, selected)
+    }
+}
+
+/// Shorten a transcription to a single-line menu label.
+pub fn menu_label(text: &str) -> String {
+    let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() > RECENT_LABEL_CHARS {
+        let truncated: String = line.chars().take(RECENT_LABEL_CHARS - 1).collect();
+        format!("{}…", truncated.trim_end())
+    } else {
+        line
+    }
+}
+
+/// Event for a submenu item of the tray-icon menus, by item ID.
+///
+/// IDs are `recent:<history ID>`, `language:<code>` and
+/// `preset:<index into QUICK_PRESETS>`.
+#[cfg_attr(target_os = "linux", allow(dead_code))]
+fn submenu_event(id: &str) -> Option<TrayEvent> {
+    if let Some(id) = id.strip_prefix(RECENT_ID_PREFIX) {
+        return id.parse().ok().map(TrayEvent::CopyTranscription);
+    }
+    if let Some(code) = id.strip_prefix(LANGUAGE_ID_PREFIX) {
+        return Some(TrayEvent::SetLanguage(code.to_string()));
+    }
+    let index: usize = id.strip_prefix(PRESET_ID_PREFIX)?.parse().ok()?;
+    QUICK_PRESETS
+        .get(index)
+        .map(|(preset, _)| TrayEvent::SetPreset(*preset))
+}
+
 /// Check if system tray is likely to be supported
 #[allow(dead_code)]
 pub fn is_tray_supported() -> bool {
@@ -108,6 +292,10 @@ mod tests {
     fn test_tray_status_as_str() {
         assert_eq!(TrayStatus::Idle.as_str(), "Status: Idle");
         assert_eq!(TrayStatus::Recording.as_str(), "Status: Recording...");
+        assert_eq!(
+            TrayStatus::Listening.as_str(),
+            "Status: Listening (hands-free)..."
+        );
         assert_eq!(TrayStatus::Processing.as_str(), "Status: Processing...");
         assert_eq!(TrayStatus::Error.as_str(), "Status: Error");
     }
@@ -116,10 +304,35 @@ mod tests {
     fn test_tray_status_icon_name() {
         assert_eq!(TrayStatus::Idle.icon_name(), "audio-input-microphone");
         assert_eq!(TrayStatus::Recording.icon_name(), "media-record");
+        assert_eq!(
+            TrayStatus::Listening.icon_name(),
+            "audio-input-microphone-high"
+        );
         assert_eq!(TrayStatus::Processing.icon_name(), "view-refresh");
         assert_eq!(TrayStatus::Error.icon_name(), "dialog-error");
     }
 
+    #[test]
+    fn test_tray_status_is_recording() {
+        assert!(TrayStatus::Recording.is_recording());
+        assert!(TrayStatus::Listening.is_recording());
+        assert!(!TrayStatus::Idle.is_recording());
+        assert!(!TrayStatus::Processing.is_recording());
+    }
+
+    #[test]
+    fn test_tray_status_from_status_str() {
+        assert_eq!(
+            TrayStatus::from_status_str("Status: Recording..."),
+            TrayStatus::Recording
+        );
+        assert_eq!(
+            TrayStatus::from_status_str(TrayStatus::Listening.as_str()),
+            TrayStatus::Listening
+        );
+        assert_eq!(TrayStatus::from_status_str("whatever"), TrayStatus::Idle);
+    }
+
     #[test]
     fn test_tray_status_equality() {
         assert_eq!(TrayStatus::Idle, TrayStatus::Idle);
@@ -160,6 +373,97 @@ mod tests {
         assert!(matches!(cloned, TrayEvent::Quit));
     }
 
+    #[test]
+    fn test_tray_event_with_payload_debug() {
+        assert_eq!(
+            format!("{:?}", TrayEvent::CopyTranscription(7)),
+            "CopyTranscription(7)"
+        );
+        assert_eq!(
+            format!("{:?}", TrayEvent::SetLanguage("de".into())),
+            "SetLanguage(\"de\")"
+        );
+        assert_eq!(
+            format!("{:?}", TrayEvent::SetPreset(TranscriptionPreset::Quality)),
+            "SetPreset(Quality)"
+        );
+    }
+
+    // ===================
+    // TrayState Tests
+    // ===================
+
+    #[test]
+    fn test_tray_state_status_line() {
+        let mut state = TrayState::default();
+        assert_eq!(state.status_line(), "Status: Idle");
+
+        state.status = TrayStatus::Processing;
+        state.queue_depth = 3;
+        assert_eq!(state.status_line(), "Status: Processing... (3 queued)");
+    }
+
+    #[test]
+    fn test_tray_state_model_line() {
+        let mut state = TrayState {
+            model: "medium".into(),
+            ..Default::default()
+        };
+        assert_eq!(state.model_line(), "Model: medium (not loaded)");
+
+        state.model_loaded = true;
+        assert_eq!(state.model_line(), "Model: medium (loaded)");
+    }
+
+    #[test]
+    fn test_tray_state_language_options() {
+        let state = TrayState {
+            language: "de".into(),
+            ..Default::default()
+        };
+        let (options, selected) = state.language_options();
+        assert_eq!(options.len(), QUICK_LANGUAGES.len());
+        assert_eq!(options[selected].0, "de");
+
+        // Languages outside the quick list are still selectable
+        let state = TrayState {
+            language: "fi".into(),
+            ..Default::default()
+        };
+        let (options, selected) = state.language_options();
+        assert_eq!(options.len(), QUICK_LANGUAGES.len() + 1);
+        assert_eq!(options[selected].0, "fi");
+    }
+
+    #[test]
+    fn test_submenu_event() {
+        assert!(matches!(
+            submenu_event("recent:42"),
+            Some(TrayEvent::CopyTranscription(42))
+        ));
+        assert!(matches!(
+            submenu_event("language:de"),
+            Some(TrayEvent::SetLanguage(code)) if code == "de"
+        ));
+        assert!(matches!(
+            submenu_event("preset:2"),
+            Some(TrayEvent::SetPreset(TranscriptionPreset::Quality))
+        ));
+        assert!(submenu_event("preset:99").is_none());
+        assert!(submenu_event("recent:x").is_none());
+        assert!(submenu_event("quit").is_none());
+    }
+
+    #[test]
+    fn test_menu_label() {
+        assert_eq!(menu_label("hello\nworld"), "hello world");
+
+        let long = "word ".repeat(20);
+        let label = menu_label(&long);
+        assert!(label.ends_with('…'));
+        assert!(label.chars().count() <= RECENT_LABEL_CHARS);
+    }
+
     // ===================
     // TrayError Tests
     // ===================
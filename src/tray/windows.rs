@@ -1,13 +1,19 @@
 //! Windows system tray implementation using tray-icon.
 
-use super::{icon, TrayError, TrayEvent, TrayStatus};
+use super::menu::QuickMenus;
+use super::{icon, submenu_event, TrayError, TrayEvent, TrayState, TrayStatus};
 use std::sync::mpsc::{self, Receiver};
 use tracing::{debug, info, warn};
-use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+use tray_icon::menu::{CheckMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem};
 use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
 
 /// Menu item IDs
 const MENU_STATUS: &str = "status";
+const MENU_TOGGLE: &str = "toggle";
+const MENU_CORRECTION: &str = "correction";
+const MENU_TRANSLATION: &str = "translation";
+const MENU_LOAD_MODEL: &str = "load_model";
+const MENU_UNLOAD_MODEL: &str = "unload_model";
 const MENU_PREFERENCES: &str = "preferences";
 const MENU_QUIT: &str = "quit";
 
@@ -17,6 +23,12 @@ pub struct TrayManager {
     tray_icon: TrayIcon,
     event_rx: Receiver<TrayEvent>,
     status_item: MenuItem,
+    toggle_item: MenuItem,
+    correction_item: CheckMenuItem,
+    translation_item: CheckMenuItem,
+    load_item: MenuItem,
+    unload_item: MenuItem,
+    quick_menus: QuickMenus,
     status: TrayStatus,
 }
 
@@ -27,7 +39,15 @@ impl TrayManager {
 
         // Create menu
         let status_item = MenuItem::with_id(MENU_STATUS, "Status: Idle", false, None);
+        let toggle_item = MenuItem::with_id(MENU_TOGGLE, "Start Recording", true, None);
+        let correction_item =
+            CheckMenuItem::with_id(MENU_CORRECTION, "LLM Correction", true, false, None);
+        let translation_item =
+            CheckMenuItem::with_id(MENU_TRANSLATION, "Translation", true, false, None);
+        let load_item = MenuItem::with_id(MENU_LOAD_MODEL, "Load Model", true, None);
+        let unload_item = MenuItem::with_id(MENU_UNLOAD_MODEL, "Unload Model", false, None);
         let preferences_item = MenuItem::with_id(MENU_PREFERENCES, "Preferences...", true, None);
+        let quick_menus = QuickMenus::new()?;
         let quit_item = MenuItem::with_id(MENU_QUIT, "Quit", true, None);
 
         let menu = Menu::new();
@@ -35,6 +55,24 @@ impl TrayManager {
             .map_err(|e| TrayError::MenuCreation(e.to_string()))?;
         menu.append(&PredefinedMenuItem::separator())
             .map_err(|e| TrayError::MenuCreation(e.to_string()))?;
+        menu.append(&toggle_item)
+            .map_err(|e| TrayError::MenuCreation(e.to_string()))?;
+        quick_menus.append_recent(&menu)?;
+        menu.append(&PredefinedMenuItem::separator())
+            .map_err(|e| TrayError::MenuCreation(e.to_string()))?;
+        quick_menus.append_settings(&menu)?;
+        menu.append(&correction_item)
+            .map_err(|e| TrayError::MenuCreation(e.to_string()))?;
+        menu.append(&translation_item)
+            .map_err(|e| TrayError::MenuCreation(e.to_string()))?;
+        menu.append(&PredefinedMenuItem::separator())
+            .map_err(|e| TrayError::MenuCreation(e.to_string()))?;
+        menu.append(&load_item)
+            .map_err(|e| TrayError::MenuCreation(e.to_string()))?;
+        menu.append(&unload_item)
+            .map_err(|e| TrayError::MenuCreation(e.to_string()))?;
+        menu.append(&PredefinedMenuItem::separator())
+            .map_err(|e| TrayError::MenuCreation(e.to_string()))?;
         menu.append(&preferences_item)
             .map_err(|e| TrayError::MenuCreation(e.to_string()))?;
         menu.append(&PredefinedMenuItem::separator())
@@ -75,7 +113,32 @@ impl TrayManager {
                         debug!("Tray: Status clicked");
                         let _ = event_tx_clone.send(TrayEvent::StatusClicked);
                     }
-                    _ => {}
+                    MENU_TOGGLE => {
+                        debug!("Tray: Toggle recording clicked");
+                        let _ = event_tx_clone.send(TrayEvent::ToggleRecording);
+                    }
+                    MENU_CORRECTION => {
+                        debug!("Tray: LLM Correction toggled");
+                        let _ = event_tx_clone.send(TrayEvent::ToggleCorrection);
+                    }
+                    MENU_TRANSLATION => {
+                        debug!("Tray: Translation toggled");
+                        let _ = event_tx_clone.send(TrayEvent::ToggleTranslation);
+                    }
+                    MENU_LOAD_MODEL => {
+                        debug!("Tray: Load Model clicked");
+                        let _ = event_tx_clone.send(TrayEvent::LoadModel);
+                    }
+                    MENU_UNLOAD_MODEL => {
+                        debug!("Tray: Unload Model clicked");
+                        let _ = event_tx_clone.send(TrayEvent::UnloadModel);
+                    }
+                    id => {
+                        if let Some(event) = submenu_event(id) {
+                            debug!("Tray: {:?}", event);
+                            let _ = event_tx_clone.send(event);
+                        }
+                    }
                 }
             }
         });
@@ -85,6 +148,12 @@ impl TrayManager {
             tray_icon,
             event_rx,
             status_item,
+            toggle_item,
+            correction_item,
+            translation_item,
+            load_item,
+            unload_item,
+            quick_menus,
             status: TrayStatus::Idle,
         })
     }
@@ -104,12 +173,7 @@ impl TrayManager {
     #[allow(dead_code)]
     pub fn update_status(&self, status: &str) {
         debug!("Updating tray status: {}", status);
-        let new_status = match status {
-            s if s.contains("Recording") => TrayStatus::Recording,
-            s if s.contains("Processing") => TrayStatus::Processing,
-            s if s.contains("Error") => TrayStatus::Error,
-            _ => TrayStatus::Idle,
-        };
+        let new_status = TrayStatus::from_status_str(status);
 
         self.status_item.set_text(new_status.as_str());
         if let Err(e) = self
@@ -133,4 +197,30 @@ impl TrayManager {
             warn!("Failed to update tray tooltip: {}", e);
         }
     }
+
+    /// Refresh the menu from the daemon state
+    pub async fn update_state(&self, state: TrayState) {
+        debug!("Updating tray state: {}", state.status_line());
+        let recording = state.status.is_recording();
+
+        self.status_item.set_text(state.status_line());
+        self.toggle_item.set_text(if recording {
+            "Stop Recording"
+        } else {
+            "Start Recording"
+        });
+        self.correction_item.set_checked(state.correction_enabled);
+        self.translation_item.set_checked(state.translation_enabled);
+        self.load_item.set_enabled(!state.model_loaded);
+        self.unload_item
+            .set_enabled(state.model_loaded && !recording);
+        self.quick_menus.update(&state);
+
+        if let Err(e) = self
+            .tray_icon
+            .set_tooltip(Some(format!("OpenHush - {}", state.status_line())))
+        {
+            warn!("Failed to update tray tooltip: {}", e);
+        }
+    }
 }
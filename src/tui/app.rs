@@ -194,6 +194,7 @@ impl App {
                 text,
                 duration_secs,
                 llm_corrected: _,
+                language: _,
             } => {
                 self.current_transcription = text.clone();
                 self.history.insert(
@@ -216,6 +217,9 @@ impl App {
             IpcEvent::Error { code: _, message } => {
                 self.status_message = Some(message);
             }
+            IpcEvent::DeviceChanged { device_id: _, name } => {
+                self.status_message = Some(format!("Microphone: {}", name));
+            }
             IpcEvent::Shutdown => {
                 self.status_message = Some("Daemon shutting down".to_string());
             }
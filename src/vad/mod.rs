@@ -72,6 +72,14 @@ pub struct VadConfig {
     /// Padding to add before/after speech segments (ms)
     #[serde(default = "default_speech_pad_ms")]
     pub speech_pad_ms: u32,
+    /// Longest utterance in hands-free dictation; longer speech is cut and
+    /// transcribed in pieces (seconds, 0 = unlimited)
+    #[serde(default = "default_max_utterance_secs")]
+    pub max_utterance_secs: f32,
+    /// Stop hands-free dictation after this long without speech
+    /// (seconds, 0 = never)
+    #[serde(default)]
+    pub auto_stop_secs: u32,
 }
 
 fn default_threshold() -> f32 {
@@ -90,6 +98,10 @@ fn default_speech_pad_ms() -> u32 {
     30
 }
 
+fn default_max_utterance_secs() -> f32 {
+    30.0
+}
+
 impl Default for VadConfig {
     fn default() -> Self {
         Self {
@@ -98,6 +110,8 @@ impl Default for VadConfig {
             min_silence_ms: default_min_silence_ms(),
             min_speech_ms: default_min_speech_ms(),
             speech_pad_ms: default_speech_pad_ms(),
+            max_utterance_secs: default_max_utterance_secs(),
+            auto_stop_secs: 0,
         }
     }
 }
@@ -112,6 +126,8 @@ pub struct SpeechSegment {
     pub end: usize,
     /// Average speech probability
     pub avg_probability: f32,
+    /// Cut at `max_utterance_secs` while speech continues
+    pub truncated: bool,
 }
 
 /// Streaming VAD state tracker.
@@ -164,6 +180,8 @@ impl VadState {
             (self.config.min_silence_ms as f32 / 1000.0 * self.sample_rate as f32) as usize;
         let min_speech_samples =
             (self.config.min_speech_ms as f32 / 1000.0 * self.sample_rate as f32) as usize;
+        let max_utterance_samples =
+            (self.config.max_utterance_secs.max(0.0) * self.sample_rate as f32) as usize;
 
         if result.is_speech {
             self.silence_samples = 0;
@@ -177,6 +195,27 @@ impl VadState {
                     prev_total,
                     result.probability
                 );
+                return None;
+            }
+
+            // Cut overlong speech so it doesn't wait for a pause that never comes
+            let start = self.speech_start.unwrap_or(0);
+            if max_utterance_samples > 0 && self.total_samples - start >= max_utterance_samples {
+                let avg_prob =
+                    self.probabilities.iter().sum::<f32>() / self.probabilities.len() as f32;
+                self.probabilities.clear();
+                self.speech_start = Some(self.total_samples);
+                tracing::debug!(
+                    "Utterance reached {:.0}s, cutting at sample {}",
+                    self.config.max_utterance_secs,
+                    self.total_samples
+                );
+                return Some(SpeechSegment {
+                    start,
+                    end: self.total_samples,
+                    avg_probability: avg_prob,
+                    truncated: true,
+                });
             }
             None
         } else {
@@ -209,6 +248,7 @@ impl VadState {
                         start,
                         end,
                         avg_probability: avg_prob,
+                        truncated: false,
                     });
                 } else {
                     tracing::debug!(
@@ -229,6 +269,11 @@ impl VadState {
         self.in_speech
     }
 
+    /// Seconds since speech was last detected (or since the reset).
+    pub fn silence_secs(&self) -> f32 {
+        self.silence_samples as f32 / self.sample_rate as f32
+    }
+
     /// Get the current speech start position, if in speech.
     #[allow(dead_code)]
     pub fn speech_start(&self) -> Option<usize> {
@@ -312,4 +357,59 @@ mod tests {
         // Should return None because speech was too short
         assert!(segment.is_none());
     }
+
+    #[test]
+    fn test_vad_state_max_utterance() {
+        let config = VadConfig {
+            min_silence_ms: 100,
+            min_speech_ms: 50,
+            max_utterance_secs: 1.0,
+            ..Default::default()
+        };
+        let mut state = VadState::new(config, 16000);
+
+        let speech = VadResult {
+            probability: 0.8,
+            is_speech: true,
+        };
+        let silence = VadResult {
+            probability: 0.1,
+            is_speech: false,
+        };
+
+        // 1s of uninterrupted speech is cut without waiting for silence
+        assert!(state.update(&speech, 8000).is_none());
+        let segment = state.update(&speech, 8000).unwrap();
+        assert!(segment.truncated);
+        assert_eq!((segment.start, segment.end), (0, 16000));
+        assert!(state.is_speech());
+
+        // The rest of the utterance ends normally at the pause
+        assert!(state.update(&speech, 4000).is_none());
+        let segment = state.update(&silence, 1600).unwrap();
+        assert!(!segment.truncated);
+        assert_eq!((segment.start, segment.end), (16000, 20000));
+    }
+
+    #[test]
+    fn test_vad_state_silence_secs() {
+        let mut state = VadState::new(VadConfig::default(), 16000);
+        let speech = VadResult {
+            probability: 0.8,
+            is_speech: true,
+        };
+        let silence = VadResult {
+            probability: 0.1,
+            is_speech: false,
+        };
+
+        state.update(&silence, 32000);
+        assert_eq!(state.silence_secs(), 2.0);
+
+        state.update(&speech, 512);
+        assert_eq!(state.silence_secs(), 0.0);
+
+        state.update(&silence, 8000);
+        assert_eq!(state.silence_secs(), 0.5);
+    }
 }